@@ -0,0 +1,71 @@
+//! Permutation search on one thread against every available thread
+//!
+//! Run with `cargo bench --bench permutation_search` on a machine with
+//! several cores; the parallel search evaluates a batch of permutations per
+//! thread at once, so its time should drop with the thread count.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use cutlist_optimizer_cli::engine::layout::{compute_layout_permutations, OptimizeOptions};
+use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, Panel};
+
+/// Orders tried by every search
+const PERMUTATIONS: usize = 32;
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material("wood".to_string())
+        .with_enabled(true)
+}
+
+/// Eight distinct panels, so every permutation is a different tile order
+fn create_eight_panel_request() -> CalculationRequest {
+    CalculationRequest {
+        configuration: Some(Configuration {
+            seed: Some(1),
+            ..Configuration::default()
+        }),
+        panels: vec![
+            create_panel(1, "720", "560", 2),
+            create_panel(2, "564", "460", 2),
+            create_panel(3, "564", "100", 3),
+            create_panel(4, "715", "396", 2),
+            create_panel(5, "400", "300", 2),
+            create_panel(6, "1200", "250", 1),
+            create_panel(7, "350", "350", 2),
+            create_panel(8, "900", "150", 2),
+        ],
+        stock_panels: vec![create_panel(100, "2440", "1220", 4)],
+    }
+}
+
+fn bench_permutation_search(c: &mut Criterion) {
+    let request = create_eight_panel_request();
+    let options = OptimizeOptions::default();
+    let available = std::thread::available_parallelism().map_or(1, |n| n.get()).min(8);
+
+    // Speed must not cost placed tiles
+    let (serial, _) = compute_layout_permutations(&request, &options, PERMUTATIONS, 1).unwrap();
+    let (parallel, _) = compute_layout_permutations(&request, &options, PERMUTATIONS, available).unwrap();
+    assert_eq!(parallel.get_nbr_final_tiles(), serial.get_nbr_final_tiles());
+
+    let mut group = c.benchmark_group("permutation_search");
+    group.sample_size(10);
+    let mut thread_counts = vec![1];
+    if available > 1 {
+        thread_counts.push(available);
+    }
+    for threads in thread_counts {
+        group.bench_with_input(BenchmarkId::from_parameter(threads), &threads, |b, &threads| {
+            b.iter(|| compute_layout_permutations(&request, &options, PERMUTATIONS, threads).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_permutation_search);
+criterion_main!(benches);
@@ -1,7 +1,8 @@
 use crate::{
-    cli::commands::{example_command, optimize_command, validate_command},
-    errors::Result,
-    constants::ConfigurationDefaults,
+    cli::commands::{example_command, optimize_command, png_command, table_command, validate_command},
+    engine::layout::{LayoutSearch, OptimizeOptions},
+    errors::{AppError, Result},
+    utils::math::UnitFormat,
 };
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
@@ -31,7 +32,7 @@ pub enum Commands {
         #[arg(short, long)]
         input: PathBuf,
 
-        /// Output file path
+        /// Output file path; the JSON response is printed to stdout when omitted
         #[arg(short, long)]
         output: Option<PathBuf>,
 
@@ -39,23 +40,51 @@ pub enum Commands {
         #[arg(short, long)]
         config: Option<PathBuf>,
 
-        /// Cut thickness (kerf) in mm
-        #[arg(long, default_value_t = ConfigurationDefaults::DEFAULT_CUT_THICKNESS)]
-        cut_thickness: i32,
+        /// Cut thickness (kerf) in mm; taken from the configuration when omitted
+        #[arg(long)]
+        cut_thickness: Option<i32>,
 
-        /// Minimum trim dimension in mm
-        #[arg(long, default_value_t = ConfigurationDefaults::DEFAULT_MIN_TRIM_DIMENSION)]
-        min_trim: i32,
+        /// Minimum trim dimension in mm; taken from the configuration when omitted
+        #[arg(long)]
+        min_trim: Option<i32>,
 
-        /// Optimization accuracy (1-10)
-        #[arg(long, default_value_t = ConfigurationDefaults::DEFAULT_OPTIMIZATION_FACTOR)]
-        accuracy: i32,
+        /// Optimization accuracy (1-10); taken from the configuration when omitted
+        #[arg(long)]
+        accuracy: Option<i32>,
+
+        /// Output format (json, png, table)
+        #[arg(long, default_value = "json")]
+        format: String,
+
+        /// Directory for per-sheet images when using the png format
+        #[arg(long, default_value = "plans")]
+        output_dir: PathBuf,
+
+        /// Free barely-used sheets by moving their panels onto other sheets
+        #[arg(long)]
+        balance_sheets: bool,
+
+        /// List cuts in the table format grouped by direction to turn the sheet as rarely as possible
+        #[arg(long)]
+        optimize_cut_order: bool,
+
+        /// Unit of the dimensions printed by the table and png formats (mm, cm, m, in);
+        /// bare millimetres when omitted
+        #[arg(long)]
+        units: Option<String>,
+
+        /// Tile orders to try (single, parallel, permutations)
+        #[arg(long, default_value = "single")]
+        search: String,
+
+        /// Tile orders tried by `--search permutations`; chosen from the part list when omitted
+        #[arg(long)]
+        max_permutations: Option<usize>,
     },
 
-    /// Validate input file format
+    /// Validate an input file and summarize its panels and stock
     Validate {
-        /// Input file to validate
-        #[arg(short, long)]
+        /// Input file to validate (CSV or JSON)
         input: PathBuf,
     },
 
@@ -77,17 +106,66 @@ impl Cli {
                 cut_thickness,
                 min_trim,
                 accuracy,
+                format,
+                output_dir,
+                balance_sheets,
+                optimize_cut_order,
+                units,
+                search,
+                max_permutations,
             } => {
-                optimize_command(
-                    input,
-                    output,
-                    config,
+                if max_permutations.is_some() && search != "permutations" {
+                    return Err(AppError::invalid_input(
+                        "--max-permutations only applies to '--search permutations'",
+                    ));
+                }
+                let search = match search.as_str() {
+                    "single" => LayoutSearch::Single,
+                    "parallel" => LayoutSearch::Parallel,
+                    "permutations" => LayoutSearch::Permutations(max_permutations),
+                    _ => {
+                        return Err(AppError::invalid_input(format!(
+                            "Unsupported search: {}. Use 'single', 'parallel' or 'permutations'",
+                            search
+                        )))
+                    }
+                };
+                if units.is_some() && format == "json" {
+                    return Err(AppError::invalid_input("--units only applies to the table and png formats"));
+                }
+                let units = match units.as_deref() {
+                    None => None,
+                    Some("mm") => Some(UnitFormat::Millimeters),
+                    Some("cm") => Some(UnitFormat::Centimeters),
+                    Some("m") => Some(UnitFormat::Meters),
+                    Some("in") => Some(UnitFormat::Inches),
+                    Some(other) => {
+                        return Err(AppError::invalid_input(format!(
+                            "Unsupported units: {}. Use 'mm', 'cm', 'm' or 'in'",
+                            other
+                        )))
+                    }
+                };
+                let options = OptimizeOptions {
                     cut_thickness,
                     min_trim,
                     accuracy,
-                    self.threads,
-                )
-                .await
+                    balance_sheets,
+                    optimize_cut_order,
+                    units,
+                    search,
+                    threads: self.threads,
+                    ..OptimizeOptions::default()
+                };
+                match format.as_str() {
+                    "json" => optimize_command(input, output, config, options).await,
+                    "png" => png_command(input, output_dir, config, options).await,
+                    "table" => table_command(input, output, config, options).await,
+                    _ => Err(AppError::invalid_input(format!(
+                        "Unsupported output format: {}. Use 'json', 'png' or 'table'",
+                        format
+                    ))),
+                }
             }
             Commands::Validate { input } => validate_command(input).await,
             Commands::Example { format } => example_command(format).await,
@@ -1,69 +1,202 @@
-use std::path::PathBuf;
-use crate::errors::{AppError, Result};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::cli::import::{load_configuration, load_request};
+use crate::engine::layout::{optimize, OptimizeOptions};
+use crate::errors::{AppError, CoreError, Result};
 use crate::logging::{log_info, log_operation_start, log_operation_success};
-/// Execute the optimize command
+use crate::models::{CalculationRequest, CalculationResponse, Panel};
+use crate::utils::{cut_map::write_cut_map_pngs, cut_table::render_cut_table};
+
+/// Execute the optimize command, writing the full response as JSON
+///
+/// The response goes to `output` when given, otherwise to stdout.
 pub async fn optimize_command(
     input: PathBuf,
     output: Option<PathBuf>,
     config: Option<PathBuf>,
-    cut_thickness: i32,
-    min_trim: i32,
-    accuracy: i32,
-    threads: usize,
+    options: OptimizeOptions,
 ) -> Result<()> {
     log_operation_start!("Optimizing cutting layout");
     log_info!("Input file: {:?}", input);
-    log_info!("Output file: {:?}", output.unwrap_or_else(|| PathBuf::from("output.json")));
+    log_info!("Output file: {:?}", output);
     log_info!("Config file: {:?}", config);
-    log_info!("Cut thickness: {}mm", cut_thickness);
-    log_info!("Min trim: {}mm", min_trim);
-    log_info!("Accuracy: {}", accuracy);
-    log_info!("Threads: {}", threads);
-    
-    // TODO: Implement actual optimization logic
-    // For now, just validate that the input file exists
-    if !input.exists() {
-        return Err(AppError::invalid_input(format!("Input file does not exist: {:?}", input)));
+    log_info!("Cut thickness: {:?}", options.cut_thickness);
+    log_info!("Min trim: {:?}", options.min_trim);
+    log_info!("Accuracy: {:?}", options.accuracy);
+    log_info!("Search: {:?}", options.search);
+    log_info!("Threads: {}", options.threads);
+
+    let request = load_job(&input, config.as_deref())?;
+
+    let (solution, factor) = optimize(&request, &options).await?;
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+    let json = serde_json::to_string_pretty(&response).map_err(CoreError::from)?;
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, json).map_err(CoreError::from)?;
+            log_info!("Wrote response to {:?}", path);
+        }
+        None => println!("{}", json),
     }
-    /* 
-    
-             return Err(AppError::invalid_configuration {
-                message: "Cut thickness cannot be negative".to_string(),
-            });
-     */
-    
+
     log_operation_success!("Optimization completed successfully");
     Ok(())
 }
 
+/// Execute the optimize command with PNG output, writing one cut map per used sheet
+pub async fn png_command(
+    input: PathBuf,
+    output_dir: PathBuf,
+    config: Option<PathBuf>,
+    options: OptimizeOptions,
+) -> Result<()> {
+    log_operation_start!("Rendering cut maps");
+    log_info!("Input file: {:?}", input);
+    log_info!("Output directory: {:?}", output_dir);
+    log_info!("Config file: {:?}", config);
+    log_info!("Search: {:?}", options.search);
+    log_info!("Threads: {}", options.threads);
+
+    let request = load_job(&input, config.as_deref())?;
+
+    let (solution, factor) = optimize(&request, &options).await?;
+    let labels: HashMap<i32, String> = request
+        .panels
+        .iter()
+        .filter_map(|panel| panel.label.clone().map(|label| (panel.id, label)))
+        .collect();
+
+    let files = write_cut_map_pngs(solution.get_mosaics(), &labels, factor, options.units, &output_dir)?;
+    log_info!("Wrote {} cut map(s) to {:?}", files.len(), output_dir);
+
+    log_operation_success!("Cut maps rendered successfully");
+    Ok(())
+}
+
+/// Execute the optimize command with table output, printing the cut list of every used sheet
+pub async fn table_command(
+    input: PathBuf,
+    output: Option<PathBuf>,
+    config: Option<PathBuf>,
+    options: OptimizeOptions,
+) -> Result<()> {
+    log_operation_start!("Printing cut list");
+    log_info!("Input file: {:?}", input);
+    log_info!("Output file: {:?}", output);
+    log_info!("Config file: {:?}", config);
+    log_info!("Search: {:?}", options.search);
+    log_info!("Threads: {}", options.threads);
+
+    let request = load_job(&input, config.as_deref())?;
+
+    let (solution, factor) = optimize(&request, &options).await?;
+    let labels: HashMap<i32, String> = request
+        .panels
+        .iter()
+        .filter_map(|panel| panel.label.clone().map(|label| (panel.id, label)))
+        .collect();
+    let table = render_cut_table(solution.get_mosaics(), &labels, factor, options.units, options.optimize_cut_order);
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, table).map_err(CoreError::from)?;
+            log_info!("Wrote cut list to {:?}", path);
+        }
+        None => print!("{}", table),
+    }
+
+    log_operation_success!("Cut list printed successfully");
+    Ok(())
+}
+
+/// Load the job to optimize from `input`
+///
+/// The configuration in `config`, when given, replaces any configuration
+/// the input file carries.
+fn load_job(input: &Path, config: Option<&Path>) -> Result<CalculationRequest> {
+    if !input.exists() {
+        return Err(AppError::invalid_input(format!("Input file does not exist: {:?}", input)));
+    }
+    let mut request = load_request(input)?;
+
+    if let Some(config) = config {
+        if !config.exists() {
+            return Err(AppError::invalid_input(format!("Config file does not exist: {:?}", config)));
+        }
+        request.configuration = Some(load_configuration(config)?);
+    }
+    Ok(request)
+}
+
 /// Execute the validate command
+///
+/// Loads the request, prints a summary of its panels and stock, and lists
+/// every warning and error found by `CalculationRequest::validate`. Hard
+/// errors fail the command so it can be used as a pre-flight check.
 pub async fn validate_command(input: PathBuf) -> Result<()> {
     log_operation_start!("Validating input file: {:?}", input);
-    
+
     if !input.exists() {
         return Err(AppError::invalid_input(format!("Input file does not exist: {:?}", input)));
     }
-    
-    // TODO: Implement actual validation logic
-    // Check file extension and basic format validation
     match input.extension().and_then(|ext| ext.to_str()) {
-        Some("csv") => {
-            log_info!("Detected CSV format");
-            // TODO: Validate CSV structure
-        }
-        Some("json") => {
-            log_info!("Detected JSON format");
-            // TODO: Validate JSON structure
-        }
-        _ => {
-            return Err(AppError::invalid_input("Unsupported file format. Expected .csv or .json"));
-        }
+        Some(ext) if ext.eq_ignore_ascii_case("csv") || ext.eq_ignore_ascii_case("json") => {}
+        _ => return Err(AppError::invalid_input("Unsupported file format. Expected .csv or .json")),
     }
-    
+
+    let request = load_request(&input)?;
+    let report = request.validate()?;
+
+    println!("Panels: {} ({} pieces)", request.panels.len(), total_count(&request.panels));
+    println!("Stock panels: {} ({} sheets)", request.stock_panels.len(), total_count(&request.stock_panels));
+    for (material, (panel_area, stock_area)) in area_per_material(&request) {
+        println!("  {}: {} panel area, {} stock area", material, panel_area, stock_area);
+    }
+    for warning in &report.warnings {
+        println!("warning: {}", warning);
+    }
+    for error in &report.errors {
+        eprintln!("error: {}", error);
+    }
+
+    if !report.is_valid() {
+        return Err(AppError::invalid_input(format!(
+            "{} error(s) found in {:?}",
+            report.errors.len(),
+            input
+        )));
+    }
+
     log_operation_success!("Input file validation completed successfully");
     Ok(())
 }
 
+/// Number of pieces across all enabled panels
+fn total_count(panels: &[Panel]) -> i32 {
+    panels.iter().filter(|panel| panel.enabled).map(|panel| panel.count.max(0)).sum()
+}
+
+/// Total panel and stock area per material, skipping disabled or unreadable panels
+fn area_per_material(request: &CalculationRequest) -> BTreeMap<String, (f64, f64)> {
+    let mut areas: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+    let enabled_areas = |panels: &[Panel]| -> Vec<(String, f64)> {
+        panels
+            .iter()
+            .filter(|panel| panel.enabled)
+            .filter_map(|panel| Some((panel.material.clone(), panel.area().ok()? * f64::from(panel.count.max(0)))))
+            .collect()
+    };
+    for (material, area) in enabled_areas(&request.panels) {
+        areas.entry(material).or_default().0 += area;
+    }
+    for (material, area) in enabled_areas(&request.stock_panels) {
+        areas.entry(material).or_default().1 += area;
+    }
+    areas
+}
+
 /// Execute the example command
 pub async fn example_command(format: String) -> Result<()> {
     log_operation_start!("Generating example input file in {} format", format);
@@ -71,10 +204,12 @@ pub async fn example_command(format: String) -> Result<()> {
     match format.as_str() {
         "csv" => {
             log_info!("\nExample CSV format:");
-            log_info!("width,height,quantity,label");
-            log_info!("1200,800,5,Panel A");
-            log_info!("600,400,10,Panel B");
-            log_info!("300,200,15,Panel C");
+            log_info!("id,width,height,count,material,label");
+            log_info!("1,1200,800,5,plywood,Panel A");
+            log_info!("2,600,400,10,plywood,Panel B");
+            log_info!("3,300,200,15,plywood,Panel C");
+            log_info!("# stock");
+            log_info!("100,2800,2070,10,plywood,Sheet");
             log_info!("\nSave this as input.csv and use with:");
             log_info!("cutlist optimize -i input.csv -o output.json");
         }
@@ -0,0 +1,135 @@
+//! Input file import
+//!
+//! Panels and stock can be supplied as a two-section CSV file: panel rows
+//! first, then stock rows, separated by a blank line or a `# stock` marker.
+//! Both sections use the columns `id,width,height,count,material,label`,
+//! where the label is optional and header rows are skipped.
+
+use std::path::Path;
+
+use crate::errors::{AppError, CoreError, Result};
+use crate::models::{CalculationRequest, Configuration, Panel};
+
+/// Number of columns every row must have; the trailing label is optional
+const REQUIRED_COLUMNS: usize = 5;
+
+/// Read panels and stock panels from a CSV file
+///
+/// Widths and heights are kept as strings so the usual decimal-place
+/// scaling applies. Malformed rows are reported with their line number.
+pub fn from_csv(path: impl AsRef<Path>) -> Result<(Vec<Panel>, Vec<Panel>)> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path).map_err(CoreError::from)?;
+
+    let mut panels = Vec::new();
+    let mut stock_panels = Vec::new();
+    let mut in_stock_section = false;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            // The first blank line after some panels starts the stock section
+            if !panels.is_empty() {
+                in_stock_section = true;
+            }
+            continue;
+        }
+        if let Some(comment) = trimmed.strip_prefix('#') {
+            if comment.trim().eq_ignore_ascii_case("stock") {
+                in_stock_section = true;
+            }
+            continue;
+        }
+
+        let fields = split_row(trimmed)
+            .map_err(|e| row_error(path, line_number, &format!("unreadable row: {}", e)))?;
+        if fields[0].eq_ignore_ascii_case("id") {
+            continue;
+        }
+
+        let panel = parse_row(&fields).map_err(|message| row_error(path, line_number, &message))?;
+        if in_stock_section {
+            stock_panels.push(panel);
+        } else {
+            panels.push(panel);
+        }
+    }
+
+    Ok((panels, stock_panels))
+}
+
+/// Load a request from a `.csv` or `.json` input file
+pub fn load_request(path: impl AsRef<Path>) -> Result<CalculationRequest> {
+    let path = path.as_ref();
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("csv") => {
+            let (panels, stock_panels) = from_csv(path)?;
+            Ok(CalculationRequest {
+                configuration: None,
+                panels,
+                stock_panels,
+            })
+        }
+        _ => {
+            let content = std::fs::read_to_string(path).map_err(CoreError::from)?;
+            Ok(serde_json::from_str(&content).map_err(CoreError::from)?)
+        }
+    }
+}
+
+/// Read an optimization configuration from a JSON file
+pub fn load_configuration(path: impl AsRef<Path>) -> Result<Configuration> {
+    let content = std::fs::read_to_string(path).map_err(CoreError::from)?;
+    Ok(serde_json::from_str(&content).map_err(CoreError::from)?)
+}
+
+/// Split one CSV line into trimmed fields, honouring quoted labels
+fn split_row(line: &str) -> std::result::Result<Vec<String>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(line.as_bytes());
+    let mut record = csv::StringRecord::new();
+    reader.read_record(&mut record)?;
+    Ok(record.iter().map(|field| field.trim().to_string()).collect())
+}
+
+fn parse_row(fields: &[String]) -> std::result::Result<Panel, String> {
+    if fields.len() < REQUIRED_COLUMNS {
+        return Err(format!(
+            "expected columns id,width,height,count,material[,label], found {} column(s)",
+            fields.len()
+        ));
+    }
+
+    let id = fields[0]
+        .parse::<i32>()
+        .map_err(|_| format!("id must be an integer, got '{}'", fields[0]))?;
+    for (name, value) in [("width", &fields[1]), ("height", &fields[2])] {
+        if value.parse::<f64>().is_err() {
+            return Err(format!("{} must be a number, got '{}'", name, value));
+        }
+    }
+    let count = fields[3]
+        .parse::<i32>()
+        .map_err(|_| format!("count must be an integer, got '{}'", fields[3]))?;
+
+    let label = fields.get(5).filter(|label| !label.is_empty()).cloned();
+
+    Ok(Panel {
+        id,
+        width: Some(fields[1].clone()),
+        height: Some(fields[2].clone()),
+        count,
+        material: fields[4].clone(),
+        enabled: true,
+        label,
+        ..Panel::default()
+    })
+}
+
+fn row_error(path: &Path, line_number: usize, message: &str) -> AppError {
+    AppError::invalid_input(format!("{}:{}: {}", path.display(), line_number, message))
+}
@@ -1,4 +1,5 @@
 pub mod args;
 pub mod commands;
+pub mod import;
 pub mod progress;
 
@@ -31,6 +31,19 @@ impl StockConstants {
     /// This controls how frequently we retry operations like solution retrieval
     /// when waiting for new solutions to be generated.
     pub const RETRY_SLEEP_MS: u64 = 100;
+
+    /// Preference weight assumed for stock entries that don't specify one.
+    /// Lower weights are consumed first, so equal defaults keep the
+    /// area-based ordering unchanged.
+    pub const DEFAULT_PREFERENCE_WEIGHT: f64 = 1.0;
+
+    /// Used-area ratio below which a sheet is considered underused and a
+    /// smaller stock size from the catalog is suggested.
+    pub const UNDERUSED_SHEET_RATIO: f64 = 0.5;
+
+    /// Stock id given to the first offcut in an offcut inventory; later
+    /// offcuts count up from it, clear of the ids requests normally use.
+    pub const FIRST_OFFCUT_INVENTORY_ID: i32 = 1_000_000;
 }
 
 /// Engine and service configuration constants
@@ -63,6 +76,18 @@ impl EngineConstants {
     
     /// Maximum reasonable number of permutations to generate (7!)
     pub const MAX_PERMUTATIONS: usize = 5040;
+
+    /// Tile orders tried per distinct tile size or elongated tile by the adaptive permutation count
+    pub const ADAPTIVE_PERMUTATIONS_PER_SIZE: usize = 10;
+
+    /// Most tile placements, summed over all orders, the adaptive permutation count allows
+    pub const ADAPTIVE_PERMUTATION_WORK: usize = 100_000;
+
+    /// Ratio of long to short side from which a tile counts as elongated
+    pub const ELONGATED_ASPECT_RATIO: i32 = 3;
+
+    /// Largest width or height a tile or panel may have
+    pub const MAX_DIMENSION: i32 = 1_000_000_000;
 }
 
 /// Configuration default values for cutting optimization
@@ -139,6 +164,14 @@ impl MaterialConstants {
     pub const DEFAULT_MATERIAL: &'static str = "DEFAULT";
 }
 
+/// Calculation response constants
+pub struct ResponseConstants;
+
+impl ResponseConstants {
+    /// Layout version of `ResponseMeta`, bumped when a field is renamed or removed
+    pub const META_SCHEMA_VERSION: u32 = 1;
+}
+
 /// Utility and conversion constants
 pub struct UtilityConstants;
 
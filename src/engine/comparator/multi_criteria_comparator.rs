@@ -0,0 +1,42 @@
+//! Lexicographic multi-criteria solution comparator
+//!
+//! Applies a prioritized list of comparators in order: the first criterion
+//! that tells two solutions apart decides, later ones only break ties.
+
+use std::cmp::Ordering;
+use crate::models::Solution;
+use super::SolutionComparator;
+
+/// Comparator applying several criteria in priority order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiCriteriaComparator {
+    criteria: Vec<SolutionComparator>,
+}
+
+impl MultiCriteriaComparator {
+    /// Create a comparator from criteria ordered by priority
+    pub fn new(criteria: Vec<SolutionComparator>) -> Self {
+        Self { criteria }
+    }
+
+    /// Criteria in priority order
+    pub fn criteria(&self) -> &[SolutionComparator] {
+        &self.criteria
+    }
+
+    /// Compare two solutions; `Ordering::Less` means `a` is better
+    ///
+    /// Solutions equal on every criterion, or compared without any, are `Equal`.
+    pub fn compare(&self, a: &Solution, b: &Solution) -> Ordering {
+        self.criteria
+            .iter()
+            .map(|criterion| criterion.compare(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Sort solutions best first
+    pub fn sort(&self, solutions: &mut [Solution]) {
+        solutions.sort_by(|a, b| self.compare(a, b));
+    }
+}
@@ -27,6 +27,18 @@ impl PriorityListFactory {
     ///   1. MOST_TILES
     ///   2. LEAST_WASTED_AREA  
     ///   3. LEAST_NBR_CUTS
+    /// - If optimization_priority is LeastTotalCutLength:
+    ///   1. MOST_TILES
+    ///   2. LEAST_TOTAL_CUT_LENGTH
+    ///   3. LEAST_WASTED_AREA
+    /// - If optimization_priority is MostBalancedUtilization:
+    ///   1. MOST_TILES
+    ///   2. LEAST_WASTED_AREA
+    ///   3. MOST_BALANCED_UTILIZATION
+    /// - If optimization_priority is SmallestLargestOffcut:
+    ///   1. MOST_TILES
+    ///   2. LEAST_WASTED_AREA
+    ///   3. SMALLEST_LARGEST_OFFCUT
     /// - Otherwise:
     ///   1. MOST_TILES
     ///   2. LEAST_NBR_CUTS
@@ -35,6 +47,8 @@ impl PriorityListFactory {
     ///   4. LEAST_NBR_MOSAICS
     ///   5. BIGGEST_UNUSED_TILE_AREA
     ///   6. MOST_HV_DISCREPANCY
+    /// - With `prefer_fewer_sheets`, LEAST_NBR_MOSAICS moves up to second place,
+    ///   right after MOST_TILES
     /// 
     /// # Examples
     /// ```
@@ -60,15 +74,34 @@ impl PriorityListFactory {
             priority_list.push(OptimizationPriority::MostTiles.to_string());
             priority_list.push(OptimizationPriority::LeastWastedArea.to_string());
             priority_list.push(OptimizationPriority::LeastNbrCuts.to_string());
+        } else if configuration.optimization_priority == OptimizationPriority::LeastTotalCutLength {
+            priority_list.push(OptimizationPriority::MostTiles.to_string());
+            priority_list.push(OptimizationPriority::LeastTotalCutLength.to_string());
+            priority_list.push(OptimizationPriority::LeastWastedArea.to_string());
+        } else if configuration.optimization_priority == OptimizationPriority::MostBalancedUtilization {
+            priority_list.push(OptimizationPriority::MostTiles.to_string());
+            priority_list.push(OptimizationPriority::LeastWastedArea.to_string());
+            priority_list.push(OptimizationPriority::MostBalancedUtilization.to_string());
+        } else if configuration.optimization_priority == OptimizationPriority::SmallestLargestOffcut {
+            priority_list.push(OptimizationPriority::MostTiles.to_string());
+            priority_list.push(OptimizationPriority::LeastWastedArea.to_string());
+            priority_list.push(OptimizationPriority::SmallestLargestOffcut.to_string());
         } else {
             // All other optimization priorities
             priority_list.push(OptimizationPriority::MostTiles.to_string());
             priority_list.push(OptimizationPriority::LeastNbrCuts.to_string());
             priority_list.push(OptimizationPriority::LeastWastedArea.to_string());
         }
+
+        // Fewer sheets outranks everything but the number of placed tiles
+        if configuration.prefer_fewer_sheets {
+            priority_list.insert(1, OptimizationPriority::LeastNbrMosaics.to_string());
+        }
         
         // Common suffix for all cases
-        priority_list.push(OptimizationPriority::LeastNbrMosaics.to_string());
+        if !configuration.prefer_fewer_sheets {
+            priority_list.push(OptimizationPriority::LeastNbrMosaics.to_string());
+        }
         priority_list.push(OptimizationPriority::BiggestUnusedTileArea.to_string());
         priority_list.push(OptimizationPriority::MostHvDiscrepancy.to_string());
         
@@ -108,14 +141,33 @@ impl PriorityListFactory {
             comparator_list.push(SolutionComparator::MostNbrTiles);
             comparator_list.push(SolutionComparator::LeastWastedArea);
             comparator_list.push(SolutionComparator::LeastNbrCuts);
+        } else if configuration.optimization_priority == OptimizationPriority::LeastTotalCutLength {
+            comparator_list.push(SolutionComparator::MostNbrTiles);
+            comparator_list.push(SolutionComparator::LeastTotalCutLength);
+            comparator_list.push(SolutionComparator::LeastWastedArea);
+        } else if configuration.optimization_priority == OptimizationPriority::MostBalancedUtilization {
+            comparator_list.push(SolutionComparator::MostNbrTiles);
+            comparator_list.push(SolutionComparator::LeastWastedArea);
+            comparator_list.push(SolutionComparator::MostBalancedUtilization);
+        } else if configuration.optimization_priority == OptimizationPriority::SmallestLargestOffcut {
+            comparator_list.push(SolutionComparator::MostNbrTiles);
+            comparator_list.push(SolutionComparator::LeastWastedArea);
+            comparator_list.push(SolutionComparator::SmallestLargestOffcut);
         } else {
             comparator_list.push(SolutionComparator::MostNbrTiles);
             comparator_list.push(SolutionComparator::LeastNbrCuts);
             comparator_list.push(SolutionComparator::LeastWastedArea);
         }
+
+        // Fewer sheets outranks everything but the number of placed tiles
+        if configuration.prefer_fewer_sheets {
+            comparator_list.insert(1, SolutionComparator::LeastNbrMosaics);
+        }
         
         // Common suffix for all cases
-        comparator_list.push(SolutionComparator::LeastNbrMosaics);
+        if !configuration.prefer_fewer_sheets {
+            comparator_list.push(SolutionComparator::LeastNbrMosaics);
+        }
         comparator_list.push(SolutionComparator::BiggestUnusedTileArea);
         comparator_list.push(SolutionComparator::HvDiscrepancy);
         
@@ -10,11 +10,14 @@ use super::{
     compare_by_least_nbr_cuts,
     compare_by_least_nbr_mosaics,
     compare_by_least_nbr_unused_tiles,
+    compare_by_least_total_cut_length,
+    compare_by_most_balanced_utilization,
     compare_by_least_wasted_area,
     compare_by_hv_discrepancy,
     compare_by_most_nbr_tiles,
     compare_by_most_unused_panel_area,
     compare_by_smallest_center_of_mass_dist_to_origin,
+    compare_by_smallest_largest_offcut,
 };
 
 /// Enum representing different solution comparison strategies
@@ -36,6 +39,10 @@ pub enum SolutionComparator {
     LeastNbrMosaics,
     /// Compare by number of unused tiles (ascending)
     LeastNbrUnusedTiles,
+    /// Compare by total cut length (ascending)
+    LeastTotalCutLength,
+    /// Compare by variance of per-sheet efficiency (ascending)
+    MostBalancedUtilization,
     /// Compare by wasted area (ascending)
     LeastWastedArea,
     /// Compare by H/V discrepancy (ascending)
@@ -46,6 +53,8 @@ pub enum SolutionComparator {
     MostUnusedPanelArea,
     /// Compare by center of mass distance to origin (ascending)
     SmallestCenterOfMassDistToOrigin,
+    /// Compare by biggest unused tile area (ascending)
+    SmallestLargestOffcut,
 }
 
 impl SolutionComparator {
@@ -59,11 +68,14 @@ impl SolutionComparator {
             Self::LeastNbrCuts => compare_by_least_nbr_cuts,
             Self::LeastNbrMosaics => compare_by_least_nbr_mosaics,
             Self::LeastNbrUnusedTiles => compare_by_least_nbr_unused_tiles,
+            Self::LeastTotalCutLength => compare_by_least_total_cut_length,
+            Self::MostBalancedUtilization => compare_by_most_balanced_utilization,
             Self::LeastWastedArea => compare_by_least_wasted_area,
             Self::HvDiscrepancy => compare_by_hv_discrepancy,
             Self::MostNbrTiles => compare_by_most_nbr_tiles,
             Self::MostUnusedPanelArea => compare_by_most_unused_panel_area,
             Self::SmallestCenterOfMassDistToOrigin => compare_by_smallest_center_of_mass_dist_to_origin,
+            Self::SmallestLargestOffcut => compare_by_smallest_largest_offcut,
         }
     }
     
@@ -86,11 +98,14 @@ impl SolutionComparator {
             Self::LeastNbrCuts => "Least number of cuts (ascending)",
             Self::LeastNbrMosaics => "Least number of mosaics (ascending)",
             Self::LeastNbrUnusedTiles => "Least number of unused tiles (ascending)",
+            Self::LeastTotalCutLength => "Least total cut length (ascending)",
+            Self::MostBalancedUtilization => "Least variance in sheet utilization (ascending)",
             Self::LeastWastedArea => "Least wasted area (ascending)",
             Self::HvDiscrepancy => "H/V discrepancy (ascending)",
             Self::MostNbrTiles => "Most number of tiles (descending)",
             Self::MostUnusedPanelArea => "Most unused panel area (descending)",
             Self::SmallestCenterOfMassDistToOrigin => "Smallest center of mass distance to origin (ascending)",
+            Self::SmallestLargestOffcut => "Biggest unused tile area (ascending)",
         }
     }
     
@@ -101,11 +116,14 @@ impl SolutionComparator {
             Self::LeastNbrCuts,
             Self::LeastNbrMosaics,
             Self::LeastNbrUnusedTiles,
+            Self::LeastTotalCutLength,
+            Self::MostBalancedUtilization,
             Self::LeastWastedArea,
             Self::HvDiscrepancy,
             Self::MostNbrTiles,
             Self::MostUnusedPanelArea,
             Self::SmallestCenterOfMassDistToOrigin,
+            Self::SmallestLargestOffcut,
         ]
     }
 }
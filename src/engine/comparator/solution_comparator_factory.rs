@@ -5,8 +5,9 @@
 
 use std::cmp::Ordering;
 use crate::models::Solution;
+use crate::errors::{AppError, CoreError};
 use crate::models::enums::OptimizationPriority;
-use super::SolutionComparator;
+use super::{MultiCriteriaComparator, SolutionComparator};
 
 /// Error type for comparator factory operations
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -32,6 +33,12 @@ impl std::fmt::Display for ComparatorFactoryError {
 
 impl std::error::Error for ComparatorFactoryError {}
 
+impl From<ComparatorFactoryError> for AppError {
+    fn from(error: ComparatorFactoryError) -> Self {
+        CoreError::invalid_configuration(error.to_string()).into()
+    }
+}
+
 /// Factory for creating solution comparators from string identifiers
 pub struct SolutionComparatorFactory;
 
@@ -140,6 +147,34 @@ impl SolutionComparatorFactory {
             .collect()
     }
 
+    /// Create a comparator from a priority name such as `"most_tiles"`
+    ///
+    /// Names are matched case-insensitively and may be surrounded by
+    /// whitespace; unlike `get_solution_comparator`, a blank name is an error.
+    ///
+    /// # Examples
+    /// ```
+    /// use cutlist_optimizer_cli::comparator::{SolutionComparator, SolutionComparatorFactory};
+    ///
+    /// let comparator = SolutionComparatorFactory::create_comparator_by_name("most_tiles").unwrap();
+    /// assert_eq!(comparator, SolutionComparator::MostNbrTiles);
+    /// assert!(SolutionComparatorFactory::create_comparator_by_name("fewest_tiles").is_err());
+    /// ```
+    pub fn create_comparator_by_name(name: &str) -> Result<SolutionComparator, AppError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ComparatorFactoryError::InvalidInput.into());
+        }
+        Ok(Self::parse_priority_string(name)?)
+    }
+
+    /// Build a lexicographic comparator from priority names, highest priority first
+    ///
+    /// Unknown names are skipped, like in `get_solution_comparator_enum_list`.
+    pub fn from_names(names: &[&str]) -> MultiCriteriaComparator {
+        MultiCriteriaComparator::new(Self::get_solution_comparator_enum_list(names))
+    }
+
     /// Parse a priority string into a SolutionComparator enum
     /// 
     /// # Arguments
@@ -161,6 +196,9 @@ impl SolutionComparatorFactory {
             "LEAST_NBR_MOSAICS" => Ok(SolutionComparator::LeastNbrMosaics),
             "LEAST_NBR_UNUSED_TILES" => Ok(SolutionComparator::LeastNbrUnusedTiles),
             "MOST_UNUSED_PANEL_AREA" => Ok(SolutionComparator::MostUnusedPanelArea),
+            "LEAST_TOTAL_CUT_LENGTH" => Ok(SolutionComparator::LeastTotalCutLength),
+            "MOST_BALANCED_UTILIZATION" => Ok(SolutionComparator::MostBalancedUtilization),
+            "SMALLEST_LARGEST_OFFCUT" => Ok(SolutionComparator::SmallestLargestOffcut),
             _ => Err(ComparatorFactoryError::UnknownPriority(priority_str.to_string())),
         }
     }
@@ -179,6 +217,9 @@ impl From<OptimizationPriority> for SolutionComparator {
             OptimizationPriority::LeastNbrMosaics => Self::LeastNbrMosaics,
             OptimizationPriority::LeastNbrUnusedTiles => Self::LeastNbrUnusedTiles,
             OptimizationPriority::MostUnusedPanelArea => Self::MostUnusedPanelArea,
+            OptimizationPriority::LeastTotalCutLength => Self::LeastTotalCutLength,
+            OptimizationPriority::MostBalancedUtilization => Self::MostBalancedUtilization,
+            OptimizationPriority::SmallestLargestOffcut => Self::SmallestLargestOffcut,
         }
     }
 }
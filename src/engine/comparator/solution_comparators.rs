@@ -32,6 +32,24 @@ pub fn compare_by_biggest_unused_tile_area(a: &Solution, b: &Solution) -> Orderi
     b.get_biggest_area().cmp(&a.get_biggest_area())
 }
 
+/// Compare solutions by biggest unused tile area (ascending order)
+/// 
+/// The reverse of `compare_by_biggest_unused_tile_area`: solutions whose
+/// largest offcut is smaller are considered "less" (better), for shops that
+/// do not keep offcuts and would rather leave only small scraps.
+/// 
+/// # Arguments
+/// * `a` - First solution to compare
+/// * `b` - Second solution to compare
+/// 
+/// # Returns
+/// * `Ordering::Less` if `a` has a smaller biggest unused tile area than `b`
+/// * `Ordering::Greater` if `a` has a bigger biggest unused tile area than `b`
+/// * `Ordering::Equal` if both have the same biggest unused tile area
+pub fn compare_by_smallest_largest_offcut(a: &Solution, b: &Solution) -> Ordering {
+    a.get_biggest_area().cmp(&b.get_biggest_area())
+}
+
 /// Compare solutions by number of cuts (ascending order)
 /// 
 /// Solutions with fewer cuts are considered "less" (better).
@@ -49,6 +67,42 @@ pub fn compare_by_least_nbr_cuts(a: &Solution, b: &Solution) -> Ordering {
     a.get_nbr_cuts().cmp(&b.get_nbr_cuts())
 }
 
+/// Compare solutions by total length of all cuts (ascending order)
+/// 
+/// Solutions whose cuts add up to less saw travel are considered "less" (better),
+/// regardless of how many cuts that takes.
+/// 
+/// # Arguments
+/// * `a` - First solution to compare
+/// * `b` - Second solution to compare
+/// 
+/// # Returns
+/// * `Ordering::Less` if `a` has a shorter total cut length than `b`
+/// * `Ordering::Greater` if `a` has a longer total cut length than `b`
+/// * `Ordering::Equal` if both have the same total cut length
+pub fn compare_by_least_total_cut_length(a: &Solution, b: &Solution) -> Ordering {
+    a.get_total_cut_length().cmp(&b.get_total_cut_length())
+}
+
+/// Compare solutions by how evenly their sheets are used (ascending variance)
+/// 
+/// The variance of the per-mosaic efficiency is compared, so two half-full
+/// sheets are considered "less" (better) than one full sheet and one empty one.
+/// 
+/// # Arguments
+/// * `a` - First solution to compare
+/// * `b` - Second solution to compare
+/// 
+/// # Returns
+/// * `Ordering::Less` if `a` has a lower utilization variance than `b`
+/// * `Ordering::Greater` if `a` has a higher utilization variance than `b`
+/// * `Ordering::Equal` if both have the same utilization variance
+pub fn compare_by_most_balanced_utilization(a: &Solution, b: &Solution) -> Ordering {
+    a.get_utilization_variance()
+        .partial_cmp(&b.get_utilization_variance())
+        .unwrap_or(Ordering::Equal)
+}
+
 /// Compare solutions by number of mosaics (ascending order)
 /// 
 /// Solutions with fewer mosaics are considered "less" (better).
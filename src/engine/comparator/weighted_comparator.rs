@@ -0,0 +1,134 @@
+//! Weighted multi-criteria solution comparator
+//!
+//! Prioritized comparator lists are applied lexicographically, so the first
+//! criterion always dominates. `WeightedComparator` instead blends several
+//! criteria into a single score: each metric is normalized against the
+//! larger value of the two solutions being compared, turned into a cost in
+//! `[0, 1]` and multiplied by its weight. The solution with the lower total
+//! cost sorts first.
+
+use std::cmp::Ordering;
+use crate::models::Solution;
+
+/// Comparator combining normalized criteria with tunable weights
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedComparator {
+    /// Weight of the number of placed tiles (more is better)
+    pub tiles_placed: f64,
+    /// Weight of the wasted area (less is better)
+    pub wasted_area: f64,
+    /// Weight of the number of cuts (less is better)
+    pub nbr_cuts: f64,
+    /// Weight of the number of mosaics (less is better)
+    pub nbr_mosaics: f64,
+}
+
+impl Default for WeightedComparator {
+    fn default() -> Self {
+        Self {
+            tiles_placed: 1.0,
+            wasted_area: 1.0,
+            nbr_cuts: 1.0,
+            nbr_mosaics: 1.0,
+        }
+    }
+}
+
+impl WeightedComparator {
+    /// Create a builder with every weight set to zero
+    pub fn builder() -> WeightedComparatorBuilder {
+        WeightedComparatorBuilder::new()
+    }
+
+    /// Weighted costs of `a` and `b`, normalized across the pair
+    ///
+    /// Lower is better. Each criterion contributes at most its weight.
+    pub fn scores(&self, a: &Solution, b: &Solution) -> (f64, f64) {
+        let tiles = normalize(a.get_nbr_final_tiles() as f64, b.get_nbr_final_tiles() as f64);
+        let wasted = normalize(a.get_unused_area() as f64, b.get_unused_area() as f64);
+        let cuts = normalize(a.get_nbr_cuts() as f64, b.get_nbr_cuts() as f64);
+        let mosaics = normalize(a.get_nbr_mosaics() as f64, b.get_nbr_mosaics() as f64);
+
+        let score = |tiles: f64, wasted: f64, cuts: f64, mosaics: f64| {
+            self.tiles_placed * (1.0 - tiles)
+                + self.wasted_area * wasted
+                + self.nbr_cuts * cuts
+                + self.nbr_mosaics * mosaics
+        };
+
+        (
+            score(tiles.0, wasted.0, cuts.0, mosaics.0),
+            score(tiles.1, wasted.1, cuts.1, mosaics.1),
+        )
+    }
+
+    /// Compare two solutions; `Ordering::Less` means `a` is better
+    pub fn compare(&self, a: &Solution, b: &Solution) -> Ordering {
+        let (score_a, score_b) = self.scores(a, b);
+        score_a.total_cmp(&score_b)
+    }
+
+    /// Sort solutions best first
+    pub fn sort(&self, solutions: &mut [Solution]) {
+        solutions.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+/// Divide both values by the larger of the two, keeping them in `[0, 1]`
+fn normalize(a: f64, b: f64) -> (f64, f64) {
+    let max = a.max(b);
+    if max > 0.0 {
+        (a / max, b / max)
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+/// Builder for `WeightedComparator`
+#[derive(Debug, Clone, Copy)]
+pub struct WeightedComparatorBuilder {
+    weights: WeightedComparator,
+}
+
+impl Default for WeightedComparatorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WeightedComparatorBuilder {
+    pub fn new() -> Self {
+        Self {
+            weights: WeightedComparator {
+                tiles_placed: 0.0,
+                wasted_area: 0.0,
+                nbr_cuts: 0.0,
+                nbr_mosaics: 0.0,
+            },
+        }
+    }
+
+    pub fn tiles_placed(mut self, weight: f64) -> Self {
+        self.weights.tiles_placed = weight;
+        self
+    }
+
+    pub fn wasted_area(mut self, weight: f64) -> Self {
+        self.weights.wasted_area = weight;
+        self
+    }
+
+    pub fn nbr_cuts(mut self, weight: f64) -> Self {
+        self.weights.nbr_cuts = weight;
+        self
+    }
+
+    pub fn nbr_mosaics(mut self, weight: f64) -> Self {
+        self.weights.nbr_mosaics = weight;
+        self
+    }
+
+    pub fn build(self) -> WeightedComparator {
+        self.weights
+    }
+}
@@ -0,0 +1,108 @@
+//! Consolidation of partially-filled sheets
+//!
+//! Two sheets of the same stock that are each only partly used can sometimes
+//! be cut as one. `consolidate` replans the panels of both sheets onto a
+//! single sheet with the guillotine placement, and `balance_sheets` uses it
+//! after optimization to free the sheets a greedy layout left barely used.
+
+use std::collections::HashMap;
+
+use crate::engine::service::{GuillotinePlacement, PlacementAlgorithm, PlacementConfig};
+use crate::engine::stock::StockSolution;
+use crate::models::{Mosaic, Solution, TileDimensions};
+
+/// Replan the panels of `target` and `source` onto a single sheet of `target`'s stock
+///
+/// `tiles` are the tiles the sheets were laid out from. Each placed panel is
+/// replanned as the tile of its id, so it keeps its grain orientation, label,
+/// edge banding and priority; kerf, trim and the other placement settings
+/// come from `config`, in the same scaled units as the mosaics.
+///
+/// Returns the combined mosaic if every panel from both sheets fits, or
+/// `None` if the sheets are incompatible, a panel is not among `tiles`, or
+/// the panels cannot be arranged.
+pub fn consolidate(target: &Mosaic, source: &Mosaic, tiles: &[TileDimensions], config: &PlacementConfig) -> Option<Mosaic> {
+    if !target.can_consolidate_with(source) {
+        return None;
+    }
+
+    let tiles_by_id: HashMap<i32, &TileDimensions> = tiles.iter().map(|tile| (tile.id, tile)).collect();
+    let mut panels = target
+        .final_tile_nodes()
+        .into_iter()
+        .chain(source.final_tile_nodes())
+        .map(|node| tiles_by_id.get(&node.external_id().unwrap_or(node.id() as i32)).map(|&tile| tile.clone()))
+        .collect::<Option<Vec<TileDimensions>>>()?;
+    panels.sort_by_key(|tile| std::cmp::Reverse(tile.area()));
+    let panel_count = panels.len();
+
+    let stock_solution = StockSolution::from_tiles(vec![target.to_tile_dimensions()]);
+    let solutions = GuillotinePlacement.place(&panels, &stock_solution, config).ok()?;
+    let solution = solutions.iter().find(|solution| {
+        solution.get_no_fit_panels().is_empty()
+            && solution.get_mosaics().len() == 1
+            && solution.get_mosaics()[0].final_tile_count() == panel_count
+    })?;
+
+    let mut combined = solution.get_mosaics()[0].clone();
+    combined.set_material(target.material().to_string());
+    combined.set_orientation(target.orientation());
+    combined.set_stock_id(target.stock_id());
+    Some(combined)
+}
+
+/// Free sheets of `solution` by moving their panels onto other sheets of the same stock
+///
+/// Starting from the least-used sheet, each sheet is replanned together
+/// with another compatible sheet; when every panel of both fits on one
+/// sheet the pair is replaced by the combined layout and the freed stock
+/// returns to the unused stock panels. Sheets are only merged when all of
+/// their panels fit, so the number of placed panels never decreases.
+///
+/// `tiles` and `config` are as for `consolidate`. Returns the number of sheets freed.
+pub fn balance_sheets(solution: &mut Solution, tiles: &[TileDimensions], config: &PlacementConfig) -> usize {
+    let mut freed = 0;
+
+    while let Some((target, source, combined)) = find_sheet_merge(solution, tiles, config) {
+        let removed = solution.mosaics.remove(source);
+        let target = if source < target { target - 1 } else { target };
+        solution.mosaics[target] = combined;
+        solution.unused_stock_panels.push_back(removed.to_tile_dimensions());
+        freed += 1;
+    }
+
+    freed
+}
+
+/// Find the first mergeable pair, trying the least-used sheets as sources
+/// and the least-used remaining sheets as targets so utilization stays even
+fn find_sheet_merge(
+    solution: &Solution,
+    tiles: &[TileDimensions],
+    config: &PlacementConfig,
+) -> Option<(usize, usize, Mosaic)> {
+    let mut by_usage: Vec<(usize, i64)> = solution
+        .mosaics
+        .iter()
+        .enumerate()
+        .map(|(index, mosaic)| {
+            let placed: i64 = mosaic.final_tile_nodes().iter().map(|node| node.area()).sum();
+            (index, placed)
+        })
+        .collect();
+    // Stable sort keeps the existing sheet order for equal usage
+    by_usage.sort_by_key(|&(_, placed)| placed);
+
+    for &(source, _) in &by_usage {
+        for &(target, _) in &by_usage {
+            if target == source {
+                continue;
+            }
+            if let Some(combined) = consolidate(&solution.mosaics[target], &solution.mosaics[source], tiles, config) {
+                return Some((target, source, combined));
+            }
+        }
+    }
+
+    None
+}
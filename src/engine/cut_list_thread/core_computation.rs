@@ -4,7 +4,7 @@
 
 use crate::{
     log_debug, log_info,
-    models::{Solution, TileNode},
+    models::{CutThickness, Solution, TileNode},
     errors::{AppError, Result},
     Status,
 };
@@ -18,22 +18,47 @@ impl CutListThread {
     /// Main computation method - equivalent to Java's computeSolutions()
     /// Implements the complete tile fitting algorithm from the original Java code
     pub fn compute_solutions(&mut self) -> Result<()> {
-        log_info!("Starting solution computation for thread group: {:?}", self.group);
-        
-        self.status = Status::Running;
-        self.start_time = Some(Instant::now());
-
-        let mut current_solutions = Vec::new();
+        let mut initial_solutions = Vec::new();
         
         // Create initial solution from stock
         if let Some(ref stock_solution) = self.stock_solution {
             let initial_solution = Solution::from_stock_solution(stock_solution);
-            current_solutions.push(initial_solution);
+            initial_solutions.push(initial_solution);
+        }
+
+        self.compute_from(initial_solutions)
+    }
+
+    /// Re-place this thread's tiles around the locked tiles of an existing solution
+    ///
+    /// The seed keeps its sheets and unused stock. Tiles locked with
+    /// `Solution::with_locked_tiles` stay where they are and are not placed
+    /// again; every other placed tile is cleared so the freed space is reused.
+    pub fn compute_solutions_incremental(&mut self, seed: &Solution) -> Result<()> {
+        let mut solution = Solution::from_solution(seed);
+        for mosaic in solution.get_mosaics_mut() {
+            mosaic.free_unlocked_tiles();
         }
 
+        for node in solution.get_final_tile_nodes() {
+            if let Some(index) = self.tiles.iter().position(|tile| Some(tile.id) == node.external_id()) {
+                self.tiles.remove(index);
+            }
+        }
+
+        self.compute_from(vec![solution])
+    }
+
+    /// Fit every tile into the given starting solutions
+    fn compute_from(&mut self, mut current_solutions: Vec<Solution>) -> Result<()> {
+        log_info!("Starting solution computation for thread group: {:?}", self.group);
+        
+        self.status = Status::Running;
+        self.start_time = Some(Instant::now());
+
         // Check if task is still running
         let task_running = if let Some(task) = &self.task {
-            task.lock().map(|t| t.is_running()).unwrap_or(false)
+            task.lock().map(|t| t.is_running() && !t.is_cancelled()).unwrap_or(false)
         } else {
             true
         };
@@ -42,6 +67,10 @@ impl CutListThread {
             return Ok(());
         }
 
+        if self.group_identical_tiles {
+            self.cluster_identical_tiles();
+        }
+
         // Process each tile with the complex fitting algorithm
         let total_tiles = self.tiles.len();
         for (tile_index, tile_dimensions) in self.tiles.iter().enumerate() {
@@ -52,6 +81,14 @@ impl CutListThread {
             }
 
             let mut new_solutions = Vec::new();
+            let group_signature = self.group_identical_tiles.then(|| tile_dimensions.get_grouping_signature());
+            // Copies still to place in this group, including this one
+            let group_size = group_signature.as_deref().map_or(1, |signature| {
+                self.tiles[tile_index..]
+                    .iter()
+                    .take_while(|tile| tile.get_grouping_signature() == signature)
+                    .count()
+            });
 
             // Try to fit the tile into each existing solution
             for solution in &current_solutions {
@@ -59,15 +96,38 @@ impl CutListThread {
                 
                 // Try to fit into each mosaic in the solution
                 let mosaics = solution.get_mosaics();
+                // A started group only continues on the sheet that already holds it
+                let group_sheet_started = group_signature
+                    .as_deref()
+                    .is_some_and(|signature| mosaics.iter().any(|mosaic| Self::mosaic_holds_group(mosaic, signature)));
+                // A new group starts on a sheet that can hold all of it, if there is one
+                let whole_group = group_size > 1
+                    && !group_sheet_started
+                    && self.group_home_exists(solution, tile_dimensions, group_size)?;
                 for mosaic in mosaics.iter() {
                     // Check material compatibility
                     let mosaic_material = mosaic.material();
                     if mosaic_material != tile_dimensions.material {
                         continue;
                     }
+                    if !self.is_stock_allowed(tile_dimensions, mosaic.stock_id()) {
+                        continue;
+                    }
+                    if let Some(signature) = group_signature.as_deref() {
+                        if group_sheet_started && !Self::mosaic_holds_group(mosaic, signature) {
+                            continue;
+                        }
+                    }
+                    if whole_group && !self.group_fits(mosaic, tile_dimensions, group_size)? {
+                        continue;
+                    }
 
                     let mut fitting_results = Vec::new();
-                    self.add_tile_to_mosaic(tile_dimensions, mosaic, &mut fitting_results)?;
+                    self.add_tile_to_mosaic(tile_dimensions, mosaic, &mut fitting_results, self.grain_mode)?;
+                    if self.group_identical_tiles {
+                        fitting_results.retain(Self::has_contiguous_groups);
+                    }
+                    fitting_results.retain(|result| self.within_cut_limit(result));
                     
                     // Create new solutions for each fitting result
                     for result_mosaic in fitting_results {
@@ -89,15 +149,29 @@ impl CutListThread {
                 if !tile_fitted_in_solution {
                     let unused_panels: Vec<_> = solution.get_unused_stock_panels().iter().cloned().collect();
                     for panel in unused_panels {
-                        if panel.fits(tile_dimensions) {
+                        // A tile may only start a new mosaic on stock of its own material
+                        if panel.material != tile_dimensions.material {
+                            continue;
+                        }
+                        if tile_dimensions.fits(&panel) && self.is_stock_allowed(tile_dimensions, panel.id) {
+                            if whole_group
+                                && !self.group_fits(&crate::models::Mosaic::from_tile_dimensions(&panel), tile_dimensions, group_size)?
+                            {
+                                continue;
+                            }
+
                             // Create new solution with new mosaic from unused panel
                             let mut new_solution = solution.clone();
-                            new_solution.get_unused_stock_panels_mut().retain(|p| p != &panel);
+                            let unused = new_solution.get_unused_stock_panels_mut();
+                            if let Some(index) = unused.iter().position(|p| p == &panel) {
+                                unused.remove(index);
+                            }
                             
                             // Create new mosaic from the panel and add the tile
                             let new_mosaic = crate::models::Mosaic::from_tile_dimensions(&panel);
                             let mut fitting_results = Vec::new();
-                            self.add_tile_to_mosaic(tile_dimensions, &new_mosaic, &mut fitting_results)?;
+                            self.add_tile_to_mosaic(tile_dimensions, &new_mosaic, &mut fitting_results, self.grain_mode)?;
+                            fitting_results.retain(|result| self.within_cut_limit(result));
                             
                             for result_mosaic in fitting_results {
                                 let mut solution_with_new_mosaic = new_solution.clone();
@@ -107,7 +181,9 @@ impl CutListThread {
                                 new_solutions.push(solution_with_new_mosaic);
                                 tile_fitted_in_solution = true;
                             }
-                            break;
+                            if tile_fitted_in_solution {
+                                break;
+                            }
                         }
                     }
                 }
@@ -167,43 +243,6 @@ impl CutListThread {
         Ok(())
     }
 
-    /// Copy a tile node tree, stopping at the target node
-    pub(crate) fn copy_tile_node(&self, original: &TileNode, target: &TileNode) -> Result<TileNode> {
-        let mut root_copy = TileNode::new(original.x1(), original.x2(), original.y1(), original.y2());
-        self.copy_children(original, &mut root_copy, target)?;
-        Ok(root_copy)
-    }
-
-    /// Recursively copy children of a tile node
-    fn copy_children(&self, original: &TileNode, copy: &mut TileNode, target: &TileNode) -> Result<()> {
-        // If we've reached the target node, stop copying
-        if std::ptr::eq(original, target) {
-            return Ok(());
-        }
-
-        // Copy child1 if it exists
-        if let Some(child1) = original.child1() {
-            let mut child1_copy = TileNode::new(child1.x1(), child1.x2(), child1.y1(), child1.y2());
-            child1_copy.set_external_id(child1.external_id());
-            child1_copy.set_final(child1.is_final());
-            child1_copy.set_rotated(child1.is_rotated());
-            copy.set_child1(Some(child1_copy.clone()));
-            self.copy_children(child1, &mut child1_copy, target)?;
-        }
-
-        // Copy child2 if it exists
-        if let Some(child2) = original.child2() {
-            let mut child2_copy = TileNode::new(child2.x1(), child2.x2(), child2.y1(), child2.y2());
-            child2_copy.set_external_id(child2.external_id());
-            child2_copy.set_final(child2.is_final());
-            child2_copy.set_rotated(child2.is_rotated());
-            copy.set_child2(Some(child2_copy.clone()));
-            self.copy_children(child2, &mut child2_copy, target)?;
-        }
-
-        Ok(())
-    }
-
     /// Find candidate tile nodes that can accommodate the given dimensions
     pub fn find_candidates(
         &self,
@@ -211,6 +250,21 @@ impl CutListThread {
         height: i32,
         tile_node: &TileNode,
         candidates: &mut Vec<TileNode>,
+    ) {
+        self.find_candidates_with_kerf(width, height, CutThickness::default(), tile_node, candidates);
+    }
+
+    /// Find candidate tile nodes like `find_candidates`, cutting with `cut_thickness`
+    ///
+    /// With `fit_within_kerf`, a node at most one kerf larger than the tile
+    /// is a candidate even below the minimum trim: the cut leaves no offcut.
+    pub fn find_candidates_with_kerf(
+        &self,
+        width: i32,
+        height: i32,
+        cut_thickness: CutThickness,
+        tile_node: &TileNode,
+        candidates: &mut Vec<TileNode>,
     ) {
         if tile_node.is_final() 
             || tile_node.width() < width 
@@ -221,9 +275,11 @@ impl CutListThread {
         // If this is a leaf node, check if it can accommodate the tile
         if tile_node.child1().is_none() && tile_node.child2().is_none() {
             let width_ok = tile_node.width() == width 
-                || tile_node.width() >= self.min_trim_dimension + width;
+                || tile_node.width() >= self.min_trim_dimension + width
+                || (self.fit_within_kerf && tile_node.width() <= width + cut_thickness.horizontal());
             let height_ok = tile_node.height() == height 
-                || tile_node.height() >= self.min_trim_dimension + height;
+                || tile_node.height() >= self.min_trim_dimension + height
+                || (self.fit_within_kerf && tile_node.height() <= height + cut_thickness.vertical());
 
             if !width_ok && tile_node.width() > width {
                 if let Some(task) = &self.task {
@@ -249,10 +305,10 @@ impl CutListThread {
 
         // Recursively check children
         if let Some(child1) = tile_node.child1() {
-            self.find_candidates(width, height, child1, candidates);
+            self.find_candidates_with_kerf(width, height, cut_thickness, child1, candidates);
         }
         if let Some(child2) = tile_node.child2() {
-            self.find_candidates(width, height, child2, candidates);
+            self.find_candidates_with_kerf(width, height, cut_thickness, child2, candidates);
         }
     }
 }
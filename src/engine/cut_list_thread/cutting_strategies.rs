@@ -3,7 +3,7 @@
 //! This module contains the various cutting algorithms and strategies for splitting tiles.
 
 use crate::{
-    models::{TileNode, TileDimensions, Cut},
+    models::{CutThickness, TileNode, TileDimensions, Cut},
     errors::Result,
 };
 
@@ -15,20 +15,21 @@ impl CutListThread {
         &self,
         node: &TileNode,
         tile_dimensions: &TileDimensions,
-        cut_thickness: i32,
+        cut_thickness: impl Into<CutThickness>,
     ) -> Result<Vec<Cut>> {
+        let cut_thickness = cut_thickness.into();
         let mut cuts = Vec::new();
         let mut working_node = node.clone();
         
         if node.width() > tile_dimensions.width {
-            let cut = self.split_horizontally_with_children(&mut working_node, tile_dimensions.width, cut_thickness)?;
+            let cut = self.split_horizontally_with_children(&mut working_node, tile_dimensions.width, cut_thickness.horizontal())?;
             cuts.push(cut);
             
             if node.height() > tile_dimensions.height {
                 // Split the left child (child1) vertically
                 if let Some(child1) = working_node.child1() {
                     let mut child1_clone = child1.clone();
-                    let vertical_cut = self.split_vertically_with_children(&mut child1_clone, tile_dimensions.height, cut_thickness)?;
+                    let vertical_cut = self.split_vertically_with_children(&mut child1_clone, tile_dimensions.height, cut_thickness.vertical())?;
                     cuts.push(vertical_cut);
                     
                     // Mark the final tile
@@ -49,7 +50,7 @@ impl CutListThread {
                 }
             }
         } else if node.height() > tile_dimensions.height {
-            let cut = self.split_vertically_with_children(&mut working_node, tile_dimensions.height, cut_thickness)?;
+            let cut = self.split_vertically_with_children(&mut working_node, tile_dimensions.height, cut_thickness.vertical())?;
             cuts.push(cut);
             
             // Mark child1 as final
@@ -69,20 +70,21 @@ impl CutListThread {
         &self,
         node: &TileNode,
         tile_dimensions: &TileDimensions,
-        cut_thickness: i32,
+        cut_thickness: impl Into<CutThickness>,
     ) -> Result<Vec<Cut>> {
+        let cut_thickness = cut_thickness.into();
         let mut cuts = Vec::new();
         let mut working_node = node.clone();
         
         if node.height() > tile_dimensions.height {
-            let cut = self.split_vertically_with_children(&mut working_node, tile_dimensions.height, cut_thickness)?;
+            let cut = self.split_vertically_with_children(&mut working_node, tile_dimensions.height, cut_thickness.vertical())?;
             cuts.push(cut);
             
             if node.width() > tile_dimensions.width {
                 // Split the top child (child1) horizontally
                 if let Some(child1) = working_node.child1() {
                     let mut child1_clone = child1.clone();
-                    let horizontal_cut = self.split_horizontally_with_children(&mut child1_clone, tile_dimensions.width, cut_thickness)?;
+                    let horizontal_cut = self.split_horizontally_with_children(&mut child1_clone, tile_dimensions.width, cut_thickness.horizontal())?;
                     cuts.push(horizontal_cut);
                     
                     // Mark the final tile
@@ -103,7 +105,7 @@ impl CutListThread {
                 }
             }
         } else if node.width() > tile_dimensions.width {
-            let cut = self.split_horizontally_with_children(&mut working_node, tile_dimensions.width, cut_thickness)?;
+            let cut = self.split_horizontally_with_children(&mut working_node, tile_dimensions.width, cut_thickness.horizontal())?;
             cuts.push(cut);
             
             // Mark child1 as final
@@ -185,9 +187,10 @@ impl CutListThread {
             node.y2(),
         );
         
-        // Create child2 (right part)
+        // Create child2 (right part), starting past the kerf; a kerf that
+        // consumes the whole remainder leaves it empty
         let child2 = TileNode::new(
-            node.x1() + width + cut_thickness,
+            (node.x1() + width + cut_thickness).min(node.x2()),
             node.x2(),
             node.y1(),
             node.y2(),
@@ -238,11 +241,11 @@ impl CutListThread {
             node.y1() + height,
         );
         
-        // Create child2 (bottom part)
+        // Create child2 (bottom part), starting past the kerf
         let child2 = TileNode::new(
             node.x1(),
             node.x2(),
-            node.y1() + height + cut_thickness,
+            (node.y1() + height + cut_thickness).min(node.y2()),
             node.y2(),
         );
         
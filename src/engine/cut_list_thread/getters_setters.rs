@@ -4,13 +4,14 @@
 
 use crate::{
     models::{
-        Solution, TileDimensions,
+        CutThickness, Solution, TileDimensions,
         task::Task,
     },
-    CutDirection, Status,
+    CutDirection, FirstCutRule, GrainMode, PlacementHeuristic, Status,
 };
 use crate::engine::stock::StockSolution;
 use std::{
+    collections::HashMap,
     sync::{Arc, Mutex},
     time::Duration,
 };
@@ -68,12 +69,29 @@ impl CutListThread {
         self.status
     }
 
-    pub fn cut_thickness(&self) -> i32 {
+    pub fn cut_thickness(&self) -> CutThickness {
         self.cut_thickness
     }
 
-    pub fn set_cut_thickness(&mut self, thickness: i32) {
-        self.cut_thickness = thickness;
+    /// Set the kerf; a plain `i32` applies the same width to both axes
+    pub fn set_cut_thickness(&mut self, thickness: impl Into<CutThickness>) {
+        self.cut_thickness = thickness.into();
+    }
+
+    pub fn material_cut_thicknesses(&self) -> &HashMap<String, CutThickness> {
+        &self.material_cut_thicknesses
+    }
+
+    pub fn set_material_cut_thicknesses(&mut self, thicknesses: HashMap<String, CutThickness>) {
+        self.material_cut_thicknesses = thicknesses;
+    }
+
+    /// Kerf used when cutting a tile of `material`
+    pub fn cut_thickness_for(&self, material: &str) -> CutThickness {
+        self.material_cut_thicknesses
+            .get(material)
+            .copied()
+            .unwrap_or(self.cut_thickness)
     }
 
     pub fn min_trim_dimension(&self) -> i32 {
@@ -92,12 +110,77 @@ impl CutListThread {
         self.first_cut_orientation = orientation;
     }
 
+    pub fn material_cut_directions(&self) -> &HashMap<String, CutDirection> {
+        &self.material_cut_directions
+    }
+
+    pub fn set_material_cut_directions(&mut self, directions: HashMap<String, CutDirection>) {
+        self.material_cut_directions = directions;
+    }
+
+    /// First-cut direction used on stock of `material`
+    pub fn first_cut_orientation_for(&self, material: &str) -> CutDirection {
+        self.material_cut_directions
+            .get(material)
+            .copied()
+            .unwrap_or(self.first_cut_orientation)
+    }
+
+    /// Whether grained tiles are turned to follow the stock grain
     pub fn consider_grain_direction(&self) -> bool {
-        self.consider_grain_direction
+        self.grain_mode == GrainMode::Alternating
     }
 
     pub fn set_consider_grain_direction(&mut self, consider: bool) {
-        self.consider_grain_direction = consider;
+        self.grain_mode = if consider { GrainMode::Alternating } else { GrainMode::Fixed };
+    }
+
+    pub fn grain_mode(&self) -> GrainMode {
+        self.grain_mode
+    }
+
+    pub fn set_grain_mode(&mut self, grain_mode: GrainMode) {
+        self.grain_mode = grain_mode;
+    }
+
+    pub fn first_cut_rule(&self) -> FirstCutRule {
+        self.first_cut_rule
+    }
+
+    pub fn set_first_cut_rule(&mut self, first_cut_rule: FirstCutRule) {
+        self.first_cut_rule = first_cut_rule;
+    }
+
+    pub fn placement_heuristic(&self) -> PlacementHeuristic {
+        self.placement_heuristic
+    }
+
+    pub fn set_placement_heuristic(&mut self, placement_heuristic: PlacementHeuristic) {
+        self.placement_heuristic = placement_heuristic;
+    }
+
+    pub fn rotation_tolerance(&self) -> i32 {
+        self.rotation_tolerance
+    }
+
+    pub fn set_rotation_tolerance(&mut self, rotation_tolerance: i32) {
+        self.rotation_tolerance = rotation_tolerance;
+    }
+
+    pub fn group_identical_tiles(&self) -> bool {
+        self.group_identical_tiles
+    }
+
+    pub fn set_group_identical_tiles(&mut self, group_identical_tiles: bool) {
+        self.group_identical_tiles = group_identical_tiles;
+    }
+
+    pub fn fit_within_kerf(&self) -> bool {
+        self.fit_within_kerf
+    }
+
+    pub fn set_fit_within_kerf(&mut self, fit_within_kerf: bool) {
+        self.fit_within_kerf = fit_within_kerf;
     }
 
     pub fn percentage_done(&self) -> i32 {
@@ -114,6 +197,15 @@ impl CutListThread {
         self.tiles = tiles;
     }
 
+    pub fn allowed_stock_ids(&self) -> &HashMap<i32, Vec<i32>> {
+        &self.allowed_stock_ids
+    }
+
+    /// Restrict tiles (by tile ID) to the listed stock IDs
+    pub fn set_allowed_stock_ids(&mut self, allowed_stock_ids: HashMap<i32, Vec<i32>>) {
+        self.allowed_stock_ids = allowed_stock_ids;
+    }
+
     pub fn solutions(&self) -> &[Solution] {
         &self.solutions
     }
@@ -130,6 +222,22 @@ impl CutListThread {
         self.accuracy_factor = factor;
     }
 
+    pub fn max_solutions_retained(&self) -> Option<usize> {
+        self.max_solutions_retained
+    }
+
+    pub fn set_max_solutions_retained(&mut self, max_solutions_retained: Option<usize>) {
+        self.max_solutions_retained = max_solutions_retained;
+    }
+
+    pub fn max_cuts_per_sheet(&self) -> Option<usize> {
+        self.max_cuts_per_sheet
+    }
+
+    pub fn set_max_cuts_per_sheet(&mut self, max_cuts_per_sheet: Option<usize>) {
+        self.max_cuts_per_sheet = max_cuts_per_sheet;
+    }
+
     pub fn all_solutions(&self) -> Arc<Mutex<Vec<Solution>>> {
         self.all_solutions.clone()
     }
@@ -10,6 +10,7 @@ pub mod validation;
 pub mod core_computation;
 pub mod tile_fitting;
 pub mod cutting_strategies;
+pub mod tile_grouping;
 pub mod execution_impls;
 
 // Re-export the main types
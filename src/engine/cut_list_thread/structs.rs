@@ -4,14 +4,15 @@
 
 use crate::{
     models::{
-        Solution, TileDimensions,
+        CutThickness, Solution, TileDimensions,
         task::Task,
     },
     constants::ConfigurationDefaults,
-    CutDirection, Status,
+    CutDirection, FirstCutRule, GrainMode, PlacementHeuristic, Status,
 };
 use crate::engine::stock::StockSolution;
 use std::{
+    collections::HashMap,
     sync::{Arc, Mutex},
     time::Instant,
 };
@@ -26,13 +27,32 @@ pub type SolutionComparator = Box<dyn Fn(&Solution, &Solution) -> std::cmp::Orde
 pub struct CutListThread {
     // Configuration fields
     pub(crate) accuracy_factor: usize,
-    pub(crate) cut_thickness: i32,
+    /// Cap on retained solutions beyond `accuracy_factor`
+    pub(crate) max_solutions_retained: Option<usize>,
+    /// Most cuts a single mosaic may hold
+    pub(crate) max_cuts_per_sheet: Option<usize>,
+    pub(crate) cut_thickness: CutThickness,
+    /// Kerf per tile material, overriding `cut_thickness`
+    pub(crate) material_cut_thicknesses: HashMap<String, CutThickness>,
     pub(crate) min_trim_dimension: i32,
     pub(crate) first_cut_orientation: CutDirection,
-    pub(crate) consider_grain_direction: bool,
+    /// First-cut direction per stock material, overriding `first_cut_orientation`
+    pub(crate) material_cut_directions: HashMap<String, CutDirection>,
+    pub(crate) grain_mode: GrainMode,
+    /// Resolves `CutDirection::Both` per node
+    pub(crate) first_cut_rule: FirstCutRule,
+    pub(crate) placement_heuristic: PlacementHeuristic,
+    /// Tiles whose sides differ by less than this are only tried unrotated
+    pub(crate) rotation_tolerance: i32,
+    /// Keep identical tiles together as one contiguous run per sheet
+    pub(crate) group_identical_tiles: bool,
+    /// Accept nodes at most one kerf larger than a tile regardless of the minimum trim
+    pub(crate) fit_within_kerf: bool,
     
     // Input data
     pub(crate) tiles: Vec<TileDimensions>,
+    /// Stock IDs each tile ID is restricted to; tiles not listed may use any stock
+    pub(crate) allowed_stock_ids: HashMap<i32, Vec<i32>>,
     pub(crate) stock_solution: Option<StockSolution>,
     pub(crate) task: Option<Arc<Mutex<Task>>>,
     
@@ -57,11 +77,21 @@ impl CutListThread {
     pub fn new() -> Self {
         Self {
             accuracy_factor: ConfigurationDefaults::DEFAULT_ACCURACY_FACTOR as usize,
-            cut_thickness: 0,
+            max_solutions_retained: None,
+            max_cuts_per_sheet: None,
+            cut_thickness: CutThickness::default(),
+            material_cut_thicknesses: HashMap::new(),
             min_trim_dimension: 0,
             first_cut_orientation: CutDirection::Both,
-            consider_grain_direction: false,
+            material_cut_directions: HashMap::new(),
+            grain_mode: GrainMode::default(),
+            first_cut_rule: FirstCutRule::default(),
+            placement_heuristic: PlacementHeuristic::default(),
+            rotation_tolerance: 0,
+            group_identical_tiles: false,
+            fit_within_kerf: true,
             tiles: Vec::new(),
+            allowed_stock_ids: HashMap::new(),
             stock_solution: None,
             task: None,
             thread_prioritized_comparators: Vec::new(),
@@ -87,10 +117,19 @@ impl std::fmt::Debug for CutListThread {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CutListThread")
             .field("accuracy_factor", &self.accuracy_factor)
+            .field("max_solutions_retained", &self.max_solutions_retained)
+            .field("max_cuts_per_sheet", &self.max_cuts_per_sheet)
             .field("cut_thickness", &self.cut_thickness)
+            .field("material_cut_thicknesses", &self.material_cut_thicknesses)
             .field("min_trim_dimension", &self.min_trim_dimension)
             .field("first_cut_orientation", &self.first_cut_orientation)
-            .field("consider_grain_direction", &self.consider_grain_direction)
+            .field("material_cut_directions", &self.material_cut_directions)
+            .field("grain_mode", &self.grain_mode)
+            .field("first_cut_rule", &self.first_cut_rule)
+            .field("placement_heuristic", &self.placement_heuristic)
+            .field("rotation_tolerance", &self.rotation_tolerance)
+            .field("group_identical_tiles", &self.group_identical_tiles)
+            .field("fit_within_kerf", &self.fit_within_kerf)
             .field("tiles", &self.tiles)
             .field("stock_solution", &self.stock_solution)
             .field("task", &self.task)
@@ -2,8 +2,10 @@
 //! 
 //! This module contains the logic for fitting tiles into mosaics and handling placement strategies.
 
+use std::collections::HashMap;
+
 use crate::{
-    models::{TileNode, TileDimensions, Mosaic, enums::cut_direction::CutDirection},
+    models::{CutThickness, Panel, Tile, TileNode, TileDimensions, Mosaic, enums::{CutDirection, GrainMode, PlacementHeuristic}},
     errors::Result,
     Orientation,
 };
@@ -11,70 +13,144 @@ use crate::{
 use super::structs::CutListThread;
 
 impl CutListThread {
+    /// Check whether a tile may be placed on the given stock
+    pub(crate) fn is_stock_allowed(&self, tile_dimensions: &TileDimensions, stock_id: i32) -> bool {
+        self.allowed_stock_ids
+            .get(&tile_dimensions.id)
+            .is_none_or(|ids| ids.contains(&stock_id))
+    }
+
+    /// Whether a tile's grain would run across the grain of the sheet
+    pub(crate) fn crosses_grain(tile_dimensions: &TileDimensions, mosaic: &Mosaic) -> bool {
+        let tile_grain = tile_dimensions.grain_direction();
+        tile_grain != Orientation::Any
+            && mosaic.orientation() != Orientation::Any
+            && tile_grain != mosaic.orientation()
+    }
+
+    /// Check whether a mosaic stays within the per-sheet cut limit
+    pub(crate) fn within_cut_limit(&self, mosaic: &Mosaic) -> bool {
+        self.max_cuts_per_sheet
+            .is_none_or(|limit| mosaic.nbr_cuts() <= limit)
+    }
+
+    /// Collect stock restrictions from panels that specify allowed stock IDs
+    /// or a stock affinity
+    pub fn allowed_stock_ids_from_panels(panels: &[Panel]) -> HashMap<i32, Vec<i32>> {
+        panels
+            .iter()
+            .filter_map(|panel| panel.permitted_stock_ids().map(|ids| (panel.id, ids)))
+            .collect()
+    }
+
     /// Add a tile to a mosaic, generating all possible fitting results
-    pub(crate) fn add_tile_to_mosaic(
+    ///
+    /// `grain_mode` decides which orientations of the tile are tried. Tiles
+    /// within the rotation tolerance of a square are only tried as given.
+    pub fn add_tile_to_mosaic(
         &self,
         tile_dimensions: &TileDimensions,
         mosaic: &Mosaic,
         results: &mut Vec<Mosaic>,
+        grain_mode: GrainMode,
     ) -> Result<()> {
-        // Check grain direction compatibility
-        if !self.consider_grain_direction 
-            || mosaic.orientation() == Orientation::Any 
-            || tile_dimensions.orientation == Orientation::Any {
-            self.fit_tile(tile_dimensions, mosaic, results, self.cut_thickness)?;
-            
-            if !tile_dimensions.is_square() {
-                let mut rotated_tile = tile_dimensions.clone();
-                rotated_tile.rotate_90();
-                self.fit_tile(&rotated_tile, mosaic, results, self.cut_thickness)?;
+        let has_grain = tile_dimensions.orientation != Orientation::Any;
+        let cut_thickness = self.cut_thickness_for(&tile_dimensions.material);
+        let rotated = || {
+            let mut rotated = tile_dimensions.clone();
+            rotated.rotate_90_ignoring_grain();
+            rotated
+        };
+
+        match grain_mode {
+            GrainMode::Fixed if has_grain => {
+                // Rotating would turn the grain, so only the given orientation is tried
+                self.fit_tile(tile_dimensions, mosaic, results, cut_thickness)?;
+            }
+            GrainMode::Alternating if has_grain && mosaic.orientation() != Orientation::Any => {
+                if mosaic.orientation() == tile_dimensions.orientation {
+                    self.fit_tile(tile_dimensions, mosaic, results, cut_thickness)?;
+                } else {
+                    self.fit_tile(&rotated(), mosaic, results, cut_thickness)?;
+                }
+            }
+            _ => {
+                self.fit_tile(tile_dimensions, mosaic, results, cut_thickness)?;
+                if !tile_dimensions.is_near_square(self.rotation_tolerance) {
+                    self.fit_tile(&rotated(), mosaic, results, cut_thickness)?;
+                }
             }
-        } else {
-            let tile_to_use = if mosaic.orientation() != tile_dimensions.orientation {
-                let mut rotated = tile_dimensions.clone();
-                rotated.rotate_90();
-                rotated
-            } else {
-                tile_dimensions.clone()
-            };
-            self.fit_tile(&tile_to_use, mosaic, results, self.cut_thickness)?;
         }
-        
+
         Ok(())
     }
 
     /// Fit a tile into a mosaic using various cutting strategies
+    ///
+    /// Unless grain is ignored, a tile with a grain direction is never placed
+    /// crosswise on a sheet that has one. The tile always goes in the top-left
+    /// corner of its node, so nodes where that would cover a keepout are skipped.
+    /// A tile the size of an untouched sheet takes the whole sheet without any
+    /// cut, whatever the kerf.
     pub(crate) fn fit_tile(
         &self,
         tile_dimensions: &TileDimensions,
         mosaic: &Mosaic,
         results: &mut Vec<Mosaic>,
-        cut_thickness: i32,
+        cut_thickness: CutThickness,
     ) -> Result<()> {
+        if self.grain_mode != GrainMode::Free && Self::crosses_grain(tile_dimensions, mosaic) {
+            return Ok(());
+        }
+
+        let root = mosaic.root_tile_node();
+        if !root.has_children()
+            && !root.is_final()
+            && root.width() == tile_dimensions.width
+            && root.height() == tile_dimensions.height
+        {
+            if mosaic.keepouts().is_empty() {
+                let mut new_mosaic = mosaic.clone();
+                Self::mark_final(new_mosaic.root_tile_node_mut(), tile_dimensions);
+                results.push(new_mosaic);
+            }
+            return Ok(());
+        }
+
         let mut candidates = Vec::new();
-        self.find_candidates(
+        self.find_candidates_with_kerf(
             tile_dimensions.width,
             tile_dimensions.height,
-            &mosaic.root_tile_node(),
+            cut_thickness,
+            root,
             &mut candidates,
         );
+        if !mosaic.keepouts().is_empty() {
+            candidates.retain(|candidate| {
+                mosaic.is_clear_of_keepouts(&Tile::new(
+                    candidate.x1(),
+                    candidate.x1() + tile_dimensions.width,
+                    candidate.y1(),
+                    candidate.y1() + tile_dimensions.height,
+                ))
+            });
+        }
+        if self.placement_heuristic == PlacementHeuristic::BottomLeft {
+            // The tile goes in the top-left corner of its node, so that corner is its position
+            candidates = candidates
+                .into_iter()
+                .min_by_key(|candidate| (candidate.y1(), candidate.x1()))
+                .into_iter()
+                .collect();
+        }
 
         for candidate in candidates {
             if candidate.width() == tile_dimensions.width 
                 && candidate.height() == tile_dimensions.height {
                 // Exact fit - copy the mosaic and mark the node as final
-                let root_copy = self.copy_tile_node(&mosaic.root_tile_node(), &candidate)?;
-                
-                // Find the corresponding node in the copy and mark it as final
-                if let Some(mut target_node) = self.find_corresponding_node(&root_copy, &candidate) {
-                    target_node.set_external_id(Some(tile_dimensions.id));
-                    target_node.set_final(true);
-                    target_node.set_rotated(tile_dimensions.is_rotated);
-                    
-                    let mut new_mosaic = mosaic.clone();
-                    new_mosaic.set_root_tile_node(root_copy);
-                    new_mosaic.set_stock_id(mosaic.stock_id());
-                    new_mosaic.set_orientation(mosaic.orientation());
+                let mut new_mosaic = mosaic.clone();
+                if let Some(target_node) = Self::find_node_mut(new_mosaic.root_tile_node_mut(), &candidate) {
+                    Self::mark_final(target_node, tile_dimensions);
                     results.push(new_mosaic);
                 }
             } else {
@@ -93,9 +169,13 @@ impl CutListThread {
         mosaic: &Mosaic,
         candidate: &TileNode,
         results: &mut Vec<Mosaic>,
-        cut_thickness: i32,
+        cut_thickness: CutThickness,
     ) -> Result<()> {
-        match self.first_cut_orientation {
+        let direction = match self.first_cut_orientation_for(mosaic.material()) {
+            CutDirection::Both => self.first_cut_rule.direction_for(candidate.width(), candidate.height()),
+            direction => direction,
+        };
+        match direction {
             CutDirection::Both => {
                 self.try_horizontal_first_cut(tile_dimensions, mosaic, candidate, results, cut_thickness)?;
                 self.try_vertical_first_cut(tile_dimensions, mosaic, candidate, results, cut_thickness)?;
@@ -117,15 +197,39 @@ impl CutListThread {
         mosaic: &Mosaic,
         candidate: &TileNode,
         results: &mut Vec<Mosaic>,
-        cut_thickness: i32,
+        cut_thickness: CutThickness,
     ) -> Result<()> {
         let mut new_mosaic = mosaic.clone();
-        let cuts = self.split_hv(candidate, tile_dimensions, cut_thickness)?;
-        
+        let mut cuts = Vec::new();
+
+        {
+            let Some(mut node) = Self::find_node_mut(new_mosaic.root_tile_node_mut(), candidate) else {
+                return Ok(());
+            };
+
+            if node.width() > tile_dimensions.width {
+                cuts.push(self.split_horizontally_with_children(node, tile_dimensions.width, cut_thickness.horizontal())?);
+                node = match node.child1_mut() {
+                    Some(child) => child,
+                    None => return Ok(()),
+                };
+            }
+
+            if node.height() > tile_dimensions.height {
+                cuts.push(self.split_vertically_with_children(node, tile_dimensions.height, cut_thickness.vertical())?);
+                node = match node.child1_mut() {
+                    Some(child) => child,
+                    None => return Ok(()),
+                };
+            }
+
+            Self::mark_final(node, tile_dimensions);
+        }
+
         for cut in cuts {
             new_mosaic.add_cut(cut);
         }
-        
+
         results.push(new_mosaic);
         Ok(())
     }
@@ -137,58 +241,77 @@ impl CutListThread {
         mosaic: &Mosaic,
         candidate: &TileNode,
         results: &mut Vec<Mosaic>,
-        cut_thickness: i32,
+        cut_thickness: CutThickness,
     ) -> Result<()> {
         let mut new_mosaic = mosaic.clone();
-        let cuts = self.split_vh(candidate, tile_dimensions, cut_thickness)?;
-        
+        let mut cuts = Vec::new();
+
+        {
+            let Some(mut node) = Self::find_node_mut(new_mosaic.root_tile_node_mut(), candidate) else {
+                return Ok(());
+            };
+
+            if node.height() > tile_dimensions.height {
+                cuts.push(self.split_vertically_with_children(node, tile_dimensions.height, cut_thickness.vertical())?);
+                node = match node.child1_mut() {
+                    Some(child) => child,
+                    None => return Ok(()),
+                };
+            }
+
+            if node.width() > tile_dimensions.width {
+                cuts.push(self.split_horizontally_with_children(node, tile_dimensions.width, cut_thickness.horizontal())?);
+                node = match node.child1_mut() {
+                    Some(child) => child,
+                    None => return Ok(()),
+                };
+            }
+
+            Self::mark_final(node, tile_dimensions);
+        }
+
         for cut in cuts {
             new_mosaic.add_cut(cut);
         }
-        
+
         results.push(new_mosaic);
         Ok(())
     }
 
-    /// Find the corresponding node in a copied tree structure
-    pub(crate) fn find_corresponding_node(
-        &self,
-        root_copy: &TileNode,
-        original_target: &TileNode,
-    ) -> Option<TileNode> {
-        self.find_node_by_coordinates(
-            root_copy,
-            original_target.x1(),
-            original_target.y1(),
-            original_target.x2(),
-            original_target.y2(),
-        )
+    /// Mark a node as holding the given tile
+    pub(crate) fn mark_final(node: &mut TileNode, tile_dimensions: &TileDimensions) {
+        node.set_external_id(Some(tile_dimensions.id));
+        node.set_label(tile_dimensions.label.clone());
+        node.set_final(true);
+        node.set_rotated(tile_dimensions.is_rotated);
     }
 
-    /// Find a node by its coordinates in the tree
-    fn find_node_by_coordinates(
-        &self,
-        node: &TileNode,
-        x1: i32,
-        y1: i32,
-        x2: i32,
-        y2: i32,
-    ) -> Option<TileNode> {
-        if node.x1() == x1 && node.y1() == y1 && node.x2() == x2 && node.y2() == y2 {
-            return Some(node.clone());
+    /// Find the leaf in a copied tree that occupies the same area as `original_target`
+    pub(crate) fn find_node_mut<'a>(
+        node: &'a mut TileNode,
+        original_target: &TileNode,
+    ) -> Option<&'a mut TileNode> {
+        if node.x1() == original_target.x1()
+            && node.y1() == original_target.y1()
+            && node.x2() == original_target.x2()
+            && node.y2() == original_target.y2()
+            && !node.has_children()
+        {
+            return Some(node);
         }
 
-        // Search in children
-        if let Some(child1) = node.child1() {
-            if let Some(found) = self.find_node_by_coordinates(child1, x1, y1, x2, y2) {
-                return Some(found);
-            }
-        }
+        let contains = |child: &TileNode| {
+            child.x1() <= original_target.x1()
+                && child.y1() <= original_target.y1()
+                && child.x2() >= original_target.x2()
+                && child.y2() >= original_target.y2()
+        };
 
-        if let Some(child2) = node.child2() {
-            if let Some(found) = self.find_node_by_coordinates(child2, x1, y1, x2, y2) {
-                return Some(found);
-            }
+        if node.child1().is_some_and(contains) {
+            return Self::find_node_mut(node.child1_mut()?, original_target);
+        }
+        if node.child2().is_some_and(contains) {
+            return Self::find_node_mut(node.child2_mut()?, original_target);
         }
 
         None
@@ -0,0 +1,100 @@
+//! Identical tile grouping for CutListThread
+//!
+//! When grouping is enabled, tiles sharing a grouping signature are fed to the
+//! placement loop back to back, and every copy after the first must land on
+//! the sheet already holding the group, forming one contiguous run of final
+//! nodes there.
+
+use std::collections::HashSet;
+
+use crate::errors::Result;
+use crate::models::{Mosaic, Solution, TileDimensions, TileNode};
+
+use super::structs::CutListThread;
+
+impl CutListThread {
+    /// Reorder tiles so identical ones are consecutive
+    ///
+    /// Groups keep the position of their first tile, and tiles within a group
+    /// keep their relative order.
+    pub fn cluster_identical_tiles(&mut self) {
+        let mut first_seen: Vec<String> = Vec::new();
+        for tile in &self.tiles {
+            let signature = tile.get_grouping_signature();
+            if !first_seen.contains(&signature) {
+                first_seen.push(signature);
+            }
+        }
+        self.tiles.sort_by_key(|tile| {
+            let signature = tile.get_grouping_signature();
+            first_seen.iter().position(|seen| *seen == signature)
+        });
+    }
+
+    /// Whether `count` copies of `tile` fit on `mosaic` as one contiguous run
+    ///
+    /// Copies are placed greedily, each into the first contiguous fit.
+    pub fn group_fits(&self, mosaic: &Mosaic, tile: &TileDimensions, count: usize) -> Result<bool> {
+        let mut current = mosaic.clone();
+        for _ in 0..count {
+            let mut results = Vec::new();
+            self.add_tile_to_mosaic(tile, &current, &mut results, self.grain_mode)?;
+            match results.into_iter().find(Self::has_contiguous_groups) {
+                Some(next) => current = next,
+                None => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Whether some used or unused sheet of the solution can hold the whole group
+    pub fn group_home_exists(&self, solution: &Solution, tile: &TileDimensions, count: usize) -> Result<bool> {
+        for mosaic in solution.get_mosaics() {
+            if mosaic.material() == tile.material
+                && self.is_stock_allowed(tile, mosaic.stock_id())
+                && self.group_fits(mosaic, tile, count)?
+            {
+                return Ok(true);
+            }
+        }
+        for panel in solution.get_unused_stock_panels() {
+            if self.is_stock_allowed(tile, panel.id)
+                && self.group_fits(&Mosaic::from_tile_dimensions(panel), tile, count)?
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Whether the mosaic already holds a tile with this grouping signature
+    pub fn mosaic_holds_group(mosaic: &Mosaic, signature: &str) -> bool {
+        mosaic
+            .final_tile_nodes()
+            .into_iter()
+            .any(|node| node_signature(node, mosaic.material()) == signature)
+    }
+
+    /// Whether every group of identical tiles forms one contiguous run of final nodes
+    pub fn has_contiguous_groups(mosaic: &Mosaic) -> bool {
+        let mut finished = HashSet::new();
+        let mut current: Option<String> = None;
+        for node in mosaic.final_tile_nodes() {
+            let signature = node_signature(node, mosaic.material());
+            if current.as_ref() == Some(&signature) {
+                continue;
+            }
+            if !finished.insert(signature.clone()) {
+                return false;
+            }
+            current = Some(signature);
+        }
+        true
+    }
+}
+
+fn node_signature(node: &TileNode, material: &str) -> String {
+    let mut dimensions = node.to_tile_dimensions();
+    dimensions.material = material.to_string();
+    dimensions.get_grouping_signature()
+}
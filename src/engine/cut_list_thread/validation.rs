@@ -12,6 +12,9 @@ use super::structs::{CutListThread, SolutionComparator};
 
 impl CutListThread {
     /// Sort solutions using the provided comparators
+    ///
+    /// Solutions the comparators consider equal are ordered by their structure
+    /// identifier, so the result does not depend on the input order.
     pub fn sort_solutions(&self, solutions: &mut [Solution], comparators: &[SolutionComparator]) {
         if comparators.is_empty() {
             return;
@@ -24,7 +27,7 @@ impl CutListThread {
                     return result;
                 }
             }
-            std::cmp::Ordering::Equal
+            a.get_structure_identifier().cmp(&b.get_structure_identifier())
         });
     }
 
@@ -36,7 +39,7 @@ impl CutListThread {
         }
 
         // Validate cut thickness
-        if self.cut_thickness < 0 {
+        if self.cut_thickness.is_negative() {
             return Err(AppError::invalid_input("Cut thickness cannot be negative"));
         }
 
@@ -93,16 +96,24 @@ impl CutListThread {
         removed_count
     }
 
-    /// Sort and limit solutions based on comparators and accuracy factor
+    /// Sort and limit solutions based on comparators, accuracy factor and retention cap
     pub(crate) fn sort_and_limit_solutions(
         &self,
         solutions: &mut Vec<Solution>,
-        _use_thread_comparators: bool,
+        use_thread_comparators: bool,
     ) -> Result<()> {
-        // For now, just limit by accuracy factor
-        // In full implementation, this would use the comparators
-        if solutions.len() > self.accuracy_factor {
-            solutions.truncate(self.accuracy_factor);
+        let comparators = if use_thread_comparators {
+            &self.thread_prioritized_comparators
+        } else {
+            &self.final_solution_prioritized_comparators
+        };
+        self.sort_solutions(solutions, comparators);
+
+        let limit = self
+            .max_solutions_retained
+            .map_or(self.accuracy_factor, |max| self.accuracy_factor.min(max));
+        if solutions.len() > limit {
+            solutions.truncate(limit);
         }
         Ok(())
     }
@@ -0,0 +1,212 @@
+//! Grid packing for jobs of one part size on one sheet size
+//!
+//! When every tile is a copy of the same part and every sheet has the same
+//! size, the best guillotine layout of a sheet is a plain grid: rows cut
+//! across the sheet, each holding as many parts as fit side by side. The
+//! grid is computed directly instead of searching tile orders.
+
+use crate::engine::service::PlacementConfig;
+use crate::models::{CalculationRequest, Configuration, Cut, CutThickness, Mosaic, Solution, TileDimensions, TileNode};
+use crate::{CutDirection, Orientation};
+
+/// Pack the tiles of one material as a grid when the request leaves nothing for a search to decide
+///
+/// Requests with per-panel stock restrictions, L-shapes, optional copies,
+/// edge banding or a cut limit always go through the search, and so do
+/// requests changing how tiles are placed or cut from the defaults. A grid
+/// needing more sheets than `max_sheets` is left to the search as well.
+/// Otherwise see `grid_solution`.
+pub fn grid_solution_for(
+    request: &CalculationRequest,
+    tiles: &[TileDimensions],
+    stock_tiles: &[TileDimensions],
+    config: &PlacementConfig,
+) -> Option<Solution> {
+    let configuration = &config.configuration;
+    let defaults = Configuration::default();
+    let plain = request.min_quantities().is_empty()
+        && config.allowed_stock_ids.is_empty()
+        && tiles.iter().all(|tile| tile.l_shape.is_none())
+        && !request.panels.iter().any(|panel| panel.enabled && panel.banding_thickness.is_some())
+        && configuration.max_cuts_per_sheet.is_none();
+    let default_placement = config.rotation_tolerance == 0
+        && config.material_cut_directions.is_empty()
+        && configuration.cut_direction() == CutDirection::Both
+        && configuration.first_cut_rule == defaults.first_cut_rule
+        && configuration.placement_heuristic == defaults.placement_heuristic
+        && configuration.fit_within_kerf == defaults.fit_within_kerf
+        && configuration.cut_style == defaults.cut_style;
+    if !plain || !default_placement {
+        return None;
+    }
+    let material = &tiles.first()?.material;
+    let kerf = config.material_cut_thicknesses.get(material).copied().unwrap_or(config.cut_thickness);
+    let solution = grid_solution(tiles, stock_tiles, kerf, config.min_trim_dimension)?;
+    let within_max_sheets = configuration.max_sheets.is_none_or(|max_sheets| solution.mosaics.len() <= max_sheets);
+    within_max_sheets.then_some(solution)
+}
+
+/// Lay out `tiles` as a grid on `stock_tiles`, sheet by sheet
+///
+/// Returns `None` when the job is not homogeneous or the grid cannot hold
+/// every tile, so the caller falls back to the search. Parts without a grain
+/// direction are laid out in whichever orientation fits more per sheet. Like
+/// the search, a part only goes where it fills the space exactly or leaves
+/// at least `min_trim`.
+pub fn grid_solution(
+    tiles: &[TileDimensions],
+    stock_tiles: &[TileDimensions],
+    kerf: CutThickness,
+    min_trim: i32,
+) -> Option<Solution> {
+    let part = tiles.first()?;
+    let sheet = stock_tiles.first()?;
+    let same_part = tiles.iter().all(|tile| {
+        tile.width == part.width
+            && tile.height == part.height
+            && tile.material == part.material
+            && tile.orientation == Orientation::Any
+            && tile.keepouts.is_empty()
+    });
+    let same_sheet = stock_tiles.iter().all(|stock| {
+        stock.width == sheet.width
+            && stock.height == sheet.height
+            && stock.edge_trim == sheet.edge_trim
+            && stock.material == part.material
+            && stock.keepouts.is_empty()
+    });
+    if !same_part || !same_sheet {
+        return None;
+    }
+
+    let usable = Mosaic::from_tile_dimensions(sheet);
+    let (width, height) = (usable.width(), usable.height());
+    let mut rotated = part.clone();
+    rotated.rotate_90_ignoring_grain();
+    let grid = Grid { kerf, min_trim };
+    let part = if grid.per_sheet(&rotated, width, height) > grid.per_sheet(part, width, height) {
+        rotated
+    } else {
+        part.clone()
+    };
+    if grid.per_sheet(&part, width, height) * stock_tiles.len() < tiles.len() {
+        return None;
+    }
+
+    let mut solution = Solution::new();
+    let mut remaining = tiles.to_vec();
+    let mut sheets = stock_tiles.iter();
+    while !remaining.is_empty() {
+        let mut mosaic = Mosaic::from_tile_dimensions(sheets.next()?);
+        let mut cuts = Vec::new();
+        grid.fill_rows(&mut mosaic.root_tile_node, &part, &mut remaining, &mut cuts);
+        mosaic.cuts = cuts;
+        solution.mosaics.push(mosaic);
+    }
+    solution.unused_stock_panels = sheets.cloned().collect();
+    solution.grid_packed = true;
+    Some(solution)
+}
+
+/// Spacing rules of the grid
+struct Grid {
+    kerf: CutThickness,
+    min_trim: i32,
+}
+
+impl Grid {
+    /// Whether a part of `part_length` may be cut from a `length` long space
+    fn fits(&self, length: i32, part_length: i32) -> bool {
+        length == part_length || length >= part_length + self.min_trim
+    }
+
+    /// Number of parts of `part_length` in a row of `length`
+    fn count(&self, mut length: i32, part_length: i32, kerf: i32) -> usize {
+        let mut count = 0;
+        while part_length > 0 && self.fits(length, part_length) {
+            count += 1;
+            length -= part_length + kerf;
+        }
+        count
+    }
+
+    /// Number of `part`s a `width` x `height` sheet holds
+    fn per_sheet(&self, part: &TileDimensions, width: i32, height: i32) -> usize {
+        self.count(width, part.width, self.kerf.horizontal()) * self.count(height, part.height, self.kerf.vertical())
+    }
+
+    /// Cut rows of `part`'s height off `node` and fill each with parts
+    fn fill_rows(&self, node: &mut TileNode, part: &TileDimensions, remaining: &mut Vec<TileDimensions>, cuts: &mut Vec<Cut>) {
+        if remaining.is_empty() || !self.fits(node.height(), part.height) || !self.fits(node.width(), part.width) {
+            return;
+        }
+        if node.height() == part.height {
+            self.fill_row(node, part, remaining, cuts);
+            return;
+        }
+        split(node, part.height, self.kerf.vertical(), false, cuts);
+        if let Some(row) = node.child1.as_deref_mut() {
+            self.fill_row(row, part, remaining, cuts);
+        }
+        if let Some(rest) = node.child2.as_deref_mut() {
+            self.fill_rows(rest, part, remaining, cuts);
+        }
+    }
+
+    /// Cut parts of `part`'s width off the row `node`
+    fn fill_row(&self, node: &mut TileNode, part: &TileDimensions, remaining: &mut Vec<TileDimensions>, cuts: &mut Vec<Cut>) {
+        if !self.fits(node.width(), part.width) {
+            return;
+        }
+        let Some(tile) = remaining.pop() else {
+            return;
+        };
+        if node.width() == part.width {
+            place(node, &tile, part.is_rotated);
+            return;
+        }
+        split(node, part.width, self.kerf.horizontal(), true, cuts);
+        if let Some(placed) = node.child1.as_deref_mut() {
+            place(placed, &tile, part.is_rotated);
+        }
+        if let Some(rest) = node.child2.as_deref_mut() {
+            self.fill_row(rest, part, remaining, cuts);
+        }
+    }
+}
+
+/// Mark `node` as the final tile of `tile`
+fn place(node: &mut TileNode, tile: &TileDimensions, is_rotated: bool) {
+    node.set_final(true);
+    node.set_external_id(Some(tile.id));
+    node.set_label(tile.label.clone());
+    node.set_rotated(is_rotated);
+}
+
+/// Cut `length` off the origin side of `node`; the remainder starts past the kerf
+///
+/// A horizontal cut runs at constant x and divides the width, as in
+/// `CutListThread::split_horizontally_with_children`.
+fn split(node: &mut TileNode, length: i32, kerf: i32, horizontal: bool, cuts: &mut Vec<Cut>) {
+    let (x1, x2, y1, y2) = (node.x1(), node.x2(), node.y1(), node.y2());
+    let (child1, child2, cut) = if horizontal {
+        let at = x1 + length;
+        let child1 = TileNode::new(x1, at, y1, y2);
+        let child2 = TileNode::new((at + kerf).min(x2), x2, y1, y2);
+        (child1, child2, (at, y1, at, y2))
+    } else {
+        let at = y1 + length;
+        let child1 = TileNode::new(x1, x2, y1, at);
+        let child2 = TileNode::new(x1, x2, (at + kerf).min(y2), y2);
+        (child1, child2, (x1, at, x2, at))
+    };
+    cuts.push(Cut::new(
+        cut.0, cut.1, cut.2, cut.3,
+        node.width(), node.height(), horizontal, length,
+        node.id() as i32, child1.id() as i32, child2.id() as i32,
+    ));
+    node.set_child1(Some(child1));
+    if child2.area() > 0 {
+        node.set_child2(Some(child2));
+    }
+}
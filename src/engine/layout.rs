@@ -0,0 +1,251 @@
+//! Layout of a request computed by the service's task computation
+//!
+//! `optimize` is the entry point used by the CLI. It computes the request as
+//! a task of its own, with the configured `PlacementAlgorithm` and the
+//! search chosen in `OptimizeOptions`, and waits for the task's result. The
+//! `compute_layout*` functions do the same on a runtime of their own, for
+//! callers outside an async context.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::engine::running_tasks::{get_running_tasks_instance, TaskManager};
+use crate::engine::service::computation::{task_compute, ComputeHooks};
+use crate::engine::service::validation::RequestValidator;
+use crate::engine::service::{GuillotinePlacement, PlacementAlgorithm};
+use crate::errors::{AppError, Result};
+use crate::logging::log_info;
+use crate::models::{CalculationRequest, Configuration, CutThickness, Solution};
+use crate::utils::math::UnitFormat;
+
+/// Source of the ids of the tasks layouts are computed as
+static LAYOUT_TASK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Options controlling how a layout is computed
+#[derive(Debug, Clone)]
+pub struct OptimizeOptions {
+    /// Cut thickness (kerf) in mm; the configuration's when `None`
+    pub cut_thickness: Option<i32>,
+    /// Minimum trim dimension in mm; the configuration's when `None`
+    pub min_trim: Option<i32>,
+    /// Optimization accuracy (1-10); the configuration's when `None`
+    pub accuracy: Option<i32>,
+    /// Free barely-used sheets by moving their panels onto other sheets; also
+    /// on when the configuration's `balance_sheets` is
+    pub balance_sheets: bool,
+    /// List cuts in an order that turns the sheet as rarely as possible
+    pub optimize_cut_order: bool,
+    /// Unit table and cut map dimensions are printed in; bare millimetres when `None`
+    pub units: Option<UnitFormat>,
+    /// Algorithm placing tiles on stock
+    pub placement: Arc<dyn PlacementAlgorithm>,
+    /// Tile orders tried for the layout
+    pub search: LayoutSearch,
+    /// Permutations evaluated at once; see `ComputeHooks::threads`
+    pub threads: usize,
+}
+
+impl Default for OptimizeOptions {
+    fn default() -> Self {
+        Self {
+            cut_thickness: None,
+            min_trim: None,
+            accuracy: None,
+            balance_sheets: false,
+            optimize_cut_order: false,
+            units: None,
+            placement: Arc::new(GuillotinePlacement),
+            search: LayoutSearch::default(),
+            threads: 1,
+        }
+    }
+}
+
+impl OptimizeOptions {
+    /// `configuration` with the kerf, trim, accuracy, sheet balancing and
+    /// search set in these options
+    ///
+    /// A `max_permutations` in the configuration takes precedence over the
+    /// count of a permutation search.
+    fn apply_to(&self, mut configuration: Configuration) -> Configuration {
+        if let Some(cut_thickness) = self.cut_thickness {
+            configuration.cut_thickness = CutThickness::from(cut_thickness);
+        }
+        if let Some(min_trim) = self.min_trim {
+            configuration.min_trim_dimension = min_trim;
+        }
+        if let Some(accuracy) = self.accuracy {
+            configuration.optimization_factor = accuracy;
+        }
+        configuration.balance_sheets |= self.balance_sheets;
+        configuration.max_permutations = match self.search {
+            LayoutSearch::Single => Some(1),
+            LayoutSearch::Permutations(count) => configuration.max_permutations.or(count),
+            LayoutSearch::Parallel => configuration.max_permutations,
+        };
+        configuration
+    }
+}
+
+/// Tile orders a layout search tries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutSearch {
+    /// Tiles by descending area only
+    #[default]
+    Single,
+    /// As many permutations as the part list calls for, evaluated on every
+    /// thread; see `compute_layout_parallel`
+    Parallel,
+    /// Orders keeping identical tiles together; see `compute_layout_permutations`.
+    /// Without a count, it is chosen from the part list as in
+    /// `compute_layout_adaptive_permutations`
+    Permutations(Option<usize>),
+}
+
+/// Compute a layout for the request using `options`
+///
+/// The request is validated as the service validates submitted tasks, then
+/// computed as a task with `options.placement` on `options.threads` threads;
+/// see `task_compute::compute_task_to_end`. The task is removed once its
+/// result is built; see `Task::final_solution`.
+///
+/// Returns the best solution together with the factor dimensions were scaled by.
+pub async fn optimize(request: &CalculationRequest, options: &OptimizeOptions) -> Result<(Solution, f64)> {
+    let mut request = request.clone();
+    request.configuration = Some(options.apply_to(request.configuration.unwrap_or_default()));
+    if let Some(status) = RequestValidator::validate_request(&request).await {
+        return Err(AppError::invalid_input(status.description()));
+    }
+
+    let task_id = format!("layout-{}", LAYOUT_TASK_COUNTER.fetch_add(1, Ordering::Relaxed));
+    let hooks = ComputeHooks {
+        placement: Arc::clone(&options.placement),
+        threads: options.threads.max(1),
+        ..ComputeHooks::default()
+    };
+    let computed = task_compute::compute_task_to_end(request, task_id.clone(), hooks).await;
+    let running_tasks = get_running_tasks_instance();
+    let result = computed.and_then(|task_arc| {
+        let task = task_arc.read();
+        let solution = task.final_solution()?;
+        log_info!("Layout task {} evaluated {} permutation(s)", task_id, solution.permutations_evaluated);
+        Ok((solution, task.factor()))
+    });
+    running_tasks.remove_task(&task_id)?;
+    result
+}
+
+/// Compute a layout for the request on a single-threaded search
+///
+/// Returns the best solution together with the factor dimensions were scaled by.
+pub fn compute_layout(
+    request: &CalculationRequest,
+    cut_thickness: i32,
+    min_trim: i32,
+    accuracy: i32,
+) -> Result<(Solution, f64)> {
+    let options = OptimizeOptions {
+        cut_thickness: Some(cut_thickness),
+        min_trim: Some(min_trim),
+        accuracy: Some(accuracy),
+        ..OptimizeOptions::default()
+    };
+    compute_layout_with_options(request, &options)
+}
+
+/// Compute a layout for the request using `options`, outside an async context
+///
+/// Runs `optimize` on a runtime of its own with `options.threads` threads.
+/// Jobs of one part size on one sheet size are packed as a grid instead of
+/// searched, marked `grid_packed`; see `grid_packing::grid_solution_for`.
+pub fn compute_layout_with_options(
+    request: &CalculationRequest,
+    options: &OptimizeOptions,
+) -> Result<(Solution, f64)> {
+    let threads = options.threads.max(1);
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(threads)
+        .max_blocking_threads(threads)
+        .enable_all()
+        .build()
+        .map_err(|e| AppError::thread_error(e.to_string()))?;
+    runtime.block_on(optimize(request, options))
+}
+
+/// Compute a layout by evaluating permutations on every available thread
+///
+/// The number of permutations is chosen from the part list, as in
+/// `compute_layout_adaptive_permutations`. With a `time_budget` in the
+/// configuration, permutations not yet started when the budget runs out are
+/// skipped and the solution is marked `time_bounded`. The first permutations
+/// always run, so a layout is still returned.
+pub fn compute_layout_parallel(
+    request: &CalculationRequest,
+    cut_thickness: i32,
+    min_trim: i32,
+    accuracy: i32,
+) -> Result<(Solution, f64)> {
+    let options = OptimizeOptions {
+        cut_thickness: Some(cut_thickness),
+        min_trim: Some(min_trim),
+        accuracy: Some(accuracy),
+        search: LayoutSearch::Parallel,
+        threads: std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+        ..OptimizeOptions::default()
+    };
+    compute_layout_with_options(request, &options)
+}
+
+/// Compute a layout by evaluating up to `max_permutations` tile orders, `threads` at a time
+///
+/// A `max_permutations` in the configuration takes precedence over the
+/// argument. Each order keeps identical tiles together; see
+/// `material_compute::compute_material`, which evaluates them in batches of
+/// `threads` and merges every batch in order, so a seeded search always
+/// yields the same layout for a given thread count.
+///
+/// With a `target_efficiency` in the configuration, the search stops after
+/// the batch in which a layout places every tile at or above the target. The
+/// number of orders laid out is recorded in `Solution::permutations_evaluated`.
+///
+/// With a `time_budget` in the configuration, orders not yet started when the
+/// budget runs out are skipped and the solution is marked `time_bounded`.
+///
+/// With `report_first_acceptable` in the configuration, every order is laid
+/// out regardless of the target, and the layout a search stopping at the
+/// target would have returned is kept in `Solution::first_acceptable`.
+/// Without a target it is the best layout.
+///
+/// Jobs of one part size on one sheet size skip the orders and are packed as
+/// a grid, marked `grid_packed`; see `grid_packing::grid_solution_for`.
+pub fn compute_layout_permutations(
+    request: &CalculationRequest,
+    options: &OptimizeOptions,
+    max_permutations: usize,
+    threads: usize,
+) -> Result<(Solution, f64)> {
+    let options = OptimizeOptions {
+        search: LayoutSearch::Permutations(Some(max_permutations)),
+        threads,
+        ..options.clone()
+    };
+    compute_layout_with_options(request, &options)
+}
+
+/// Compute a layout like `compute_layout_permutations`, with the number of
+/// tile orders chosen from the size and diversity of the part list
+///
+/// See `PermutationUtils::adaptive_permutation_count`. A `max_permutations`
+/// in the configuration still takes precedence.
+pub fn compute_layout_adaptive_permutations(
+    request: &CalculationRequest,
+    options: &OptimizeOptions,
+    threads: usize,
+) -> Result<(Solution, f64)> {
+    let options = OptimizeOptions {
+        search: LayoutSearch::Permutations(None),
+        threads,
+        ..options.clone()
+    };
+    compute_layout_with_options(request, &options)
+}
@@ -9,6 +9,9 @@ pub mod service;
 pub mod running_tasks;
 pub mod watch_dog;
 pub mod stock;
+pub mod grid_packing;
+pub mod consolidation;
+pub mod layout;
 
 
 pub use cut_list_thread::CutListThread;
@@ -20,6 +23,7 @@ pub use running_tasks::{
     StatusManager, 
     StatisticsCollector, 
     TaskCleanup as RunningTasksCleanup, 
+    TaskCheckpointing,
     TaskManagerSingleton,
     get_running_tasks_instance,
 };
@@ -0,0 +1,58 @@
+//! Task checkpointing operations
+//!
+//! This module writes task snapshots to disk and rebuilds tasks from them,
+//! so a restarted process can pick up long computations where they stopped.
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    errors::{AppError, Result},
+    models::{enums::Status, TaskCheckpoint},
+};
+use crate::logging::{debug, info};
+
+use super::{structs::RunningTasks, task_management::TaskManager};
+
+/// Trait for task checkpoint operations
+pub trait TaskCheckpointing {
+    /// Write a checkpoint of a task into `dir`, returning the file written
+    fn checkpoint_task(&self, task_id: &str, dir: &Path) -> Result<PathBuf>;
+
+    /// Write checkpoints of all running tasks into `dir`
+    fn checkpoint_running_tasks(&self, dir: &Path) -> Result<usize>;
+
+    /// Rebuild a task from a checkpoint file and add it, returning its ID
+    fn resume_from_checkpoint(&self, path: &Path) -> Result<String>;
+}
+
+impl TaskCheckpointing for RunningTasks {
+    /// Write a checkpoint of a task into `dir`, returning the file written
+    fn checkpoint_task(&self, task_id: &str, dir: &Path) -> Result<PathBuf> {
+        let task = self.get_task(task_id).ok_or_else(|| AppError::task_not_found(task_id))?;
+        let checkpoint = TaskCheckpoint::from_task(&task.read());
+
+        let path = TaskCheckpoint::path_for(dir, task_id);
+        checkpoint.write(&path)?;
+        debug!("Checkpointed task {} to {}", task_id, path.display());
+        Ok(path)
+    }
+
+    /// Write checkpoints of all running tasks into `dir`
+    fn checkpoint_running_tasks(&self, dir: &Path) -> Result<usize> {
+        let task_ids = self.get_tasks_with_status(Status::Running);
+        for task_id in &task_ids {
+            self.checkpoint_task(task_id, dir)?;
+        }
+        Ok(task_ids.len())
+    }
+
+    /// Rebuild a task from a checkpoint file and add it, returning its ID
+    fn resume_from_checkpoint(&self, path: &Path) -> Result<String> {
+        let checkpoint = TaskCheckpoint::read(path)?;
+        let task_id = checkpoint.task_id.clone();
+
+        self.add_task(checkpoint.to_task())?;
+        info!("Resumed task {} from {}", task_id, path.display());
+        Ok(task_id)
+    }
+}
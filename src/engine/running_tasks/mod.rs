@@ -8,12 +8,14 @@ pub mod task_management;
 pub mod status_management;
 pub mod statistics;
 pub mod cleanup;
+pub mod checkpoint;
 pub mod singleton;
 
 // Re-export the main struct and key types
-pub use structs::RunningTasks;
+pub use structs::{RunningTasks, ServiceTaskInfo};
 pub use task_management::TaskManager;
 pub use status_management::StatusManager;
 pub use statistics::StatisticsCollector;
 pub use cleanup::TaskCleanup;
+pub use checkpoint::TaskCheckpointing;
 pub use singleton::{TaskManagerSingleton, get_running_tasks_instance};
@@ -26,6 +26,32 @@ pub trait StatisticsCollector {
     fn get_finished_threads_count(&self) -> u64;
 }
 
+/// Average of millisecond durations, 0.0 when there are none
+fn average_ms(values: &[u64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<u64>() as f64 / values.len() as f64
+}
+
+impl RunningTasks {
+    /// Average queue wait and compute time across tasks, in milliseconds
+    ///
+    /// Tasks that are still queued are left out of the queue wait average, and
+    /// tasks that have not completed are left out of the compute time average.
+    pub fn average_latencies(&self) -> (f64, f64) {
+        let mut queue_waits = Vec::new();
+        let mut compute_times = Vec::new();
+
+        for info in self.task_info.iter() {
+            queue_waits.extend(info.queue_wait_time());
+            compute_times.extend(info.compute_time());
+        }
+
+        (average_ms(&queue_waits), average_ms(&compute_times))
+    }
+}
+
 impl StatisticsCollector for RunningTasks {
     /// Get current statistics
     fn get_stats(&self) -> Stats {
@@ -47,6 +73,7 @@ impl StatisticsCollector for RunningTasks {
             
             let progress_percentage = task.percentage_done();
             
+            let info = self.task_info(&task_id);
             let report = TaskReport {
                 task_id: task_id.clone(),
                 client_id,
@@ -57,12 +84,16 @@ impl StatisticsCollector for RunningTasks {
                     .unwrap_or_default()
                     .as_secs() as i64,
                 duration_ms: Some(running_time_ms),
+                queue_wait_ms: info.and_then(|info| info.queue_wait_time()).map(|ms| ms as i64),
+                compute_time_ms: info.and_then(|info| info.compute_time()).map(|ms| ms as i64),
                 error_message: None,
             };
             
             task_reports.push(report);
         }
         
+        let (avg_queue_wait, avg_compute_time) = self.average_latencies();
+        
         Stats {
             nbr_idle_tasks: self.nbr_idle_tasks.load(Ordering::Relaxed),
             nbr_running_tasks: self.nbr_running_tasks.load(Ordering::Relaxed),
@@ -73,6 +104,8 @@ impl StatisticsCollector for RunningTasks {
             nbr_running_threads: 0, // Will be set by service implementation
             nbr_queued_threads: 0,  // Will be set by service implementation
             nbr_finished_threads: self.nbr_finished_threads.load(Ordering::Relaxed) as i64,
+            avg_queue_wait,
+            avg_compute_time,
             task_reports,
         }
     }
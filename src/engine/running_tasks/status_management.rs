@@ -7,10 +7,10 @@ use crate::{
     errors::Result,
     models::enums::Status,
 };
-use std::sync::atomic::Ordering;
+use std::{sync::atomic::Ordering, time::SystemTime};
 use crate::logging::debug;
 
-use super::structs::RunningTasks;
+use super::structs::{RunningTasks, ServiceTaskInfo};
 
 /// Trait for status management operations
 pub trait StatusManager {
@@ -32,6 +32,7 @@ impl StatusManager for RunningTasks {
             
             self.decrement_status_counter(old_status);
             self.increment_status_counter(new_status);
+            self.record_status_time(task_id, new_status);
         }
         
         Ok(())
@@ -50,3 +51,46 @@ impl StatusManager for RunningTasks {
             .as_secs()
     }
 }
+
+impl RunningTasks {
+    /// Timing recorded for a task, if it was added to this collection
+    pub fn task_info(&self, task_id: &str) -> Option<ServiceTaskInfo> {
+        self.task_info.get(task_id).map(|info| *info)
+    }
+
+    /// Record when a task starts computing or reaches a final status
+    fn record_status_time(&self, task_id: &str, new_status: Status) {
+        let Some(mut info) = self.task_info.get_mut(task_id) else {
+            return;
+        };
+        let now = SystemTime::now();
+        match new_status {
+            Status::Running => {
+                info.compute_started_at.get_or_insert(now);
+            }
+            Status::Finished | Status::Stopped | Status::Terminated | Status::Error => {
+                info.completed_at.get_or_insert(now);
+            }
+            Status::Queued => {}
+        }
+    }
+}
+
+impl ServiceTaskInfo {
+    /// Time from submission until computation started, in milliseconds
+    ///
+    /// Returns `None` while the task is still queued.
+    pub fn queue_wait_time(&self) -> Option<u64> {
+        self.compute_started_at
+            .map(|started| started.duration_since(self.submitted_at).unwrap_or_default().as_millis() as u64)
+    }
+
+    /// Time spent computing, from the start of computation to completion, in milliseconds
+    ///
+    /// Returns `None` until the task has both started and completed.
+    pub fn compute_time(&self) -> Option<u64> {
+        let started = self.compute_started_at?;
+        let ended = self.completed_at?;
+        Some(ended.duration_since(started).unwrap_or_default().as_millis() as u64)
+    }
+}
@@ -24,6 +24,19 @@ pub struct TaskStats {
     pub failed_tasks: u64,
 }
 
+/// Service-side timing of a task, kept apart from the task model
+///
+/// Recorded by [`RunningTasks`] as the task is added and changes status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServiceTaskInfo {
+    /// When the task was submitted
+    pub submitted_at: SystemTime,
+    /// When computation started, `None` while queued
+    pub compute_started_at: Option<SystemTime>,
+    /// When the task finished, stopped, terminated or failed
+    pub completed_at: Option<SystemTime>,
+}
+
 /// Thread-safe container for managing running optimization tasks
 /// 
 /// This structure provides concurrent access to task storage and maintains
@@ -32,6 +45,9 @@ pub struct TaskStats {
 pub struct RunningTasks {
     /// Map of task_id -> Task for fast lookup (using DashMap for better concurrency)
     pub(crate) tasks: DashMap<String, Arc<RwLock<Task>>>,
+
+    /// Submission and computation times per task_id
+    pub(crate) task_info: DashMap<String, ServiceTaskInfo>,
     
     /// Atomic counters for different task states (optimized for performance)
     pub(crate) nbr_idle_tasks: AtomicI32,
@@ -57,6 +73,7 @@ impl RunningTasks {
     pub fn new() -> Self {
         Self {
             tasks: DashMap::new(),
+            task_info: DashMap::new(),
             nbr_idle_tasks: AtomicI32::new(0),
             nbr_running_tasks: AtomicI32::new(0),
             nbr_finished_tasks: AtomicI32::new(0),
@@ -111,6 +128,7 @@ impl RunningTasks {
     /// Get comprehensive statistics for monitoring
     pub fn get_comprehensive_stats(&self) -> Stats {
         let (idle, running, finished, stopped, terminated, error) = self.get_task_counts();
+        let (avg_queue_wait, avg_compute_time) = self.average_latencies();
         
         Stats {
             nbr_idle_tasks: idle,
@@ -122,6 +140,8 @@ impl RunningTasks {
             nbr_running_threads: 0, // Will be updated by thread pool
             nbr_queued_threads: 0,  // Will be updated by thread pool
             nbr_finished_threads: self.nbr_finished_threads.load(Ordering::Relaxed) as i64,
+            avg_queue_wait,
+            avg_compute_time,
             task_reports: Vec::new(), // Will be populated by watch dog
         }
     }
@@ -14,7 +14,7 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 use crate::logging::{debug, warn};
 
-use super::structs::RunningTasks;
+use super::structs::{RunningTasks, ServiceTaskInfo};
 
 /// Trait for task management operations
 pub trait TaskManager {
@@ -42,6 +42,12 @@ impl TaskManager for RunningTasks {
         
         debug!("Adding task {} with status {:?}", task_id, status);
         
+        self.task_info.insert(task_id.clone(), ServiceTaskInfo {
+            submitted_at: task.start_time,
+            compute_started_at: None,
+            completed_at: None,
+        });
+
         // Insert task into collection
         let task_arc = Arc::new(RwLock::new(task));
         if self.tasks.insert(task_id.clone(), task_arc).is_some() {
@@ -58,6 +64,7 @@ impl TaskManager for RunningTasks {
     fn remove_task(&self, task_id: &str) -> Result<Option<Arc<RwLock<Task>>>> {
         debug!("Removing task {}", task_id);
         
+        self.task_info.remove(task_id);
         if let Some((_, task_arc)) = self.tasks.remove(task_id) {
             let status = *task_arc.read().status.read().unwrap();
             self.decrement_status_counter(status);
@@ -177,6 +177,10 @@ pub fn debug_compute_complete(
     result.add_step("Grouping by materials".to_string());
     let (tiles_per_material, stock_per_material) = group_by_materials(&tiles, &stock_tiles);
     result.materials_found = tiles_per_material.keys().cloned().collect();
+    if let Some(seed) = request.configuration.as_ref().and_then(|config| config.seed) {
+        result.materials_found.sort();
+        arrangement::seeded_shuffle(&mut result.materials_found, seed);
+    }
     result.add_step(format!("Grouped tiles into {} materials", tiles_per_material.len()));
 
     if debug_config.verbose_logging {
@@ -286,7 +290,7 @@ pub fn debug_compute_material(
 
     // Step 3: Generate permutations
     result.add_step("Generating permutations".to_string());
-    let permutations = debug_generate_permutations(&distinct_groups, &mut result)?;
+    let permutations = debug_generate_permutations(&distinct_groups, configuration.seed, &mut result)?;
     result.permutations_generated = permutations.len();
 
     let max_permutations = std::cmp::min(permutations.len(), debug_config.max_permutations);
@@ -347,7 +351,7 @@ fn calculate_scaling_factor(request: &CalculationRequest) -> Result<f64> {
     let mut max_decimal_places = std::cmp::max(max_decimal_panels, max_decimal_stock);
     
     if let Some(config) = &request.configuration {
-        let cut_thickness_str = config.cut_thickness.to_string();
+        let cut_thickness_str = config.cut_thickness.horizontal().max(config.cut_thickness.vertical()).to_string();
         max_decimal_places = std::cmp::max(max_decimal_places, 
             DimensionUtils::get_nbr_decimal_places(&cut_thickness_str));
         
@@ -382,7 +386,7 @@ fn convert_panels_to_tiles(
     // Convert regular panels
     for panel in &request.panels {
         if panel.is_valid()? {
-            for _ in 0..panel.count {
+            for _ in 0..panel.max_quantity() {
                 let width_str = panel.width.as_ref()
                     .ok_or_else(|| crate::errors::CoreError::InvalidInput { 
                         details: "Panel width is None".to_string() 
@@ -397,15 +401,18 @@ fn convert_panels_to_tiles(
                 let height_f64 = height_str.parse::<f64>()
                     .map_err(|e| crate::errors::CoreError::ParseFloat(e))?;
                 
-                let scaled_width = (width_f64 * scaling_factor).round() as i32;
-                let scaled_height = (height_f64 * scaling_factor).round() as i32;
+                let scaled_width = DimensionUtils::scale_dimension(width_f64, scaling_factor)?;
+                let scaled_height = DimensionUtils::scale_dimension(height_f64, scaling_factor)?;
                 
                 let mut tile = TileDimensions::new(panel.id, scaled_width, scaled_height);
                 tile.material = panel.material.clone();
                 tile.orientation = DimensionUtils::convert_orientation(panel.orientation);
                 tile.label = panel.label.clone();
+                tile.scale = scaling_factor;
+                tile.priority = panel.priority;
+                tile.edge = panel.edge.clone();
                 
-                tiles.push(tile);
+                tiles.push(tile.with_banding_allowance(&panel.scaled_banding_thickness(scaling_factor)));
             }
         }
     }
@@ -413,7 +420,7 @@ fn convert_panels_to_tiles(
     // Convert stock panels
     for panel in &request.stock_panels {
         if panel.is_valid()? {
-            for _ in 0..panel.count {
+            for _ in 0..DimensionUtils::stock_copies(panel, tiles.len()) {
                 let width_str = panel.width.as_ref()
                     .ok_or_else(|| crate::errors::CoreError::InvalidInput { 
                         details: "Stock panel width is None".to_string() 
@@ -428,13 +435,16 @@ fn convert_panels_to_tiles(
                 let height_f64 = height_str.parse::<f64>()
                     .map_err(|e| crate::errors::CoreError::ParseFloat(e))?;
                 
-                let scaled_width = (width_f64 * scaling_factor).round() as i32;
-                let scaled_height = (height_f64 * scaling_factor).round() as i32;
+                let scaled_width = DimensionUtils::scale_dimension(width_f64, scaling_factor)?;
+                let scaled_height = DimensionUtils::scale_dimension(height_f64, scaling_factor)?;
                 
                 let mut tile = TileDimensions::new(panel.id, scaled_width, scaled_height);
                 tile.material = panel.material.clone();
                 tile.orientation = DimensionUtils::convert_orientation(panel.orientation);
                 tile.label = panel.label.clone();
+                tile.scale = scaling_factor;
+                tile.edge_trim = (panel.edge_trim as f64 * scaling_factor).round() as i32;
+                tile.keepouts = panel.keepouts.iter().map(|keepout| keepout.scaled(scaling_factor)).collect();
                 
                 stock_tiles.push(tile);
             }
@@ -536,19 +546,24 @@ fn debug_is_one_dimensional_optimization(
 /// Generate permutations (simplified version)
 fn debug_generate_permutations(
     distinct_groups: &HashMap<GroupedTileDimensions, i32>,
+    seed: Option<u64>,
     result: &mut DebugResult,
 ) -> Result<Vec<Vec<GroupedTileDimensions>>> {
-    let mut groups: Vec<GroupedTileDimensions> = distinct_groups.keys().cloned().collect();
+    let mut groups: Vec<GroupedTileDimensions> = match seed {
+        Some(seed) => CollectionUtils::seeded_group_order(distinct_groups, seed),
+        None => distinct_groups.keys().cloned().collect(),
+    };
     
     if groups.is_empty() {
         return Ok(vec![]);
     }
     
-    // Sort by area (largest first)
+    // Sort by area (largest first), then by descending placement priority
     groups.sort_by(|a, b| {
         let area_a = a.tile_dimensions.area();
         let area_b = b.tile_dimensions.area();
         area_b.cmp(&area_a)
+            .then_with(|| b.tile_dimensions.priority.cmp(&a.tile_dimensions.priority))
     });
     
     result.add_step(format!("Sorted {} groups by area", groups.len()));
@@ -719,6 +734,7 @@ pub fn create_debug_test_case() -> CalculationRequest {
             orientation: 0,
             label: Some("Panel A".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 2,
@@ -730,6 +746,7 @@ pub fn create_debug_test_case() -> CalculationRequest {
             orientation: 0,
             label: Some("Panel B".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 3,
@@ -741,6 +758,7 @@ pub fn create_debug_test_case() -> CalculationRequest {
             orientation: 0,
             label: Some("Panel C".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -755,6 +773,7 @@ pub fn create_debug_test_case() -> CalculationRequest {
             orientation: 0,
             label: Some("Stock Wood".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 102,
@@ -766,6 +785,7 @@ pub fn create_debug_test_case() -> CalculationRequest {
             orientation: 0,
             label: Some("Stock Metal".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -9,7 +9,9 @@ use crate::{
         tile_dimensions::structs::TileDimensions,
         panel::structs::Panel,
         enums::Orientation,
+        CalculationRequest,
     },
+    logging::macros::warn,
 };
 
 /// Utility functions for dimension processing and validation
@@ -198,35 +200,98 @@ impl DimensionUtils {
         Ok(())
     }
 
+    /// Decimal places the dimensions of `request` are scaled by
+    ///
+    /// The most decimal places of any panel, stock panel, kerf or minimum
+    /// trim, reduced so that integer and decimal places together do not
+    /// exceed `max_allowed_digits` (Java: MAX_ALLOWED_DIGITS).
+    pub fn get_request_decimal_places(request: &CalculationRequest, max_allowed_digits: usize) -> usize {
+        let panels = &request.panels;
+        let stock_panels = &request.stock_panels;
+
+        // Get maximum decimal places from panels and stock panels
+        let max_decimal_panels = Self::get_max_nbr_decimal_places(panels);
+        let max_decimal_stock = Self::get_max_nbr_decimal_places(stock_panels);
+
+        // Include configuration values in decimal calculation
+        let mut max_decimal_places = std::cmp::max(max_decimal_panels, max_decimal_stock);
+
+        if let Some(config) = &request.configuration {
+            let cut_thickness_str = config.cut_thickness.horizontal().max(config.cut_thickness.vertical()).to_string();
+            max_decimal_places = std::cmp::max(max_decimal_places, Self::get_nbr_decimal_places(&cut_thickness_str));
+
+            let min_trim_str = config.min_trim_dimension.to_string();
+            max_decimal_places = std::cmp::max(max_decimal_places, Self::get_nbr_decimal_places(&min_trim_str));
+        }
+
+        // Get maximum integer places
+        let max_integer_panels = Self::get_max_nbr_integer_places(panels);
+        let max_integer_stock = Self::get_max_nbr_integer_places(stock_panels);
+        let max_integer_places = std::cmp::max(max_integer_panels, max_integer_stock);
+
+        if max_decimal_places + max_integer_places > max_allowed_digits {
+            warn!("Maximum allowed digits exceeded: decimal[{}] + integer[{}] = {} > max[{}]",
+                  max_decimal_places, max_integer_places,
+                  max_decimal_places + max_integer_places, max_allowed_digits);
+            max_decimal_places = max_allowed_digits.saturating_sub(max_integer_places);
+        }
+
+        max_decimal_places
+    }
+
+    /// Convert the panels and stock panels of `request` to tile dimensions
+    ///
+    /// Dimensions are scaled by the decimal places from
+    /// `get_request_decimal_places`, as the service does for submitted tasks.
+    pub fn convert_request_to_tiles(
+        request: &CalculationRequest,
+        max_allowed_digits: usize,
+    ) -> Result<(Vec<TileDimensions>, Vec<TileDimensions>, f64)> {
+        let decimal_places = Self::get_request_decimal_places(request, max_allowed_digits);
+        Self::convert_with_decimal_places(&request.panels, &request.stock_panels, decimal_places)
+    }
+
     /// Convert panels to tile dimensions with proper scaling
     pub fn convert_panels_to_tiles(
         panels: &[Panel], 
         stock_panels: &[Panel],
         _max_allowed_digits: usize
     ) -> Result<(Vec<TileDimensions>, Vec<TileDimensions>, f64)> {
-        let mut tiles = Vec::new();
-        let mut stock_tiles = Vec::new();
-
         // Calculate scaling factor based on decimal places (like Java)
         let max_decimal_places = Self::get_max_decimal_places_from_both(panels, stock_panels);
-        let factor = 10.0_f64.powi(max_decimal_places as i32);
+        Self::convert_with_decimal_places(panels, stock_panels, max_decimal_places)
+    }
+
+    /// Convert panels to tile dimensions, scaling by `decimal_places` powers of ten
+    fn convert_with_decimal_places(
+        panels: &[Panel],
+        stock_panels: &[Panel],
+        decimal_places: usize,
+    ) -> Result<(Vec<TileDimensions>, Vec<TileDimensions>, f64)> {
+        let mut tiles = Vec::new();
+        let mut stock_tiles = Vec::new();
+        let factor = 10.0_f64.powi(decimal_places as i32);
 
         // Convert regular panels
         for panel in panels {
             if panel.is_valid()? {
-                for _ in 0..panel.count {
+                for _ in 0..panel.max_quantity() {
                     let width_str = panel.width.as_ref().ok_or_else(|| AppError::invalid_input("Panel width is None"))?;
                     let height_str = panel.height.as_ref().ok_or_else(|| AppError::invalid_input("Panel height is None"))?;
                     
-                    let width = (width_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))? * factor).round() as i32;
-                    let height = (height_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))? * factor).round() as i32;
+                    let width = Self::scale_dimension(width_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?, factor)?;
+                    let height = Self::scale_dimension(height_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?, factor)?;
                     
                     let mut tile = TileDimensions::new(panel.id, width, height);
                     tile.material = panel.material.clone();
                     tile.orientation = Self::convert_orientation(panel.orientation);
                     tile.label = panel.label.clone();
+                    tile.scale = factor;
+                    tile.priority = panel.priority;
+                    tile.edge = panel.edge.clone();
+                    tile.l_shape = panel.l_shape;
                     
-                    tiles.push(tile);
+                    tiles.push(tile.with_banding_allowance(&panel.scaled_banding_thickness(factor)));
                 }
             }
         }
@@ -234,17 +299,20 @@ impl DimensionUtils {
         // Convert stock panels
         for panel in stock_panels {
             if panel.is_valid()? {
-                for _ in 0..panel.count {
+                for _ in 0..Self::stock_copies(panel, tiles.len()) {
                     let width_str = panel.width.as_ref().ok_or_else(|| AppError::invalid_input("Panel width is None"))?;
                     let height_str = panel.height.as_ref().ok_or_else(|| AppError::invalid_input("Panel height is None"))?;
                     
-                    let width = (width_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))? * factor).round() as i32;
-                    let height = (height_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))? * factor).round() as i32;
+                    let width = Self::scale_dimension(width_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?, factor)?;
+                    let height = Self::scale_dimension(height_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?, factor)?;
                     
                     let mut tile = TileDimensions::new(panel.id, width, height);
                     tile.material = panel.material.clone();
                     tile.orientation = Self::convert_orientation(panel.orientation);
                     tile.label = panel.label.clone();
+                    tile.scale = factor;
+                    tile.edge_trim = (panel.edge_trim as f64 * factor).round() as i32;
+                    tile.keepouts = panel.keepouts.iter().map(|keepout| keepout.scaled(factor)).collect();
                     
                     stock_tiles.push(tile);
                 }
@@ -272,6 +340,38 @@ impl DimensionUtils {
         max_decimal
     }
 
+    /// Scale a real dimension to the integer units used during optimization
+    ///
+    /// Fails instead of saturating when the scaled value does not fit in an
+    /// `i32`, so absurd dimensions are reported rather than silently clamped.
+    ///
+    /// # Examples
+    /// ```
+    /// use cutlist_optimizer_cli::engine::service::computation::dimension_utils::DimensionUtils;
+    ///
+    /// assert_eq!(DimensionUtils::scale_dimension(400.5, 10.0).unwrap(), 4005);
+    /// assert!(DimensionUtils::scale_dimension(5e9, 1.0).is_err());
+    /// ```
+    pub fn scale_dimension(value: f64, factor: f64) -> Result<i32> {
+        let scaled = (value * factor).round();
+        if !scaled.is_finite() || scaled > i32::MAX as f64 || scaled < i32::MIN as f64 {
+            return Err(AppError::invalid_input(format!(
+                "Dimension {} is too large to optimize at scale factor {}",
+                value, factor
+            )));
+        }
+        Ok(scaled as i32)
+    }
+
+    /// Number of sheets a stock panel contributes when `nbr_tiles` tiles are placed
+    ///
+    /// Every copy of the panel may hold part of the layout, but no layout
+    /// uses more sheets than it has tiles, so larger counts are capped there
+    /// to keep stock combination generation bounded.
+    pub fn stock_copies(stock_panel: &Panel, nbr_tiles: usize) -> i32 {
+        stock_panel.count.min(i32::try_from(nbr_tiles.max(1)).unwrap_or(i32::MAX))
+    }
+
     /// Convert integer orientation to Orientation enum
     pub fn convert_orientation(orientation: i32) -> Orientation {
         match orientation {
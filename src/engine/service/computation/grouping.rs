@@ -8,6 +8,7 @@ use tracing::{debug, info, trace};
 
 use crate::{
     errors::{Result, AppError},
+    utils::arrangement,
     models::{
         tile_dimensions::structs::TileDimensions,
         grouped_tile_dimensions::structs::GroupedTileDimensions,
@@ -204,4 +205,30 @@ impl CollectionUtils {
         
         Ok(result)
     }
+
+    /// Order distinct groups by their contents, independently of HashMap iteration order
+    pub fn group_order(distinct_groups: &HashMap<GroupedTileDimensions, i32>) -> Vec<GroupedTileDimensions> {
+        let mut groups: Vec<GroupedTileDimensions> = distinct_groups.keys().cloned().collect();
+        groups.sort_by(|a, b| {
+            let key = |g: &GroupedTileDimensions| {
+                let tile = &g.tile_dimensions;
+                (tile.material.clone(), tile.id, tile.width, tile.height, g.group, tile.label.clone(), tile.priority)
+            };
+            key(a).cmp(&key(b))
+        });
+        groups
+    }
+
+    /// Order distinct groups independently of HashMap iteration order
+    ///
+    /// Groups are sorted by their contents and then shuffled with `seed`, so
+    /// the same groups and seed always give the same order.
+    pub fn seeded_group_order(
+        distinct_groups: &HashMap<GroupedTileDimensions, i32>,
+        seed: u64,
+    ) -> Vec<GroupedTileDimensions> {
+        let mut groups = Self::group_order(distinct_groups);
+        arrangement::seeded_shuffle(&mut groups, seed);
+        groups
+    }
 }
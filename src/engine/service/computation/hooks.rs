@@ -0,0 +1,53 @@
+//! Pluggable parts a task is computed with
+
+use std::sync::Arc;
+
+use crate::engine::service::{
+    free_corner::FreeCornerPlacement,
+    placement::{GuillotinePlacement, PlacementAlgorithm},
+    progress::ProgressCallback,
+    stock_provider::{GeneratedStockSolutionProvider, StockSolutionProvider},
+};
+use crate::models::Configuration;
+use crate::CutStyle;
+
+/// Strategies and listener the service computes a task with
+///
+/// The default draws generated stock solutions, places tiles with the
+/// built-in guillotine placement, evaluates one permutation at a time and
+/// reports no progress.
+#[derive(Debug, Clone)]
+pub struct ComputeHooks {
+    /// Source of candidate stock solutions
+    pub stock_provider: Arc<dyn StockSolutionProvider>,
+    /// Lays out the tiles of one order on a stock solution
+    pub placement: Arc<dyn PlacementAlgorithm>,
+    /// Receives an update as every material's search advances
+    pub progress: Option<ProgressCallback>,
+    /// Permutations of a material evaluated at once
+    pub threads: usize,
+}
+
+impl Default for ComputeHooks {
+    fn default() -> Self {
+        Self {
+            stock_provider: Arc::new(GeneratedStockSolutionProvider),
+            placement: Arc::new(GuillotinePlacement),
+            progress: None,
+            threads: 1,
+        }
+    }
+}
+
+impl ComputeHooks {
+    /// Placement the tiles of a task with `configuration` are laid out with
+    ///
+    /// A free-corner cut style is laid out with `FreeCornerPlacement`
+    /// whatever `placement` is, since the other placements only cut guillotine.
+    pub fn placement_for(&self, configuration: &Configuration) -> Arc<dyn PlacementAlgorithm> {
+        match configuration.cut_style {
+            CutStyle::Guillotine => Arc::clone(&self.placement),
+            CutStyle::FreeCorner => Arc::new(FreeCornerPlacement),
+        }
+    }
+}
@@ -5,28 +5,41 @@
 //! 
 //! Based on Java CutListOptimizerServiceImpl.compute() method (lines 300-500)
 
-use std::{collections::HashMap, sync::Arc};
+use std::{cmp::Ordering as CmpOrdering, collections::{HashMap, HashSet}, sync::{atomic::{AtomicUsize, Ordering}, Arc}};
+use parking_lot::{Mutex, RwLock};
+use tracing::{instrument::WithSubscriber, Instrument, Span};
 use crate::{
-    errors::Result,
+    errors::{AppError, Result},
     models::{
         tile_dimensions::structs::TileDimensions,
         grouped_tile_dimensions::structs::GroupedTileDimensions,
         configuration::structs::Configuration,
         task::structs::Task,
+        response_meta::PermutationStats,
         performance_thresholds::structs::PerformanceThresholds,
         solution::structs::Solution,
         enums::status::Status,
     },
-    logging::macros::{debug, info, trace, warn, error},
-    utils::arrangement,
-    engine::stock::{
-        stock_panel_picker::StockPanelPicker,
-        stock_solution::StockSolution,
+    logging::{macros::{debug, info, trace, warn}, spans::permutation_span},
+    utils::{arrangement, timing::Timer},
+    engine::{
+        comparator::PriorityListFactory,
+        cut_list_thread::CutListThread,
+        grid_packing,
+        service::{placement::PlacementConfig, progress::ProgressUpdate},
+        stock::{
+            stock_panel_picker::StockPanelPicker,
+            stock_solution::StockSolution,
+            StockSolutionGenerator,
+        },
     },
+    CutDirection,
 };
 
 use super::{
     grouping::CollectionUtils,
+    permutation_utils::PermutationUtils,
+    hooks::ComputeHooks,
 };
 
 // Constants from Java implementation
@@ -34,6 +47,44 @@ const MAX_PERMUTATION_ITERATIONS: usize = 1000;
 const MAX_STOCK_ITERATIONS: usize = 1000;
 const MAX_PERMUTATIONS_WITH_SOLUTION: usize = 150;
 
+/// Placement runs of a material after which thread groups must earn their place
+const MIN_FINISHED_RUNS_FOR_RANKING: usize = 10;
+
+/// Thread groups run for every stock solution, with the first-cut direction each lays out with
+const THREAD_GROUPS: [(&str, CutDirection); 3] = [
+    ("AREA", CutDirection::Both),
+    ("AREA_HCUTS_1ST", CutDirection::Horizontal),
+    ("AREA_VCUTS_1ST", CutDirection::Vertical),
+];
+
+type FinalComparator = fn(&Solution, &Solution) -> CmpOrdering;
+
+/// Solutions of one placement run, with the thread group that ran it
+type PlacementRun = (&'static str, Vec<Solution>);
+
+/// What every permutation of one material's search shares
+struct MaterialSearch {
+    task_arc: Arc<RwLock<Task>>,
+    task_id: String,
+    material: String,
+    hooks: ComputeHooks,
+    stock_panel_picker: StockPanelPicker,
+    performance_thresholds: PerformanceThresholds,
+    /// Settings of every placement run; only the first-cut direction varies
+    placement_config: PlacementConfig,
+    /// Orders the material's solutions, best first
+    comparators: Vec<FinalComparator>,
+    /// Ids of the offcuts to use before other stock
+    consume_first_ids: HashSet<i32>,
+    /// Most solutions kept for the material between runs
+    max_solutions: usize,
+    total_permutations: usize,
+    /// Placement runs finished so far, for thread group eligibility
+    finished_runs: AtomicUsize,
+    /// Progress last reported for the material, which never goes backwards
+    reported_percentage: Mutex<i32>,
+}
+
 /// Compute optimization for a specific material
 /// 
 /// This is the Rust equivalent of the Java compute method for individual materials
@@ -43,14 +94,32 @@ const MAX_PERMUTATIONS_WITH_SOLUTION: usize = 150;
 /// - Performance threshold management
 /// - Complex permutation generation and processing
 /// - Stock solution optimization with multiple iterations
-/// - Thread spawning with different cut orientations
+/// - Placement runs with different cut orientations
 /// - Solution ranking and comparison
+///
+/// Without `max_permutations` in the configuration, the number of permutations
+/// tried is chosen from the part list; see `PermutationUtils::adaptive_permutation_count`.
+///
+/// Jobs of one part size on one sheet size are packed as a grid instead of
+/// searched; see `grid_packing::grid_solution_for`.
+///
+/// Permutations are evaluated `hooks.threads` at a time. The placement runs
+/// of a batch see the solutions of earlier batches only, and are merged in
+/// permutation order once the batch is done, so the result depends on the
+/// thread count but never on how the runs are scheduled.
+///
+/// Solutions are kept in the task's solutions for `material`, best first.
+/// When the task already has progress for `material`, as a task resumed from
+/// a checkpoint does, the permutations that progress covers are skipped.
+/// `hooks.progress` receives an update as stock solutions of each permutation
+/// are tried, after every batch, and a final one at 100%.
 pub async fn compute_material(
     tiles: Vec<TileDimensions>,
     stock_tiles: Vec<TileDimensions>,
     configuration: &Configuration,
-    task_arc: std::sync::Arc<parking_lot::RwLock<Task>>,
+    task_arc: Arc<RwLock<Task>>,
     material: &str,
+    hooks: &ComputeHooks,
 ) -> Result<()> {
     
     debug!("Computing material: {} with {} tiles and {} stock tiles", 
@@ -75,56 +144,73 @@ pub async fn compute_material(
         task.id.clone()
     };
 
-    // Step 1: Setup performance thresholds (Java PerformanceThresholds setup)
-    let performance_thresholds = setup_performance_thresholds(configuration)?;
-    
-    // Step 2: Get solutions collection for this material (Java: final List<Solution> solutions = task.getSolutions(str))
-    let solutions = {
+    // Jobs of one part size on one sheet size are packed as a grid instead of searched
+    let grid = {
         let task = task_arc.read();
-        let solutions_map = task.solutions.lock().unwrap();
-        solutions_map.get(material).cloned().unwrap_or_default()
+        let config = placement_config(configuration, &task)?;
+        task.calculation_request()
+            .as_ref()
+            .and_then(|request| grid_packing::grid_solution_for(request, &tiles, &stock_tiles, &config))
     };
+    if let Some(grid) = grid {
+        info!("Task[{}] Packed {} identical tiles of material {} as a grid on {} sheet(s)",
+              task_id, tiles.len(), material, grid.mosaics.len());
+        finish_with_grid(grid, configuration, &task_arc, material, hooks);
+        return Ok(());
+    }
 
-    // Step 3: Generate groups (Java: generateGroups method)
+    // Step 1: Setup performance thresholds (Java PerformanceThresholds setup)
+    let performance_thresholds = setup_performance_thresholds(configuration)?;
+
+    // Step 2: Generate groups (Java: generateGroups method)
     let grouped_tiles = generate_groups(&tiles, &stock_tiles, &task_arc)?;
     
-    // Step 4: Get distinct grouped tile dimensions (Java: getDistinctGroupedTileDimensions)
+    // Step 3: Get distinct grouped tile dimensions (Java: getDistinctGroupedTileDimensions)
     let distinct_groups = get_distinct_grouped_tile_dimensions(&grouped_tiles, configuration)?;
     
-    // Step 5: Generate permutations (Java: Arrangement.generatePermutations)
+    // Step 4: Generate permutations (Java: Arrangement.generatePermutations)
     debug!("Task[{}] Calculating permutations...", task_id);
-    let mut permutations = generate_complex_permutations(&distinct_groups)?;
+    let permutations = generate_complex_permutations(&distinct_groups, configuration.seed, configuration.max_permutations)?;
     
-    // Step 6: Sort tiles according to permutations (Java: groupedTileDimensionsList2TileDimensionsList)
+    // Step 5: Sort tiles according to permutations (Java: groupedTileDimensionsList2TileDimensionsList)
     debug!("Task[{}] Sorting tiles according to permutations...", task_id);
-    let tile_permutations = convert_permutations_to_tiles(&permutations, &grouped_tiles)?;
+    let mut tile_permutations = convert_permutations_to_tiles(&permutations, &grouped_tiles)?;
+    let permutations_generated = tile_permutations.len();
     
-    // Step 7: Remove duplicated permutations (Java: removeDuplicatedPermutations)
+    // Step 6: Remove duplicated permutations (Java: removeDuplicatedPermutations)
     debug!("Removing duplicated permutations...");
-    let removed_count = remove_duplicated_permutations(&mut permutations);
+    let removed_count = remove_duplicated_permutations(&mut tile_permutations);
     debug!("Removed {} duplicated permutations", removed_count);
     
-    // Step 8: Set task to running status (Java: task.setRunningStatus())
+    // Step 7: Set task to running status (Java: task.setRunningStatus())
     {
         let task = task_arc.read();
         let _ = task.set_running_status();
     }
     
-    // Step 9: Initialize StockPanelPicker (Java: StockPanelPicker stockPanelPicker = new StockPanelPicker(...))
+    // Step 8: Initialize StockPanelPicker (Java: StockPanelPicker stockPanelPicker = new StockPanelPicker(...))
     // Note: We need to create a new Arc<Task> from the RwLock content for StockPanelPicker
     let task_for_picker = {
         let task = task_arc.read();
         Arc::new(task.clone())
     };
+    let max_stock_solution_length_hint = if configuration.use_single_stock_unit { Some(1) } else { None };
+    let stock_source = hooks.stock_provider.stock_solutions(
+        &tiles,
+        &stock_tiles,
+        &task_for_picker,
+        max_stock_solution_length_hint,
+    )?;
     let stock_panel_picker = StockPanelPicker::new(
         tiles.clone(),
         stock_tiles.clone(),
         task_for_picker,
-        if configuration.use_single_stock_unit { Some(1) } else { None }
-    )?;
+        max_stock_solution_length_hint,
+    )?
+    .with_solution_source(stock_source);
     stock_panel_picker.init().await?;
     
-    // Step 10: Calculate optimization factor (Java: optimizationFactor calculation)
+    // Step 9: Calculate optimization factor (Java: optimizationFactor calculation)
     let mut optimization_factor = if configuration.optimization_factor > 0 {
         (100.0 * configuration.optimization_factor as f64) as i32
     } else {
@@ -135,12 +221,64 @@ pub async fn compute_material(
         optimization_factor = (optimization_factor as f64 * (0.5 / (tiles.len() as f64 / 100.0))) as i32;
         info!("Limiting solution pool elements to [{}]", optimization_factor);
     }
+
+    let search = Arc::new(MaterialSearch {
+        task_arc: Arc::clone(&task_arc),
+        task_id: task_id.clone(),
+        material: material.to_string(),
+        hooks: ComputeHooks {
+            placement: hooks.placement_for(configuration),
+            ..hooks.clone()
+        },
+        stock_panel_picker,
+        performance_thresholds,
+        placement_config: placement_config(configuration, &task_arc.read())?,
+        comparators: PriorityListFactory::get_final_solution_prioritized_comparator_functions(configuration),
+        consume_first_ids: task_arc
+            .read()
+            .calculation_request()
+            .as_ref()
+            .map(|request| StockSolutionGenerator::consume_first_ids_from_panels(&request.stock_panels))
+            .unwrap_or_default(),
+        max_solutions: optimization_factor.max(1) as usize,
+        total_permutations: tile_permutations.len().min(MAX_PERMUTATION_ITERATIONS).min(
+            configuration
+                .max_permutations
+                .unwrap_or_else(|| PermutationUtils::adaptive_permutation_count(&tiles)),
+        ),
+        finished_runs: AtomicUsize::new(0),
+        reported_percentage: Mutex::new(0),
+    });
+    let batch_size = hooks.threads.max(1);
+    
+    // Step 10: Process permutations (Java: main permutation loop)
+    // A task resumed from a checkpoint continues after the permutations it had finished
+    let resumed_percentage = task_arc.read().per_material_percentage_done.lock().unwrap().get(material).copied().unwrap_or(0);
+    let mut permutation_index = search.total_permutations * resumed_percentage.clamp(0, 100) as usize / 100;
+    if permutation_index > 0 {
+        info!("Task[{}] Resuming material {} at permutationIdx[{}]", task_id, material, permutation_index);
+    }
+    let mut early_exit_triggered = false;
     
-    // Step 11: Process permutations (Java: main permutation loop)
-    let mut permutation_index = 0;
-    let total_permutations = std::cmp::min(permutations.len(), MAX_PERMUTATION_ITERATIONS);
-    
-    while permutation_index < total_permutations {
+    let timer = Timer::new(format!("Material {}", material));
+    let cancellation = task_arc.read().cancellation_token();
+
+    while permutation_index < search.total_permutations {
+        // Stop as soon as the task is stopped or terminated; the best solution so far stands
+        if cancellation.load(Ordering::Acquire) {
+            info!("Task[{}] Cancelled at permutationIdx[{}]", task_id, permutation_index);
+            break;
+        }
+
+        // Stop spawning once the time budget is spent; the best solution so far stands
+        if let Some(budget) = configuration.time_budget {
+            if permutation_index > 0 && timer.elapsed() >= budget {
+                info!("Task[{}] Time budget of {:?} exhausted at permutationIdx[{}]", task_id, budget, permutation_index);
+                task_arc.write().set_time_bounded(true);
+                break;
+            }
+        }
+
         // Check if task is still running (Java: if (!task.isRunning()))
         {
             let task = task_arc.read();
@@ -151,56 +289,322 @@ pub async fn compute_material(
         }
         
         // Check if we have solution and reached max permutations (Java: task.hasSolutionAllFit() check)
-        {
-            let task = task_arc.read();
-            if task.has_solution_all_fit() && permutation_index > MAX_PERMUTATIONS_WITH_SOLUTION {
-                task.set_material_percentage_done(material.to_string(), 100);
-                debug!("Task has solution and spawned max permutations threads");
-                break;
-            }
+        if search.has_solution_all_fit() && permutation_index > MAX_PERMUTATIONS_WITH_SOLUTION {
+            debug!("Task has solution and spawned max permutations threads");
+            early_exit_triggered = true;
+            break;
         }
         
-        // Process this permutation (Java: lambda function call)
-        if let Some(permutation) = tile_permutations.get(permutation_index) {
-            process_permutation_complex(
-                &stock_panel_picker,
-                permutation_index,
-                &task_arc,
-                &solutions,
-                &tile_permutations,
-                configuration,
-                permutation,
-                optimization_factor,
-                &performance_thresholds,
-                material,
-            ).await?;
+        // Stop once the best solution is good enough, unless the best one is wanted as well
+        if !configuration.report_first_acceptable && search.has_solution_meeting_target() {
+            info!("Task[{}] Target efficiency reached at permutationIdx[{}]", task_id, permutation_index);
+            early_exit_triggered = true;
+            break;
         }
-        
-        permutation_index += 1;
-        
-        // Update progress
-        let progress = (permutation_index as f64 / total_permutations as f64 * 100.0) as i32;
-        {
-            let task = task_arc.read();
-            task.set_material_percentage_done(material.to_string(), progress);
+
+        // Process the next batch of permutations at once (Java: lambda function call)
+        let batch_end = (permutation_index + batch_size).min(search.total_permutations);
+        let batch: Vec<_> = (permutation_index..batch_end)
+            .filter_map(|index| {
+                let permutation = tile_permutations.get(index)?.clone();
+                let search = Arc::clone(&search);
+                let span = permutation_span(material, index);
+                // Spawned permutations report to the caller's subscriber; the
+                // span is closed inside the permutation so it closes there too
+                let runs = async move { process_permutation_complex(&search, index, &permutation).instrument(span).await };
+                Some((index, tokio::spawn(runs.with_current_subscriber())))
+            })
+            .collect();
+        for (index, handle) in batch {
+            let runs = handle.await.map_err(|e| AppError::thread_error(e.to_string()))??;
+            for (group, solutions) in runs {
+                search.merge_run(index, group, solutions);
+            }
         }
+
+        permutation_index = batch_end;
+
+        // Update progress; 100% finishes the material, which waits for the statistics below
+        let percentage = ProgressUpdate::percentage_of_work(permutation_index, search.total_permutations, 0, 1).min(99);
+        search.advance_progress(&Span::none(), permutation_index, percentage);
     }
     
-    // Step 12: Wait for all threads to complete (Java: while loop waiting for threads)
+    search.record_best_as_first_acceptable(permutation_index);
+    task_arc.write().add_permutation_stats(PermutationStats {
+        permutations_generated,
+        permutations_evaluated: permutation_index,
+        duplicates_removed: removed_count,
+        early_exit_triggered,
+    });
+
+    // Step 11: Wait for all threads to complete (Java: while loop waiting for threads)
     wait_for_computation_completion(&task_arc, material).await?;
     
-    // Step 13: Mark material as complete (Java: task.setMaterialPercentageDone(str, 100))
+    // Step 12: Mark material as complete (Java: task.setMaterialPercentageDone(str, 100))
     {
         let task = task_arc.read();
         if task.status() == Status::Running {
             task.set_material_percentage_done(material.to_string(), 100);
         }
     }
+    search.report_progress(&Span::none(), permutation_index, 100);
 
     info!("Completed material computation for: {} with {} permutations", material, permutation_index);
     Ok(())
 }
 
+/// Record `grid` as the material's only solution and mark the material done
+fn finish_with_grid(
+    grid: Solution,
+    configuration: &Configuration,
+    task_arc: &Arc<RwLock<Task>>,
+    material: &str,
+    hooks: &ComputeHooks,
+) {
+    let task = task_arc.read();
+    let _ = task.set_running_status();
+    if configuration.report_first_acceptable {
+        task.first_acceptable_solutions.lock().unwrap().insert(material.to_string(), grid.clone());
+    }
+    if let Some(progress) = &hooks.progress {
+        progress.report(ProgressUpdate::new(&task.id, material, 0, 100, std::slice::from_ref(&grid)));
+    }
+    task.add_solution(material, grid);
+    task.set_material_percentage_done(material.to_string(), 100);
+}
+
+/// Settings for the material's placement runs, with lengths scaled by the task's factor
+pub(crate) fn placement_config(configuration: &Configuration, task: &Task) -> Result<PlacementConfig> {
+    let factor = task.factor();
+    let scale = |value: i32| (value as f64 * factor).round() as i32;
+    Ok(PlacementConfig {
+        configuration: configuration.clone(),
+        cut_thickness: configuration.effective_cut_thickness(configuration.cut_thickness).scaled(factor),
+        material_cut_thicknesses: configuration.material_cut_thicknesses(factor)?,
+        min_trim_dimension: scale(configuration.min_trim_dimension),
+        rotation_tolerance: scale(configuration.rotation_tolerance),
+        accuracy_factor: configuration.optimization_factor.max(1) as usize,
+        first_cut_orientation: configuration.cut_direction(),
+        material_cut_directions: configuration.material_cut_directions(),
+        allowed_stock_ids: task
+            .calculation_request
+            .as_ref()
+            .map(|request| CutListThread::allowed_stock_ids_from_panels(&request.panels))
+            .unwrap_or_default(),
+    })
+}
+
+impl MaterialSearch {
+    /// Run `f` on the material's best solution so far
+    fn with_best_solution<R>(&self, f: impl FnOnce(Option<&Solution>) -> R) -> R {
+        let task = self.task_arc.read();
+        let solutions = task.solutions.lock().unwrap();
+        f(solutions.get(&self.material).and_then(|solutions| solutions.first()))
+    }
+
+    /// Order of the material's solutions, best first, ties broken by structure
+    fn compare(&self, a: &Solution, b: &Solution) -> CmpOrdering {
+        self.comparators
+            .iter()
+            .map(|compare| compare(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.get_structure_identifier().cmp(&b.get_structure_identifier()))
+    }
+
+    /// Run `f` on the best of the material's solutions so far and `runs`
+    fn with_best_of_runs<R>(&self, runs: &[PlacementRun], f: impl FnOnce(Option<&Solution>) -> R) -> R {
+        self.with_best_solution(|best| {
+            f(best.into_iter()
+                .chain(runs.iter().flat_map(|(_, solutions)| solutions))
+                .min_by(|a, b| self.compare(a, b)))
+        })
+    }
+
+    /// Area of the stock holding every tile on a single sheet, when the best of
+    /// the material's solutions so far and `runs` does
+    fn single_sheet_fit_area(&self, runs: &[PlacementRun]) -> Option<i64> {
+        self.with_best_of_runs(runs, |best| {
+            best.filter(|best| best.no_fit_panels.is_empty() && best.get_mosaics().len() == 1)
+                .map(Solution::get_total_area)
+        })
+    }
+
+    /// Whether the best of the material's solutions so far and `runs` holds
+    /// every tile using an offcut to consume first
+    fn consume_first_fit(&self, runs: &[PlacementRun]) -> bool {
+        !self.consume_first_ids.is_empty()
+            && self.with_best_of_runs(runs, |best| {
+                best.is_some_and(|best| {
+                    best.no_fit_panels.is_empty()
+                        && best.get_mosaics().iter().any(|mosaic| self.consume_first_ids.contains(&mosaic.stock_id()))
+                })
+            })
+    }
+
+    /// Whether `stock_solution` includes an offcut to consume first
+    fn uses_consume_first(&self, stock_solution: &StockSolution) -> bool {
+        stock_solution
+            .get_stock_tile_dimensions()
+            .iter()
+            .any(|stock| self.consume_first_ids.contains(&stock.id))
+    }
+
+    /// Whether the material's best solution so far places every tile
+    fn has_solution_all_fit(&self) -> bool {
+        self.with_best_solution(|best| best.is_some_and(|best| best.no_fit_panels.is_empty()))
+    }
+
+    /// Add the solutions of a placement run to the material's, keeping the best
+    /// `max_solutions` without duplicates
+    ///
+    /// Returns whether the run produced the new best solution.
+    fn merge_solutions(&self, run: Vec<Solution>) -> bool {
+        if run.is_empty() {
+            return false;
+        }
+        let task = self.task_arc.read();
+        let mut solutions = task.solutions.lock().unwrap();
+        let material_solutions = solutions.entry(self.material.clone()).or_default();
+        let previous_best = material_solutions.first().map(|best| best.id);
+
+        material_solutions.extend(run);
+        material_solutions.sort_by(|a, b| self.compare(a, b));
+        let mut seen = HashSet::new();
+        material_solutions.retain(|solution| seen.insert(solution.get_structure_identifier()));
+        material_solutions.truncate(self.max_solutions);
+
+        material_solutions.first().map(|best| best.id) != previous_best
+    }
+
+    /// Merge a placement run of the permutation at `permutation_index` into the material's solutions
+    ///
+    /// The run's group moves up in the rankings when it produced the new best
+    /// solution. Failed runs, which have no solutions, still count as finished.
+    fn merge_run(&self, permutation_index: usize, group: &str, solutions: Vec<Solution>) {
+        let solutions = solutions
+            .into_iter()
+            .map(|mut solution| {
+                solution.creator_thread_group = Some(group.to_string());
+                solution
+            })
+            .collect();
+        if self.merge_solutions(solutions) {
+            self.task_arc.read().increment_thread_group_rankings(&self.material, group);
+            self.record_first_acceptable(permutation_index);
+        }
+        self.finished_runs.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Whether the material's best solution so far meets the target efficiency
+    fn has_solution_meeting_target(&self) -> bool {
+        self.with_best_solution(|best| best.is_some_and(|best| self.meets_target(best)))
+    }
+
+    /// Whether `solution` places every tile at or above the target efficiency
+    fn meets_target(&self, solution: &Solution) -> bool {
+        self.placement_config.configuration.target_efficiency.is_some_and(|target| {
+            solution.no_fit_panels.is_empty() && f64::from(solution.get_efficiency()) >= target
+        })
+    }
+
+    /// Keep the material's best solution as its first acceptable one the first
+    /// time it meets the target, when the request asks for it
+    ///
+    /// The solution records the permutations evaluated up to the one at
+    /// `permutation_index`, where a search stopping at the target would end.
+    fn record_first_acceptable(&self, permutation_index: usize) {
+        if !self.placement_config.configuration.report_first_acceptable {
+            return;
+        }
+        let task = self.task_arc.read();
+        let mut first_acceptable = task.first_acceptable_solutions.lock().unwrap();
+        if first_acceptable.contains_key(&self.material) {
+            return;
+        }
+        let solutions = task.solutions.lock().unwrap();
+        if let Some(best) = solutions.get(&self.material).and_then(|solutions| solutions.first()) {
+            if self.meets_target(best) {
+                let mut first = best.clone();
+                first.permutations_evaluated = permutation_index + 1;
+                first_acceptable.insert(self.material.clone(), first);
+            }
+        }
+    }
+
+    /// Keep the material's best solution as its first acceptable one when the
+    /// search ended without meeting the target, when the request asks for it
+    ///
+    /// A search stopping at the target would have run to the end, over
+    /// `permutations_evaluated` permutations.
+    fn record_best_as_first_acceptable(&self, permutations_evaluated: usize) {
+        if !self.placement_config.configuration.report_first_acceptable {
+            return;
+        }
+        let task = self.task_arc.read();
+        let mut first_acceptable = task.first_acceptable_solutions.lock().unwrap();
+        if first_acceptable.contains_key(&self.material) {
+            return;
+        }
+        let solutions = task.solutions.lock().unwrap();
+        if let Some(best) = solutions.get(&self.material).and_then(|solutions| solutions.first()) {
+            let mut first = best.clone();
+            first.permutations_evaluated = permutations_evaluated;
+            first_acceptable.insert(self.material.clone(), first);
+        }
+    }
+
+    /// Whether a thread group still earns placement runs (Java: isThreadEligibleToStart)
+    ///
+    /// Every group runs until the material has finished a few runs; after that
+    /// a group runs only while it produced the best solution in more than a
+    /// fifth of them.
+    fn is_thread_eligible_to_start(&self, group: &str) -> bool {
+        let finished_runs = self.finished_runs.load(Ordering::Acquire);
+        if finished_runs < MIN_FINISHED_RUNS_FOR_RANKING {
+            return true;
+        }
+        let ranking = self.task_arc.read()
+            .thread_group_rankings(&self.material)
+            .and_then(|rankings| rankings.get(group).copied())
+            .unwrap_or(0);
+        ranking as usize > finished_runs / 5
+    }
+
+    /// Record `percentage` as the material's progress and report it, unless
+    /// progress already reached it
+    ///
+    /// Permutations of a batch advance concurrently; keeping the progress
+    /// monotonic keeps the reports in order.
+    fn advance_progress(&self, span: &Span, permutation_index: usize, percentage: i32) {
+        let mut reported = self.reported_percentage.lock();
+        if percentage <= *reported {
+            return;
+        }
+        *reported = percentage;
+        self.task_arc.read().set_material_percentage_done(self.material.clone(), percentage);
+        self.report_progress(span, permutation_index, percentage);
+    }
+
+    /// Send the material's best-so-far figures to the progress listener, if any,
+    /// and record the best efficiency on the permutation's span
+    fn report_progress(&self, span: &Span, permutation_index: usize, percentage: i32) {
+        let progress = self.hooks.progress.as_ref();
+        if progress.is_none() && span.is_disabled() {
+            return;
+        }
+        let update = {
+            let task = self.task_arc.read();
+            let solutions = task.solutions.lock().unwrap();
+            let material_solutions = solutions.get(&self.material).map(Vec::as_slice).unwrap_or_default();
+            ProgressUpdate::new(&self.task_id, &self.material, permutation_index, percentage, material_solutions)
+        };
+        span.record("best_efficiency", update.best_efficiency);
+        if let Some(progress) = progress {
+            progress.report(update);
+        }
+    }
+}
+
 /// Setup performance thresholds (Java: PerformanceThresholds setup)
 fn setup_performance_thresholds(configuration: &Configuration) -> Result<PerformanceThresholds> {
     let mut thresholds = configuration.performance_thresholds.clone();
@@ -337,22 +741,40 @@ fn get_distinct_grouped_tile_dimensions(
 /// - Sorting by area (largest first)
 /// - Limiting to first 7 groups for permutation
 /// - Adding remaining groups to each permutation
+///
+/// With a `seed`, groups of equal area are ordered by the seed rather than by
+/// HashMap iteration order, so permutations are reproducible.
+///
+/// With `max_permutations`, every group takes part instead of only the first
+/// 7: the area order comes first, followed by orders sampled with the seed, up
+/// to `max_permutations` in total.
 fn generate_complex_permutations(
     distinct_groups: &HashMap<GroupedTileDimensions, i32>,
+    seed: Option<u64>,
+    max_permutations: Option<usize>,
 ) -> Result<Vec<Vec<GroupedTileDimensions>>> {
-    let mut groups: Vec<GroupedTileDimensions> = distinct_groups.keys().cloned().collect();
+    let mut groups: Vec<GroupedTileDimensions> = match seed {
+        Some(seed) => CollectionUtils::seeded_group_order(distinct_groups, seed),
+        None => CollectionUtils::group_order(distinct_groups),
+    };
     
     if groups.is_empty() {
         return Ok(vec![]);
     }
     
     // Sort by area (largest first) - Java: Collections.sort with area comparator
+    // Equal areas are ordered by descending placement priority
     groups.sort_by(|a, b| {
         let area_a = a.tile_dimensions.area();
         let area_b = b.tile_dimensions.area();
         area_b.cmp(&area_a) // Reverse order for largest first
+            .then_with(|| b.tile_dimensions.priority.cmp(&a.tile_dimensions.priority))
     });
     
+    if let Some(max_permutations) = max_permutations {
+        return Ok(arrangement::sample_permutations(groups, max_permutations, seed.unwrap_or_default()));
+    }
+
     // Split into permutation groups and fixed groups (Java: if (arrayList2.size() > 7))
     let (permutation_groups, fixed_groups) = if groups.len() > 7 {
         let permutation_groups = groups[0..7].to_vec();
@@ -400,56 +822,38 @@ fn convert_permutations_to_tiles(
 }
 
 /// Remove duplicated permutations (Java: removeDuplicatedPermutations)
-fn remove_duplicated_permutations(permutations: &mut Vec<Vec<GroupedTileDimensions>>) -> usize {
-    let mut seen_hashes = Vec::new();
-    let mut removed_count = 0;
-    
+///
+/// Tile orders with the same sequence of sizes lay out the same way, so only
+/// the first of them is kept. Returns the number removed.
+fn remove_duplicated_permutations(permutations: &mut Vec<Vec<TileDimensions>>) -> usize {
+    let mut seen = std::collections::HashSet::new();
+    let before = permutations.len();
     permutations.retain(|permutation| {
-        // Calculate hash based on dimensions (Java: dimensionsBasedHashCode logic)
-        let mut hash: i32 = 0;
-        for group in permutation {
-            hash = hash.wrapping_mul(31).wrapping_add(group.tile_dimensions.dimensions_hash() as i32);
-        }
-        
-        if seen_hashes.contains(&hash) {
-            removed_count += 1;
-            false
-        } else {
-            seen_hashes.push(hash);
-            true
-        }
+        let dimensions: Vec<u64> = permutation.iter().map(TileDimensions::dimensions_hash).collect();
+        seen.insert(dimensions)
     });
-    
-    removed_count
+    before - permutations.len()
 }
 
 /// Process a single permutation with complex Java lambda logic
 /// 
 /// This implements the complex Java lambda function that processes each permutation
-/// with stock solutions and spawns computation threads with different orientations.
+/// with stock solutions and runs placements with different orientations.
+/// The placement runs are returned in the order they ran, for the caller to
+/// merge into the material's solutions.
 /// 
 /// Java reference: lambda function in compute method around lines 400-500
 async fn process_permutation_complex(
-    stock_panel_picker: &StockPanelPicker,
+    search: &MaterialSearch,
     permutation_index: usize,
-    task_arc: &std::sync::Arc<parking_lot::RwLock<Task>>,
-    solutions: &[Solution],
-    all_permutations: &[Vec<TileDimensions>],
-    configuration: &Configuration,
     permutation: &[TileDimensions],
-    optimization_factor: i32,
-    performance_thresholds: &PerformanceThresholds,
-    material: &str,
-) -> Result<()> {
-    let task_id = {
-        let task = task_arc.read();
-        task.id.clone()
-    };
-    
+) -> Result<Vec<PlacementRun>> {
+    let mut runs = Vec::new();
+
     // Process stock solutions (Java: for (int i4 = 0; i4 < 1000; i4++))
     for stock_index in 0..MAX_STOCK_ITERATIONS {
         // Get stock solution (Java: StockSolution stockSolution = stockPanelPicker.getStockSolution(i4))
-        let stock_solution = match stock_panel_picker.get_stock_solution(stock_index) {
+        let stock_solution = match search.stock_panel_picker.wait_for_stock_solution(stock_index).await {
             Ok(Some(solution)) => solution,
             Ok(None) => {
                 debug!("No more possible stock solutions: stockSolution[{}] permutationIdx[{}]", 
@@ -465,78 +869,52 @@ async fn process_permutation_complex(
         
         // Check if task is still running (Java: if (!task.isRunning()))
         {
-            let task = task_arc.read();
+            let task = search.task_arc.read();
             if !task.is_running() {
                 debug!("Task no longer has running status. Stopping stock loop for permutationIdx[{}]", 
                        permutation_index);
-                return Ok(());
+                return Ok(runs);
             }
         }
         
-        // Check solution optimization conditions (Java: complex if condition)
-        let should_process = {
-            let task = task_arc.read();
-            !task.has_solution_all_fit() || 
-            solutions.is_empty() || 
-            solutions[0].get_mosaics().len() != 1 || 
-            solutions[0].get_total_area() >= stock_solution.get_total_area()
-        };
+        // Skip stock solutions larger than a single sheet that already holds everything
+        // (Java: complex if condition), and stock without offcuts to consume
+        // first once those hold everything
+        let best_area = search.single_sheet_fit_area(&runs);
+        let should_process = best_area.is_none_or(|area| area >= stock_solution.get_total_area())
+            && (search.uses_consume_first(&stock_solution) || !search.consume_first_fit(&runs));
         
         if should_process {
             debug!("Starting permutationIdx[{}/{}] with stock solution [{}] {{nbrPanels[{}] area[{}] {}}}", 
-                   permutation_index, all_permutations.len(), stock_index, 
+                   permutation_index, search.total_permutations, stock_index, 
                    stock_solution.get_stock_tile_dimensions().len(), 
                    stock_solution.get_total_area(), stock_solution.to_string());
             
-            // Setup cut thickness and trim dimension (Java: parsing configuration values)
-            let cut_thickness = parse_configuration_value(configuration.cut_thickness, &task_arc, "cut thickness")?;
-            let min_trim_dimension = parse_configuration_value(configuration.min_trim_dimension, &task_arc, "minimum trim dimension")?;
-            
             // Wait for thread availability (Java: while loop checking thread limits)
-            wait_for_thread_availability(task_arc, performance_thresholds).await?;
-            
-            // Spawn threads for different cut orientations (Java: isThreadEligibleToStart checks)
-            spawn_cut_orientation_threads(
-                task_arc,
-                material,
-                configuration,
-                permutation,
-                &stock_solution,
-                cut_thickness,
-                min_trim_dimension,
-                optimization_factor,
-                permutation_index,
-                stock_index,
-                solutions,
-            ).await?;
+            wait_for_thread_availability(&search.task_arc, &search.performance_thresholds).await?;
             
+            // Run placements for the different cut orientations (Java: isThreadEligibleToStart checks)
+            runs.extend(spawn_cut_orientation_threads(search, permutation, &stock_solution).await);
+
+            // Report progress through the stock solutions of this permutation;
+            // more solutions may be generated later, so never go backwards
+            let stock_solutions = search.stock_panel_picker.get_solution_count_unchecked().max(stock_index + 1);
+            let stock_percentage =
+                ProgressUpdate::percentage_of_work(permutation_index, search.total_permutations, stock_index + 1, stock_solutions);
+            search.advance_progress(&Span::current(), permutation_index, stock_percentage);
         } else {
             debug!("Stopping stock loop for permutationIdx[{}/{}] at stock solution {} with area [{}] because there's already an all fit solution using stock solution with area [{}]", 
-                   permutation_index, all_permutations.len(), stock_solution.to_string(), 
-                   stock_solution.get_total_area(), solutions[0].get_total_area());
+                   permutation_index, search.total_permutations, stock_solution.to_string(), 
+                   stock_solution.get_total_area(), best_area.unwrap_or_default());
         }
     }
     
-    Ok(())
-}
-
-/// Parse configuration value with error handling (Java: parsing logic with try-catch)
-fn parse_configuration_value(
-    value: i32,
-    task_arc: &std::sync::Arc<parking_lot::RwLock<Task>>,
-    value_name: &str,
-) -> Result<i32> {
-    let factor = {
-        let task = task_arc.read();
-        task.factor()
-    };
-    
-    Ok((value as f64 * factor).round() as i32)
+    Ok(runs)
 }
 
 /// Wait for thread availability (Java: while loop checking thread limits)
 async fn wait_for_thread_availability(
-    task_arc: &std::sync::Arc<parking_lot::RwLock<Task>>,
+    task_arc: &Arc<RwLock<Task>>,
     performance_thresholds: &PerformanceThresholds,
 ) -> Result<()> {
     use tokio::time::{sleep, Duration};
@@ -560,62 +938,50 @@ async fn wait_for_thread_availability(
     Ok(())
 }
 
-/// Spawn threads for different cut orientations (Java: isThreadEligibleToStart and thread spawning)
+/// Lay out the permutation on the stock solution once per eligible cut orientation
+/// (Java: isThreadEligibleToStart and thread spawning)
+///
+/// The runs go to the blocking pool in parallel and are returned in thread
+/// group order. A failed run is logged and returns no solutions.
 async fn spawn_cut_orientation_threads(
-    task_arc: &std::sync::Arc<parking_lot::RwLock<Task>>,
-    material: &str,
-    configuration: &Configuration,
+    search: &MaterialSearch,
     permutation: &[TileDimensions],
     stock_solution: &StockSolution,
-    cut_thickness: i32,
-    min_trim_dimension: i32,
-    optimization_factor: i32,
-    permutation_index: usize,
-    stock_index: usize,
-    solutions: &[Solution],
-) -> Result<()> {
-    // For now, just simulate the thread spawning logic
-    // TODO: Implement actual CutListThreadBuilder and thread execution
-    
-    // Check thread eligibility for AREA orientation (Java: isThreadEligibleToStart("AREA", task, str))
-    if is_thread_eligible_to_start("AREA", task_arc, material)? {
-        debug!("Spawning AREA thread for permutation[{}] stock[{}]", permutation_index, stock_index);
-        // TODO: Spawn actual CutListThread with AREA orientation
-    }
-    
-    // Check thread eligibility for AREA_HCUTS_1ST (Java: horizontal cuts first)
-    if is_thread_eligible_to_start("AREA_HCUTS_1ST", task_arc, material)? {
-        debug!("Spawning AREA_HCUTS_1ST thread for permutation[{}] stock[{}]", permutation_index, stock_index);
-        // TODO: Spawn actual CutListThread with horizontal cuts first
-    }
-    
-    // Check thread eligibility for AREA_VCUTS_1ST (Java: vertical cuts first)
-    if is_thread_eligible_to_start("AREA_VCUTS_1ST", task_arc, material)? {
-        debug!("Spawning AREA_VCUTS_1ST thread for permutation[{}] stock[{}]", permutation_index, stock_index);
-        // TODO: Spawn actual CutListThread with vertical cuts first
-    }
-    
-    Ok(())
-}
+) -> Vec<PlacementRun> {
+    let preference = search.placement_config.configuration.cut_direction();
+    let runs: Vec<_> = THREAD_GROUPS
+        .into_iter()
+        .filter(|&(_, direction)| preference == CutDirection::Both || direction == preference)
+        .filter(|&(group, _)| search.is_thread_eligible_to_start(group))
+        .map(|(group, direction)| {
+            trace!("Spawning {} thread", group);
+            let placement = Arc::clone(&search.hooks.placement);
+            let tiles = permutation.to_vec();
+            let stock_solution = stock_solution.clone();
+            let config = PlacementConfig { first_cut_orientation: direction, ..search.placement_config.clone() };
+            let handle = tokio::task::spawn_blocking(move || placement.place(&tiles, &stock_solution, &config));
+            (group, handle)
+        })
+        .collect();
 
-/// Check if thread is eligible to start (Java: isThreadEligibleToStart)
-fn is_thread_eligible_to_start(
-    group: &str,
-    task_arc: &std::sync::Arc<parking_lot::RwLock<Task>>,
-    material: &str,
-) -> Result<bool> {
-    // For now, always return true
-    // TODO: Implement actual thread eligibility logic based on thread group rankings
-    // Java logic: check thread group rankings and finished threads count
-    
-    let task = task_arc.read();
-    let finished_threads = task.nbr_finished_threads_for_material(material);
-    
-    // Simple eligibility check - allow if we haven't finished too many threads
-    Ok(finished_threads < 10)
+    let mut finished = Vec::with_capacity(runs.len());
+    for (group, handle) in runs {
+        let solutions = match handle.await {
+            Ok(Ok(solutions)) => solutions,
+            Ok(Err(e)) => {
+                warn!("Task[{}] {} placement failed: {}", search.task_id, group, e);
+                Vec::new()
+            }
+            Err(e) => {
+                warn!("Task[{}] {} placement did not finish: {}", search.task_id, group, e);
+                Vec::new()
+            }
+        };
+        finished.push((group, solutions));
+    }
+    finished
 }
 
-/// Wait for computation completion (Java: while loop waiting for threads)
 async fn wait_for_computation_completion(
     task_arc: &std::sync::Arc<parking_lot::RwLock<Task>>,
     material: &str,
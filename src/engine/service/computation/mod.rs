@@ -5,6 +5,7 @@
 
 pub mod dimension_utils;
 pub mod grouping;
+pub mod hooks;
 pub mod task_compute;
 pub mod material_compute;
 pub mod permutation_utils;
@@ -13,5 +14,6 @@ pub mod debug_single_thread;
 // Re-export main utilities for easier access
 pub use dimension_utils::DimensionUtils;
 pub use grouping::CollectionUtils;
-pub use permutation_utils::PermutationUtils;
+pub use hooks::ComputeHooks;
+pub use permutation_utils::{PermutationUtils, TileStatistics};
 pub use debug_single_thread::{DebugConfig, DebugResult, debug_compute_complete, create_debug_test_case};
@@ -3,7 +3,22 @@
 //! This module provides utilities for working with permutations and duplicates
 //! in tile arrangements and groupings.
 
+use std::collections::HashSet;
+
+use crate::constants::EngineConstants;
 use crate::models::tile_dimensions::structs::TileDimensions;
+use crate::utils::arrangement;
+
+/// Size and diversity figures of a tile list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TileStatistics {
+    /// Number of tiles
+    pub tile_count: usize,
+    /// Number of distinct sizes, ignoring rotation
+    pub unique_sizes: usize,
+    /// Number of tiles whose long side is at least `ELONGATED_ASPECT_RATIO` times the short one
+    pub elongated_count: usize,
+}
 
 /// Utility struct for permutation-related operations
 pub struct PermutationUtils;
@@ -134,6 +149,50 @@ impl PermutationUtils {
             .collect()
     }
     
+    /// Counts tiles, distinct sizes and elongated tiles
+    pub fn calculate_statistics(tiles: &[TileDimensions]) -> TileStatistics {
+        let sizes: HashSet<(i32, i32)> = tiles
+            .iter()
+            .map(|tile| (tile.width.min(tile.height), tile.width.max(tile.height)))
+            .collect();
+        let elongated_count = tiles
+            .iter()
+            .filter(|tile| {
+                let (short, long) = (tile.width.min(tile.height), tile.width.max(tile.height));
+                long as i64 >= short as i64 * EngineConstants::ELONGATED_ASPECT_RATIO as i64
+            })
+            .count();
+
+        TileStatistics {
+            tile_count: tiles.len(),
+            unique_sizes: sizes.len(),
+            elongated_count,
+        }
+    }
+
+    /// Number of tile orders worth trying for `tiles`
+    ///
+    /// Orders keep identical tiles together, so a few distinct sizes allow
+    /// only a few orders however many tiles there are. Within that, every
+    /// distinct size and every elongated tile, whose placement depends most
+    /// on order, earns `ADAPTIVE_PERMUTATIONS_PER_SIZE` orders. Total work,
+    /// counted in tile placements, is capped by `ADAPTIVE_PERMUTATION_WORK`,
+    /// and the result never exceeds `MAX_PERMUTATIONS`. At least one order is
+    /// always tried.
+    pub fn adaptive_permutation_count(tiles: &[TileDimensions]) -> usize {
+        let statistics = Self::calculate_statistics(tiles);
+        if statistics.tile_count == 0 {
+            return 1;
+        }
+
+        let distinct_orders = arrangement::expected_permutation_count(statistics.unique_sizes).unwrap_or(usize::MAX);
+        let wanted = (statistics.unique_sizes + statistics.elongated_count)
+            .saturating_mul(EngineConstants::ADAPTIVE_PERMUTATIONS_PER_SIZE);
+        let affordable = EngineConstants::ADAPTIVE_PERMUTATION_WORK / statistics.tile_count;
+
+        distinct_orders.min(wanted).min(affordable).clamp(1, EngineConstants::MAX_PERMUTATIONS)
+    }
+
     /// Validates that permutation removal was successful
     /// 
     /// # Arguments
@@ -11,20 +11,30 @@ use crate::{
         calculation_request::CalculationRequest,
         calculation_submission_result::CalculationSubmissionResult,
         task::Task,
+        task_checkpoint::TaskCheckpoint,
         tile_dimensions::TileDimensions,
         enums::status_code::StatusCode,
     },
     engine::{
         running_tasks::{TaskManager, get_running_tasks_instance},
-        service::computation::{
-            dimension_utils::DimensionUtils,
-            material_compute,
+        service::{
+            computation::{
+                dimension_utils::DimensionUtils,
+                hooks::ComputeHooks,
+                material_compute,
+            },
         },
     },
-    logging::macros::{info, debug, warn, error},
+    constants::EngineConstants,
+    logging::{macros::{info, debug, warn, error}, spans::task_span},
+    utils::arrangement,
 };
 
 use std::collections::{HashSet, HashMap};
+use std::sync::{atomic::Ordering, Arc};
+use parking_lot::RwLock;
+use tokio::task::JoinHandle;
+use tracing::Instrument;
 
 /// Group tiles by material (Java equivalent of getTileDimensionsPerMaterial)
 /// 
@@ -58,6 +68,61 @@ pub async fn compute_task_complete(
     request: CalculationRequest, 
     task_id: String
 ) -> Result<()> {
+    compute_task_complete_with_hooks(request, task_id, ComputeHooks::default()).await
+}
+
+/// Same as `compute_task_complete`, computing every material with `hooks`
+pub async fn compute_task_complete_with_hooks(
+    request: CalculationRequest,
+    task_id: String,
+    hooks: ComputeHooks,
+) -> Result<()> {
+    compute_into_task(request, Task::new(task_id), hooks).await?;
+    Ok(())
+}
+
+/// Compute `request` as task `task_id` with `hooks` and wait for every material
+///
+/// Unlike `compute_task_complete_with_hooks`, which returns once the
+/// materials are spawned, this returns the task once their computation is
+/// over, failing with the first material computation that failed. The task
+/// stays in the running tasks; the caller removes it when done.
+pub async fn compute_task_to_end(
+    request: CalculationRequest,
+    task_id: String,
+    hooks: ComputeHooks,
+) -> Result<Arc<RwLock<Task>>> {
+    let (task_arc, materials) = compute_into_task(request, Task::new(task_id), hooks).await?;
+    for material in materials {
+        material.await.map_err(|e| AppError::thread_error(e.to_string()))??;
+    }
+    Ok(task_arc)
+}
+
+/// Continue computing a task from `checkpoint`, the request it was computing
+///
+/// The task starts from the checkpointed solutions and progress; each
+/// material's search skips the permutations it had already finished.
+pub async fn resume_task_with_hooks(
+    request: CalculationRequest,
+    checkpoint: TaskCheckpoint,
+    hooks: ComputeHooks,
+) -> Result<()> {
+    compute_into_task(request, checkpoint.to_task(), hooks).await?;
+    Ok(())
+}
+
+/// Compute `request` into `task`, which may already hold solutions and progress
+///
+/// Returns the task and the computations spawned for its materials.
+async fn compute_into_task(
+    request: CalculationRequest,
+    mut task: Task,
+    hooks: ComputeHooks,
+) -> Result<(Arc<RwLock<Task>>, Vec<JoinHandle<Result<()>>>)> {
+    let task_id = task.id.clone();
+    let span = task_span(&task_id);
+    let _entered = span.enter();
     info!("Starting complete computation for task: {}", task_id);
 
     // Step 1: Input validation
@@ -67,102 +132,21 @@ pub async fn compute_task_complete(
     if request.stock_panels.is_empty() {
         return Err(AppError::invalid_input("No stock panels provided"));
     }
-
-    // Step 2: Calculate scaling factor (Java lines ~205-215)
-    let panels = &request.panels;
-    let stock_panels = &request.stock_panels;
-    
-    // Get maximum decimal places from panels and stock panels
-    let max_decimal_panels = DimensionUtils::get_max_nbr_decimal_places(panels);
-    let max_decimal_stock = DimensionUtils::get_max_nbr_decimal_places(stock_panels);
-    
-    // Include configuration values in decimal calculation
-    let mut max_decimal_places = std::cmp::max(max_decimal_panels, max_decimal_stock);
-    
-    if let Some(config) = &request.configuration {
-        let cut_thickness_str = config.cut_thickness.to_string();
-        max_decimal_places = std::cmp::max(max_decimal_places, DimensionUtils::get_nbr_decimal_places(&cut_thickness_str));
-        
-        let min_trim_str = config.min_trim_dimension.to_string();
-        max_decimal_places = std::cmp::max(max_decimal_places, DimensionUtils::get_nbr_decimal_places(&min_trim_str));
-    }
-    
-    // Get maximum integer places
-    let max_integer_panels = DimensionUtils::get_max_nbr_integer_places(panels);
-    let max_integer_stock = DimensionUtils::get_max_nbr_integer_places(stock_panels);
-    let max_integer_places = std::cmp::max(max_integer_panels, max_integer_stock);
-    
-    // Check digit limits (Java MAX_ALLOWED_DIGITS = 6)
-    const MAX_ALLOWED_DIGITS: usize = 6;
-    if max_decimal_places + max_integer_places > MAX_ALLOWED_DIGITS {
-        warn!("Maximum allowed digits exceeded: decimal[{}] + integer[{}] = {} > max[{}]", 
-              max_decimal_places, max_integer_places, 
-              max_decimal_places + max_integer_places, MAX_ALLOWED_DIGITS);
-        max_decimal_places = MAX_ALLOWED_DIGITS.saturating_sub(max_integer_places);
+    if let Err(errors) = request.validate_semantic() {
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        return Err(AppError::invalid_input(messages.join("; ")));
     }
-    
-    // Calculate scaling factor: double dPow = Math.pow(10.0d, iMax);
-    let scaling_factor = 10.0_f64.powi(max_decimal_places as i32);
-    
-    debug!("Scaling factor: {} (decimal places: {}, integer places: {})", 
-           scaling_factor, max_decimal_places, max_integer_places);
 
-    // Step 3: Convert panels to TileDimensions with scaling (Java lines ~216-235)
-    let mut tiles = Vec::new();
-    let mut stock_tiles = Vec::new();
-    
-    // Convert regular panels
-    for panel in panels {
-        if panel.is_valid()? {
-            for _ in 0..panel.count {
-                let width_str = panel.width.as_ref().ok_or_else(|| AppError::invalid_input("Panel width is None"))?;
-                let height_str = panel.height.as_ref().ok_or_else(|| AppError::invalid_input("Panel height is None"))?;
-                
-                let width_f64 = width_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?;
-                let height_f64 = height_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?;
-                
-                // Apply scaling: (int) Math.round(Double.parseDouble(panel.getWidth()) * dPow)
-                let scaled_width = (width_f64 * scaling_factor).round() as i32;
-                let scaled_height = (height_f64 * scaling_factor).round() as i32;
-                
-                let mut tile = TileDimensions::new(panel.id, scaled_width, scaled_height);
-                tile.material = panel.material.clone();
-                tile.orientation = DimensionUtils::convert_orientation(panel.orientation);
-                tile.label = panel.label.clone();
-                
-                tiles.push(tile);
-            }
-        }
-    }
-    
-    // Convert stock panels
-    for panel in stock_panels {
-        if panel.is_valid()? {
-            for _ in 0..panel.count {
-                let width_str = panel.width.as_ref().ok_or_else(|| AppError::invalid_input("Stock panel width is None"))?;
-                let height_str = panel.height.as_ref().ok_or_else(|| AppError::invalid_input("Stock panel height is None"))?;
-                
-                let width_f64 = width_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?;
-                let height_f64 = height_str.parse::<f64>().map_err(|e| AppError::Core(crate::errors::CoreError::ParseFloat(e)))?;
-                
-                let scaled_width = (width_f64 * scaling_factor).round() as i32;
-                let scaled_height = (height_f64 * scaling_factor).round() as i32;
-                
-                let mut tile = TileDimensions::new(panel.id, scaled_width, scaled_height);
-                tile.material = panel.material.clone();
-                tile.orientation = DimensionUtils::convert_orientation(panel.orientation);
-                tile.label = panel.label.clone();
-                
-                stock_tiles.push(tile);
-            }
-        }
-    }
+    // Steps 2-3: Scale by the request's decimal places and convert panels to
+    // TileDimensions (Java lines ~205-235)
+    let (tiles, stock_tiles, scaling_factor) =
+        DimensionUtils::convert_request_to_tiles(&request, EngineConstants::MAX_ALLOWED_DIGITS)?;
+    debug!("Scaling factor: {}", scaling_factor);
     
     info!("Converted {} panels to {} tiles, {} stock panels to {} stock tiles", 
-          panels.len(), tiles.len(), stock_panels.len(), stock_tiles.len());
+          request.panels.len(), tiles.len(), request.stock_panels.len(), stock_tiles.len());
 
     // Step 4: Create and setup task (Java lines ~236-242)
-    let mut task = Task::new(task_id.clone());
     task.set_calculation_request(request.clone());
     task.set_factor(scaling_factor);
     
@@ -188,6 +172,12 @@ pub async fn compute_task_complete(
     let mut all_materials = HashSet::new();
     all_materials.extend(tiles_per_material.keys().cloned());
     all_materials.extend(stock_per_material.keys().cloned());
+    let mut all_materials: Vec<String> = all_materials.into_iter().collect();
+    // A seed fixes the order materials are visited in
+    if let Some(seed) = request.configuration.as_ref().and_then(|config| config.seed) {
+        all_materials.sort();
+        arrangement::seeded_shuffle(&mut all_materials, seed);
+    }
     
     let mut materials_to_compute = Vec::new();
     
@@ -198,9 +188,12 @@ pub async fn compute_task_complete(
                 materials_to_compute.push(material.clone());
                 
                 // Add material to task: task.addMaterialToCompute(str2);
+                // A resumed task already holds the material's solutions and progress
                 {
                     let task = task_arc.read();
-                    task.add_material_to_compute(material.clone());
+                    if !task.per_material_percentage_done.lock().unwrap().contains_key(material) {
+                        task.add_material_to_compute(material.clone());
+                    }
                 }
                 
                 debug!("Material '{}' added for computation with {} tiles", material, material_tiles.len());
@@ -216,6 +209,7 @@ pub async fn compute_task_complete(
     }
 
     // Step 6: Spawn computation for each material (Java lines ~261-270)
+    let mut computations = Vec::new();
     for material in materials_to_compute {
         if let (Some(material_tiles), Some(material_stock)) = (
             tiles_per_material.get(&material),
@@ -223,29 +217,38 @@ pub async fn compute_task_complete(
         ) {
             let material_tiles_clone = material_tiles.clone();
             let material_stock_clone = material_stock.clone();
-            let configuration_clone = request.configuration.clone();
+            // Without a configuration the defaults apply
+            let configuration = request.configuration.clone().unwrap_or_default();
             let material_clone = material.clone();
             let task_id_clone = task_id.clone();
+            let hooks = hooks.clone();
+            let cancellation = task_arc.read().cancellation_token();
             
             debug!("Spawning computation thread for material: {}", material);
             
             // Spawn async computation (Java equivalent of new Thread().start())
-            tokio::spawn(async move {
-                if let Some(config) = configuration_clone.as_ref() {
-                    let running_tasks = get_running_tasks_instance();
-                    if let Some(task_arc) = running_tasks.get_task(&task_id_clone) {
-                        if let Err(e) = material_compute::compute_material(
-                            material_tiles_clone,
-                            material_stock_clone,
-                            config,
-                            task_arc,
-                            &material_clone,
-                        ).await {
-                            error!("Material computation failed for {}: {}", material_clone, e);
-                        }
-                    }
+            computations.push(tokio::spawn(async move {
+                if cancellation.load(Ordering::Acquire) {
+                    debug!("Task {} cancelled before computing material {}", task_id_clone, material_clone);
+                    return Ok(());
                 }
-            });
+                let running_tasks = get_running_tasks_instance();
+                let Some(task_arc) = running_tasks.get_task(&task_id_clone) else {
+                    return Ok(());
+                };
+                let result = material_compute::compute_material(
+                    material_tiles_clone,
+                    material_stock_clone,
+                    &configuration,
+                    task_arc,
+                    &material_clone,
+                    &hooks,
+                ).await;
+                if let Err(e) = &result {
+                    error!("Material computation failed for {}: {}", material_clone, e);
+                }
+                result
+            }.instrument(span.clone())));
         }
     }
     
@@ -256,12 +259,21 @@ pub async fn compute_task_complete(
     }
     
     info!("Task {} computation setup complete", task_id);
-    Ok(())
+    Ok((task_arc, computations))
 }
 
 /// Legacy compute method for backward compatibility
 pub async fn compute_task(request: CalculationRequest, task_id: String) -> Result<CalculationSubmissionResult> {
-    compute_task_complete(request, task_id.clone()).await?;
+    compute_task_with_hooks(request, task_id, ComputeHooks::default()).await
+}
+
+/// Same as `compute_task`, computing every material with `hooks`
+pub async fn compute_task_with_hooks(
+    request: CalculationRequest,
+    task_id: String,
+    hooks: ComputeHooks,
+) -> Result<CalculationSubmissionResult> {
+    compute_task_complete_with_hooks(request, task_id.clone(), hooks).await?;
     
     Ok(CalculationSubmissionResult::new(
         StatusCode::Ok,
@@ -314,23 +326,22 @@ pub async fn compute_task_simple(request: CalculationRequest, task_id: String) -
     for (material, material_tiles) in tiles_per_material {
         if let Some(material_stock) = stock_per_material.get(&material) {
             let material_stock_clone = material_stock.clone();
-            let configuration_clone = request.configuration.clone();
+            let configuration = request.configuration.clone().unwrap_or_default();
             let task_id_clone = task_id.clone();
             let material_clone = material.clone();
             
             tokio::spawn(async move {
-                if let Some(config) = configuration_clone.as_ref() {
-                    // Get task from running tasks for the computation
-                    let running_tasks = get_running_tasks_instance();
-                    if let Some(task_arc) = running_tasks.get_task(&task_id_clone) {
-                        let _ = material_compute::compute_material(
-                            material_tiles,
-                            material_stock_clone,
-                            config,
-                            task_arc,
-                            &material_clone,
-                        ).await;
-                    }
+                // Get task from running tasks for the computation
+                let running_tasks = get_running_tasks_instance();
+                if let Some(task_arc) = running_tasks.get_task(&task_id_clone) {
+                    let _ = material_compute::compute_material(
+                        material_tiles,
+                        material_stock_clone,
+                        &configuration,
+                        task_arc,
+                        &material_clone,
+                        &ComputeHooks::default(),
+                    ).await;
                 }
             });
         }
@@ -1,9 +1,10 @@
 //! Core service implementation
 //! This module contains only the main service struct and basic utilities
 
+use std::collections::HashSet;
 use std::sync::{
     atomic::{AtomicBool, AtomicU64, Ordering},
-    Arc,
+    Arc, Mutex,
 };
 use tokio::sync::Semaphore;
 use chrono::{DateTime, Utc};
@@ -11,10 +12,17 @@ use chrono::{DateTime, Utc};
 use crate::{
     errors::Result,
     engine::{
-        watch_dog::core::WatchDog,
+        watch_dog::{config::WatchDogConfig, core::WatchDog},
         running_tasks::structs::RunningTasks,
+        service::{
+            placement::{GuillotinePlacement, PlacementAlgorithm},
+            computation::ComputeHooks,
+            progress::{ProgressCallback, ProgressUpdate},
+            stock_provider::{GeneratedStockSolutionProvider, StockSolutionProvider},
+        },
     },
     constants::EngineConstants,
+    models::{CalculationRequest, CalculationResponse, OffcutInventory},
 };
 
 /// Main implementation of the CutList Optimizer Service
@@ -32,20 +40,30 @@ pub struct CutListOptimizerServiceImpl {
     /// Thread coordination semaphore
     #[allow(dead_code)]
     thread_semaphore: Arc<Semaphore>,
-    /// Maximum threads per task
-    #[allow(dead_code)]
-    max_threads_per_task: usize,
+    /// Permutations of a material evaluated at once, the pool size given to `init`
+    pub(crate) max_threads_per_task: usize,
     /// Service start time
     #[allow(dead_code)]
     start_time: DateTime<Utc>,
     /// Running tasks manager (singleton pattern)
     #[allow(dead_code)]
     running_tasks: Option<Arc<RunningTasks>>,
-    /// Watch dog for monitoring
-    #[allow(dead_code)]
-    watch_dog: Option<Arc<WatchDog>>,
+    /// Watch dog for monitoring, started by `init`
+    pub(crate) watch_dog: Option<Arc<WatchDog>>,
+    /// Configuration the watch dog is started with
+    watch_dog_config: WatchDogConfig,
     /// Date format for task ID generation
     date_format: String,
+    /// Strategy supplying candidate stock solutions to the optimizer
+    stock_solution_provider: Arc<dyn StockSolutionProvider>,
+    /// Algorithm placing tiles on the sheets of a stock solution
+    placement_algorithm: Arc<dyn PlacementAlgorithm>,
+    /// Listener receiving per-permutation progress of submitted tasks
+    progress_callback: Option<ProgressCallback>,
+    /// Offcuts carried from one task to the next, when enabled
+    offcut_inventory: Option<Mutex<OffcutInventory>>,
+    /// Tasks whose results have already been recorded in the offcut inventory
+    recorded_offcut_tasks: Mutex<HashSet<String>>,
 }
 
 impl CutListOptimizerServiceImpl {
@@ -60,7 +78,114 @@ impl CutListOptimizerServiceImpl {
             start_time: Utc::now(),
             running_tasks: None,
             watch_dog: None,
+            watch_dog_config: WatchDogConfig::default(),
             date_format: "%Y%m%d%H%M".to_string(),
+            stock_solution_provider: Arc::new(GeneratedStockSolutionProvider),
+            placement_algorithm: Arc::new(GuillotinePlacement),
+            progress_callback: None,
+            offcut_inventory: None,
+            recorded_offcut_tasks: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Use `provider` instead of the built-in stock solution generator
+    pub fn with_stock_solution_provider(mut self, provider: Box<dyn StockSolutionProvider>) -> Self {
+        self.set_stock_solution_provider(provider);
+        self
+    }
+
+    /// Replace the strategy supplying candidate stock solutions
+    pub fn set_stock_solution_provider(&mut self, provider: Box<dyn StockSolutionProvider>) {
+        self.stock_solution_provider = Arc::from(provider);
+    }
+
+    /// Get the strategy supplying candidate stock solutions
+    pub fn stock_solution_provider(&self) -> Arc<dyn StockSolutionProvider> {
+        Arc::clone(&self.stock_solution_provider)
+    }
+
+    /// Use `algorithm` instead of the built-in guillotine placement
+    pub fn with_placement_algorithm(mut self, algorithm: Box<dyn PlacementAlgorithm>) -> Self {
+        self.set_placement_algorithm(algorithm);
+        self
+    }
+
+    /// Replace the algorithm placing tiles on stock
+    pub fn set_placement_algorithm(&mut self, algorithm: Box<dyn PlacementAlgorithm>) {
+        self.placement_algorithm = Arc::from(algorithm);
+    }
+
+    /// Get the algorithm placing tiles on stock
+    pub fn placement_algorithm(&self) -> Arc<dyn PlacementAlgorithm> {
+        Arc::clone(&self.placement_algorithm)
+    }
+
+    /// Start the watch dog with `config` instead of the defaults
+    pub fn with_watch_dog_config(mut self, config: WatchDogConfig) -> Self {
+        self.set_watch_dog_config(config);
+        self
+    }
+
+    /// Replace the configuration the watch dog is started with; takes effect on `init`
+    pub fn set_watch_dog_config(&mut self, config: WatchDogConfig) {
+        self.watch_dog_config = config;
+    }
+
+    /// Get the configuration the watch dog is started with
+    pub fn watch_dog_config(&self) -> &WatchDogConfig {
+        &self.watch_dog_config
+    }
+
+    /// Receive a progress update after every permutation of submitted tasks
+    pub fn set_progress_callback(&mut self, callback: Box<dyn Fn(ProgressUpdate) + Send + Sync>) {
+        self.progress_callback = Some(ProgressCallback::new(callback));
+    }
+
+    /// Get the registered progress listener, if any
+    pub fn progress_callback(&self) -> Option<ProgressCallback> {
+        self.progress_callback.clone()
+    }
+
+    /// Carry reusable offcuts from one task to the next
+    ///
+    /// Offcuts in `inventory` are offered as stock to every submitted task.
+    /// Once a task is seen finished, the offcuts it cut from are removed and
+    /// its reusable remainders are added.
+    pub fn set_offcut_inventory(&mut self, inventory: OffcutInventory) {
+        self.offcut_inventory = Some(Mutex::new(inventory));
+    }
+
+    /// Get a snapshot of the offcut inventory, if one is set
+    pub fn offcut_inventory(&self) -> Option<OffcutInventory> {
+        self.offcut_inventory
+            .as_ref()
+            .map(|inventory| inventory.lock().unwrap().clone())
+    }
+
+    /// Add the inventory's offcuts to the stock of a request
+    pub(crate) fn add_offcut_stock(&self, request: &mut CalculationRequest) {
+        if let Some(inventory) = &self.offcut_inventory {
+            inventory.lock().unwrap().add_to_request(request);
+        }
+    }
+
+    /// Record a finished task in the offcut inventory, once per task
+    pub(crate) fn record_offcuts(&self, task_id: &str, response: &CalculationResponse) {
+        let Some(inventory) = &self.offcut_inventory else {
+            return;
+        };
+        if self.recorded_offcut_tasks.lock().unwrap().insert(task_id.to_string()) {
+            inventory.lock().unwrap().record_job(response);
+        }
+    }
+
+    /// Strategies, listener and thread count tasks are computed with
+    pub(crate) fn compute_hooks(&self) -> ComputeHooks {
+        ComputeHooks {
+            stock_provider: self.stock_solution_provider(),
+            placement: self.placement_algorithm(),
+            progress: self.progress_callback(),
+            threads: self.max_threads_per_task,
         }
     }
 
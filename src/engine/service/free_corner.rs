@@ -0,0 +1,447 @@
+//! Free-corner placement for cutters that are not bound to guillotine cuts
+//!
+//! Lasers and waterjets can cut a tile out of the top-left corner of a free
+//! node along the tile's own edges, without running a cut across the whole
+//! node. The rest of the node is left as up to three remainders: right of the
+//! tile, below it, and diagonal to it. The remainders are only split from
+//! each other logically, so no kerf is spent between them until a tile cut
+//! out next to such a boundary needs it; the kerf is then taken from the free
+//! node across the boundary.
+
+use crate::engine::cut_list_thread::CutListThread;
+use crate::engine::stock::StockSolution;
+use crate::errors::Result;
+use crate::models::{Cut, CutThickness, Mosaic, Solution, Tile, TileDimensions, TileNode};
+use crate::{GrainMode, Orientation};
+
+use super::placement::{PlacementAlgorithm, PlacementConfig};
+
+/// Places tiles with free-corner cuts, one tile at a time
+///
+/// Each tile goes to the free node it fills best, on a sheet already in use
+/// when it fits on one; ties go to the highest, then leftmost node. The
+/// tiles are laid out in the given order into a single solution.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FreeCornerPlacement;
+
+/// A tile cut out of one free node of a sheet
+struct Fit {
+    /// Area the node has left beside the tile, then the node's position
+    rank: (i64, i32, i32),
+    mosaic: Mosaic,
+}
+
+/// Edge of a placed tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Side {
+    const ALL: [Side; 4] = [Side::Left, Side::Right, Side::Top, Side::Bottom];
+
+    /// Whether the edge runs up and down, so that it is cut across the sheet's width
+    fn is_upright(self) -> bool {
+        matches!(self, Side::Left | Side::Right)
+    }
+
+    /// Kerf of a cut along this edge
+    fn kerf(self, cut_thickness: CutThickness) -> i32 {
+        if self.is_upright() {
+            cut_thickness.horizontal()
+        } else {
+            cut_thickness.vertical()
+        }
+    }
+
+    /// Whether this edge of `area` lies on the border of the usable sheet
+    fn on_border(self, area: &Tile, sheet: &Tile) -> bool {
+        match self {
+            Side::Left => area.x1 <= sheet.x1,
+            Side::Right => area.x2 >= sheet.x2,
+            Side::Top => area.y1 <= sheet.y1,
+            Side::Bottom => area.y2 >= sheet.y2,
+        }
+    }
+
+    /// Material a cut along this edge of `area` removes, outside `area`
+    fn kerf_strip(self, area: &Tile, kerf: i32, sheet: &Tile) -> Tile {
+        match self {
+            Side::Left => Tile::new((area.x1 - kerf).max(sheet.x1), area.x1, area.y1, area.y2),
+            Side::Right => Tile::new(area.x2, (area.x2 + kerf).min(sheet.x2), area.y1, area.y2),
+            Side::Top => Tile::new(area.x1, area.x2, (area.y1 - kerf).max(sheet.y1), area.y1),
+            Side::Bottom => Tile::new(area.x1, area.x2, area.y2, (area.y2 + kerf).min(sheet.y2)),
+        }
+    }
+
+    /// `node` without the part of it in `strip`, which lies on this side of the placed tile
+    fn clear(self, node: &Tile, strip: &Tile) -> Tile {
+        let mut cleared = node.clone();
+        match self {
+            Side::Left => cleared.x2 = strip.x1.clamp(node.x1, node.x2),
+            Side::Right => cleared.x1 = strip.x2.clamp(node.x1, node.x2),
+            Side::Top => cleared.y2 = strip.y1.clamp(node.y1, node.y2),
+            Side::Bottom => cleared.y1 = strip.y2.clamp(node.y1, node.y2),
+        }
+        cleared
+    }
+
+    /// Whether earlier cuts already run the whole length of this edge of `area`
+    ///
+    /// The edge counts as cut when cuts along it or one kerf further out
+    /// cover it; the line of a cut does not tell which side its kerf is on.
+    fn is_cut(self, area: &Tile, cuts: &[Cut], kerf: i32) -> bool {
+        let (line, outer, start, end) = match self {
+            Side::Left => (area.x1, area.x1 - kerf, area.y1, area.y2),
+            Side::Right => (area.x2, area.x2 + kerf, area.y1, area.y2),
+            Side::Top => (area.y1, area.y1 - kerf, area.x1, area.x2),
+            Side::Bottom => (area.y2, area.y2 + kerf, area.x1, area.x2),
+        };
+        let mut spans: Vec<(i32, i32)> = cuts
+            .iter()
+            .filter(|cut| cut.is_horizontal == self.is_upright())
+            .filter_map(|cut| {
+                let (position, from, to) = if self.is_upright() {
+                    (cut.x1, cut.y1.min(cut.y2), cut.y1.max(cut.y2))
+                } else {
+                    (cut.y1, cut.x1.min(cut.x2), cut.x1.max(cut.x2))
+                };
+                (position == line || position == outer).then_some((from, to))
+            })
+            .collect();
+        spans.sort_unstable();
+
+        let mut covered = start;
+        for (from, to) in spans {
+            if from > covered {
+                break;
+            }
+            covered = covered.max(to);
+        }
+        covered >= end
+    }
+
+    /// Cut along this edge of the placed tile, away from the node it borders
+    fn edge_cut(self, area: &Tile, placed_id: u32, across_id: Option<u32>) -> Cut {
+        let (x1, y1, x2, y2, cut_coord) = match self {
+            Side::Left => (area.x1, area.y1, area.x1, area.y2, 0),
+            Side::Right => (area.x2, area.y1, area.x2, area.y2, area.width()),
+            Side::Top => (area.x1, area.y1, area.x2, area.y1, 0),
+            Side::Bottom => (area.x1, area.y2, area.x2, area.y2, area.height()),
+        };
+        Cut {
+            x1,
+            y1,
+            x2,
+            y2,
+            original_width: area.width(),
+            original_height: area.height(),
+            is_horizontal: self.is_upright(),
+            cut_coord,
+            original_tile_id: placed_id as i32,
+            child1_tile_id: placed_id as i32,
+            child2_tile_id: across_id.unwrap_or(placed_id) as i32,
+        }
+    }
+}
+
+impl PlacementAlgorithm for FreeCornerPlacement {
+    fn place(
+        &self,
+        tiles: &[TileDimensions],
+        stock_solution: &StockSolution,
+        config: &PlacementConfig,
+    ) -> Result<Vec<Solution>> {
+        let mut solution = Solution::from_stock_solution(stock_solution);
+        for tile in tiles {
+            if !Self::place_on_sheets(&mut solution, tile, config)
+                && !Self::place_on_unused_stock(&mut solution, tile, config)
+            {
+                solution.add_no_fit_panel(tile.clone());
+            }
+        }
+        solution.get_mosaics_mut().retain(Mosaic::has_final_tiles);
+        Ok(vec![solution])
+    }
+}
+
+impl FreeCornerPlacement {
+    /// Place the tile on the sheet of `solution` it fits best, if any
+    fn place_on_sheets(solution: &mut Solution, tile: &TileDimensions, config: &PlacementConfig) -> bool {
+        let best = solution
+            .get_mosaics()
+            .iter()
+            .filter(|mosaic| mosaic.material() == tile.material && Self::is_stock_allowed(tile, mosaic.stock_id(), config))
+            .filter_map(|mosaic| Self::best_fit(mosaic, tile, config).map(|fit| (mosaic, fit)))
+            .min_by_key(|(_, fit)| fit.rank);
+        let Some((mosaic, fit)) = best else {
+            return false;
+        };
+
+        let mut placed = Solution::from_solution_excluding_mosaic(solution, mosaic);
+        placed.add_mosaic(fit.mosaic);
+        *solution = placed;
+        true
+    }
+
+    /// Start a sheet of unused stock with the tile, taking the first panel it fits on
+    fn place_on_unused_stock(solution: &mut Solution, tile: &TileDimensions, config: &PlacementConfig) -> bool {
+        let panels: Vec<TileDimensions> = solution.get_unused_stock_panels().iter().cloned().collect();
+        for panel in panels {
+            if panel.material != tile.material || !tile.fits(&panel) || !Self::is_stock_allowed(tile, panel.id, config) {
+                continue;
+            }
+            let Some(fit) = Self::best_fit(&Mosaic::from_tile_dimensions(&panel), tile, config) else {
+                continue;
+            };
+
+            let unused = solution.get_unused_stock_panels_mut();
+            if let Some(index) = unused.iter().position(|unused_panel| unused_panel == &panel) {
+                unused.remove(index);
+            }
+            solution.add_mosaic(fit.mosaic);
+            return true;
+        }
+        false
+    }
+
+    fn is_stock_allowed(tile: &TileDimensions, stock_id: i32, config: &PlacementConfig) -> bool {
+        config
+            .allowed_stock_ids
+            .get(&tile.id)
+            .is_none_or(|ids| ids.contains(&stock_id))
+    }
+
+    /// Orientations of the tile to try on the sheet, chosen as the guillotine placement chooses them
+    ///
+    /// See `CutListThread::add_tile_to_mosaic`.
+    fn orientations(tile: &TileDimensions, mosaic: &Mosaic, config: &PlacementConfig) -> Vec<TileDimensions> {
+        let grain_mode = config.configuration.grain_mode;
+        let has_grain = tile.orientation != Orientation::Any;
+        let mut rotated = tile.clone();
+        rotated.rotate_90_ignoring_grain();
+
+        let mut orientations = match grain_mode {
+            GrainMode::Fixed if has_grain => vec![tile.clone()],
+            GrainMode::Alternating if has_grain && mosaic.orientation() != Orientation::Any => {
+                if mosaic.orientation() == tile.orientation {
+                    vec![tile.clone()]
+                } else {
+                    vec![rotated]
+                }
+            }
+            _ if tile.is_near_square(config.rotation_tolerance) => vec![tile.clone()],
+            _ => vec![tile.clone(), rotated],
+        };
+        if grain_mode != GrainMode::Free {
+            orientations.retain(|oriented| !CutListThread::crosses_grain(oriented, mosaic));
+        }
+        orientations
+    }
+
+    /// Best free node of the sheet to cut the tile out of, with the sheet after the cut
+    ///
+    /// A node must match the tile exactly or leave at least the minimum trim
+    /// beside it in each direction, as for the guillotine placement.
+    fn best_fit(mosaic: &Mosaic, tile: &TileDimensions, config: &PlacementConfig) -> Option<Fit> {
+        let cut_thickness = config
+            .material_cut_thicknesses
+            .get(&tile.material)
+            .copied()
+            .unwrap_or(config.cut_thickness);
+        let fits_along = |room: i32, length: i32| room == length || room >= length + config.min_trim_dimension;
+
+        let mut candidates: Vec<((i64, i32, i32), u32, TileDimensions)> = Vec::new();
+        for oriented in Self::orientations(tile, mosaic, config) {
+            for node in mosaic.root_tile_node().unused_tiles() {
+                if fits_along(node.width(), oriented.width) && fits_along(node.height(), oriented.height) {
+                    let rank = (node.area() - oriented.area(), node.y1(), node.x1());
+                    candidates.push((rank, node.id(), oriented.clone()));
+                }
+            }
+        }
+        candidates.sort_by_key(|(rank, _, _)| *rank);
+
+        candidates.into_iter().find_map(|(rank, node_id, oriented)| {
+            Self::cut_out(mosaic, node_id, &oriented, cut_thickness)
+                .filter(|cut| config.configuration.max_cuts_per_sheet.is_none_or(|limit| cut.nbr_cuts() <= limit))
+                .map(|mosaic| Fit { rank, mosaic })
+        })
+    }
+
+    /// Cut the tile out of the top-left corner of the free node `node_id`
+    ///
+    /// The node is split into the tile and its right, bottom and diagonal
+    /// remainders; only the tile's edges inside the node are cut. Every other
+    /// edge of the tile not on the sheet border or along an earlier cut is cut
+    /// as well, its kerf taken from the free nodes across it. Returns `None`
+    /// when the tile would cover a keepout or a kerf would run into a placed tile.
+    fn cut_out(mosaic: &Mosaic, node_id: u32, tile: &TileDimensions, cut_thickness: CutThickness) -> Option<Mosaic> {
+        let node = mosaic.root_tile_node().find_tile_by_id(node_id)?;
+        let area = Tile::new(node.x1(), node.x1() + tile.width, node.y1(), node.y1() + tile.height);
+        if !mosaic.is_clear_of_keepouts(&area) {
+            return None;
+        }
+        let has_right = area.x2 < node.x2();
+        let has_bottom = area.y2 < node.y2();
+        let sheet = mosaic.root_tile_node().tile().clone();
+
+        let mut mosaic = mosaic.clone();
+        let node = Self::node_mut(mosaic.root_tile_node_mut(), node_id)?;
+        let (placed_id, cuts) = Self::split_corner(node, &area, tile);
+        for cut in cuts {
+            mosaic.add_cut(cut);
+        }
+
+        for side in Side::ALL {
+            if side.on_border(&area, &sheet) {
+                continue;
+            }
+            let inner = match side {
+                Side::Right => has_right,
+                Side::Bottom => has_bottom,
+                Side::Left | Side::Top => false,
+            };
+            let kerf = side.kerf(cut_thickness);
+            let strip = side.kerf_strip(&area, kerf, &sheet);
+            let across: Vec<u32> = mosaic
+                .root_tile_node()
+                .unused_tiles()
+                .into_iter()
+                .filter(|free| free.tile().overlaps_with(&strip))
+                .map(TileNode::id)
+                .collect();
+            if mosaic.final_tile_nodes().iter().any(|placed| placed.tile().overlaps_with(&strip)) {
+                return None;
+            }
+            if !inner && across.is_empty() && side.is_cut(&area, mosaic.cuts(), kerf) {
+                continue;
+            }
+
+            for &free_id in &across {
+                let free = Self::node_mut(mosaic.root_tile_node_mut(), free_id)?;
+                let cleared = side.clear(free.tile(), &strip);
+                free.set_tile(cleared);
+            }
+            if !inner {
+                mosaic.add_cut(side.edge_cut(&area, placed_id, across.first().copied()));
+            }
+        }
+
+        Self::prune_used_up(mosaic.root_tile_node_mut());
+        Some(mosaic)
+    }
+
+    /// Split `node` into the tile at `area`, in its top-left corner, and the remainders
+    ///
+    /// With remainders both right of and below the tile, the node is split
+    /// into the tile's row and the rest, which are only separated along the
+    /// tile's bottom edge. The row is cut along the tile's right edge; the rest
+    /// is split into the bottom and diagonal remainders without a cut. The
+    /// remainders are created whole; the kerf is taken from them afterwards.
+    ///
+    /// Returns the id of the tile's node and the cuts made inside `node`.
+    fn split_corner(node: &mut TileNode, area: &Tile, tile: &TileDimensions) -> (u32, Vec<Cut>) {
+        let has_right = area.x2 < node.x2();
+        let has_bottom = area.y2 < node.y2();
+        if !has_right && !has_bottom {
+            CutListThread::mark_final(node, tile);
+            return (node.id(), Vec::new());
+        }
+
+        let mut placed = TileNode::new(area.x1, area.x2, area.y1, area.y2);
+        CutListThread::mark_final(&mut placed, tile);
+        let placed_id = placed.id();
+        let right = TileNode::new(area.x2, node.x2(), area.y1, area.y2);
+        let bottom = TileNode::new(node.x1(), area.x2, area.y2, node.y2());
+
+        let right_edge_cut = |split: &TileNode, right_id: u32| Cut {
+            x1: area.x2,
+            y1: split.y1(),
+            x2: area.x2,
+            y2: split.y2(),
+            original_width: split.width(),
+            original_height: split.height(),
+            is_horizontal: true,
+            cut_coord: area.width(),
+            original_tile_id: split.id() as i32,
+            child1_tile_id: placed_id as i32,
+            child2_tile_id: right_id as i32,
+        };
+        let bottom_edge_cut = |split: &TileNode, x2: i32, child1_id: u32, child2_id: u32| Cut {
+            x1: split.x1(),
+            y1: area.y2,
+            x2,
+            y2: area.y2,
+            original_width: split.width(),
+            original_height: split.height(),
+            is_horizontal: false,
+            cut_coord: area.height(),
+            original_tile_id: split.id() as i32,
+            child1_tile_id: child1_id as i32,
+            child2_tile_id: child2_id as i32,
+        };
+
+        let cuts = match (has_right, has_bottom) {
+            (true, false) => {
+                let cut = right_edge_cut(node, right.id());
+                node.set_child1(Some(placed));
+                node.set_child2(Some(right));
+                vec![cut]
+            }
+            (false, true) => {
+                let cut = bottom_edge_cut(node, node.x2(), placed_id, bottom.id());
+                node.set_child1(Some(placed));
+                node.set_child2(Some(bottom));
+                vec![cut]
+            }
+            _ => {
+                let mut row = TileNode::new(node.x1(), node.x2(), node.y1(), area.y2);
+                let mut rest = TileNode::new(node.x1(), node.x2(), area.y2, node.y2());
+                let diagonal = TileNode::new(area.x2, node.x2(), area.y2, node.y2());
+                let cuts = vec![
+                    bottom_edge_cut(node, area.x2, row.id(), rest.id()),
+                    right_edge_cut(&row, right.id()),
+                ];
+                row.set_child1(Some(placed));
+                row.set_child2(Some(right));
+                rest.set_child1(Some(bottom));
+                rest.set_child2(Some(diagonal));
+                node.set_child1(Some(row));
+                node.set_child2(Some(rest));
+                cuts
+            }
+        };
+        (placed_id, cuts)
+    }
+
+    /// Node of the tree with the given id
+    fn node_mut(root: &mut TileNode, id: u32) -> Option<&mut TileNode> {
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if node.id == id {
+                return Some(node);
+            }
+            stack.extend(node.child1.as_deref_mut());
+            stack.extend(node.child2.as_deref_mut());
+        }
+        None
+    }
+
+    /// Drop free nodes the kerf has used up, and split nodes left with nothing in them
+    fn prune_used_up(node: &mut TileNode) {
+        for slot in [&mut node.child1, &mut node.child2] {
+            let Some(child) = slot else {
+                continue;
+            };
+            let was_split = child.has_children();
+            Self::prune_used_up(child);
+            if !child.is_final() && (child.area() == 0 || (was_split && !child.has_children())) {
+                *slot = None;
+            }
+        }
+    }
+}
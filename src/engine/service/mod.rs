@@ -6,6 +6,10 @@ pub mod trait_impl;         // Complete trait implementation
 pub mod validation;         // Request validation utilities
 pub mod computation;        // Computational logic
 pub mod utilities;          // Helper utilities
+pub mod stock_provider;     // Pluggable stock solution strategy
+pub mod placement;          // Pluggable placement algorithm
+pub mod free_corner;        // Non-guillotine placement for lasers and waterjets
+pub mod progress;           // Streaming progress callback
 
 // Legacy modules - kept for backward compatibility but not re-exported
 // to avoid namespace pollution. Use full paths to access:
@@ -18,6 +22,10 @@ pub mod service_management;
 pub use trait_def::CutListOptimizerService;
 pub use core::CutListOptimizerServiceImpl;
 pub use validation::RequestValidator;
+pub use stock_provider::{GeneratedStockSolutionProvider, StockSolutionProvider};
+pub use placement::{GuillotinePlacement, PlacementAlgorithm, PlacementConfig};
+pub use free_corner::FreeCornerPlacement;
+pub use progress::{ProgressCallback, ProgressUpdate};
 
 // For utilities, use full paths to avoid namespace pollution:
 // use crate::engine::service::utilities::{TaskMonitor, StatsCollector};
@@ -0,0 +1,129 @@
+//! Pluggable placement algorithm for the optimizer
+//!
+//! The layout asks a `PlacementAlgorithm` to place the tiles of one order on
+//! the sheets of a stock solution. `GuillotinePlacement` reproduces the
+//! built-in behaviour; `FreeCornerPlacement` lays out tasks whose
+//! configuration asks for free-corner cuts.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::engine::comparator::PriorityListFactory;
+use crate::engine::cut_list_thread::{CutListThread, SolutionComparator};
+use crate::engine::stock::StockSolution;
+use crate::errors::{AppError, Result};
+use crate::models::{Configuration, CutThickness, Solution, TileDimensions};
+use crate::CutDirection;
+
+/// Settings for one placement run
+///
+/// Lengths are already scaled by the request's factor, like the tiles.
+#[derive(Debug, Clone)]
+pub struct PlacementConfig {
+    /// Configuration of the request
+    pub configuration: Configuration,
+    /// Kerf of the default blade
+    pub cut_thickness: CutThickness,
+    /// Kerf per material, overriding `cut_thickness`
+    pub material_cut_thicknesses: HashMap<String, CutThickness>,
+    /// Minimum trim dimension
+    pub min_trim_dimension: i32,
+    /// Tiles whose sides differ by less than this are not tried rotated
+    pub rotation_tolerance: i32,
+    /// Accuracy factor, bounding the solutions kept between tiles
+    pub accuracy_factor: usize,
+    /// First-cut direction
+    pub first_cut_orientation: CutDirection,
+    /// First-cut direction per material, overriding `first_cut_orientation`
+    pub material_cut_directions: HashMap<String, CutDirection>,
+    /// Stock ids each panel is restricted to, by panel id
+    pub allowed_stock_ids: HashMap<i32, Vec<i32>>,
+}
+
+impl Default for PlacementConfig {
+    /// Default configuration without kerf or trim
+    fn default() -> Self {
+        let configuration = Configuration::default();
+        Self {
+            cut_thickness: CutThickness::default(),
+            material_cut_thicknesses: HashMap::new(),
+            min_trim_dimension: 0,
+            rotation_tolerance: 0,
+            accuracy_factor: configuration.optimization_factor.max(1) as usize,
+            first_cut_orientation: configuration.cut_direction(),
+            material_cut_directions: HashMap::new(),
+            allowed_stock_ids: HashMap::new(),
+            configuration,
+        }
+    }
+}
+
+/// Places tiles on the sheets of a stock solution
+pub trait PlacementAlgorithm: Send + Sync + fmt::Debug {
+    /// Place `tiles`, in the given order, on the sheets of `stock_solution`
+    ///
+    /// Solutions are returned best first. Tiles a solution could not place
+    /// are listed as its no-fit panels; an empty result means nothing was
+    /// placed at all.
+    fn place(
+        &self,
+        tiles: &[TileDimensions],
+        stock_solution: &StockSolution,
+        config: &PlacementConfig,
+    ) -> Result<Vec<Solution>>;
+}
+
+/// The default algorithm, backed by the guillotine `CutListThread`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GuillotinePlacement;
+
+impl GuillotinePlacement {
+    fn comparators(configuration: &Configuration) -> Vec<SolutionComparator> {
+        PriorityListFactory::get_final_solution_prioritized_comparator_functions(configuration)
+            .into_iter()
+            .map(|compare| Box::new(compare) as SolutionComparator)
+            .collect()
+    }
+}
+
+impl PlacementAlgorithm for GuillotinePlacement {
+    fn place(
+        &self,
+        tiles: &[TileDimensions],
+        stock_solution: &StockSolution,
+        config: &PlacementConfig,
+    ) -> Result<Vec<Solution>> {
+        let configuration = &config.configuration;
+        let mut thread = CutListThread::new();
+        thread.set_thread_prioritized_comparators(Self::comparators(configuration));
+        thread.set_final_solution_prioritized_comparators(Self::comparators(configuration));
+        thread.set_tiles(tiles.to_vec());
+        thread.set_allowed_stock_ids(config.allowed_stock_ids.clone());
+        thread.set_stock_solution(Some(stock_solution.clone()));
+        thread.set_cut_thickness(config.cut_thickness);
+        thread.set_material_cut_thicknesses(config.material_cut_thicknesses.clone());
+        thread.set_min_trim_dimension(config.min_trim_dimension);
+        thread.set_rotation_tolerance(config.rotation_tolerance);
+        thread.set_max_solutions_retained(configuration.max_solutions_retained);
+        thread.set_max_cuts_per_sheet(configuration.max_cuts_per_sheet);
+        thread.set_accuracy_factor(config.accuracy_factor);
+        thread.set_first_cut_orientation(config.first_cut_orientation);
+        thread.set_material_cut_directions(config.material_cut_directions.clone());
+        thread.set_first_cut_rule(configuration.first_cut_rule);
+        thread.set_placement_heuristic(configuration.placement_heuristic);
+        thread.set_group_identical_tiles(configuration.group_identical_tiles);
+        thread.set_fit_within_kerf(configuration.fit_within_kerf);
+        thread.set_grain_mode(configuration.grain_mode);
+        thread.run();
+
+        if thread.has_error() {
+            return Err(AppError::optimization_failed("Layout computation failed"));
+        }
+
+        let solutions = thread.all_solutions();
+        let solutions = solutions
+            .lock()
+            .map_err(|_| AppError::thread_sync("Failed to lock all_solutions"))?;
+        Ok(solutions.clone())
+    }
+}
@@ -0,0 +1,98 @@
+//! Streaming progress reports from the optimizer
+//!
+//! A `ProgressCallback` registered on the service receives a
+//! `ProgressUpdate` after every stock solution tried within a permutation,
+//! after every permutation tried for a material, and a final one at 100%
+//! when the material is done.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::models::Solution;
+
+/// Snapshot of a material's computation after one permutation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressUpdate {
+    /// Task being computed
+    pub task_id: String,
+    /// Material the permutation belongs to
+    pub material: String,
+    /// Number of permutations tried so far
+    pub permutation_index: usize,
+    /// Percentage of the material's permutations done, 0 to 100
+    pub percentage: i32,
+    /// Efficiency of the best solution found so far, 0.0 when there is none
+    pub best_efficiency: f64,
+    /// Tiles placed by the best solution found so far
+    pub tiles_placed: usize,
+}
+
+impl ProgressUpdate {
+    /// Build an update whose best-so-far figures come from `solutions`
+    ///
+    /// The best solution places the most tiles, then has the highest efficiency.
+    pub fn new(
+        task_id: impl Into<String>,
+        material: impl Into<String>,
+        permutation_index: usize,
+        percentage: i32,
+        solutions: &[Solution],
+    ) -> Self {
+        let (tiles_placed, best_efficiency) = solutions
+            .iter()
+            .map(|solution| (solution.get_nbr_final_tiles().max(0) as usize, solution.get_efficiency() as f64))
+            .max_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)))
+            .unwrap_or((0, 0.0));
+
+        Self {
+            task_id: task_id.into(),
+            material: material.into(),
+            permutation_index,
+            percentage,
+            best_efficiency,
+            tiles_placed,
+        }
+    }
+
+    /// Percentage of a material's work done after `stock_done` of the
+    /// `stock_solutions` stock solutions of the next permutation
+    ///
+    /// Work is `total_permutations` permutations times the stock solutions
+    /// each one tries. Only finishing every permutation gives 100, so a
+    /// material stopped early, once a good enough solution is found, stays
+    /// below it until it is marked done.
+    pub fn percentage_of_work(
+        permutations_done: usize,
+        total_permutations: usize,
+        stock_done: usize,
+        stock_solutions: usize,
+    ) -> i32 {
+        if permutations_done >= total_permutations {
+            return 100;
+        }
+        let permutation_fraction = stock_done.min(stock_solutions) as f64 / stock_solutions.max(1) as f64;
+        let done = (permutations_done as f64 + permutation_fraction) / total_permutations as f64;
+        ((done * 100.0) as i32).min(99)
+    }
+}
+
+/// Shared handle to a progress listener
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(ProgressUpdate) + Send + Sync>);
+
+impl ProgressCallback {
+    pub fn new(callback: Box<dyn Fn(ProgressUpdate) + Send + Sync>) -> Self {
+        Self(Arc::from(callback))
+    }
+
+    /// Deliver `update` to the listener
+    pub fn report(&self, update: ProgressUpdate) {
+        (self.0)(update)
+    }
+}
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
@@ -0,0 +1,63 @@
+//! Pluggable stock-combination strategy for the optimizer
+//!
+//! The optimizer asks a `StockSolutionProvider` for the candidate stock
+//! solutions to try for each material. `GeneratedStockSolutionProvider`
+//! reproduces the built-in behaviour.
+
+use std::fmt;
+
+use crate::engine::stock::{StockSolutionGenerator, StockSolutionSource};
+use crate::errors::Result;
+use crate::models::{Task, TileDimensions};
+
+/// Supplies candidate stock solutions for one material of a task
+pub trait StockSolutionProvider: Send + Sync + fmt::Debug {
+    /// Build the candidates for `tiles_to_fit`, drawn from `stock_tiles`
+    ///
+    /// Candidates are tried in the order the source yields them. A hint of
+    /// `Some(1)` means the configuration asks for a single stock unit.
+    fn stock_solutions(
+        &self,
+        tiles_to_fit: &[TileDimensions],
+        stock_tiles: &[TileDimensions],
+        task: &Task,
+        max_stock_solution_length_hint: Option<usize>,
+    ) -> Result<StockSolutionSource>;
+}
+
+/// The default provider, backed by `StockSolutionGenerator`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeneratedStockSolutionProvider;
+
+impl StockSolutionProvider for GeneratedStockSolutionProvider {
+    fn stock_solutions(
+        &self,
+        tiles_to_fit: &[TileDimensions],
+        stock_tiles: &[TileDimensions],
+        task: &Task,
+        max_stock_solution_length_hint: Option<usize>,
+    ) -> Result<StockSolutionSource> {
+        let request = task.calculation_request().as_ref();
+        let preference_weights = request
+            .map(|request| StockSolutionGenerator::preference_weights_from_panels(&request.stock_panels))
+            .unwrap_or_default();
+        let consume_first = request
+            .map(|request| StockSolutionGenerator::consume_first_ids_from_panels(&request.stock_panels))
+            .unwrap_or_default();
+        let configuration = request.and_then(|request| request.configuration.as_ref());
+        let max_sheets = configuration.and_then(|configuration| configuration.max_sheets);
+        let single_stock_unit = configuration.is_some_and(|configuration| configuration.use_single_stock_unit);
+
+        let generator = StockSolutionGenerator::new(
+            tiles_to_fit.to_vec(),
+            stock_tiles.to_vec(),
+            max_stock_solution_length_hint,
+        )?
+        .with_preference_weights(preference_weights)
+        .with_consume_first(consume_first)
+        .with_max_sheets(max_sheets)
+        .with_single_stock_unit(single_stock_unit);
+
+        Ok(StockSolutionSource::from_generator(generator))
+    }
+}
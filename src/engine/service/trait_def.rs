@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use crate::{
     errors::Result,
     models::{
-        CalculationRequest, CalculationSubmissionResult, TaskStatusResponse, Stats,
+        CalculationRequest, CalculationSubmissionResult, FeasibilityReport, TaskStatusResponse, Stats,
         enums::Status,
     },
 };
@@ -38,6 +38,11 @@ pub trait CutListOptimizerService {
     /// Set whether to allow multiple tasks per client
     fn set_allow_multiple_tasks_per_client(&mut self, allow: bool);
     
+    /// Quick check that a request could fit its stock, without optimizing
+    fn feasibility_check(&self, request: &CalculationRequest) -> FeasibilityReport {
+        request.feasibility()
+    }
+    
     // /// Set cut list logger (если нужно)
     // fn set_cut_list_logger(&mut self, logger: Arc<dyn CutListLogger>);
 }
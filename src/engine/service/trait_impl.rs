@@ -1,14 +1,18 @@
 //! Complete trait implementation for CutListOptimizerService
 //! This module contains the full implementation of all trait methods
 
+use std::path::Path;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use crate::{
     errors::Result,
     models::{
-        CalculationRequest, CalculationSubmissionResult, TaskStatusResponse, Stats,
+        CalculationRequest, CalculationSubmissionResult, TaskCheckpoint, TaskStatusResponse, Stats,
         enums::{Status, StatusCode},
     },
     logging::macros::error,
+    engine::watch_dog::core::WatchDog,
 };
 
 use super::{
@@ -32,7 +36,7 @@ impl CutListOptimizerService for CutListOptimizerServiceImpl {
         }
 
         // Initialize RunningTasks singleton (corresponds to Java: this.runningTasks = RunningTasks.getInstance())
-        let _running_tasks = get_running_tasks_instance();
+        let running_tasks = get_running_tasks_instance();
         info!("RunningTasks singleton initialized");
 
         // TODO: Initialize ThreadPoolExecutor equivalent
@@ -40,14 +44,17 @@ impl CutListOptimizerService for CutListOptimizerServiceImpl {
         //          new ArrayBlockingQueue(THREAD_QUEUE_SIZE), Executors.defaultThreadFactory(), rejectedExecutionHandlerImpl);
         // For now, we use the semaphore-based approach in core.rs
         
-        // TODO: Initialize WatchDog
-        // In Java: WatchDog watchDog = new WatchDog();
-        //          this.watchDog = watchDog;
-        //          watchDog.setCutListLogger(this.cutListLogger);
-        //          this.watchDog.setRunningTasks(this.runningTasks);
-        //          this.watchDog.setTaskExecutor(this.taskExecutor);
-        //          this.watchDog.setCutListOptimizerService(this);
-        //          new Thread(this.watchDog, "watchDog").start();
+        // Start the WatchDog (corresponds to Java: new Thread(this.watchDog, "watchDog").start())
+        if self.watch_dog.is_none() {
+            let watch_dog = Arc::new(WatchDog::with_config(Arc::clone(running_tasks), self.watch_dog_config().clone()));
+            let monitor = Arc::clone(&watch_dog);
+            tokio::spawn(async move { monitor.start().await });
+            self.watch_dog = Some(watch_dog);
+            info!("WatchDog started");
+        }
+
+        // Every task evaluates as many permutations at once as the pool has threads
+        self.max_threads_per_task = thread_pool_size;
 
         // Set initialization status (corresponds to Java implicit initialization completion)
         self.set_initialized(true);
@@ -56,7 +63,7 @@ impl CutListOptimizerService for CutListOptimizerServiceImpl {
         Ok(())
     }
 
-    async fn submit_task(&self, request: CalculationRequest) -> Result<CalculationSubmissionResult> {
+    async fn submit_task(&self, mut request: CalculationRequest) -> Result<CalculationSubmissionResult> {
         use crate::engine::running_tasks::{get_running_tasks_instance, TaskManager};
         use crate::models::task::Task;
         use crate::logging::macros::info;
@@ -64,6 +71,9 @@ impl CutListOptimizerService for CutListOptimizerServiceImpl {
         self.ensure_initialized()?;
         self.ensure_not_shutdown()?;
 
+        // Offer offcuts kept from earlier tasks as stock
+        self.add_offcut_stock(&mut request);
+
         // Validate panels using RequestValidator (already exists)
         if let Some(error_code) = RequestValidator::validate_request(&request).await {
             return Ok(CalculationSubmissionResult {
@@ -88,9 +98,10 @@ impl CutListOptimizerService for CutListOptimizerServiceImpl {
         // Launch tokio::spawn with compute_task in background
         let request_clone = request.clone();
         let task_id_clone = task_id.clone();
+        let hooks = self.compute_hooks();
         
         tokio::spawn(async move {
-            if let Err(e) = task_compute::compute_task(request_clone, task_id_clone).await {
+            if let Err(e) = task_compute::compute_task_with_hooks(request_clone, task_id_clone, hooks).await {
                 error!("Task computation failed: {}", e);
             }
         });
@@ -133,6 +144,12 @@ impl CutListOptimizerService for CutListOptimizerServiceImpl {
             let percentage_done = task.percentage_done() as u8;
             let init_percentage = task.max_thread_progress_percentage() as u8;
             let solution = task.solution.read().unwrap().clone();
+
+            if status == Status::Finished {
+                if let Some(response) = &solution {
+                    self.record_offcuts(task_id, response);
+                }
+            }
             
             Ok(Some(TaskStatusResponse {
                 status,
@@ -279,6 +296,42 @@ impl CutListOptimizerService for CutListOptimizerServiceImpl {
 
 impl CutListOptimizerServiceImpl {
 
+    /// Resume a task from a checkpoint file written by the watch dog
+    ///
+    /// The task is rebuilt with its checkpointed solutions and progress, and
+    /// its search continues after the permutations it had already finished,
+    /// so the result is never worse than the checkpointed one. The task keeps
+    /// the ID it had when the checkpoint was written.
+    pub async fn resume_task(&self, path: &Path) -> Result<CalculationSubmissionResult> {
+        use crate::engine::running_tasks::{get_running_tasks_instance, TaskManager};
+        use crate::logging::macros::info;
+
+        self.ensure_initialized()?;
+        self.ensure_not_shutdown()?;
+
+        let checkpoint = TaskCheckpoint::read(path)?;
+        let Some(request) = checkpoint.calculation_request.clone() else {
+            return Err(crate::errors::AppError::invalid_input("Checkpoint holds no calculation request"));
+        };
+        let task_id = checkpoint.task_id.clone();
+
+        // Add the task before spawning, as submit_task does
+        get_running_tasks_instance().add_task(checkpoint.to_task())?;
+        info!("Task {} resumed from {}", task_id, path.display());
+
+        let hooks = self.compute_hooks();
+        tokio::spawn(async move {
+            if let Err(e) = task_compute::resume_task_with_hooks(request, checkpoint, hooks).await {
+                error!("Resumed task computation failed: {}", e);
+            }
+        });
+
+        Ok(CalculationSubmissionResult {
+            status_code: StatusCode::Ok,
+            task_id: Some(task_id),
+        })
+    }
+
     /// Shutdown the service gracefully
     /// 
     /// This method stops all running tasks and cleans up resources.
@@ -290,6 +343,10 @@ impl CutListOptimizerServiceImpl {
         self.set_shutdown(true);
         info!("Service shutdown initiated");
 
+        if let Some(watch_dog) = &self.watch_dog {
+            watch_dog.stop();
+        }
+
         // TODO: Implement graceful shutdown:
         // 1. Stop accepting new tasks
         // 2. Wait for running tasks to complete (with timeout)
@@ -321,10 +378,13 @@ impl CutListOptimizerServiceImpl {
         // Set shutdown status
         self.set_shutdown(true);
         
+        if let Some(watch_dog) = self.watch_dog.take() {
+            watch_dog.stop();
+        }
+
         // TODO: Additional cleanup:
-        // 1. Stop WatchDog thread
-        // 2. Clean up thread pool executor
-        // 3. Release any held resources
+        // 1. Clean up thread pool executor
+        // 2. Release any held resources
         
         info!("Service destroyed successfully");
         Ok(())
@@ -1,7 +1,7 @@
 //! Request validation utilities
 
 use crate::{
-    models::{CalculationRequest, enums::StatusCode},
+    models::{CalculationRequest, Panel, enums::StatusCode},
     constants::EngineConstants,
 };
 
@@ -38,6 +38,38 @@ impl RequestValidator {
             return Some(StatusCode::TooManyStockPanels);
         }
 
+        let max_sheets = request.configuration.as_ref().and_then(|config| config.max_sheets);
+        if let Some(max_sheets) = max_sheets {
+            if !Self::fits_within_sheets(request, max_sheets) {
+                return Some(StatusCode::NotEnoughStock);
+            }
+        }
+
         None // Request is valid
     }
+
+    /// Whether the `max_sheets` biggest stock sheets have enough area for all panels
+    fn fits_within_sheets(request: &CalculationRequest, max_sheets: usize) -> bool {
+        let valid_areas = |panels: &[Panel]| -> Vec<(f64, usize)> {
+            panels
+                .iter()
+                .filter(|panel| panel.is_valid().unwrap_or(false))
+                .filter_map(|panel| panel.area().ok().map(|area| (area, panel.count as usize)))
+                .collect()
+        };
+
+        let required: f64 = valid_areas(&request.panels)
+            .into_iter()
+            .map(|(area, count)| area * count as f64)
+            .sum();
+
+        let mut sheets: Vec<f64> = valid_areas(&request.stock_panels)
+            .into_iter()
+            .flat_map(|(area, count)| std::iter::repeat_n(area, count))
+            .collect();
+        sheets.sort_by(|a, b| b.total_cmp(a));
+        let available: f64 = sheets.into_iter().take(max_sheets).sum();
+
+        required <= available
+    }
 }
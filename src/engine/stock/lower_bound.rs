@@ -0,0 +1,44 @@
+//! Area-based lower bound on the number of sheets a job needs
+//!
+//! No layout can use fewer sheets than the tile area divided by the sheet
+//! area, so the bound frames a solution's sheet count: matching it means no
+//! layout can do better.
+
+use std::collections::HashMap;
+
+use crate::models::TileDimensions;
+
+/// Fewest copies of `sheet` that could hold the tiles of its material
+///
+/// Only tiles of the sheet's material count. Returns 0 when there are no
+/// such tiles or the sheet has no area.
+pub fn min_sheets_lower_bound(tiles: &[TileDimensions], sheet: &TileDimensions) -> usize {
+    let sheet_area = sheet.area();
+    if sheet_area <= 0 {
+        return 0;
+    }
+    let tile_area: i64 = tiles
+        .iter()
+        .filter(|tile| tile.material == sheet.material)
+        .map(TileDimensions::area)
+        .sum();
+    (tile_area as u64).div_ceil(sheet_area as u64) as usize
+}
+
+/// Lower bound on sheets per material, against the largest sheet of each
+///
+/// Materials without tiles are left out.
+pub fn min_sheets_lower_bounds(tiles: &[TileDimensions], stock_tiles: &[TileDimensions]) -> HashMap<String, usize> {
+    let mut largest: HashMap<&str, &TileDimensions> = HashMap::new();
+    for sheet in stock_tiles {
+        let entry = largest.entry(sheet.material.as_str()).or_insert(sheet);
+        if sheet.area() > entry.area() {
+            *entry = sheet;
+        }
+    }
+    largest
+        .into_iter()
+        .map(|(material, sheet)| (material.to_string(), min_sheets_lower_bound(tiles, sheet)))
+        .filter(|&(_, bound)| bound > 0)
+        .collect()
+}
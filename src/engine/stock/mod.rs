@@ -2,8 +2,12 @@
 pub mod stock_solution;
 pub mod stock_solution_generator;
 pub mod stock_panel_picker;
+pub mod stock_solution_source;
+pub mod lower_bound;
 
 // Export main types to avoid ambiguous glob re-exports
 pub use stock_solution::{StockSolution};
 pub use stock_solution_generator::{StockSolutionGenerator};
+pub use stock_solution_source::StockSolutionSource;
+pub use lower_bound::{min_sheets_lower_bound, min_sheets_lower_bounds};
 pub use stock_panel_picker::{StockPanelPicker, StockPanelPickerBuilder, StockPanelPickerStats, SolutionSortConfig};
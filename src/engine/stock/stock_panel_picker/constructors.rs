@@ -2,7 +2,7 @@
 
 use std::sync::{Arc, Mutex};
 use crate::models::{task::Task, TileDimensions};
-use crate::engine::stock::StockSolutionGenerator;
+use crate::engine::stock::{StockSolutionGenerator, StockSolutionSource};
 use crate::errors::{AppError, Result};
 use super::{StockPanelPicker, StockPanelPickerBuilder};
 
@@ -17,14 +17,25 @@ impl StockPanelPicker {
         task: Arc<Task>,
         max_stock_solution_length_hint: Option<usize>,
     ) -> Result<Self> {
+        let request = task.calculation_request().as_ref();
+        let preference_weights = request
+            .map(|request| StockSolutionGenerator::preference_weights_from_panels(&request.stock_panels))
+            .unwrap_or_default();
+        let consume_first = request
+            .map(|request| StockSolutionGenerator::consume_first_ids_from_panels(&request.stock_panels))
+            .unwrap_or_default();
+
         let stock_solution_generator = StockSolutionGenerator::new(
             tiles_to_fit,
             stock_tiles,
             max_stock_solution_length_hint,
-        )?;
+        )?
+        .with_preference_weights(preference_weights)
+        .with_consume_first(consume_first);
 
         Ok(Self {
             stock_solution_generator,
+            solution_source: Arc::new(Mutex::new(None)),
             task,
             stock_solutions: Arc::new(Mutex::new(Vec::new())),
             max_retrieved_idx: Arc::new(Mutex::new(0)),
@@ -45,6 +56,16 @@ impl StockPanelPicker {
         Self::new(tiles_to_fit, stock_tiles, task, None)
     }
 
+    /// Draw candidate stock solutions from `source` instead of the built-in generator
+    ///
+    /// Must be called before `init`, which takes ownership of the source.
+    pub fn with_solution_source(self, source: StockSolutionSource) -> Self {
+        if let Ok(mut guard) = self.solution_source.lock() {
+            *guard = Some(source);
+        }
+        self
+    }
+
     /// Create a builder for StockPanelPicker
     pub fn builder() -> StockPanelPickerBuilder {
         StockPanelPickerBuilder::new()
@@ -20,44 +20,18 @@ impl StockPanelPicker {
     /// * `Err(...)` - If an error occurs during retrieval
     pub fn get_stock_solution(&self, index: usize) -> Result<Option<StockSolution>> {
         // Check if thread is initialized
-      
         if !self.is_initialized()? {
             return Err(AppError::stock_panel_picker_not_initialized());
         }
 
         // Wait for solution to be available or generation to complete
         loop {
-            // Atomically check state and retrieve solution if available
-            let (solution_available, solution) = {
-                let solutions = self.stock_solutions.lock()
-                    .map_err(|e| AppError::thread_sync(
-                        format!("Failed to lock stock solutions: {}", e)
-                    ))?;
-
-                if solutions.len() > index {
-                    (true, Some(solutions[index].clone()))
-                } else {
-                    (false, None)
-                }
-            };
-
-            if solution_available {
-                if let Some(solution) = solution {
-                    // Update max retrieved index atomically
-                    {
-                        let mut max_idx = self.max_retrieved_idx.lock()
-                            .map_err(|e| AppError::thread_sync(
-                                format!("Failed to lock max retrieved index: {}", e)
-                            ))?;
-                        *max_idx = (*max_idx).max(index);
-                    }
-                    return Ok(Some(solution));
-                }
+            if let Some(solution) = self.available_stock_solution(index)? {
+                return Ok(Some(solution));
             }
 
             // Check if generation is still active
-            let is_generating = self.is_generating()?;
-            if !is_generating {
+            if !self.is_generating()? {
                 crate::log_debug!("No more possible stock solutions");
                 return Ok(None);
             }
@@ -68,6 +42,49 @@ impl StockPanelPicker {
         }
     }
 
+    /// Same as `get_stock_solution`, yielding to the runtime while it waits
+    ///
+    /// The generation task may be queued on the caller's worker, so async
+    /// callers must not block that worker while waiting for it.
+    pub async fn wait_for_stock_solution(&self, index: usize) -> Result<Option<StockSolution>> {
+        if !self.is_initialized()? {
+            return Err(AppError::stock_panel_picker_not_initialized());
+        }
+
+        loop {
+            if let Some(solution) = self.available_stock_solution(index)? {
+                return Ok(Some(solution));
+            }
+            if !self.is_generating()? {
+                crate::log_debug!("No more possible stock solutions");
+                return Ok(None);
+            }
+            crate::log_debug!("Waiting for stock solution generation: idx[{}]", index);
+            tokio::time::sleep(Duration::from_millis(StockConstants::RETRY_SLEEP_MS)).await;
+        }
+    }
+
+    /// The solution at `index` if it was generated already, recording the retrieval
+    fn available_stock_solution(&self, index: usize) -> Result<Option<StockSolution>> {
+        let solution = {
+            let solutions = self.stock_solutions.lock()
+                .map_err(|e| AppError::thread_sync(
+                    format!("Failed to lock stock solutions: {}", e)
+                ))?;
+            solutions.get(index).cloned()
+        };
+
+        if solution.is_some() {
+            // Update max retrieved index atomically
+            let mut max_idx = self.max_retrieved_idx.lock()
+                .map_err(|e| AppError::thread_sync(
+                    format!("Failed to lock max retrieved index: {}", e)
+                ))?;
+            *max_idx = (*max_idx).max(index);
+        }
+        Ok(solution)
+    }
+
     /// Get multiple stock solutions starting from the given index
     /// 
     /// # Arguments
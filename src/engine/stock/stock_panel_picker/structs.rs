@@ -6,7 +6,7 @@ use std::sync::{Arc, Mutex};
 use tokio::task::JoinHandle;
 use tokio::sync::mpsc;
 use crate::models::{task::Task, TileDimensions};
-use crate::engine::stock::{StockSolution, StockSolutionGenerator};
+use crate::engine::stock::{StockSolution, StockSolutionGenerator, StockSolutionSource};
 use crate::errors::Result;
 
 
@@ -22,6 +22,9 @@ pub struct StockPanelPicker {
     /// The stock solution generator
     pub(crate) stock_solution_generator: StockSolutionGenerator,
     
+    /// Custom source of candidate stock solutions; the generator is used when unset
+    pub(crate) solution_source: Arc<Mutex<Option<StockSolutionSource>>>,
+    
     /// Reference to the task for status checking
     pub(crate) task: Arc<Task>,
     
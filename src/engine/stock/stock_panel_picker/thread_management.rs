@@ -7,7 +7,7 @@ use tokio::time::sleep;
 use crate::{log_debug, log_error};
 
 use crate::models::task::Task;
-use crate::engine::stock::StockSolution;
+use crate::engine::stock::{StockSolution, StockSolutionSource};
 use crate::constants::StockConstants;
 use crate::errors::{AppError, Result};
 use super::StockPanelPicker;
@@ -36,7 +36,12 @@ impl StockPanelPicker {
         }
 
         // Clone necessary data for the background task
-        let stock_solution_generator = Arc::new(Mutex::new(self.stock_solution_generator.clone()));
+        let source = self
+            .solution_source
+            .lock()
+            .map_err(|_| AppError::thread_error("Failed to acquire solution source lock"))?
+            .take()
+            .unwrap_or_else(|| StockSolutionSource::from_generator(self.stock_solution_generator.clone()));
         let stock_solutions = Arc::clone(&self.stock_solutions);
         let max_retrieved_idx = Arc::clone(&self.max_retrieved_idx);
         let task = Arc::clone(&self.task);
@@ -44,7 +49,7 @@ impl StockPanelPicker {
         // Spawn the background generation task
         let handle = tokio::spawn(async move {
             Self::generation_loop(
-                stock_solution_generator,
+                source,
                 stock_solutions,
                 max_retrieved_idx,
                 task,
@@ -58,7 +63,7 @@ impl StockPanelPicker {
 
     /// Main generation loop that runs in the background
     async fn generation_loop(
-        stock_solution_generator: Arc<Mutex<crate::engine::stock::StockSolutionGenerator>>,
+        mut source: StockSolutionSource,
         stock_solutions: Arc<Mutex<Vec<StockSolution>>>,
         max_retrieved_idx: Arc<Mutex<usize>>,
         task: Arc<Task>,
@@ -100,21 +105,7 @@ impl StockPanelPicker {
 
             if should_generate {
                 // Generate new solution
-                let new_solution = {
-                    let mut generator_guard = match stock_solution_generator.lock() {
-                        Ok(guard) => guard,
-                        Err(e) => {
-                            log_error!("Failed to acquire stock solution generator lock: {}", e);
-                            break;
-                        }
-                    };
-
-                    match generator_guard.generate_stock_solution() {
-                        crate::models::enums::StockSolutionResult::Solution(solution) => Some(solution),
-                        crate::models::enums::StockSolutionResult::NoSolution => None,
-                        crate::models::enums::StockSolutionResult::AllExcluded => None,
-                    }
-                };
+                let new_solution = source.next();
 
                 if let Some(solution) = new_solution {
                     // Add the solution to our collection atomically
@@ -194,7 +185,7 @@ impl StockPanelPicker {
             }
 
             // Check task termination conditions
-            if !task.is_running() {
+            if !task.is_running() || task.is_cancelled() {
                 let solutions_count = stock_solutions.lock()
                     .map(|guard| guard.len())
                     .unwrap_or(0);
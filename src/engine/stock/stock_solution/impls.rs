@@ -7,6 +7,7 @@ impl StockSolution {
     pub fn new() -> Self {
         Self {
             stock_tile_dimensions: Vec::new(),
+            preference_weight: 0.0,
         }
     }
 
@@ -14,6 +15,7 @@ impl StockSolution {
     pub fn from_tiles(tiles: Vec<TileDimensions>) -> Self {
         Self {
             stock_tile_dimensions: tiles,
+            preference_weight: 0.0,
         }
     }
 
@@ -21,6 +23,7 @@ impl StockSolution {
     pub fn from_slice(tiles: &[TileDimensions]) -> Self {
         Self {
             stock_tile_dimensions: tiles.to_vec(),
+            preference_weight: 0.0,
         }
     }
 
@@ -44,6 +47,16 @@ impl StockSolution {
         self.stock_tile_dimensions = tiles;
     }
 
+    /// Get the total preference weight of the chosen stock tiles
+    pub fn get_preference_weight(&self) -> f64 {
+        self.preference_weight
+    }
+
+    /// Set the total preference weight of the chosen stock tiles
+    pub fn set_preference_weight(&mut self, weight: f64) {
+        self.preference_weight = weight;
+    }
+
     /// Sort panels in ascending order by area
     pub fn sort_panels_asc(&mut self) {
         self.stock_tile_dimensions.sort_by(|a, b| a.area().cmp(&b.area()));
@@ -66,7 +79,7 @@ impl StockSolution {
 
     /// Calculate the total area of all tiles
     pub fn get_total_area(&self) -> i64 {
-        self.stock_tile_dimensions.iter().map(|tile| tile.area() as i64).sum()
+        self.stock_tile_dimensions.iter().map(|tile| tile.area()).sum()
     }
 
     /// Get a string representation of all tiles (Java-compatible method)
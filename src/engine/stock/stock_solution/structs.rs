@@ -6,4 +6,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockSolution {
     pub(crate) stock_tile_dimensions: Vec<TileDimensions>,
+
+    /// Sum of the preference weights of the chosen stock tiles
+    #[serde(default)]
+    pub(crate) preference_weight: f64,
 }
@@ -1,3 +1,5 @@
+use std::cmp::Reverse;
+
 use super::structs::{StockSolutionGenerator, StockSolutionConfig};
 use crate::models::TileDimensions;
 use crate::engine::stock::StockSolution;
@@ -15,7 +17,7 @@ impl StockSolutionGenerator {
         self.smallest_tile_area = i64::MAX;
 
         for tile in &self.tiles_to_fit {
-            let area = tile.area() as i64;
+            let area = tile.area();
             self.required_area += area;
             
             if tile.max_dimension() > self.required_max_dimension {
@@ -31,7 +33,7 @@ impl StockSolutionGenerator {
     /// Get the area of the biggest stock tile
     pub(crate) fn get_biggest_stock_tile_area(&self) -> i64 {
         self.stock_tiles.iter()
-            .map(|tile| tile.area() as i64)
+            .map(|tile| tile.area())
             .max()
             .unwrap_or(0)
     }
@@ -49,16 +51,19 @@ impl StockSolutionGenerator {
     /// Generate a stock solution using all available panels
     pub(crate) fn gen_all_panel_stock_solution(&self) -> StockSolution {
         let max_tiles = StockSolutionConfig::default().max_stock_solution_length
-            .min(self.stock_tiles.len());
+            .min(self.stock_tiles.len())
+            .min(self.max_sheets.unwrap_or(usize::MAX));
         
-        let tiles: Vec<TileDimensions> = self.stock_tiles.iter()
-            .rev() // Take from the end (largest tiles first after sorting)
+        // Largest tiles first; among equal areas keep the preferred (lower weight) ones
+        let mut candidates: Vec<&TileDimensions> = self.stock_tiles.iter().collect();
+        candidates.sort_by_key(|c| Reverse(c.area()));
+        let tiles: Vec<TileDimensions> = candidates.into_iter()
             .take(max_tiles)
             .cloned()
             .collect();
 
         let mut solution = StockSolution::from_tiles(tiles);
-        solution.sort_panels_asc();
+        self.apply_preference(&mut solution);
         solution
     }
 }
@@ -2,7 +2,7 @@ use super::structs::StockSolutionGenerator;
 use crate::errors::AppError;
 use crate::models::TileDimensions;
 use crate::engine::stock::StockSolution;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 impl StockSolutionGenerator {
     /// Create a new StockSolutionGenerator with tiles to fit and available stock tiles
@@ -29,6 +29,11 @@ impl StockSolutionGenerator {
             required_max_dimension: 0,
             smallest_tile_area: i64::MAX,
             all_panel_stock_solution: StockSolution::new(),
+            stock_preference_weights: HashMap::new(),
+            consume_first_ids: HashSet::new(),
+            max_sheets: None,
+            single_stock_unit: false,
+            consider_rotation: true,
         };
 
         // Sort stock tiles by area (ascending)
@@ -50,6 +55,53 @@ impl StockSolutionGenerator {
     ) -> Result<Self, AppError> {
         Self::new(tiles_to_fit, stock_tiles, None)
     }
+
+    /// Attach stock preference weights keyed by stock tile id
+    ///
+    /// Among equally sized stock tiles, lower-weight ones are picked first.
+    /// Tiles without an entry use `StockConstants::DEFAULT_PREFERENCE_WEIGHT`.
+    pub fn with_preference_weights(mut self, weights: HashMap<i32, f64>) -> Self {
+        self.stock_preference_weights = weights;
+        self.sort_stock_tiles_area_asc();
+        self.all_panel_stock_solution = self.gen_all_panel_stock_solution();
+        self
+    }
+
+    /// Mark stock tiles, by id, as offcuts to use before any other stock
+    ///
+    /// Offcuts are ordered ahead of other stock regardless of area, so they
+    /// are picked first whenever they are enough for the tiles to fit.
+    pub fn with_consume_first(mut self, stock_ids: HashSet<i32>) -> Self {
+        self.consume_first_ids = stock_ids;
+        self.sort_stock_tiles_area_asc();
+        self.all_panel_stock_solution = self.gen_all_panel_stock_solution();
+        self
+    }
+
+    /// Never generate solutions using more than `max_sheets` stock tiles
+    pub fn with_max_sheets(mut self, max_sheets: Option<usize>) -> Self {
+        self.max_sheets = max_sheets;
+        self.all_panel_stock_solution = self.gen_all_panel_stock_solution();
+        self
+    }
+
+    /// Whether a sheet and its rotation count as one stock size
+    ///
+    /// On by default. Rotated copies are then kept next to each other and
+    /// skipped together when searching combinations. Sheets with a grain
+    /// direction are always told apart by orientation.
+    pub fn with_consider_rotation(mut self, consider_rotation: bool) -> Self {
+        self.consider_rotation = consider_rotation;
+        self.sort_stock_tiles_area_asc();
+        self.all_panel_stock_solution = self.gen_all_panel_stock_solution();
+        self
+    }
+
+    /// Only generate solutions made of copies of a single stock size
+    pub fn with_single_stock_unit(mut self, single_stock_unit: bool) -> Self {
+        self.single_stock_unit = single_stock_unit;
+        self
+    }
 }
 
 impl Default for StockSolutionGenerator {
@@ -65,6 +117,11 @@ impl Default for StockSolutionGenerator {
             required_max_dimension: 0,
             smallest_tile_area: i64::MAX,
             all_panel_stock_solution: StockSolution::new(),
+            stock_preference_weights: HashMap::new(),
+            consume_first_ids: HashSet::new(),
+            max_sheets: None,
+            single_stock_unit: false,
+            consider_rotation: true,
         }
     }
 }
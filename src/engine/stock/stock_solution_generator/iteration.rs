@@ -24,8 +24,7 @@ impl StockSolutionGenerator {
 
         // If we're not at the last position, recurse
         if iteration_index < num_tiles - 1 {
-            let mut last_width = 0;
-            let mut last_height = 0;
+            let mut last_size = (0, 0);
             let mut current_index = 0;
 
             while current_index < self.stock_tiles.len() {
@@ -43,23 +42,22 @@ impl StockSolutionGenerator {
                     return Some(solution);
                 }
 
-                // Skip tiles with same dimensions and insufficient area
+                // Skip tiles with same size and insufficient area
                 loop {
                     current_index += 1;
                     if current_index >= self.stock_tiles.len() {
                         break;
                     }
                     let tile = &self.stock_tiles[current_index];
-                    if tile.width != last_width || tile.height != last_height {
-                        if tile.area() as i64 >= smallest_tile_area {
+                    if self.stock_size(tile) != last_size {
+                        if tile.area() >= smallest_tile_area {
                             break;
                         }
                     }
                 }
 
                 if current_index < self.stock_tiles.len() {
-                    last_width = self.stock_tiles[current_index].width;
-                    last_height = self.stock_tiles[current_index].height;
+                    last_size = self.stock_size(&self.stock_tiles[current_index]);
                     
                     // Update indexes from iteration_index onwards
                     let mut idx = iteration_index;
@@ -81,7 +79,7 @@ impl StockSolutionGenerator {
             // Calculate remaining area and check dimensions
             for &index in &indexes {
                 let tile = &self.stock_tiles[index];
-                total_area -= tile.area() as i64;
+                total_area -= tile.area();
                 if tile.max_dimension() >= required_max_dimension {
                     has_required_dimension = true;
                 }
@@ -98,7 +96,7 @@ impl StockSolutionGenerator {
                     .collect();
                 
                 let mut solution = StockSolution::from_tiles(tiles);
-                solution.sort_panels_asc();
+                self.apply_preference(&mut solution);
 
                 // Update state for next iteration
                 self.previous_returned_stock_tiles_indexes = indexes.clone();
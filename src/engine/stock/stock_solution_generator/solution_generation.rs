@@ -1,6 +1,7 @@
 use super::structs::{StockSolutionGenerator, StockSolutionConfig};
-use crate::models::enums::StockSolutionResult;
+use crate::models::enums::{StatusCode, StockSolutionResult};
 use crate::engine::stock::StockSolution;
+use crate::models::TileDimensions;
 use crate::{log_info, log_operation_start, log_operation_success};
 
 impl StockSolutionGenerator {
@@ -13,8 +14,16 @@ impl StockSolutionGenerator {
             self.required_area
         );
 
+        if self.single_stock_unit {
+            return self.generate_single_stock_unit_solution();
+        }
+
         // If all stock tiles are the same, use the all-panel solution
         if self.is_unique_stock_panel() {
+            if self.exceeds_max_sheets(self.min_sheets_needed()) {
+                log_info!("Unique stock panel cannot cover the required area within {:?} sheets", self.max_sheets);
+                return StockSolutionResult::NoSolution;
+            }
             log_info!("Using unique stock panel optimization");
             if self.is_excluded(&self.all_panel_stock_solution) {
                 log_info!("All-panel solution already excluded");
@@ -26,8 +35,12 @@ impl StockSolutionGenerator {
         }
 
         // Calculate minimum number of tiles needed
-        let min_tiles_needed = (self.required_area as f64 / self.get_biggest_stock_tile_area() as f64).ceil() as usize;
+        let min_tiles_needed = self.min_sheets_needed();
         log_info!("Minimum tiles needed: {}", min_tiles_needed);
+        if self.exceeds_max_sheets(min_tiles_needed) {
+            log_info!("At least {} sheets are needed but at most {:?} may be used", min_tiles_needed, self.max_sheets);
+            return StockSolutionResult::NoSolution;
+        }
         
         // Determine maximum solution length
         let max_length = if let Some(hint) = self.max_stock_solution_length_hint {
@@ -52,10 +65,12 @@ impl StockSolutionGenerator {
 
         // Try to find a solution with increasing number of tiles
         log_info!("Searching for solution with {}-{} tiles", min_tiles_needed, max_length.min(self.stock_tiles.len()));
+        let max_length = max_length.min(self.max_sheets.unwrap_or(usize::MAX));
         for num_tiles in min_tiles_needed..=max_length.min(self.stock_tiles.len()) {
             log_info!("Trying solution with {} tiles", num_tiles);
             if let Some(solution) = self.get_candidate_stock_solution(num_tiles) {
-                log_info!("Found solution with {} tiles, total area: {}", solution.len(), solution.get_total_area());
+                log_info!("Found solution with {} tiles, total area: {}, preference weight: {}",
+                    solution.len(), solution.get_total_area(), solution.get_preference_weight());
                 self.stock_solutions_to_exclude.insert(solution.clone());
                 log_operation_success!("stock_solution_generation");
                 return StockSolutionResult::Solution(solution);
@@ -66,6 +81,70 @@ impl StockSolutionGenerator {
         StockSolutionResult::NoSolution
     }
 
+    /// Generate the next solution made only of copies of one stock size
+    ///
+    /// Every size that holds each tile to fit is a candidate, taken as few
+    /// times as its area allows and then once more per further copy in stock.
+    /// Candidates are tried smallest total area first.
+    fn generate_single_stock_unit_solution(&mut self) -> StockSolutionResult {
+        let mut sizes: Vec<Vec<&TileDimensions>> = Vec::new();
+        for stock in &self.stock_tiles {
+            match sizes.iter_mut().find(|copies| copies[0].has_same_dimensions(stock)) {
+                Some(copies) => copies.push(stock),
+                None => sizes.push(vec![stock]),
+            }
+        }
+
+        let mut candidates = Vec::new();
+        for copies in sizes {
+            if !self.tiles_to_fit.iter().all(|tile| tile.fits(copies[0])) {
+                continue;
+            }
+            let area = copies[0].area().max(1);
+            let needed = ((self.required_area + area - 1) / area).max(1) as usize;
+            let available = copies.len().min(self.max_sheets.unwrap_or(usize::MAX));
+            for count in needed..=available {
+                let mut solution = StockSolution::from_tiles(copies[..count].iter().map(|&tile| tile.clone()).collect());
+                self.apply_preference(&mut solution);
+                candidates.push(solution);
+            }
+        }
+        candidates.sort_by_key(StockSolution::get_total_area);
+
+        match candidates.into_iter().find(|solution| !self.is_excluded(solution)) {
+            Some(solution) => {
+                log_info!("Using {} copies of a single stock size", solution.len());
+                self.stock_solutions_to_exclude.insert(solution.clone());
+                log_operation_success!("stock_solution_generation");
+                StockSolutionResult::Solution(solution)
+            }
+            None => {
+                log_info!("No single stock size can hold the tiles to fit");
+                StockSolutionResult::NoSolution
+            }
+        }
+    }
+
+    /// Smallest number of the biggest stock tile whose area covers the tiles to fit
+    pub fn min_sheets_needed(&self) -> usize {
+        let biggest = self.get_biggest_stock_tile_area().max(1);
+        (self.required_area as f64 / biggest as f64).ceil() as usize
+    }
+
+    /// Status of the sheet cap: `NotEnoughStock` when even the biggest stock
+    /// tiles cannot cover the required area within `max_sheets`
+    pub fn max_sheets_status(&self) -> StatusCode {
+        if self.exceeds_max_sheets(self.min_sheets_needed()) {
+            StatusCode::NotEnoughStock
+        } else {
+            StatusCode::Ok
+        }
+    }
+
+    fn exceeds_max_sheets(&self, sheets: usize) -> bool {
+        self.max_sheets.is_some_and(|max| sheets > max)
+    }
+
     /// Get a candidate stock solution with the specified number of tiles
     pub(crate) fn get_candidate_stock_solution(&mut self, num_tiles: usize) -> Option<StockSolution> {
         let indexes = if self.previous_returned_stock_tiles_indexes.len() == num_tiles {
@@ -1,7 +1,7 @@
 use crate::models::TileDimensions;
 use crate::engine::stock::StockSolution;
 use crate::constants::PerformanceConstants;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Configuration and state for generating stock solutions
 /// 
@@ -39,6 +39,21 @@ pub struct StockSolutionGenerator {
     
     /// Pre-computed solution using all available panels
     pub(crate) all_panel_stock_solution: StockSolution,
+
+    /// Preference weight per stock tile id; lower weights are used first
+    pub(crate) stock_preference_weights: HashMap<i32, f64>,
+
+    /// Stock tile ids of offcuts that are used before any other stock
+    pub(crate) consume_first_ids: HashSet<i32>,
+
+    /// Hard cap on the number of sheets in any generated solution
+    pub(crate) max_sheets: Option<usize>,
+
+    /// Only generate solutions made of copies of one stock size
+    pub(crate) single_stock_unit: bool,
+
+    /// Treat a sheet and its 90° rotation as the same stock size
+    pub(crate) consider_rotation: bool,
 }
 
 /// Configuration for stock solution generation
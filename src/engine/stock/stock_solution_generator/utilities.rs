@@ -1,7 +1,9 @@
 use super::structs::StockSolutionGenerator;
-use crate::models::TileDimensions;
+use crate::constants::StockConstants;
+use crate::models::{enums::Orientation, Panel, TileDimensions};
 use crate::engine::stock::StockSolution;
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 
 impl StockSolutionGenerator {
     /// Check if a stock solution is excluded
@@ -29,8 +31,76 @@ impl StockSolutionGenerator {
         indexes.iter().all(|&i| seen.insert(i))
     }
 
-    /// Sort stock tiles by area in ascending order
+    /// Sort stock tiles by area in ascending order, lower preference weight first on ties
+    ///
+    /// Offcuts marked to be consumed first come ahead of all other stock.
     pub(crate) fn sort_stock_tiles_area_asc(&mut self) {
-        self.stock_tiles.sort_by(|a, b| a.area().cmp(&b.area()));
+        let mut stock_tiles = std::mem::take(&mut self.stock_tiles);
+        stock_tiles.sort_by(|a, b| self.compare_stock(a, b));
+        self.stock_tiles = stock_tiles;
+    }
+
+    /// Stock order: offcuts to consume first, then ascending area, then lower
+    /// weight, then size so that equal sizes sit together
+    fn compare_stock(&self, a: &TileDimensions, b: &TileDimensions) -> Ordering {
+        let weights = &self.stock_preference_weights;
+        self.is_consume_first(b)
+            .cmp(&self.is_consume_first(a))
+            .then_with(|| a.area().cmp(&b.area()))
+            .then_with(|| Self::weight_of(weights, a).total_cmp(&Self::weight_of(weights, b)))
+            .then_with(|| self.stock_size(a).cmp(&self.stock_size(b)))
+    }
+
+    /// Size a stock tile is told apart by
+    ///
+    /// With rotation considered, grainless sheets are keyed as (short, long)
+    /// side so a sheet and its rotation share one size.
+    pub(crate) fn stock_size(&self, tile: &TileDimensions) -> (i32, i32) {
+        if self.consider_rotation && tile.orientation == Orientation::Any {
+            (tile.width.min(tile.height), tile.width.max(tile.height))
+        } else {
+            (tile.width, tile.height)
+        }
+    }
+
+    /// Check whether a stock tile is an offcut to use before other stock
+    pub fn is_consume_first(&self, tile: &TileDimensions) -> bool {
+        self.consume_first_ids.contains(&tile.id)
+    }
+
+    /// Get the preference weight of a stock tile
+    pub fn preference_weight(&self, tile: &TileDimensions) -> f64 {
+        Self::weight_of(&self.stock_preference_weights, tile)
+    }
+
+    fn weight_of(weights: &HashMap<i32, f64>, tile: &TileDimensions) -> f64 {
+        weights
+            .get(&tile.id)
+            .copied()
+            .unwrap_or(StockConstants::DEFAULT_PREFERENCE_WEIGHT)
+    }
+
+    /// Order a solution's panels like the stock tiles and record its total weight
+    pub(crate) fn apply_preference(&self, solution: &mut StockSolution) {
+        solution.get_stock_tile_dimensions_mut().sort_by(|a, b| self.compare_stock(a, b));
+        let total = solution.iter().map(|tile| self.preference_weight(tile)).sum();
+        solution.set_preference_weight(total);
+    }
+
+    /// Collect the ids of stock panels marked to be consumed first
+    pub fn consume_first_ids_from_panels(panels: &[Panel]) -> HashSet<i32> {
+        panels
+            .iter()
+            .filter(|panel| panel.consume_first)
+            .map(|panel| panel.id)
+            .collect()
+    }
+
+    /// Collect preference weights from stock panels that specify one
+    pub fn preference_weights_from_panels(panels: &[Panel]) -> HashMap<i32, f64> {
+        panels
+            .iter()
+            .filter_map(|panel| panel.preference_weight.map(|weight| (panel.id, weight)))
+            .collect()
     }
 }
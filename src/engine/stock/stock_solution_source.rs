@@ -0,0 +1,43 @@
+//! Boxed stream of candidate stock solutions
+//!
+//! The stock panel picker pulls candidates from a source instead of talking
+//! to the built-in generator directly, so callers can plug in their own
+//! stock-combination strategy.
+
+use std::fmt;
+
+use crate::engine::stock::{StockSolution, StockSolutionGenerator};
+use crate::models::enums::StockSolutionResult;
+
+/// A sendable iterator over candidate stock solutions, in the order they
+/// should be tried
+pub struct StockSolutionSource(Box<dyn Iterator<Item = StockSolution> + Send>);
+
+impl StockSolutionSource {
+    /// Wrap any sendable iterator of stock solutions
+    pub fn new(solutions: impl Iterator<Item = StockSolution> + Send + 'static) -> Self {
+        Self(Box::new(solutions))
+    }
+
+    /// Draw candidates from the built-in generator until it runs out
+    pub fn from_generator(mut generator: StockSolutionGenerator) -> Self {
+        Self::new(std::iter::from_fn(move || match generator.generate_stock_solution() {
+            StockSolutionResult::Solution(solution) => Some(solution),
+            StockSolutionResult::NoSolution | StockSolutionResult::AllExcluded => None,
+        }))
+    }
+}
+
+impl Iterator for StockSolutionSource {
+    type Item = StockSolution;
+
+    fn next(&mut self) -> Option<StockSolution> {
+        self.0.next()
+    }
+}
+
+impl fmt::Debug for StockSolutionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StockSolutionSource").finish_non_exhaustive()
+    }
+}
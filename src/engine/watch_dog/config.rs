@@ -1,5 +1,6 @@
 //! Configuration module for WatchDog service
 
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// Configuration for WatchDog service
@@ -11,6 +12,12 @@ pub struct WatchDogConfig {
     pub task_timeout: Duration,
     /// Grace period for completed tasks before cleanup
     pub grace_period: Duration,
+    /// Directory running tasks are checkpointed to; `None` disables checkpoints
+    pub checkpoint_dir: Option<PathBuf>,
+    /// Minimum time between two checkpoint writes
+    pub checkpoint_interval: Duration,
+    /// Running tasks holding more mosaics than this are terminated; `None` disables the limit
+    pub max_active_mosaics: Option<usize>,
 }
 
 impl Default for WatchDogConfig {
@@ -19,6 +26,9 @@ impl Default for WatchDogConfig {
             check_interval: Duration::from_secs(30), // Check every 30 seconds
             task_timeout: Duration::from_secs(3600), // 1 hour timeout
             grace_period: Duration::from_secs(300),  // 5 minutes grace period
+            checkpoint_dir: None,
+            checkpoint_interval: Duration::from_secs(300), // Checkpoint every 5 minutes
+            max_active_mosaics: None,
         }
     }
 }
@@ -30,6 +40,9 @@ impl WatchDogConfig {
             check_interval,
             task_timeout,
             grace_period,
+            checkpoint_dir: None,
+            checkpoint_interval: Duration::from_secs(300),
+            max_active_mosaics: None,
         }
     }
 
@@ -51,6 +64,24 @@ impl WatchDogConfig {
         self
     }
 
+    /// Creates a config that checkpoints running tasks into `dir`
+    pub fn with_checkpoint_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.checkpoint_dir = Some(dir.into());
+        self
+    }
+
+    /// Creates a config with custom checkpoint interval
+    pub fn with_checkpoint_interval(mut self, interval: Duration) -> Self {
+        self.checkpoint_interval = interval;
+        self
+    }
+
+    /// Creates a config that terminates tasks holding more than `limit` mosaics
+    pub fn with_max_active_mosaics(mut self, limit: usize) -> Self {
+        self.max_active_mosaics = Some(limit);
+        self
+    }
+
     /// Validates the configuration
     pub fn validate(&self) -> Result<(), String> {
         if self.check_interval.is_zero() {
@@ -65,6 +96,14 @@ impl WatchDogConfig {
             return Err("Grace period cannot be zero".to_string());
         }
         
+        if self.checkpoint_dir.is_some() && self.checkpoint_interval.is_zero() {
+            return Err("Checkpoint interval cannot be zero".to_string());
+        }
+        
+        if self.max_active_mosaics == Some(0) {
+            return Err("Max active mosaics cannot be zero".to_string());
+        }
+        
         if self.check_interval > self.task_timeout {
             return Err("Check interval should not be greater than task timeout".to_string());
         }
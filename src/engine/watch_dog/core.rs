@@ -1,7 +1,7 @@
 //! Core WatchDog implementation
 
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::time::interval;
 use crate::logging::{error, info, warn};
 
@@ -9,7 +9,7 @@ use super::config::WatchDogConfig;
 use super::monitoring::TaskMonitor;
 use super::cleanup::TaskCleanup;
 use super::statistics::WatchDogStatistics;
-use crate::engine::running_tasks::RunningTasks;
+use crate::engine::running_tasks::{RunningTasks, TaskCheckpointing};
 
 /// WatchDog monitors running tasks and handles cleanup of stale tasks
 #[derive(Debug)]
@@ -18,6 +18,8 @@ pub struct WatchDog {
     cleanup: TaskCleanup,
     statistics: WatchDogStatistics,
     config: WatchDogConfig,
+    running_tasks: Arc<RunningTasks>,
+    last_checkpoint: Mutex<Option<Instant>>,
     is_running: std::sync::atomic::AtomicBool,
 }
 
@@ -32,13 +34,15 @@ impl WatchDog {
     pub fn with_config(running_tasks: Arc<RunningTasks>, config: WatchDogConfig) -> Self {
         let monitor = TaskMonitor::new(running_tasks.clone(), config.clone());
         let cleanup = TaskCleanup::new(running_tasks.clone());
-        let statistics = WatchDogStatistics::new(running_tasks);
+        let statistics = WatchDogStatistics::new(running_tasks.clone());
 
         Self {
             monitor,
             cleanup,
             statistics,
             config,
+            running_tasks,
+            last_checkpoint: Mutex::new(None),
             is_running: std::sync::atomic::AtomicBool::new(false),
         }
     }
@@ -94,6 +98,9 @@ impl WatchDog {
 
     /// Performs a single check and cleanup cycle
     async fn check_and_cleanup_tasks(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // Checkpoint before timed-out tasks are removed
+        self.checkpoint_if_due()?;
+        
         // Check for tasks that need cleanup
         let tasks_to_cleanup = self.monitor.check_tasks().await?;
         
@@ -125,6 +132,37 @@ impl WatchDog {
         self.cleanup.force_cleanup_task(task_id)
     }
 
+    /// Writes checkpoints of all running tasks if a checkpoint directory is configured
+    ///
+    /// Returns the number of tasks checkpointed.
+    pub fn write_checkpoints(&self) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(dir) = &self.config.checkpoint_dir else {
+            return Ok(0);
+        };
+
+        std::fs::create_dir_all(dir)?;
+        let written = self.running_tasks.checkpoint_running_tasks(dir)?;
+        *self.last_checkpoint.lock().unwrap() = Some(Instant::now());
+        if written > 0 {
+            info!("WatchDog checkpointed {} running tasks", written);
+        }
+        Ok(written)
+    }
+
+    /// Writes checkpoints when the checkpoint interval has passed since the last write
+    fn checkpoint_if_due(&self) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let due = self
+            .last_checkpoint
+            .lock()
+            .unwrap()
+            .is_none_or(|last| last.elapsed() >= self.config.checkpoint_interval);
+        if due {
+            self.write_checkpoints()
+        } else {
+            Ok(0)
+        }
+    }
+
     /// Gets the current status of the WatchDog
     pub fn is_running(&self) -> bool {
         self.is_running.load(std::sync::atomic::Ordering::SeqCst)
@@ -195,6 +233,8 @@ impl WatchDog {
     pub async fn check_once(&self) -> Result<super::cleanup::CleanupResult, Box<dyn std::error::Error + Send + Sync>> {
         info!("Performing one-time WatchDog check");
         
+        self.checkpoint_if_due()?;
+        
         let tasks_to_cleanup = self.monitor.check_tasks().await?;
         let cleanup_result = if !tasks_to_cleanup.is_empty() {
             self.cleanup.cleanup_tasks(tasks_to_cleanup).await
@@ -47,6 +47,14 @@ impl TaskMonitor {
                     continue;
                 }
                 
+                if status == Status::Running && self.exceeds_mosaic_limit(task.active_mosaic_count()) {
+                    warn!("Task {} exceeds the active mosaic limit, terminating", task_id);
+                    if let Err(e) = task.terminate() {
+                        warn!("Failed to terminate task {}: {}", task_id, e);
+                    }
+                    continue;
+                }
+                
                 // Check if task is in a terminal state but still in running tasks
                 if self.should_cleanup_completed_task(status, elapsed) {
                     debug!("Task {} is completed and past grace period, marking for cleanup", task_id);
@@ -63,6 +71,11 @@ impl TaskMonitor {
         elapsed > self.config.task_timeout
     }
 
+    /// Checks if a task holds more mosaics than allowed
+    fn exceeds_mosaic_limit(&self, active_mosaics: usize) -> bool {
+        self.config.max_active_mosaics.is_some_and(|limit| active_mosaics > limit)
+    }
+
     /// Checks if a completed task should be cleaned up
     fn should_cleanup_completed_task(&self, status: Status, elapsed: Duration) -> bool {
         match status {
@@ -25,7 +25,7 @@ pub mod utils;
 // Публичный API библиотеки
 pub use errors::{AppError, Result};
 pub use models::{
-    enums::{CutDirection, OptimizationPriority, Orientation, Status, StatusCode},
+    enums::{CutDirection, CutStyle, FirstCutRule, GrainMode, OptimizationPriority, Orientation, PlacementHeuristic, Status, StatusCode},
     Configuration,
     TileDimensions,
 };
@@ -42,7 +42,7 @@ pub mod prelude {
     //! Основные типы и трейты для удобного импорта
     pub use crate::errors::{AppError, Result};
     pub use crate::models::{
-        enums::{CutDirection, OptimizationPriority, Orientation, Status, StatusCode},
+        enums::{CutDirection, CutStyle, FirstCutRule, GrainMode, OptimizationPriority, Orientation, PlacementHeuristic, Status, StatusCode},
         Configuration,
         TileDimensions,
     };
@@ -7,6 +7,8 @@ use tracing_subscriber::{
 };
 
 /// Инициализация логирования
+///
+/// Логи пишутся в stderr, чтобы stdout оставался за результатом команды.
 pub fn init_logging(config: LogConfig) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let env_filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new(String::from(config.level)));
@@ -15,7 +17,8 @@ pub fn init_logging(config: LogConfig) -> Result<(), Box<dyn std::error::Error +
         .with_ansi(true)
         .with_level(config.show_level)
         .with_target(config.show_target)
-        .with_env_filter(env_filter);
+        .with_env_filter(env_filter)
+        .with_writer(std::io::stderr);
 
     if config.compact {
         if config.show_time {
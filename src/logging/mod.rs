@@ -6,12 +6,14 @@
 pub mod enums;
 pub mod init;
 pub mod macros;
+pub mod spans;
 pub mod structs;
 
 // Реэкспорт основных типов и функций
 pub use enums::LogLevel;
 pub use init::{init_cli, init_default, init_logging};
 pub use macros::{debug, error, info, trace, warn};
+pub use spans::{permutation_span, task_span};
 pub use structs::LogConfig;
 
 // Реэкспорт макросов
@@ -0,0 +1,25 @@
+//! Спаны tracing для задач оптимизации
+//!
+//! Каждая задача открывает спан `task`, а каждая перестановка материала —
+//! вложенный спан `permutation`. Без инициализированного подписчика спаны
+//! ничего не стоят, и обычные макросы логирования работают как прежде.
+
+use tracing::{field, info_span, Span};
+
+/// Span covering one optimization task
+pub fn task_span(task_id: &str) -> Span {
+    info_span!("task", task_id = %task_id)
+}
+
+/// Span covering one permutation of a material
+///
+/// `best_efficiency` is empty when the span opens and is recorded once the
+/// permutation has been processed.
+pub fn permutation_span(material: &str, permutation_index: usize) -> Span {
+    info_span!(
+        "permutation",
+        material = %material,
+        permutation_index,
+        best_efficiency = field::Empty
+    )
+}
@@ -0,0 +1,122 @@
+//! Fast feasibility check of calculation requests
+//!
+//! Compares areas and sheet sizes per material without running the
+//! optimizer, so a request that cannot possibly be laid out in full is
+//! caught before a long run. Stock is parsed once per material, keeping the
+//! check linear in the number of panels. When the stock is too small,
+//! `suggest_stock` lays the panels out on larger candidate sheets to find one
+//! that would do.
+
+use std::collections::HashMap;
+
+use super::structs::{CalculationRequest, FeasibilityConstraint, FeasibilityReport};
+use crate::engine::service::{GuillotinePlacement, PlacementAlgorithm, PlacementConfig};
+use crate::engine::stock::StockSolution;
+use crate::models::TileDimensions;
+
+impl CalculationRequest {
+    /// Check whether every enabled panel could fit the stock
+    ///
+    /// Checks run in order: missing material, then panels larger than every
+    /// sheet of their material, then panel area exceeding stock area. The
+    /// first failure becomes the binding constraint. Panels or stock whose
+    /// dimensions do not parse are skipped; `validate` reports them.
+    pub fn feasibility(&self) -> FeasibilityReport {
+        let mut sheets: HashMap<&str, Vec<(f64, f64)>> = HashMap::new();
+        let mut report = FeasibilityReport::default();
+
+        for stock in self.stock_panels.iter().filter(|stock| stock.enabled && stock.count > 0) {
+            let (Ok(width), Ok(height)) = (stock.width_as_f64(), stock.height_as_f64()) else {
+                continue;
+            };
+            sheets.entry(stock.material.as_str()).or_default().push((width, height));
+            *report.stock_area.entry(stock.material.clone()).or_insert(0.0) += width * height * stock.count as f64;
+        }
+
+        let mut missing_material = None;
+        let mut oversized = None;
+        for panel in self.panels.iter().filter(|panel| panel.enabled && panel.count > 0) {
+            let (Ok(width), Ok(height)) = (panel.width_as_f64(), panel.height_as_f64()) else {
+                continue;
+            };
+            *report.panel_area.entry(panel.material.clone()).or_insert(0.0) += width * height * panel.count as f64;
+
+            let Some(material_sheets) = sheets.get(panel.material.as_str()) else {
+                missing_material.get_or_insert_with(|| FeasibilityConstraint::MaterialUnavailable {
+                    material: panel.material.clone(),
+                });
+                continue;
+            };
+            let may_rotate = panel.orientation == 0;
+            let fits = material_sheets.iter().any(|&(stock_width, stock_height)| {
+                (width <= stock_width && height <= stock_height)
+                    || (may_rotate && height <= stock_width && width <= stock_height)
+            });
+            if !fits {
+                oversized.get_or_insert(FeasibilityConstraint::OversizedPanel {
+                    panel_id: panel.id,
+                    width,
+                    height,
+                });
+            }
+        }
+
+        let mut materials: Vec<&String> = report.panel_area.keys().collect();
+        materials.sort();
+        let short_of_area = materials.into_iter().find_map(|material| {
+            let panel_area = report.panel_area[material];
+            let stock_area = report.stock_area.get(material).copied().unwrap_or(0.0);
+            (stock_area > 0.0 && panel_area > stock_area).then(|| FeasibilityConstraint::InsufficientArea {
+                material: material.clone(),
+                panel_area,
+                stock_area,
+            })
+        });
+
+        report.binding_constraint = missing_material.or(oversized).or(short_of_area);
+        report.feasible = report.binding_constraint.is_none();
+        report
+    }
+
+    /// Number of sheets the request stocks, summed over enabled stock panels
+    pub fn stock_sheet_count(&self) -> usize {
+        self.stock_panels
+            .iter()
+            .filter(|stock| stock.enabled)
+            .map(|stock| stock.count.max(0) as usize)
+            .sum()
+    }
+}
+
+/// Smallest of `candidate_sheets` on which every tile can be placed
+///
+/// Candidates are tried by ascending area, ties in the given order, each with
+/// `sheet_count` copies, the number of sheets the request stocks. A candidate
+/// qualifies when the placement with `config`'s kerf, trim and settings
+/// places every tile. Tiles, sheets and `config` are in the same scaled
+/// units. Returns `None` when there are no tiles or no candidate holds them all.
+pub fn suggest_stock(
+    tiles: &[TileDimensions],
+    candidate_sheets: &[TileDimensions],
+    sheet_count: usize,
+    config: &PlacementConfig,
+) -> Option<TileDimensions> {
+    if tiles.is_empty() || sheet_count == 0 {
+        return None;
+    }
+
+    let places_every_tile = |sheet: &TileDimensions| {
+        let stock_solution = StockSolution::from_tiles(vec![sheet.clone(); sheet_count]);
+        GuillotinePlacement
+            .place(tiles, &stock_solution, config)
+            .ok()
+            .and_then(|solutions| solutions.into_iter().next())
+            .is_some_and(|best| {
+                best.get_no_fit_panels().is_empty() && best.get_nbr_final_tiles() as usize == tiles.len()
+            })
+    };
+
+    let mut candidates: Vec<&TileDimensions> = candidate_sheets.iter().collect();
+    candidates.sort_by_key(|sheet| sheet.area());
+    candidates.into_iter().find(|sheet| places_every_tile(sheet)).cloned()
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::structs::CalculationRequest;
 use crate::models::{Configuration, Panel};
 
@@ -79,6 +81,22 @@ impl CalculationRequest {
         self.stock_panels.push(panel);
     }
 
+    /// Whether any enabled stock panel is made of `material`
+    pub fn has_stock_of_material(&self, material: &str) -> bool {
+        self.stock_panels
+            .iter()
+            .any(|panel| panel.enabled && panel.count > 0 && panel.material == material)
+    }
+
+    /// Fewest copies to place of each enabled panel with optional copies, by panel id
+    pub fn min_quantities(&self) -> HashMap<i32, i32> {
+        self.panels
+            .iter()
+            .filter(|panel| panel.enabled && panel.is_flexible())
+            .map(|panel| (panel.id, panel.min_quantity()))
+            .collect()
+    }
+
     /// Converts panels with count > 0 to a string representation
     pub fn tiles_to_string(&self) -> String {
         self.panels
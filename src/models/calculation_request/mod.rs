@@ -1,4 +1,6 @@
 pub mod structs;
 pub mod impls;
+pub mod validation;
+pub mod feasibility;
 
-pub use structs::CalculationRequest;
+pub use structs::{CalculationRequest, FeasibilityConstraint, FeasibilityReport, FieldError, ValidationReport};
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use crate::models::{Configuration, Panel};
 
@@ -14,3 +16,55 @@ pub struct CalculationRequest {
     pub stock_panels: Vec<Panel>,
 }
 
+
+/// A semantic problem found in a request, located by a path such as `panels[2].count`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    /// Path to the offending field
+    pub path: String,
+
+    /// What is wrong with the field
+    pub message: String,
+}
+
+/// Outcome of checking a request before it is submitted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Problems that make the request impossible to compute
+    pub errors: Vec<FieldError>,
+
+    /// Problems that leave some panels unplaced but still allow a layout
+    pub warnings: Vec<FieldError>,
+}
+
+/// The check that decides a feasibility report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FeasibilityConstraint {
+    /// A panel's material has no stock at all
+    MaterialUnavailable { material: String },
+
+    /// A panel is larger than every stock sheet of its material, even rotated when allowed
+    OversizedPanel { panel_id: i32, width: f64, height: f64 },
+
+    /// The panels of a material need more area than its stock provides
+    InsufficientArea { material: String, panel_area: f64, stock_area: f64 },
+}
+
+/// Quick verdict on whether a request can possibly be laid out in full
+///
+/// Passing is necessary but not sufficient: the area totals ignore kerf and
+/// how panels pack, so a full run can still leave panels unplaced.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FeasibilityReport {
+    /// Whether no check ruled the request out
+    pub feasible: bool,
+
+    /// First check that failed, if any
+    pub binding_constraint: Option<FeasibilityConstraint>,
+
+    /// Total area of enabled panels per material
+    pub panel_area: HashMap<String, f64>,
+
+    /// Total area of enabled stock per material
+    pub stock_area: HashMap<String, f64>,
+}
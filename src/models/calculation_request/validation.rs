@@ -0,0 +1,410 @@
+//! Semantic validation of calculation requests
+//!
+//! Deserialization only checks shape; these checks catch values that parse
+//! but make no sense, and report each one with the path of the field.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use super::structs::{CalculationRequest, FieldError, ValidationReport};
+use crate::constants::{ConfigurationDefaults, EngineConstants, MaterialConstants};
+use crate::engine::service::computation::dimension_utils::DimensionUtils;
+use crate::errors::AppError;
+use crate::models::{enums::{CutDirection, NoFitReason}, Mosaic, NoFitTile, Panel, TileDimensions};
+
+impl FieldError {
+    /// Create a new field error
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl ValidationReport {
+    /// Whether the request has no hard errors
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl CalculationRequest {
+    /// Check the request before submitting it
+    ///
+    /// Hard errors are the semantic problems found by `validate_semantic`.
+    /// Once those are clear, each enabled panel is checked against the stock:
+    /// panels with no stock of their material, or whose cut size (after
+    /// banding) fits no sheet of it, are reported as warnings since they will
+    /// end up as no-fit panels.
+    pub fn validate(&self) -> Result<ValidationReport, AppError> {
+        let mut report = ValidationReport::default();
+        if let Err(errors) = self.validate_semantic() {
+            report.errors = errors;
+            return Ok(report);
+        }
+
+        for (index, panel) in self.panels.iter().enumerate() {
+            if !panel.enabled {
+                continue;
+            }
+            let path = format!("panels[{}]", index);
+            if !self.has_stock_of_material(&panel.material) {
+                report.warnings.push(FieldError::new(
+                    format!("{}.material", path),
+                    format!("no stock panel is made of {:?}", panel.material),
+                ));
+                continue;
+            }
+
+            let (width, height) = (panel.width_as_f64()?, panel.height_as_f64()?);
+            let [top, left, bottom, right] = panel.banding_thickness.unwrap_or([0.0; 4]);
+            let (cut_width, cut_height) = (width - left - right, height - top - bottom);
+            if !self.fits_some_stock(&panel.material, cut_width, cut_height, panel.orientation == 0) {
+                report.warnings.push(FieldError::new(
+                    path,
+                    format!("{}x{} is larger than every {} stock panel", width, height, panel.material),
+                ));
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Why a `width` x `height` tile of `material` may have been left unplaced
+    pub fn no_fit_reason(&self, material: &str, width: f64, height: f64, may_rotate: bool) -> NoFitReason {
+        if !self.has_stock_of_material(material) {
+            NoFitReason::MaterialUnavailable
+        } else if !self.fits_some_stock(material, width, height, may_rotate) {
+            NoFitReason::OversizedForAllStock
+        } else {
+            NoFitReason::NoRemainingSpace
+        }
+    }
+
+    /// Record on `tile` why it was left unplaced and what would have let it fit
+    ///
+    /// Besides the reason, a tile larger than every sheet of its material
+    /// gets the smallest width and height shortfall against any of them, and
+    /// a tile kept to its grain is flagged when rotating it would have fit.
+    pub fn classify_no_fit(&self, tile: &mut NoFitTile, may_rotate: bool) {
+        let material = tile.material.clone().unwrap_or_else(|| MaterialConstants::DEFAULT_MATERIAL.to_string());
+        let (width, height) = (tile.width, tile.height);
+        tile.reason = self.no_fit_reason(&material, width, height, may_rotate);
+        tile.fits_if_rotated = tile.reason == NoFitReason::OversizedForAllStock
+            && !may_rotate
+            && self.fits_some_stock(&material, height, width, false);
+        tile.stock_deficit = if tile.reason == NoFitReason::OversizedForAllStock {
+            self.stock_of_material(&material)
+                .flat_map(|(stock_width, stock_height)| {
+                    let upright = ((width - stock_width).max(0.0), (height - stock_height).max(0.0));
+                    let rotated = ((height - stock_width).max(0.0), (width - stock_height).max(0.0));
+                    std::iter::once(upright).chain(may_rotate.then_some(rotated))
+                })
+                .min_by(|a, b| (a.0 + a.1).total_cmp(&(b.0 + b.1)))
+        } else {
+            None
+        };
+    }
+
+    /// Width and height of every enabled stock panel of `material`
+    fn stock_of_material<'a>(&'a self, material: &'a str) -> impl Iterator<Item = (f64, f64)> + 'a {
+        self.stock_panels
+            .iter()
+            .filter(move |stock| stock.enabled && stock.count > 0 && stock.material == material)
+            .filter_map(|stock| Some((stock.width_as_f64().ok()?, stock.height_as_f64().ok()?)))
+    }
+
+    /// Whether a tile fits on at least one enabled stock panel of `material`
+    ///
+    /// The tile has to fit the usable area of the sheet, inside its edge trim
+    /// and clear of its keepouts, compared in the scaled units the optimizer uses.
+    fn fits_some_stock(&self, material: &str, width: f64, height: f64, may_rotate: bool) -> bool {
+        let factor = 10.0_f64.powi(
+            DimensionUtils::get_max_nbr_decimal_places(&self.panels)
+                .max(DimensionUtils::get_max_nbr_decimal_places(&self.stock_panels)) as i32,
+        );
+        let scale = |value: f64| (value * factor).round() as i32;
+        let (width, height) = (scale(width), scale(height));
+
+        self.stock_panels
+            .iter()
+            .filter(|stock| stock.enabled && stock.count > 0 && stock.material == material)
+            .filter_map(|stock| {
+                let mut sheet = TileDimensions::new(stock.id, scale(stock.width_as_f64().ok()?), scale(stock.height_as_f64().ok()?));
+                sheet.edge_trim = scale(stock.edge_trim as f64);
+                sheet.keepouts = stock.keepouts.iter().map(|keepout| keepout.scaled(factor)).collect();
+                Some(Mosaic::from_tile_dimensions(&sheet))
+            })
+            .any(|sheet| sheet.has_room_for(width, height) || (may_rotate && sheet.has_room_for(height, width)))
+    }
+
+    /// Check the request for semantically invalid values
+    ///
+    /// Every problem is reported, not just the first. Disabled panels are
+    /// ignored apart from their position in the list.
+    pub fn validate_semantic(&self) -> Result<(), Vec<FieldError>> {
+        let mut errors = Vec::new();
+
+        if self.panels.is_empty() {
+            errors.push(FieldError::new("panels", "at least one panel is required"));
+        }
+        if self.stock_panels.is_empty() {
+            errors.push(FieldError::new("stock_panels", "at least one stock panel is required"));
+        }
+
+        for (path, panels) in [("panels", &self.panels), ("stock_panels", &self.stock_panels)] {
+            let duplicates = Self::duplicate_ids(panels);
+            if !duplicates.is_empty() {
+                let ids: Vec<String> = duplicates.iter().map(ToString::to_string).collect();
+                errors.push(FieldError::new(path, format!("ids must be unique, duplicated: {}", ids.join(", "))));
+            }
+        }
+
+        let stock_ids: HashSet<i32> = self.stock_panels.iter().map(|panel| panel.id).collect();
+        for (index, panel) in self.panels.iter().enumerate() {
+            let path = format!("panels[{}]", index);
+            Self::validate_panel(panel, &path, &mut errors);
+
+            if !panel.enabled {
+                continue;
+            }
+            if let Some(shape) = &panel.l_shape {
+                let width = panel.width.as_deref().and_then(|width| width.trim().parse::<f64>().ok());
+                let height = panel.height.as_deref().and_then(|height| height.trim().parse::<f64>().ok());
+                if let (Some(width), Some(height)) = (width, height) {
+                    if !shape.is_valid_for(width, height) {
+                        errors.push(FieldError::new(
+                            format!("{}.l_shape", path),
+                            format!(
+                                "notch must be positive and smaller than the panel, got {}x{} in {}x{}",
+                                shape.notch_width, shape.notch_height, width, height
+                            ),
+                        ));
+                    }
+                }
+            }
+            if let Some(min_count) = panel.min_count.filter(|&min_count| min_count < 0) {
+                errors.push(FieldError::new(
+                    format!("{}.min_count", path),
+                    format!("must not be negative, got {}", min_count),
+                ));
+            }
+            if let Some(max_count) = panel.max_count {
+                if max_count < 1 {
+                    errors.push(FieldError::new(
+                        format!("{}.max_count", path),
+                        format!("must be at least 1, got {}", max_count),
+                    ));
+                } else if max_count < panel.min_quantity() {
+                    errors.push(FieldError::new(
+                        format!("{}.max_count", path),
+                        format!("must be at least min_count ({}), got {}", panel.min_quantity(), max_count),
+                    ));
+                }
+            } else if panel.min_quantity() > panel.count {
+                errors.push(FieldError::new(
+                    format!("{}.min_count", path),
+                    format!("must be at most count ({}) when max_count is omitted, got {}", panel.count, panel.min_quantity()),
+                ));
+            }
+            match &panel.allowed_stock_ids {
+                Some(ids) if ids.is_empty() => errors.push(FieldError::new(
+                    format!("{}.allowed_stock_ids", path),
+                    "must list at least one stock id, or be omitted to allow any stock",
+                )),
+                Some(ids) => {
+                    for (id_index, id) in ids.iter().enumerate() {
+                        if !stock_ids.contains(id) {
+                            errors.push(FieldError::new(
+                                format!("{}.allowed_stock_ids[{}]", path, id_index),
+                                format!("no stock panel has id {}", id),
+                            ));
+                        }
+                    }
+                }
+                None => {}
+            }
+            if let Some(stock_id) = panel.stock_affinity {
+                if !stock_ids.contains(&stock_id) {
+                    errors.push(FieldError::new(
+                        format!("{}.stock_affinity", path),
+                        format!("no stock panel has id {}", stock_id),
+                    ));
+                } else if !panel.allows_stock(stock_id) {
+                    errors.push(FieldError::new(
+                        format!("{}.stock_affinity", path),
+                        format!("stock id {} is not in allowed_stock_ids", stock_id),
+                    ));
+                }
+            }
+        }
+
+        for (index, panel) in self.stock_panels.iter().enumerate() {
+            let path = format!("stock_panels[{}]", index);
+            Self::validate_panel(panel, &path, &mut errors);
+
+            if panel.enabled {
+                if let Some(weight) = panel.preference_weight {
+                    if !weight.is_finite() || weight < 0.0 {
+                        errors.push(FieldError::new(
+                            format!("{}.preference_weight", path),
+                            format!("must be a non-negative number, got {}", weight),
+                        ));
+                    }
+                }
+                for (keepout_index, keepout) in panel.keepouts.iter().enumerate() {
+                    if keepout.x1 < 0 || keepout.y1 < 0 || keepout.width() <= 0 || keepout.height() <= 0 {
+                        errors.push(FieldError::new(
+                            format!("{}.keepouts[{}]", path, keepout_index),
+                            format!(
+                                "must be a non-empty area at non-negative coordinates, got ({},{})-({},{})",
+                                keepout.x1, keepout.y1, keepout.x2, keepout.y2
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if let Some(configuration) = &self.configuration {
+            if configuration.cut_thickness.is_negative() {
+                errors.push(FieldError::new("configuration.cut_thickness", "must not be negative"));
+            }
+            if configuration.min_trim_dimension < 0 {
+                errors.push(FieldError::new(
+                    "configuration.min_trim_dimension",
+                    format!("must not be negative, got {}", configuration.min_trim_dimension),
+                ));
+            }
+            if configuration.max_solutions_retained == Some(0) {
+                errors.push(FieldError::new("configuration.max_solutions_retained", "must be at least 1"));
+            }
+            if configuration.max_permutations == Some(0) {
+                errors.push(FieldError::new("configuration.max_permutations", "must be at least 1"));
+            }
+            if let Some(saw_time) = configuration.saw_time.filter(|saw_time| !saw_time.is_valid()) {
+                errors.push(FieldError::new(
+                    "configuration.saw_time.feed_rate",
+                    format!("must be greater than 0, got {}", saw_time.feed_rate),
+                ));
+            }
+            if configuration.rotation_tolerance < 0 {
+                errors.push(FieldError::new(
+                    "configuration.rotation_tolerance",
+                    format!("must not be negative, got {}", configuration.rotation_tolerance),
+                ));
+            }
+            if CutDirection::from_preference(configuration.cut_orientation_preference).is_none() {
+                errors.push(FieldError::new(
+                    "configuration.cut_orientation_preference",
+                    format!("must be 0, 1 or 2, got {}", configuration.cut_orientation_preference),
+                ));
+            }
+            for (material, &preference) in &configuration.material_cut_preferences {
+                if CutDirection::from_preference(preference).is_none() {
+                    errors.push(FieldError::new(
+                        format!("configuration.material_cut_preferences.{}", material),
+                        format!("must be 0, 1 or 2, got {}", preference),
+                    ));
+                }
+            }
+            for (material, thickness) in &configuration.material_cut_thickness {
+                match thickness.parse::<f64>() {
+                    Ok(value) if value.is_finite() && value >= 0.0 => {}
+                    _ => errors.push(FieldError::new(
+                        format!("configuration.material_cut_thickness.{}", material),
+                        format!("must be a non-negative number, got {}", thickness),
+                    )),
+                }
+            }
+            let factor_range =
+                ConfigurationDefaults::MIN_OPTIMIZATION_FACTOR..=ConfigurationDefaults::MAX_OPTIMIZATION_FACTOR;
+            if !factor_range.contains(&configuration.optimization_factor) {
+                errors.push(FieldError::new(
+                    "configuration.optimization_factor",
+                    format!(
+                        "must be between {} and {}, got {}",
+                        factor_range.start(),
+                        factor_range.end(),
+                        configuration.optimization_factor
+                    ),
+                ));
+            }
+            if let Some(threshold) = &configuration.reusable_offcut_threshold {
+                match threshold.parse::<f64>() {
+                    Ok(value) if value.is_finite() && value >= 0.0 => {}
+                    _ => errors.push(FieldError::new(
+                        "configuration.reusable_offcut_threshold",
+                        format!("must be a non-negative number, got {}", threshold),
+                    )),
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// IDs used by more than one enabled panel, in ascending order
+    fn duplicate_ids(panels: &[Panel]) -> Vec<i32> {
+        let mut seen = HashSet::new();
+        let mut duplicates: Vec<i32> = panels
+            .iter()
+            .filter(|panel| panel.enabled && !seen.insert(panel.id))
+            .map(|panel| panel.id)
+            .collect();
+        duplicates.sort_unstable();
+        duplicates.dedup();
+        duplicates
+    }
+
+    /// Checks shared by panels and stock panels
+    fn validate_panel(panel: &Panel, path: &str, errors: &mut Vec<FieldError>) {
+        if !panel.enabled {
+            return;
+        }
+
+        if panel.count < 1 {
+            errors.push(FieldError::new(
+                format!("{}.count", path),
+                format!("must be at least 1, got {}", panel.count),
+            ));
+        }
+
+        for (name, value) in [("width", &panel.width), ("height", &panel.height)] {
+            let message = match value.as_deref().map(|text| (text, text.trim().parse::<f64>())) {
+                None => "is required".to_string(),
+                Some((text, Err(_))) => format!("must be a number, got {:?}", text),
+                Some((_, Ok(number))) if !number.is_finite() || number <= 0.0 => {
+                    format!("must be greater than 0, got {}", number)
+                }
+                Some((_, Ok(number))) if !TileDimensions::is_valid_dimension(number) => {
+                    format!("must be at most {}, got {}", EngineConstants::MAX_DIMENSION, number)
+                }
+                Some(_) => continue,
+            };
+            errors.push(FieldError::new(format!("{}.{}", path, name), message));
+        }
+
+        if panel.material.trim().is_empty() {
+            errors.push(FieldError::new(format!("{}.material", path), "must not be empty"));
+        }
+
+        if !(0..=2).contains(&panel.orientation) {
+            errors.push(FieldError::new(
+                format!("{}.orientation", path),
+                format!("must be 0 (any), 1 (horizontal) or 2 (vertical), got {}", panel.orientation),
+            ));
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Building a response directly from a computed solution
+
+use std::collections::HashMap;
+
+use super::structs::CalculationResponse;
+use crate::models::{enums::Orientation, CalculationRequest, FinalTile, NoFitTile, ResponseMeta, Solution};
+use crate::utils::math;
+
+impl CalculationResponse {
+    /// Build a response for `request` from a solution computed at scale `factor`
+    ///
+    /// Areas, lengths and panel dimensions are converted back to real units;
+    /// the mosaics are kept in scaled units as computed. A first acceptable
+    /// layout on the solution is converted the same way.
+    pub fn from_solution(request: &CalculationRequest, solution: &Solution, factor: f64) -> Self {
+        let labels: HashMap<i32, &String> = request
+            .panels
+            .iter()
+            .filter_map(|panel| panel.label.as_ref().map(|label| (panel.id, label)))
+            .collect();
+        let banding: HashMap<i32, &[f64; 4]> = request
+            .panels
+            .iter()
+            .filter_map(|panel| panel.banding_thickness.as_ref().map(|thickness| (panel.id, thickness)))
+            .collect();
+
+        let mut panels = Vec::new();
+        let mut total_stock_area = 0.0;
+        let mut total_used_area = 0.0;
+        let mut total_cut_length = 0.0;
+        let mut total_nbr_cuts = 0u64;
+
+        for mosaic in &solution.mosaics {
+            total_stock_area += math::area(mosaic.width(), mosaic.height(), factor);
+            for node in mosaic.final_tile_nodes() {
+                total_used_area += math::area(node.width(), node.height(), factor);
+                let id = node.external_id.unwrap_or(node.id as i32);
+                let tile = FinalTile {
+                    request_obj_id: id,
+                    width: node.width() as f64 / factor,
+                    height: node.height() as f64 / factor,
+                    label: node.label.clone().or_else(|| labels.get(&id).map(|label| label.to_string())),
+                    count: 1,
+                    ..FinalTile::default()
+                };
+                panels.push(match banding.get(&id) {
+                    Some(thickness) => tile.with_banding(thickness, node.is_rotated()),
+                    None => tile,
+                });
+            }
+            for cut in mosaic.cuts() {
+                let length = (cut.x2 - cut.x1).abs() + (cut.y2 - cut.y1).abs();
+                total_cut_length += length as f64 / factor;
+            }
+            total_nbr_cuts += mosaic.nbr_cuts() as u64;
+        }
+
+        let no_fit_panels = solution
+            .no_fit_panels
+            .iter()
+            .map(|tile| {
+                let mut no_fit = NoFitTile {
+                    id: tile.id,
+                    width: tile.width as f64 / factor,
+                    height: tile.height as f64 / factor,
+                    count: 1,
+                    label: tile.label.clone(),
+                    material: Some(tile.material.clone()),
+                    ..NoFitTile::default()
+                };
+                request.classify_no_fit(&mut no_fit, tile.orientation == Orientation::Any);
+                no_fit
+            })
+            .collect();
+
+        let mut response = Self::new();
+        response.panels = Some(panels);
+        response.request = Some(request.clone());
+        response.total_cut_length = total_cut_length;
+        response.total_nbr_cuts = total_nbr_cuts;
+        response.total_used_area = total_used_area;
+        response.total_used_area_ratio = if total_stock_area > 0.0 {
+            total_used_area / total_stock_area
+        } else {
+            0.0
+        };
+        response.total_wasted_area = total_stock_area - total_used_area;
+        response.no_fit_panels = no_fit_panels;
+        response.mosaics = solution.mosaics.clone();
+        response.stock_recommendations = Self::stock_recommendations_for(request, solution, factor);
+        response.sheet_yields = solution.get_sheet_yields(factor);
+        response.time_bounded = solution.time_bounded;
+        response.grid_packed = solution.grid_packed;
+        response.reusable_offcuts = Self::reusable_offcuts_for(request, solution, factor);
+        response.offcut_histogram = Self::offcut_histogram_for(request, solution, factor);
+        response.min_sheets_lower_bound = Self::min_sheets_lower_bound_for(request);
+        response.first_acceptable = solution.first_acceptable.as_deref().map(|first| {
+            let mut first = Self::from_solution(request, first, factor);
+            first.request = None;
+            Box::new(first)
+        });
+        response.meta = ResponseMeta::from_response(&response);
+        response
+    }
+}
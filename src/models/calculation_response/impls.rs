@@ -1,7 +1,9 @@
 //! CalculationResponse implementation methods
 
 use super::CalculationResponse;
-use crate::models::{CalculationRequest, FinalTile, Mosaic, NoFitTile};
+use crate::engine::service::computation::DimensionUtils;
+use crate::engine::stock;
+use crate::models::{CalculationRequest, FinalTile, Mosaic, NoFitTile, ResponseMeta, SheetYield, Solution, TileDimensions};
 use std::collections::HashMap;
 
 impl CalculationResponse {
@@ -23,6 +25,15 @@ impl CalculationResponse {
             used_stock_panels: None,
             no_fit_panels: Vec::new(),
             mosaics: Vec::new(),
+            stock_recommendations: Vec::new(),
+            sheet_yields: Vec::new(),
+            time_bounded: false,
+            grid_packed: false,
+            reusable_offcuts: Vec::new(),
+            offcut_histogram: Vec::new(),
+            min_sheets_lower_bound: HashMap::new(),
+            first_acceptable: None,
+            meta: ResponseMeta::default(),
         }
     }
 
@@ -219,6 +230,70 @@ impl CalculationResponse {
         self.mosaics = mosaics;
     }
 
+    /// Get the per-sheet usage figures
+    pub fn sheet_yields(&self) -> &Vec<SheetYield> {
+        &self.sheet_yields
+    }
+
+    /// Remainders of `solution` that reach the request's reusable offcut threshold
+    ///
+    /// Empty when the request sets no threshold.
+    pub fn reusable_offcuts_for(request: &CalculationRequest, solution: &Solution, factor: f64) -> Vec<TileDimensions> {
+        let threshold = request
+            .configuration
+            .as_ref()
+            .and_then(|configuration| configuration.reusable_offcut_threshold_as_f64().ok().flatten());
+        match threshold {
+            Some(threshold) => solution
+                .classify_offcuts((threshold * factor).round() as i32, factor)
+                .reusable_offcuts,
+            None => Vec::new(),
+        }
+    }
+
+    /// Remainders of `solution` counted per the request's offcut histogram buckets
+    ///
+    /// Empty when the request sets no buckets.
+    pub fn offcut_histogram_for(request: &CalculationRequest, solution: &Solution, factor: f64) -> Vec<(f64, usize)> {
+        let Some(buckets) = request
+            .configuration
+            .as_ref()
+            .map(|configuration| &configuration.offcut_histogram_buckets)
+        else {
+            return Vec::new();
+        };
+        let scaled: Vec<i64> = buckets.iter().map(|bound| (bound * factor * factor).round() as i64).collect();
+        buckets
+            .iter()
+            .zip(solution.offcut_histogram(&scaled))
+            .map(|(&bound, (_, count))| (bound, count))
+            .collect()
+    }
+
+    /// Area-based lower bound on the sheets each material of `request` needs
+    ///
+    /// Empty when the request's panels cannot be converted to tiles.
+    pub fn min_sheets_lower_bound_for(request: &CalculationRequest) -> HashMap<String, usize> {
+        DimensionUtils::convert_panels_to_tiles(&request.panels, &request.stock_panels, 6)
+            .map(|(tiles, stock_tiles, _)| stock::min_sheets_lower_bounds(&tiles, &stock_tiles))
+            .unwrap_or_default()
+    }
+
+    /// Get the area-based lower bound on sheets per material
+    pub fn min_sheets_lower_bound(&self) -> &HashMap<String, usize> {
+        &self.min_sheets_lower_bound
+    }
+
+    /// Get the offcut counts per size range
+    pub fn offcut_histogram(&self) -> &Vec<(f64, usize)> {
+        &self.offcut_histogram
+    }
+
+    /// Get the remainders kept as reusable stock
+    pub fn reusable_offcuts(&self) -> &Vec<TileDimensions> {
+        &self.reusable_offcuts
+    }
+
     /// Add a panel to the no-fit panels list
     pub fn add_no_fit_panel(&mut self, panel: NoFitTile) {
         self.no_fit_panels.push(panel);
@@ -0,0 +1,88 @@
+//! Combining the responses of a job split across several runs
+
+use super::structs::CalculationResponse;
+use crate::models::ResponseMeta;
+
+impl CalculationResponse {
+    /// Append the results of `other` to this response
+    ///
+    /// Panels, sheets and no-fit panels are concatenated and the totals
+    /// summed; the used area ratio is recomputed from the merged areas rather
+    /// than averaged. Sheet indices of `other` are shifted past this
+    /// response's sheets so they stay unique. Edge band lengths of the same
+    /// band type are added together, as are offcut counts of the same bucket
+    /// and sheet lower bounds of the same material, and `meta` is recomputed.
+    /// First acceptable layouts are merged likewise, and dropped unless both
+    /// responses have one. The merged response is grid packed only when both are.
+    /// Identity fields (`id`, `task_id`, `request`, `version`) are kept from
+    /// `self`.
+    pub fn merge(&mut self, other: CalculationResponse) {
+        let sheet_offset = self.mosaics.len();
+
+        if let Some(other_bands) = other.edge_bands {
+            let bands = self.edge_bands.get_or_insert_with(Default::default);
+            for (band_type, length) in other_bands {
+                *bands.entry(band_type).or_insert(0.0) += length;
+            }
+        }
+        if let Some(other_panels) = other.panels {
+            self.panels.get_or_insert_with(Vec::new).extend(other_panels);
+        }
+        if let Some(other_stock) = other.used_stock_panels {
+            self.used_stock_panels.get_or_insert_with(Vec::new).extend(other_stock);
+        }
+        self.solution_elapsed_time = match (self.solution_elapsed_time, other.solution_elapsed_time) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+        };
+
+        self.elapsed_time += other.elapsed_time;
+        self.total_cut_length += other.total_cut_length;
+        self.total_nbr_cuts += other.total_nbr_cuts;
+        self.total_used_area += other.total_used_area;
+        self.total_wasted_area += other.total_wasted_area;
+        let total_area = self.total_used_area + self.total_wasted_area;
+        self.total_used_area_ratio = if total_area > 0.0 {
+            self.total_used_area / total_area
+        } else {
+            0.0
+        };
+
+        self.no_fit_panels.extend(other.no_fit_panels);
+        self.mosaics.extend(other.mosaics);
+        self.stock_recommendations.extend(other.stock_recommendations);
+        self.sheet_yields.extend(other.sheet_yields.into_iter().map(|mut sheet| {
+            sheet.sheet_index += sheet_offset;
+            sheet
+        }));
+        self.time_bounded |= other.time_bounded;
+        self.grid_packed &= other.grid_packed;
+        self.reusable_offcuts.extend(other.reusable_offcuts);
+        for (bound, count) in other.offcut_histogram {
+            match self.offcut_histogram.iter_mut().find(|(existing, _)| *existing == bound) {
+                Some(bucket) => bucket.1 += count,
+                None => self.offcut_histogram.push((bound, count)),
+            }
+        }
+        self.offcut_histogram.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (material, bound) in other.min_sheets_lower_bound {
+            *self.min_sheets_lower_bound.entry(material).or_insert(0) += bound;
+        }
+        self.first_acceptable = match (self.first_acceptable.take(), other.first_acceptable) {
+            (Some(mut first), Some(other_first)) => {
+                first.merge(*other_first);
+                Some(first)
+            }
+            _ => None,
+        };
+        let permutation_stats = match (self.meta.permutation_stats, other.meta.permutation_stats) {
+            (Some(mut stats), Some(other_stats)) => {
+                stats.combine(&other_stats);
+                Some(stats)
+            }
+            (stats, other_stats) => stats.or(other_stats),
+        };
+        self.meta = ResponseMeta::from_response(self);
+        self.meta.permutation_stats = permutation_stats;
+    }
+}
@@ -2,5 +2,8 @@
 
 pub mod structs;
 pub mod impls;
+pub mod recommendations;
+pub mod from_solution;
+pub mod merge;
 
 pub use structs::*;
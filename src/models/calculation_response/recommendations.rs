@@ -0,0 +1,105 @@
+//! Stock size recommendations for underused sheets
+
+use super::CalculationResponse;
+use crate::constants::StockConstants;
+use crate::engine::service::computation::dimension_utils::DimensionUtils;
+use crate::models::{CalculationRequest, Mosaic, Solution, StockRecommendation, TileDimensions};
+use crate::Orientation;
+
+impl CalculationResponse {
+    /// Suggest a smaller catalog size for every underused sheet
+    ///
+    /// A sheet is underused when its placed tiles cover less than
+    /// `StockConstants::UNDERUSED_SHEET_RATIO` of it. The suggestion is the
+    /// smallest catalog entry of the same material that still contains the
+    /// sheet's `placements_bounding_box`. Catalog sizes must use the same
+    /// units as the mosaics. Rotated sizes are only considered for sheets
+    /// without a grain orientation.
+    pub fn recommend_stock(&mut self, catalog: &[TileDimensions]) {
+        self.stock_recommendations = self
+            .mosaics
+            .iter()
+            .filter_map(|mosaic| Self::recommend_for_mosaic(mosaic, catalog))
+            .collect();
+    }
+
+    /// Recommendations for the underused sheets of `solution` from the
+    /// request's `stock_catalog`
+    ///
+    /// Catalog sizes are scaled by `factor` to the units of the mosaics.
+    /// Empty when the request has no catalog; entries whose dimensions do not
+    /// parse are skipped.
+    pub fn stock_recommendations_for(
+        request: &CalculationRequest,
+        solution: &Solution,
+        factor: f64,
+    ) -> Vec<StockRecommendation> {
+        let Some(configuration) = request.configuration.as_ref() else {
+            return Vec::new();
+        };
+        let catalog: Vec<TileDimensions> = configuration
+            .stock_catalog
+            .iter()
+            .filter_map(|size| {
+                let width = DimensionUtils::scale_dimension(size.width_as_f64().ok()?, factor).ok()?;
+                let height = DimensionUtils::scale_dimension(size.height_as_f64().ok()?, factor).ok()?;
+                Some(TileDimensions {
+                    material: size.material.clone(),
+                    ..TileDimensions::new(size.id, width, height)
+                })
+            })
+            .collect();
+
+        solution
+            .mosaics
+            .iter()
+            .filter_map(|mosaic| Self::recommend_for_mosaic(mosaic, &catalog))
+            .collect()
+    }
+
+    /// Get the stock recommendations
+    pub fn stock_recommendations(&self) -> &Vec<StockRecommendation> {
+        &self.stock_recommendations
+    }
+
+    fn recommend_for_mosaic(mosaic: &Mosaic, catalog: &[TileDimensions]) -> Option<StockRecommendation> {
+        let total_area = mosaic.total_area();
+        if total_area == 0 {
+            return None;
+        }
+
+        let used_area: i64 = mosaic.final_tile_nodes().iter().map(|node| node.area()).sum();
+        let used_area_ratio = used_area as f64 / total_area as f64;
+        if used_area_ratio >= StockConstants::UNDERUSED_SHEET_RATIO {
+            return None;
+        }
+
+        let (box_width, box_height) = mosaic.placements_bounding_box()?;
+        let can_rotate = mosaic.orientation() == Orientation::Any;
+
+        let best = catalog
+            .iter()
+            .filter(|size| size.material == mosaic.material())
+            .filter(|size| (size.width as i64 * size.height as i64) < total_area)
+            .filter_map(|size| {
+                if size.width >= box_width && size.height >= box_height {
+                    Some((size.width, size.height))
+                } else if can_rotate && size.height >= box_width && size.width >= box_height {
+                    Some((size.height, size.width))
+                } else {
+                    None
+                }
+            })
+            .min_by_key(|(width, height)| *width as i64 * *height as i64)?;
+
+        Some(StockRecommendation {
+            stock_id: mosaic.stock_id(),
+            material: mosaic.material().to_string(),
+            current_width: mosaic.width(),
+            current_height: mosaic.height(),
+            recommended_width: best.0,
+            recommended_height: best.1,
+            used_area_ratio,
+        })
+    }
+}
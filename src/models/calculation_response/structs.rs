@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
-use crate::models::{CalculationRequest, FinalTile, NoFitTile, Mosaic};
+use crate::models::{CalculationRequest, FinalTile, NoFitTile, Mosaic, ResponseMeta, SheetYield, StockRecommendation, TileDimensions};
 
 /// Response structure containing the results of a cutting calculation
 /// 
@@ -57,5 +57,43 @@ pub struct CalculationResponse {
     
     /// List of cutting mosaics representing the complete solutions
     pub mosaics: Vec<Mosaic>,
+
+    /// Smaller catalog sizes suggested for underused sheets
+    #[serde(default)]
+    pub stock_recommendations: Vec<StockRecommendation>,
+
+    /// Usage figures for each sheet, in mosaic order
+    #[serde(default)]
+    pub sheet_yields: Vec<SheetYield>,
+
+    /// Whether the search stopped early because its time budget ran out
+    #[serde(default)]
+    pub time_bounded: bool,
+
+    /// Whether every material was packed as a grid instead of searched
+    #[serde(default)]
+    pub grid_packed: bool,
+
+    /// Unused remainders at least as large as the configured reusable offcut threshold
+    #[serde(default)]
+    pub reusable_offcuts: Vec<TileDimensions>,
+
+    /// Number of unused remainders per configured size range, keyed by the
+    /// lower bound of the range in square units
+    #[serde(default)]
+    pub offcut_histogram: Vec<(f64, usize)>,
+
+    /// Area-based lower bound on the sheets needed, per material
+    #[serde(default)]
+    pub min_sheets_lower_bound: HashMap<String, usize>,
+
+    /// With `report_first_acceptable`, the layout the search would have
+    /// stopped at on reaching the target efficiency, without its request
+    #[serde(default)]
+    pub first_acceptable: Option<Box<CalculationResponse>>,
+
+    /// Typed, versioned summary of the figures above
+    #[serde(default)]
+    pub meta: ResponseMeta,
 }
 
@@ -0,0 +1,196 @@
+//! Fluent construction of a validated `Configuration`
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::enums::CutThickness;
+use super::structs::{Configuration, ConfigurationBuilder};
+use crate::errors::Result;
+use crate::models::enums::{CutStyle, FirstCutRule, GrainMode, OptimizationPriority, PlacementHeuristic};
+use crate::models::performance_thresholds::PerformanceThresholds;
+use crate::models::Panel;
+use crate::models::saw_time::SawTimeParams;
+
+impl Configuration {
+    /// Start building a configuration from the defaults
+    pub fn builder() -> ConfigurationBuilder {
+        ConfigurationBuilder::new()
+    }
+}
+
+impl ConfigurationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cut_thickness(mut self, cut_thickness: impl Into<CutThickness>) -> Self {
+        self.configuration.cut_thickness = cut_thickness.into();
+        self
+    }
+
+    pub fn min_trim_dimension(mut self, min_trim_dimension: i32) -> Self {
+        self.configuration.min_trim_dimension = min_trim_dimension;
+        self
+    }
+
+    pub fn consider_orientation(mut self, consider_orientation: bool) -> Self {
+        self.configuration.consider_orientation = consider_orientation;
+        self
+    }
+
+    pub fn optimization_factor(mut self, optimization_factor: i32) -> Self {
+        self.configuration.optimization_factor = optimization_factor;
+        self
+    }
+
+    pub fn optimization_priority(mut self, optimization_priority: OptimizationPriority) -> Self {
+        self.configuration.optimization_priority = optimization_priority;
+        self
+    }
+
+    pub fn use_single_stock_unit(mut self, use_single_stock_unit: bool) -> Self {
+        self.configuration.use_single_stock_unit = use_single_stock_unit;
+        self
+    }
+
+    pub fn units(mut self, units: impl Into<String>) -> Self {
+        self.configuration.units = units.into();
+        self
+    }
+
+    pub fn performance_thresholds(mut self, performance_thresholds: PerformanceThresholds) -> Self {
+        self.configuration.performance_thresholds = performance_thresholds;
+        self
+    }
+
+    pub fn cut_orientation_preference(mut self, preference: i32) -> Self {
+        self.configuration.cut_orientation_preference = preference;
+        self
+    }
+
+    pub fn material_cut_preferences(mut self, preferences: HashMap<String, i32>) -> Self {
+        self.configuration.material_cut_preferences = preferences;
+        self
+    }
+
+    pub fn material_cut_thickness(mut self, thicknesses: HashMap<String, String>) -> Self {
+        self.configuration.material_cut_thickness = thicknesses;
+        self
+    }
+
+    pub fn max_sheets(mut self, max_sheets: usize) -> Self {
+        self.configuration.max_sheets = Some(max_sheets);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.configuration.seed = Some(seed);
+        self
+    }
+
+    pub fn cut_style(mut self, cut_style: CutStyle) -> Self {
+        self.configuration.cut_style = cut_style;
+        self
+    }
+
+    pub fn first_cut_rule(mut self, first_cut_rule: FirstCutRule) -> Self {
+        self.configuration.first_cut_rule = first_cut_rule;
+        self
+    }
+
+    pub fn time_budget(mut self, time_budget: Duration) -> Self {
+        self.configuration.time_budget = Some(time_budget);
+        self
+    }
+
+    pub fn target_efficiency(mut self, target_efficiency: f64) -> Self {
+        self.configuration.target_efficiency = Some(target_efficiency);
+        self
+    }
+
+    pub fn group_identical_tiles(mut self, group_identical_tiles: bool) -> Self {
+        self.configuration.group_identical_tiles = group_identical_tiles;
+        self
+    }
+
+    pub fn reusable_offcut_threshold(mut self, threshold: impl Into<String>) -> Self {
+        self.configuration.reusable_offcut_threshold = Some(threshold.into());
+        self
+    }
+
+    pub fn offcut_histogram_buckets(mut self, buckets: Vec<f64>) -> Self {
+        self.configuration.offcut_histogram_buckets = buckets;
+        self
+    }
+
+    pub fn placement_heuristic(mut self, placement_heuristic: PlacementHeuristic) -> Self {
+        self.configuration.placement_heuristic = placement_heuristic;
+        self
+    }
+
+    pub fn grain_mode(mut self, grain_mode: GrainMode) -> Self {
+        self.configuration.grain_mode = grain_mode;
+        self
+    }
+
+    pub fn stock_catalog(mut self, stock_catalog: Vec<Panel>) -> Self {
+        self.configuration.stock_catalog = stock_catalog;
+        self
+    }
+
+    pub fn rotation_tolerance(mut self, rotation_tolerance: i32) -> Self {
+        self.configuration.rotation_tolerance = rotation_tolerance;
+        self
+    }
+
+    pub fn cut_removes_material(mut self, cut_removes_material: bool) -> Self {
+        self.configuration.cut_removes_material = cut_removes_material;
+        self
+    }
+
+    pub fn max_solutions_retained(mut self, max_solutions_retained: usize) -> Self {
+        self.configuration.max_solutions_retained = Some(max_solutions_retained);
+        self
+    }
+
+    pub fn max_permutations(mut self, max_permutations: usize) -> Self {
+        self.configuration.max_permutations = Some(max_permutations);
+        self
+    }
+
+    pub fn max_cuts_per_sheet(mut self, max_cuts_per_sheet: usize) -> Self {
+        self.configuration.max_cuts_per_sheet = Some(max_cuts_per_sheet);
+        self
+    }
+
+    pub fn prefer_fewer_sheets(mut self, prefer_fewer_sheets: bool) -> Self {
+        self.configuration.prefer_fewer_sheets = prefer_fewer_sheets;
+        self
+    }
+
+    pub fn balance_sheets(mut self, balance_sheets: bool) -> Self {
+        self.configuration.balance_sheets = balance_sheets;
+        self
+    }
+
+    pub fn saw_time(mut self, saw_time: SawTimeParams) -> Self {
+        self.configuration.saw_time = Some(saw_time);
+        self
+    }
+
+    pub fn fit_within_kerf(mut self, fit_within_kerf: bool) -> Self {
+        self.configuration.fit_within_kerf = fit_within_kerf;
+        self
+    }
+
+    pub fn report_first_acceptable(mut self, report_first_acceptable: bool) -> Self {
+        self.configuration.report_first_acceptable = report_first_acceptable;
+        self
+    }
+
+    /// Validate and return the configuration
+    pub fn build(self) -> Result<Configuration> {
+        self.configuration.validate()?;
+        Ok(self.configuration)
+    }
+}
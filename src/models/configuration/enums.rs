@@ -1,2 +1,80 @@
-// В данном модуле нет enum'ов, но файл создан для единообразия структуры
-// Если в будущем появятся enum'ы для конфигурации, они будут добавлены сюда
+use serde::{Deserialize, Serialize};
+
+/// Blade width (kerf) consumed by each cut
+///
+/// Accepts either a single value applied to both axes, or separate values
+/// for machines whose rip and crosscut kerf differ:
+///
+/// ```json
+/// "cut_thickness": 3
+/// "cut_thickness": { "horizontal": 3, "vertical": 1 }
+/// ```
+///
+/// The horizontal kerf is consumed by horizontal splits (which divide a tile's
+/// width) and the vertical kerf by vertical splits (which divide its height).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CutThickness {
+    /// Same kerf for both axes
+    Uniform(i32),
+    /// Separate kerf per axis
+    PerAxis { horizontal: i32, vertical: i32 },
+}
+
+impl CutThickness {
+    /// Create a kerf with separate values per axis
+    pub fn per_axis(horizontal: i32, vertical: i32) -> Self {
+        Self::PerAxis { horizontal, vertical }
+    }
+
+    /// Kerf consumed by horizontal splits
+    pub fn horizontal(&self) -> i32 {
+        match *self {
+            Self::Uniform(value) => value,
+            Self::PerAxis { horizontal, .. } => horizontal,
+        }
+    }
+
+    /// Kerf consumed by vertical splits
+    pub fn vertical(&self) -> i32 {
+        match *self {
+            Self::Uniform(value) => value,
+            Self::PerAxis { vertical, .. } => vertical,
+        }
+    }
+
+    /// Check whether either axis has a negative kerf
+    pub fn is_negative(&self) -> bool {
+        self.horizontal() < 0 || self.vertical() < 0
+    }
+
+    /// Scale both axes by the given factor, rounding to the nearest integer
+    pub fn scaled(&self, factor: f64) -> Self {
+        let scale = |value: i32| (value as f64 * factor).round() as i32;
+        match *self {
+            Self::Uniform(value) => Self::Uniform(scale(value)),
+            Self::PerAxis { horizontal, vertical } => Self::per_axis(scale(horizontal), scale(vertical)),
+        }
+    }
+}
+
+impl Default for CutThickness {
+    fn default() -> Self {
+        Self::Uniform(0)
+    }
+}
+
+impl From<i32> for CutThickness {
+    fn from(value: i32) -> Self {
+        Self::Uniform(value)
+    }
+}
+
+impl std::fmt::Display for CutThickness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uniform(value) => write!(f, "{}", value),
+            Self::PerAxis { horizontal, vertical } => write!(f, "{}x{}", horizontal, vertical),
+        }
+    }
+}
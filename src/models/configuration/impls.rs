@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
+use super::enums::CutThickness;
 use super::structs::Configuration;
-use crate::models::enums::OptimizationPriority;
+use crate::models::enums::{CutDirection, CutStyle, FirstCutRule, GrainMode, OptimizationPriority, PlacementHeuristic};
 use crate::errors::{AppError, Result};
 use crate::models::performance_thresholds::PerformanceThresholds;
 use crate::constants::ConfigurationDefaults;
@@ -7,7 +10,7 @@ use crate::constants::ConfigurationDefaults;
 impl Default for Configuration {
     fn default() -> Self {
         Self {
-            cut_thickness: ConfigurationDefaults::DEFAULT_CUT_THICKNESS,
+            cut_thickness: CutThickness::Uniform(ConfigurationDefaults::DEFAULT_CUT_THICKNESS),
             min_trim_dimension: ConfigurationDefaults::DEFAULT_MIN_TRIM_DIMENSION,
             consider_orientation: true,
             optimization_factor: ConfigurationDefaults::DEFAULT_OPTIMIZATION_FACTOR,
@@ -15,6 +18,31 @@ impl Default for Configuration {
             use_single_stock_unit: false,
             units: "mm".to_string(),
             performance_thresholds: PerformanceThresholds::default(),
+            cut_orientation_preference: 0,
+            material_cut_preferences: HashMap::new(),
+            material_cut_thickness: HashMap::new(),
+            max_sheets: None,
+            seed: None,
+            cut_style: CutStyle::default(),
+            first_cut_rule: FirstCutRule::default(),
+            time_budget: None,
+            target_efficiency: None,
+            group_identical_tiles: false,
+            reusable_offcut_threshold: None,
+            offcut_histogram_buckets: Vec::new(),
+            placement_heuristic: PlacementHeuristic::default(),
+            rotation_tolerance: 0,
+            cut_removes_material: true,
+            max_solutions_retained: None,
+            max_cuts_per_sheet: None,
+            max_permutations: None,
+            prefer_fewer_sheets: false,
+            balance_sheets: false,
+            saw_time: None,
+            fit_within_kerf: true,
+            report_first_acceptable: false,
+            grain_mode: GrainMode::default(),
+            stock_catalog: Vec::new(),
         }
     }
 }
@@ -23,7 +51,7 @@ impl Default for Configuration {
 impl Configuration {
     /// Validate configuration parameters
     pub fn validate(&self) -> Result<()> {
-        if self.cut_thickness < 0 {
+        if self.cut_thickness.is_negative() {
             return Err(AppError::invalid_configuration("Cut thickness cannot be negative"));
         }
         
@@ -37,6 +65,123 @@ impl Configuration {
                     ConfigurationDefaults::MAX_OPTIMIZATION_FACTOR)));
         }
         
+        if self.target_efficiency.is_some_and(|target| !(target > 0.0 && target <= 1.0)) {
+            return Err(AppError::invalid_configuration("Target efficiency must be greater than 0 and at most 1"));
+        }
+        
+        if self.max_solutions_retained == Some(0) {
+            return Err(AppError::invalid_configuration("Max solutions retained must be at least 1"));
+        }
+        
+        if self.max_permutations == Some(0) {
+            return Err(AppError::invalid_configuration("Max permutations must be at least 1"));
+        }
+        
+        if self.rotation_tolerance < 0 {
+            return Err(AppError::invalid_configuration("Rotation tolerance cannot be negative"));
+        }
+
+        if self.saw_time.is_some_and(|saw_time| !saw_time.is_valid()) {
+            return Err(AppError::invalid_configuration("Saw feed rate must be greater than 0"));
+        }
+        
+        if CutDirection::from_preference(self.cut_orientation_preference).is_none() {
+            return Err(AppError::invalid_configuration(format!(
+                "Cut orientation preference must be 0, 1 or 2, got {}",
+                self.cut_orientation_preference
+            )));
+        }
+
+        for (material, &preference) in &self.material_cut_preferences {
+            if CutDirection::from_preference(preference).is_none() {
+                return Err(AppError::invalid_configuration(format!(
+                    "Cut orientation preference for material '{}' must be 0, 1 or 2, got {}",
+                    material, preference
+                )));
+            }
+        }
+
+        for (material, thickness) in &self.material_cut_thickness {
+            match thickness.parse::<f64>() {
+                Ok(value) if value.is_finite() && value >= 0.0 => {}
+                _ => {
+                    return Err(AppError::invalid_configuration(format!(
+                        "Cut thickness for material '{}' must be a non-negative number, got {}",
+                        material, thickness
+                    )));
+                }
+            }
+        }
+
+        if self.reusable_offcut_threshold_as_f64()?.is_some_and(|threshold| threshold < 0.0) {
+            return Err(AppError::invalid_configuration("Reusable offcut threshold cannot be negative"));
+        }
+
+        if self.offcut_histogram_buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(AppError::invalid_configuration("Offcut histogram buckets must be in ascending order"));
+        }
+
         Ok(())
     }
+
+    /// Get the reusable offcut threshold as a parsed f64 value
+    pub fn reusable_offcut_threshold_as_f64(&self) -> Result<Option<f64>> {
+        self.reusable_offcut_threshold
+            .as_deref()
+            .map(|threshold| {
+                threshold.parse().map_err(|_| {
+                    AppError::invalid_configuration(format!("Invalid reusable offcut threshold: {}", threshold))
+                })
+            })
+            .transpose()
+    }
+
+    /// Kerf to lay out with: `cut_thickness`, or none when cuts remove no material
+    pub fn effective_cut_thickness(&self, cut_thickness: CutThickness) -> CutThickness {
+        if self.cut_removes_material {
+            cut_thickness
+        } else {
+            CutThickness::default()
+        }
+    }
+
+    /// Kerf per material with its own blade, scaled by `factor`
+    ///
+    /// Like the global kerf, these are zero when cuts remove no material.
+    pub fn material_cut_thicknesses(&self, factor: f64) -> Result<HashMap<String, CutThickness>> {
+        self.material_cut_thickness
+            .iter()
+            .map(|(material, thickness)| {
+                let value: f64 = thickness.parse().map_err(|_| {
+                    AppError::invalid_configuration(format!(
+                        "Invalid cut thickness for material '{}': {}",
+                        material, thickness
+                    ))
+                })?;
+                let kerf = CutThickness::from((value * factor).round() as i32);
+                Ok((material.clone(), self.effective_cut_thickness(kerf)))
+            })
+            .collect()
+    }
+
+    /// First-cut direction for the global preference
+    pub fn cut_direction(&self) -> CutDirection {
+        CutDirection::from_preference(self.cut_orientation_preference).unwrap_or_default()
+    }
+
+    /// First-cut direction for `material`, falling back to the global preference
+    pub fn cut_direction_for(&self, material: &str) -> CutDirection {
+        self.material_cut_preferences
+            .get(material)
+            .and_then(|&preference| CutDirection::from_preference(preference))
+            .unwrap_or_else(|| self.cut_direction())
+    }
+
+    /// First-cut directions of every material with its own preference
+    pub fn material_cut_directions(&self) -> HashMap<String, CutDirection> {
+        self.material_cut_preferences
+            .keys()
+            .map(|material| (material.clone(), self.cut_direction_for(material)))
+            .collect()
+    }
 }
@@ -1,6 +1,8 @@
 pub mod enums;
 pub mod structs;
 pub mod impls;
+pub mod builder;
 
 
-pub use structs::Configuration;
+pub use enums::CutThickness;
+pub use structs::{Configuration, ConfigurationBuilder};
@@ -1,12 +1,18 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
-use crate::models::enums::OptimizationPriority;
+use crate::models::enums::{CutStyle, FirstCutRule, GrainMode, OptimizationPriority, PlacementHeuristic};
 use crate::models::performance_thresholds::PerformanceThresholds;
+use crate::models::Panel;
+use crate::models::saw_time::SawTimeParams;
+use super::enums::CutThickness;
 
 /// Configuration parameters for the optimization process
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Configuration {
-    /// Thickness of the cutting blade (kerf)
-    pub cut_thickness: i32,
+    /// Thickness of the cutting blade (kerf), optionally per axis
+    pub cut_thickness: CutThickness,
     
     /// Minimum trim dimension (waste edge)
     pub min_trim_dimension: i32,
@@ -28,4 +34,131 @@ pub struct Configuration {
     
     /// Performance constraints
     pub performance_thresholds: PerformanceThresholds,
+
+    /// First-cut direction: 0 = both, 1 = horizontal, 2 = vertical
+    #[serde(default)]
+    pub cut_orientation_preference: i32,
+
+    /// First-cut direction per material, overriding `cut_orientation_preference`
+    #[serde(default)]
+    pub material_cut_preferences: HashMap<String, i32>,
+
+    /// Kerf per material, overriding `cut_thickness` for materials cut with another blade
+    #[serde(default)]
+    pub material_cut_thickness: HashMap<String, String>,
+
+    /// Maximum number of physical sheets a solution may use
+    #[serde(default)]
+    pub max_sheets: Option<usize>,
+
+    /// Seed for a reproducible search order; `None` keeps the default order
+    #[serde(default)]
+    pub seed: Option<u64>,
+
+    /// Whether tiles need full-span guillotine cuts or may be cut from a node corner
+    #[serde(default)]
+    pub cut_style: CutStyle,
+
+    /// Which cut comes first on nodes that may be cut in either direction
+    #[serde(default)]
+    pub first_cut_rule: FirstCutRule,
+
+    /// Wall-clock limit for the search, in whole milliseconds when serialized;
+    /// when it runs out the best solution so far is returned
+    #[serde(default, with = "crate::utils::timing::optional_millis")]
+    pub time_budget: Option<Duration>,
+
+    /// Used area ratio (0-1] at which the search stops; the first solution that
+    /// places every tile at or above it is returned
+    #[serde(default)]
+    pub target_efficiency: Option<f64>,
+
+    /// Place all copies of an identical tile as one contiguous run on a single sheet
+    #[serde(default)]
+    pub group_identical_tiles: bool,
+
+    /// Smallest side an unused remainder needs to be kept as reusable stock
+    #[serde(default)]
+    pub reusable_offcut_threshold: Option<String>,
+
+    /// Lower bounds, in square units, of the offcut size ranges counted in the response
+    #[serde(default)]
+    pub offcut_histogram_buckets: Vec<f64>,
+
+    /// How the node for each tile is chosen
+    #[serde(default)]
+    pub placement_heuristic: PlacementHeuristic,
+
+    /// Tiles whose sides differ by less than this are not tried rotated
+    #[serde(default)]
+    pub rotation_tolerance: i32,
+
+    /// Whether cuts remove material; `false` for score-and-snap cuts, which
+    /// are still counted but leave no kerf
+    #[serde(default = "default_cut_removes_material")]
+    pub cut_removes_material: bool,
+
+    /// Upper bound on the solutions each layout thread keeps between tiles,
+    /// on top of the accuracy factor
+    #[serde(default)]
+    pub max_solutions_retained: Option<usize>,
+
+    /// Most cuts the saw may make on one sheet; placements needing more go
+    /// to another sheet
+    #[serde(default)]
+    pub max_cuts_per_sheet: Option<usize>,
+
+    /// Most tile orders to try; large part lists are sampled with `seed`
+    /// instead of being cut down to their largest groups
+    #[serde(default)]
+    pub max_permutations: Option<usize>,
+
+    /// Rank solutions on fewer sheets above ones that waste less area
+    #[serde(default)]
+    pub prefer_fewer_sheets: bool,
+
+    /// Free barely-used sheets by moving their panels onto other sheets once
+    /// the layout is chosen
+    #[serde(default)]
+    pub balance_sheets: bool,
+
+    /// Saw speed used to estimate machining time in the response; `None` skips the estimate
+    #[serde(default)]
+    pub saw_time: Option<SawTimeParams>,
+
+    /// Let a tile take a space at most one kerf larger than it, the kerf
+    /// consuming the rest, even when that rest is below `min_trim_dimension`
+    #[serde(default = "default_fit_within_kerf")]
+    pub fit_within_kerf: bool,
+
+    /// Keep searching past `target_efficiency` and report the first layout
+    /// that met it next to the best one
+    #[serde(default)]
+    pub report_first_acceptable: bool,
+
+    /// How tiles with a grain direction may be rotated during placement
+    #[serde(default)]
+    pub grain_mode: GrainMode,
+
+    /// Standard sheet sizes proposed in place of underused sheets; each
+    /// entry's width, height and material are used
+    #[serde(default)]
+    pub stock_catalog: Vec<Panel>,
+}
+
+fn default_cut_removes_material() -> bool {
+    true
+}
+
+fn default_fit_within_kerf() -> bool {
+    true
+}
+
+/// Builder for `Configuration`
+///
+/// Starts from `Configuration::default()`; only the fields that are set
+/// change, and `build` validates the result.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigurationBuilder {
+    pub(crate) configuration: Configuration,
 }
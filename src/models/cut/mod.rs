@@ -1,4 +1,4 @@
 pub mod structs;
 pub mod impls;
 
-pub use structs::{Cut, CutBuilder};
+pub use structs::{Cut, CutBuilder, CutStep};
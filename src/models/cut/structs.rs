@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::models::enums::CutDirection;
 
 /// Represents a cut operation on a tile, defining how a tile is divided into two child tiles
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -42,3 +43,24 @@ pub struct CutBuilder {
     pub(crate) child1_tile_id: i32,
     pub(crate) child2_tile_id: i32,
 }
+
+/// One step of the order in which a saw operator makes the cuts of a sheet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CutStep {
+    /// Position of the step in the sequence, starting at 0
+    pub index: usize,
+    /// ID of the piece being divided
+    pub piece_id: i32,
+    /// Width of the piece being divided
+    pub piece_width: i32,
+    /// Height of the piece being divided
+    pub piece_height: i32,
+    /// `Horizontal` for cuts recorded with `is_horizontal`, `Vertical` otherwise
+    pub direction: CutDirection,
+    /// Absolute coordinate of the cut along the axis it divides
+    pub position: i32,
+    /// ID of the first piece produced by the cut
+    pub child1_id: i32,
+    /// ID of the second piece produced by the cut
+    pub child2_id: i32,
+}
@@ -0,0 +1,75 @@
+//! Edge banding implementations
+
+use super::{EdgeBandConfig, EdgeBandSummary};
+use crate::models::{Edge, Mosaic, Panel, TileDimensions};
+use crate::utils::edge_banding::calc_tile_edge_bands;
+
+impl EdgeBandConfig {
+    /// Create an empty config for solutions scaled by `scale_factor`
+    pub fn new(scale_factor: f64) -> Self {
+        Self {
+            edges: Default::default(),
+            scale_factor,
+        }
+    }
+
+    /// Collect the edge specs of every panel that has one
+    pub fn from_panels(panels: &[Panel], scale_factor: f64) -> Self {
+        let mut config = Self::new(scale_factor);
+        for panel in panels {
+            if let Some(edge) = &panel.edge {
+                config.edges.insert(panel.id, edge.clone());
+            }
+        }
+        config
+    }
+
+    /// Collect the edge specs carried by tiles
+    pub fn from_tiles(tiles: &[TileDimensions], scale_factor: f64) -> Self {
+        let mut config = Self::new(scale_factor);
+        for tile in tiles {
+            if let Some(edge) = &tile.edge {
+                config.edges.insert(tile.id, edge.clone());
+            }
+        }
+        config
+    }
+
+    /// Set the edge spec of a tile id
+    pub fn with_edge(mut self, id: i32, edge: Edge) -> Self {
+        self.edges.insert(id, edge);
+        self
+    }
+
+    /// Edge spec of a tile id, if any
+    pub fn edge_for(&self, id: i32) -> Option<&Edge> {
+        self.edges.get(&id)
+    }
+}
+
+impl EdgeBandSummary {
+    /// Add the banding of every final tile of a mosaic
+    pub fn add_mosaic(&mut self, mosaic: &Mosaic, config: &EdgeBandConfig) {
+        for node in mosaic.final_tile_nodes() {
+            let Some(edge) = node.external_id().and_then(|id| config.edge_for(id)) else {
+                continue;
+            };
+
+            for (band_type, length) in calc_tile_edge_bands(node, edge, config.scale_factor) {
+                *self.by_band_type.entry(band_type).or_insert(0.0) += length;
+                *self.by_material.entry(mosaic.material().to_string()).or_insert(0.0) += length;
+                self.total_length += length;
+            }
+        }
+    }
+
+    /// Banding length for a band type
+    pub fn band_type_length(&self, band_type: &str) -> f64 {
+        self.by_band_type.get(band_type).copied().unwrap_or(0.0)
+    }
+
+    /// Banding length on sheets of a material
+    pub fn material_length(&self, material: &str) -> f64 {
+        self.by_material.get(material).copied().unwrap_or(0.0)
+    }
+}
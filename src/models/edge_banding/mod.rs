@@ -0,0 +1,9 @@
+//! Edge banding model module
+//!
+//! Contains the EdgeBandConfig describing which sides of each tile are banded
+//! and the EdgeBandSummary totalling banding length over a solution.
+
+pub mod structs;
+pub mod impls;
+
+pub use structs::*;
@@ -0,0 +1,24 @@
+//! Edge banding structure definitions
+
+use std::collections::HashMap;
+use crate::models::Edge;
+
+/// Banded sides per tile id, plus the factor the solution was scaled by
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EdgeBandConfig {
+    /// Edge spec keyed by tile (panel) id
+    pub edges: HashMap<i32, Edge>,
+    /// Factor used to convert solution lengths back to real units
+    pub scale_factor: f64,
+}
+
+/// Banding length needed by a solution, in real units
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct EdgeBandSummary {
+    /// Banding length per sheet material
+    pub by_material: HashMap<String, f64>,
+    /// Banding length per band type
+    pub by_band_type: HashMap<String, f64>,
+    /// Total banding length
+    pub total_length: f64,
+}
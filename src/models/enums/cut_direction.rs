@@ -13,3 +13,16 @@ impl Default for CutDirection {
         Self::Both
     }
 }
+
+impl CutDirection {
+    /// Map a numeric cut orientation preference (0 = both, 1 = horizontal,
+    /// 2 = vertical) to a direction
+    pub fn from_preference(preference: i32) -> Option<Self> {
+        match preference {
+            0 => Some(Self::Both),
+            1 => Some(Self::Horizontal),
+            2 => Some(Self::Vertical),
+            _ => None,
+        }
+    }
+}
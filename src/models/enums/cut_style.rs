@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// How tiles are cut out of the free nodes of a sheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CutStyle {
+    /// Every cut spans the whole node, as panel saws require
+    #[default]
+    Guillotine,
+    /// Tiles are cut out of a node corner with cuts along their own edges
+    /// only, as lasers and waterjets can, leaving right, bottom and diagonal
+    /// remainders; see `FreeCornerPlacement`
+    FreeCorner,
+}
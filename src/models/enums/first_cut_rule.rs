@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use super::CutDirection;
+
+/// How a node that may be cut either way decides which cut comes first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FirstCutRule {
+    /// Try both directions and let the comparators pick
+    #[default]
+    MatchGlobalPreference,
+    /// Cut along the longest edge of the node first, ripping before crosscutting
+    LongestEdge,
+    /// Cut along the shortest edge of the node first
+    ShortestEdge,
+}
+
+impl FirstCutRule {
+    /// First-cut direction for a `width` x `height` node
+    ///
+    /// A vertical cut splits a node into top and bottom parts, so it runs
+    /// along the width. Square nodes have no longer edge and try both.
+    pub fn direction_for(self, width: i32, height: i32) -> CutDirection {
+        let along_width = match self {
+            Self::MatchGlobalPreference => return CutDirection::Both,
+            _ if width == height => return CutDirection::Both,
+            Self::LongestEdge => width > height,
+            Self::ShortestEdge => width < height,
+        };
+        if along_width {
+            CutDirection::Vertical
+        } else {
+            CutDirection::Horizontal
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// How tile grain constrains rotation during placement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GrainMode {
+    /// Grain is ignored; every tile may be rotated
+    Free,
+    /// Tiles with a grain direction are placed only as given, never rotated
+    #[default]
+    Fixed,
+    /// Tiles with a grain direction are turned to follow the grain of the stock
+    Alternating,
+}
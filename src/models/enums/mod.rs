@@ -1,13 +1,23 @@
 pub mod cut_direction;
+pub mod cut_style;
+pub mod first_cut_rule;
+pub mod grain_mode;
+pub mod no_fit_reason;
 pub mod optimization_priority;
 pub mod orientation;
+pub mod placement_heuristic;
 pub mod status;
 pub mod status_code;
 pub mod stock_solution_result;
 
 pub use cut_direction::CutDirection;
+pub use cut_style::CutStyle;
+pub use first_cut_rule::FirstCutRule;
+pub use grain_mode::GrainMode;
+pub use no_fit_reason::NoFitReason;
 pub use optimization_priority::OptimizationPriority;
 pub use orientation::Orientation;
+pub use placement_heuristic::PlacementHeuristic;
 pub use status::Status;
 pub use status_code::StatusCode;
 pub use stock_solution_result::StockSolutionResult;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Why a tile could not be placed in the final layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NoFitReason {
+    /// There is stock of the tile's material large enough for it, but the
+    /// other tiles took up the room
+    #[default]
+    NoRemainingSpace,
+    /// The tile is larger than every stock panel of its material
+    OversizedForAllStock,
+    /// No stock panel of the tile's material was supplied
+    MaterialUnavailable,
+}
@@ -12,6 +12,9 @@ pub enum OptimizationPriority {
     LeastNbrMosaics,
     LeastNbrUnusedTiles,
     MostUnusedPanelArea,
+    LeastTotalCutLength,
+    MostBalancedUtilization,
+    SmallestLargestOffcut,
 }
 
 impl std::fmt::Display for OptimizationPriority {
@@ -26,6 +29,9 @@ impl std::fmt::Display for OptimizationPriority {
             Self::LeastNbrMosaics => "LEAST_NBR_MOSAICS",
             Self::LeastNbrUnusedTiles => "LEAST_NBR_UNUSED_TILES",
             Self::MostUnusedPanelArea => "MOST_UNUSED_PANEL_AREA",
+            Self::LeastTotalCutLength => "LEAST_TOTAL_CUT_LENGTH",
+            Self::MostBalancedUtilization => "MOST_BALANCED_UTILIZATION",
+            Self::SmallestLargestOffcut => "SMALLEST_LARGEST_OFFCUT",
         };
         write!(f, "{}", text)
     }
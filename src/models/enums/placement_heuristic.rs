@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// How a free node is chosen when a tile is placed in a mosaic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PlacementHeuristic {
+    /// Every node the tile fits in is tried and the solution comparators keep
+    /// the placements that leave the least waste
+    #[default]
+    BestAreaFit,
+    /// Only the node giving the lowest placement is tried, ties broken by the
+    /// leftmost one
+    BottomLeft,
+}
@@ -11,6 +11,7 @@ pub enum StatusCode {
     ServerUnavailable = 4,
     TooManyPanels = 5,
     TooManyStockPanels = 6,
+    NotEnoughStock = 7,
 }
 
 impl StatusCode {
@@ -34,6 +35,7 @@ impl StatusCode {
             4 => Some(StatusCode::ServerUnavailable),
             5 => Some(StatusCode::TooManyPanels),
             6 => Some(StatusCode::TooManyStockPanels),
+            7 => Some(StatusCode::NotEnoughStock),
             _ => None,
         }
     }
@@ -58,6 +60,7 @@ impl StatusCode {
             StatusCode::ServerUnavailable => "Server is unavailable",
             StatusCode::TooManyPanels => "Too many panels specified",
             StatusCode::TooManyStockPanels => "Too many stock panels specified",
+            StatusCode::NotEnoughStock => "Panels cannot fit within the allowed number of sheets",
         }
     }
 }
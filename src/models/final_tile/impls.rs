@@ -77,4 +77,20 @@ impl FinalTile {
     pub fn total_area(&self) -> f64 {
         self.area() * self.count as f64
     }
+
+    /// Record the finished size of a tile cut smaller to allow for banding
+    ///
+    /// `thickness` is per side (top, left, bottom, right) of the panel as
+    /// requested; a rotated tile has its width and height allowances swapped.
+    pub fn with_banding(mut self, thickness: &[f64; 4], is_rotated: bool) -> Self {
+        let [top, left, bottom, right] = *thickness;
+        let (width_allowance, height_allowance) = if is_rotated {
+            (top + bottom, left + right)
+        } else {
+            (left + right, top + bottom)
+        };
+        self.finished_width = Some(self.width + width_allowance);
+        self.finished_height = Some(self.height + height_allowance);
+        self
+    }
 }
@@ -16,6 +16,12 @@ pub struct FinalTile {
     pub label: Option<String>,
     /// Number of tiles of this type in the solution
     pub count: i32,
+    /// Width once banding is applied, when the panel is banded
+    #[serde(default)]
+    pub finished_width: Option<f64>,
+    /// Height once banding is applied, when the panel is banded
+    #[serde(default)]
+    pub finished_height: Option<f64>,
 }
 
 impl Default for FinalTile {
@@ -26,6 +32,8 @@ impl Default for FinalTile {
             height: 0.0,
             label: None,
             count: 0,
+            finished_width: None,
+            finished_height: None,
         }
     }
 }
@@ -64,7 +64,7 @@ impl GroupedTileDimensions {
         self.tile_dimensions.height
     }
 
-    pub fn area(&self) -> i32 {
+    pub fn area(&self) -> i64 {
         self.tile_dimensions.area()
     }
 
@@ -0,0 +1,41 @@
+//! LShape implementation
+
+use super::LShape;
+use crate::models::{GroupedTileDimensions, TileDimensions};
+
+impl LShape {
+    /// Create an L-shape with a `notch_width` x `notch_height` notch
+    pub fn new(notch_width: f64, notch_height: f64) -> Self {
+        Self { notch_width, notch_height }
+    }
+
+    /// Whether the notch leaves both a base and a leg of a `width` x `height` panel
+    pub fn is_valid_for(&self, width: f64, height: f64) -> bool {
+        self.notch_width.is_finite()
+            && self.notch_height.is_finite()
+            && self.notch_width > 0.0
+            && self.notch_height > 0.0
+            && self.notch_width < width
+            && self.notch_height < height
+    }
+
+    /// Split the bounding box `tile` into its two rectangles, sharing `group`
+    ///
+    /// `tile` is the unrotated bounding box in scaled units; the notch is
+    /// scaled by its `scale`. The first part is the full-width base, the
+    /// second the leg beside the notch. Both keep the tile's id.
+    pub fn parts(&self, tile: &TileDimensions, group: i32) -> [GroupedTileDimensions; 2] {
+        let notch_width = (self.notch_width * tile.scale).round() as i32;
+        let notch_height = (self.notch_height * tile.scale).round() as i32;
+
+        let mut base = tile.clone();
+        base.height = tile.height - notch_height;
+        let mut leg = tile.clone();
+        leg.width = tile.width - notch_width;
+        leg.height = notch_height;
+        [
+            GroupedTileDimensions::from_tile_dimensions(base, group),
+            GroupedTileDimensions::from_tile_dimensions(leg, group),
+        ]
+    }
+}
@@ -0,0 +1,9 @@
+//! LShape model module
+//!
+//! Contains the LShape structure describing an L-shaped panel as its
+//! bounding box with a rectangular notch taken out of one corner.
+
+pub mod structs;
+pub mod impls;
+
+pub use structs::*;
@@ -0,0 +1,15 @@
+//! LShape structure definition
+
+use serde::{Deserialize, Serialize};
+
+/// Notch cut out of a panel's bounding box to make it L-shaped
+///
+/// The notch sits in the corner opposite the panel's origin, so the panel
+/// is a full-width base with a narrower leg on top of it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LShape {
+    /// Width of the notch, in the units of the panel dimensions
+    pub notch_width: f64,
+    /// Height of the notch, in the units of the panel dimensions
+    pub notch_height: f64,
+}
@@ -8,37 +8,53 @@ pub mod enums;
 pub mod final_tile;
 // pub mod geometry;
 pub mod grouped_tile_dimensions;
+pub mod l_shape;
 pub mod mosaic;
 pub mod no_fit_tile;
+pub mod offcut_inventory;
 pub mod performance_thresholds;
+pub mod response_meta;
+pub mod saw_time;
+pub mod sheet_yield;
 pub mod solution;
 pub mod stats;
+pub mod stock_recommendation;
 pub mod task;
+pub mod task_checkpoint;
 pub mod task_status_response;
 pub mod tile;
 pub mod edge;
+pub mod edge_banding;
 pub mod panel;
 pub mod tile_dimensions;
 pub mod tile_node;
 
-pub use calculation_request::CalculationRequest;
+pub use calculation_request::{CalculationRequest, FeasibilityConstraint, FeasibilityReport, FieldError, ValidationReport};
 pub use calculation_response::CalculationResponse;
 pub use calculation_submission_result::CalculationSubmissionResult;
-pub use configuration::Configuration;
-pub use cut::{Cut, CutBuilder};
+pub use configuration::{Configuration, ConfigurationBuilder, CutThickness};
+pub use cut::{Cut, CutBuilder, CutStep};
 pub use edge::Edge;
+pub use edge_banding::{EdgeBandConfig, EdgeBandSummary};
 pub use enums::Orientation;
 pub use final_tile::FinalTile;
 // pub use geometry::{Cut, Mosaic, TileNode};
 pub use grouped_tile_dimensions::GroupedTileDimensions;
+pub use l_shape::LShape;
 pub use mosaic::Mosaic;
 pub use no_fit_tile::NoFitTile;
+pub use offcut_inventory::OffcutInventory;
 pub use panel::Panel;
 pub use performance_thresholds::PerformanceThresholds;
-pub use solution::Solution;
+pub use response_meta::{PermutationStats, ResponseMeta};
+pub use saw_time::SawTimeParams;
+pub use sheet_yield::SheetYield;
+pub use solution::{GeometryError, OffcutClassification, OffcutInfo, Solution, SolutionDiff, WasteBreakdown, WasteByLocation};
 pub use stats::{Stats, TaskReport};
+pub use stock_recommendation::StockRecommendation;
 pub use task_status_response::TaskStatusResponse;
 pub use task::Task;
+pub use task_checkpoint::TaskCheckpoint;
 pub use tile::Tile;
 pub use tile_dimensions::TileDimensions;
 pub use tile_node::TileNode;
@@ -0,0 +1,52 @@
+//! Consolidation check for partially-filled sheets
+//!
+//! Two sheets of the same stock that are each only partly used can sometimes
+//! be cut as one. This checks whether that is plausible; the replanning is
+//! done by `engine::consolidation`.
+
+use super::structs::Mosaic;
+
+impl Mosaic {
+    /// Check whether this sheet and `other` could be cut as a single sheet
+    ///
+    /// Both sheets must share material, grain orientation and dimensions, hold
+    /// no locked panels or L-shaped panel parts, and their placed panels
+    /// together must not exceed the area of one sheet.
+    /// Passing this check does not guarantee the panels can be arranged on one
+    /// sheet; `engine::consolidation::consolidate` does the actual replanning.
+    pub fn can_consolidate_with(&self, other: &Mosaic) -> bool {
+        if self.material != other.material
+            || self.orientation != other.orientation
+            || self.width() != other.width()
+            || self.height() != other.height()
+        {
+            return false;
+        }
+
+        if !self.has_final_tiles() || !other.has_final_tiles() {
+            return false;
+        }
+
+        // Replanning would move locked panels
+        if self.has_locked_tiles() || other.has_locked_tiles() {
+            return false;
+        }
+
+        // The two rectangles of an L-shaped panel would be replanned apart
+        if self.has_l_shape_parts() || other.has_l_shape_parts() {
+            return false;
+        }
+
+        self.placed_area() + other.placed_area() <= self.total_area()
+    }
+
+    /// Whether any panel on this sheet is a rectangle of a split L-shaped panel
+    fn has_l_shape_parts(&self) -> bool {
+        self.final_tile_nodes().iter().any(|node| node.group.is_some())
+    }
+
+    /// Total area of the panels placed on this sheet
+    fn placed_area(&self) -> i64 {
+        self.final_tile_nodes().iter().map(|node| node.area()).sum()
+    }
+}
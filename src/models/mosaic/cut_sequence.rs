@@ -0,0 +1,107 @@
+//! Ordering the cuts of a sheet for the saw operator
+//!
+//! Cuts are recorded in the order the optimizer tried them, which need not be
+//! an order that can be followed at the saw. Walking the node tree parent
+//! first gives a sequence where every cut divides a piece that already exists;
+//! the optimized order additionally groups cuts of the same direction so the
+//! sheet is turned as rarely as possible.
+
+use std::collections::HashMap;
+
+use super::structs::Mosaic;
+use crate::models::{enums::CutDirection, Cut, CutStep};
+
+impl Mosaic {
+    /// Cuts of this sheet in an order that can be made one after another
+    ///
+    /// The node tree is walked in pre-order, so a piece is always divided
+    /// before the pieces it produces. Nodes that were only split logically,
+    /// without a recorded cut, are passed over. A tile cut out with
+    /// free-corner cuts may also need cuts along its own edges, which come
+    /// right after the cuts that produced its node.
+    pub fn cut_sequence(&self) -> Vec<CutStep> {
+        let mut cuts_by_piece: HashMap<i32, Vec<&Cut>> = HashMap::new();
+        for cut in self.cuts() {
+            cuts_by_piece.entry(cut.original_tile_id).or_default().push(cut);
+        }
+
+        self.root_tile_node()
+            .iter()
+            .filter(|node| node.has_children() || node.is_final())
+            .flat_map(|node| cuts_by_piece.get(&(node.id() as i32)).into_iter().flatten().copied())
+            .enumerate()
+            .map(|(index, cut)| CutStep {
+                index,
+                piece_id: cut.original_tile_id,
+                piece_width: cut.original_width,
+                piece_height: cut.original_height,
+                direction: if cut.is_horizontal {
+                    CutDirection::Horizontal
+                } else {
+                    CutDirection::Vertical
+                },
+                position: if cut.is_horizontal { cut.x1 } else { cut.y1 },
+                child1_id: cut.child1_tile_id,
+                child2_id: cut.child2_tile_id,
+            })
+            .collect()
+    }
+
+    /// Cuts of this sheet reordered to keep the blade in one direction as long as possible
+    ///
+    /// A cut still comes after the cut that produced its piece. Among the
+    /// cuts whose piece exists, one in the current direction is taken first;
+    /// the sheet is only turned when none is left. The pre-order sequence is
+    /// returned instead when it already needs fewer turns.
+    pub fn cut_sequence_optimized(&self) -> Vec<CutStep> {
+        let steps = self.cut_sequence();
+        let mut steps_by_piece: HashMap<i32, Vec<usize>> = HashMap::new();
+        for step in &steps {
+            steps_by_piece.entry(step.piece_id).or_default().push(step.index);
+        }
+
+        // Step that produced the piece of each step, through nodes split without a cut
+        let mut parents: Vec<Option<usize>> = vec![None; steps.len()];
+        let mut stack = vec![(self.root_tile_node(), None)];
+        while let Some((node, parent)) = stack.pop() {
+            let own = steps_by_piece.get(&(node.id() as i32));
+            for &index in own.into_iter().flatten() {
+                parents[index] = parent;
+            }
+            let own = own.and_then(|indices| indices.first().copied());
+            for child in [node.child1(), node.child2()].into_iter().flatten() {
+                stack.push((child, own.or(parent)));
+            }
+        }
+
+        let mut done = vec![false; steps.len()];
+        let mut order: Vec<usize> = Vec::with_capacity(steps.len());
+        while order.len() < steps.len() {
+            let ready = |&index: &usize| !done[index] && parents[index].is_none_or(|parent| done[parent]);
+            let direction = order.last().map(|&last| steps[last].direction);
+            let next = (0..steps.len())
+                .filter(ready)
+                .find(|&index| Some(steps[index].direction) == direction)
+                .or_else(|| (0..steps.len()).find(ready))
+                .expect("the root cut is always ready, and every other cut once its parent is done");
+            done[next] = true;
+            order.push(next);
+        }
+
+        let optimized: Vec<CutStep> = order
+            .into_iter()
+            .enumerate()
+            .map(|(index, step)| CutStep { index, ..steps[step].clone() })
+            .collect();
+        if Self::direction_changes(&optimized) <= Self::direction_changes(&steps) {
+            optimized
+        } else {
+            steps
+        }
+    }
+
+    /// Number of times the blade direction changes between consecutive steps
+    pub fn direction_changes(steps: &[CutStep]) -> usize {
+        steps.windows(2).filter(|pair| pair[0].direction != pair[1].direction).count()
+    }
+}
@@ -1,5 +1,5 @@
 use super::structs::Mosaic;
-use crate::{models::{ Cut, TileDimensions, TileNode}, Orientation, };
+use crate::{models::{ Cut, Tile, TileDimensions, TileNode}, Orientation, };
 use std::collections::HashSet;
 
 impl Mosaic {
@@ -50,6 +50,35 @@ impl Mosaic {
         self.stock_id = stock_id;
     }
 
+    /// Get the areas of the sheet no tile may cover
+    pub fn keepouts(&self) -> &[Tile] {
+        &self.keepouts
+    }
+
+    /// Check that an area of the sheet does not overlap any keepout
+    pub fn is_clear_of_keepouts(&self, area: &Tile) -> bool {
+        !self.keepouts.iter().any(|keepout| keepout.overlaps_with(area))
+    }
+
+    /// Check whether a `width` x `height` piece fits somewhere in the usable
+    /// area of an uncut sheet without covering a keepout
+    ///
+    /// Any clear position can be slid towards the top-left corner until it
+    /// meets the usable area's edge or a keepout, so only those corners are tried.
+    pub fn has_room_for(&self, width: i32, height: i32) -> bool {
+        let root = &self.root_tile_node;
+        let xs = std::iter::once(root.x1()).chain(self.keepouts.iter().map(|keepout| keepout.x2));
+        let ys: Vec<i32> = std::iter::once(root.y1()).chain(self.keepouts.iter().map(|keepout| keepout.y2)).collect();
+        xs.flat_map(|x| ys.iter().map(move |&y| Tile::new(x, x + width, y, y + height)))
+            .any(|area| {
+                area.x1 >= root.x1()
+                    && area.y1 >= root.y1()
+                    && area.x2 <= root.x2()
+                    && area.y2 <= root.y2()
+                    && self.is_clear_of_keepouts(&area)
+            })
+    }
+
     /// Get a reference to the material string
     pub fn material(&self) -> &str {
         &self.material
@@ -86,7 +115,7 @@ impl Mosaic {
     }
 
     /// Get the distinct tile set
-    pub fn distinct_tile_set(&self) -> HashSet<i32> {
+    pub fn distinct_tile_set(&self) -> HashSet<i64> {
         self.root_tile_node.distinct_tile_set()
     }
 
@@ -114,6 +143,18 @@ impl Mosaic {
             .copied()
     }
 
+    /// Get the width and height of the smallest box anchored at the sheet origin
+    /// that contains every placed tile. Returns None if nothing is placed.
+    pub fn placements_bounding_box(&self) -> Option<(i32, i32)> {
+        let origin_x = self.root_tile_node.x1();
+        let origin_y = self.root_tile_node.y1();
+        self.root_tile_node
+            .final_tile_nodes()
+            .into_iter()
+            .map(|node| (node.x2() - origin_x, node.y2() - origin_y))
+            .reduce(|(w1, h1), (w2, h2)| (w1.max(w2), h1.max(h2)))
+    }
+
     /// Calculate the center of mass distance to origin
     /// Returns a normalized distance from 0.0 to 1.0
     pub fn center_of_mass_distance_to_origin(&self) -> f32 {
@@ -212,6 +253,52 @@ impl Mosaic {
         self.root_tile_node.has_final()
     }
 
+    /// Check if any final tile on this sheet is locked in place
+    pub fn has_locked_tiles(&self) -> bool {
+        self.root_tile_node.iter().any(|node| node.is_final && node.is_locked)
+    }
+
+    /// Clear every final tile that is not locked so its space can be reused
+    ///
+    /// Regions holding no locked tile collapse back into a single free node
+    /// and the cuts that divided them, or freed their tiles, are dropped.
+    pub fn free_unlocked_tiles(&mut self) {
+        let mut dropped_cuts = HashSet::new();
+        Self::free_unlocked_node(&mut self.root_tile_node, &mut dropped_cuts);
+        self.cuts.retain(|cut| !dropped_cuts.contains(&cut.original_tile_id));
+    }
+
+    fn free_unlocked_node(node: &mut TileNode, dropped_cuts: &mut HashSet<i32>) {
+        node.is_area_totally_used = false;
+        node.totally_used_area = 0;
+
+        if node.iter().any(|child| child.is_final && child.is_locked) {
+            if let Some(child1) = node.child1.as_deref_mut() {
+                Self::free_unlocked_node(child1, dropped_cuts);
+            }
+            if let Some(child2) = node.child2.as_deref_mut() {
+                Self::free_unlocked_node(child2, dropped_cuts);
+            }
+            return;
+        }
+
+        dropped_cuts.extend(
+            node.iter()
+                .filter(|child| child.has_children() || child.is_final)
+                .map(|child| child.id as i32),
+        );
+        node.child1 = None;
+        node.child2 = None;
+        // The root keeps the stock ID it carries
+        if node.is_final {
+            node.is_final = false;
+            node.is_rotated = false;
+            node.external_id = None;
+            node.label = None;
+            node.group = None;
+        }
+    }
+
     /// Get the width of the root tile
     pub fn width(&self) -> i32 {
         self.root_tile_node.width()
@@ -222,16 +309,22 @@ impl Mosaic {
         self.root_tile_node.height()
     }
 
-    /// Convert to TileDimensions
+    /// Convert to TileDimensions of the nominal (untrimmed) stock sheet
     pub fn to_tile_dimensions(&self) -> TileDimensions {
         TileDimensions {
             id: self.stock_id,
-            width: self.width(),
-            height: self.height(),
+            width: self.width() + 2 * self.edge_trim,
+            height: self.height() + 2 * self.edge_trim,
             label: None,
             material: self.material.clone(),
             orientation: self.orientation,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: self.edge_trim,
+            scale: 1.0,
+            keepouts: self.keepouts.clone(),
+            l_shape: None,
         }
     }
 }
@@ -0,0 +1,136 @@
+//! Reassembling L-shaped panels on a sheet
+//!
+//! L-shaped panels are laid out as their bounding box. Once placed, the box
+//! is split into the panel's two rectangles and the notch, which is left
+//! unused. One cut separates the base from the strip holding the leg and the
+//! notch, a second one separates those two, so the parts stay adjacent and
+//! keep the panel's orientation.
+
+use std::collections::HashMap;
+
+use super::structs::Mosaic;
+use crate::models::{Cut, GroupedTileDimensions, LShape, Tile, TileDimensions, TileNode};
+
+impl Mosaic {
+    /// Split every placed bounding box of an L-shaped panel into its rectangles
+    ///
+    /// `shapes` maps panel ids to their notch, in units scaled down by
+    /// `scale`. Each split panel gets the group `next_group`, which is then
+    /// advanced. Returns the number of panels split.
+    pub fn decompose_l_shapes(&mut self, shapes: &HashMap<i32, LShape>, scale: f64, next_group: &mut i32) -> usize {
+        if shapes.is_empty() {
+            return 0;
+        }
+
+        let mut cuts = Vec::new();
+        let mut stack: Vec<&mut TileNode> = vec![&mut self.root_tile_node];
+        while let Some(node) = stack.pop() {
+            if node.is_final {
+                let Some(shape) = node.external_id.and_then(|id| shapes.get(&id)) else {
+                    continue;
+                };
+                let parts = shape.parts(&Self::unrotated_bounds(node, scale), *next_group);
+                if let Some(split_cuts) = Self::split_l_shape(node, &parts) {
+                    cuts.extend(split_cuts);
+                    *next_group += 1;
+                }
+                continue;
+            }
+            stack.extend(node.child1.as_deref_mut());
+            stack.extend(node.child2.as_deref_mut());
+        }
+
+        let split = cuts.len() / 2;
+        self.cuts.extend(cuts);
+        split
+    }
+
+    /// Bounding box of the panel on `node` as it was requested, before any rotation
+    fn unrotated_bounds(node: &TileNode, scale: f64) -> TileDimensions {
+        let (width, height) = if node.is_rotated {
+            (node.height(), node.width())
+        } else {
+            (node.width(), node.height())
+        };
+        let mut bounds = TileDimensions::new(node.external_id.unwrap_or_default(), width, height);
+        bounds.scale = scale;
+        bounds
+    }
+
+    /// Split a final bounding box node into base, leg and notch
+    ///
+    /// Unrotated, the base spans the box on the origin side and the leg sits
+    /// beside it, next to the notch in the far corner. A rotated panel is
+    /// turned a quarter: the base spans the far side of the box and the leg
+    /// sits at the origin, above the notch. Leaves the node untouched and
+    /// returns `None` when a part would be empty.
+    fn split_l_shape(node: &mut TileNode, parts: &[GroupedTileDimensions; 2]) -> Option<[Cut; 2]> {
+        let [base, leg] = parts;
+        if base.height() <= 0 || leg.width() <= 0 || leg.height() <= 0 {
+            return None;
+        }
+        let group = base.get_group();
+        let (x1, x2, y1, y2) = (node.x1(), node.x2(), node.y1(), node.y2());
+
+        let (base_node, mut strip, leg_node, notch) = if node.is_rotated {
+            let strip_x2 = x1 + leg.height();
+            (
+                Self::part_of(node, Tile::new(strip_x2, x2, y1, y2), group),
+                TileNode::new(x1, strip_x2, y1, y2),
+                Self::part_of(node, Tile::new(x1, strip_x2, y1, y1 + leg.width()), group),
+                TileNode::new(x1, strip_x2, y1 + leg.width(), y2),
+            )
+        } else {
+            let base_y2 = y1 + base.height();
+            (
+                Self::part_of(node, Tile::new(x1, x2, y1, base_y2), group),
+                TileNode::new(x1, x2, base_y2, y2),
+                Self::part_of(node, Tile::new(x1, x1 + leg.width(), base_y2, y2), group),
+                TileNode::new(x1 + leg.width(), x2, base_y2, y2),
+            )
+        };
+
+        // The box is cut into base and strip first, then the strip into leg and notch
+        let leg_cut = Self::cut_between(&strip, &leg_node, &notch);
+        strip.set_child1(Some(leg_node));
+        strip.set_child2(Some(notch));
+        let (child1, child2) = if node.is_rotated { (strip, base_node) } else { (base_node, strip) };
+        let strip_cut = Self::cut_between(node, &child1, &child2);
+
+        node.set_final(false);
+        node.set_external_id(None);
+        node.set_label(None);
+        node.set_child1(Some(child1));
+        node.set_child2(Some(child2));
+        Some([strip_cut, leg_cut])
+    }
+
+    /// A final node covering `tile` for the same panel as `source`
+    fn part_of(source: &TileNode, tile: Tile, group: i32) -> TileNode {
+        let mut part = TileNode::new(tile.x1, tile.x2, tile.y1, tile.y2);
+        part.set_final(true);
+        part.set_external_id(source.external_id);
+        part.set_label(source.label.clone());
+        part.set_rotated(source.is_rotated);
+        part.set_group(Some(group));
+        part
+    }
+
+    /// The cut dividing `parent` into `child1`, on the origin side, and `child2`
+    fn cut_between(parent: &TileNode, child1: &TileNode, child2: &TileNode) -> Cut {
+        let ids = (parent.id() as i32, child1.id() as i32, child2.id() as i32);
+        if child1.height() == parent.height() {
+            Cut::new(
+                child1.x2(), parent.y1(), child1.x2(), parent.y2(),
+                parent.width(), parent.height(), true, child1.width(),
+                ids.0, ids.1, ids.2,
+            )
+        } else {
+            Cut::new(
+                parent.x1(), child1.y2(), parent.x2(), child1.y2(),
+                parent.width(), parent.height(), false, child1.height(),
+                ids.0, ids.1, ids.2,
+            )
+        }
+    }
+}
@@ -5,5 +5,8 @@
 
 pub mod structs;
 pub mod impls;
+pub mod consolidation;
+pub mod cut_sequence;
+pub mod l_shapes;
 
 pub use structs::Mosaic;
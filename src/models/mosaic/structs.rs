@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::{models::{Cut, TileDimensions, TileNode}, Orientation};
+use crate::{models::{Cut, Tile, TileDimensions, TileNode}, Orientation};
 
 /// Represents a complete cutting solution for a piece of material
 /// 
@@ -22,6 +22,15 @@ pub struct Mosaic {
     
     /// Identifier for the stock/source material
     pub stock_id: i32,
+
+    /// Border trimmed off every side of the stock sheet; the root node covers
+    /// only the usable region inside it
+    #[serde(default)]
+    pub edge_trim: i32,
+
+    /// Areas of the stock sheet no tile may be placed over
+    #[serde(default)]
+    pub keepouts: Vec<Tile>,
 }
 
 impl Mosaic {
@@ -33,6 +42,8 @@ impl Mosaic {
             stock_id: other.stock_id,
             material: other.material.clone(),
             orientation: other.orientation,
+            edge_trim: other.edge_trim,
+            keepouts: other.keepouts.clone(),
         }
     }
 
@@ -44,12 +55,30 @@ impl Mosaic {
             stock_id: tile_node.external_id().unwrap_or(tile_node.id() as i32),
             material,
             orientation: Orientation::Any,
+            edge_trim: 0,
+            keepouts: Vec::new(),
         }
     }
 
     /// Create a new Mosaic from TileDimensions
+    ///
+    /// A stock `edge_trim` insets the root node on all sides, so only the
+    /// usable region is available for cutting. Stock keepouts are registered
+    /// with the root node so that no tile is placed over them.
     pub fn from_tile_dimensions(tile_dimensions: &TileDimensions) -> Self {
-        let mut root_node = TileNode::from_dimensions(tile_dimensions);
+        let edge_trim = tile_dimensions
+            .edge_trim
+            .clamp(0, tile_dimensions.width.min(tile_dimensions.height) / 2);
+        let mut root_node = if edge_trim > 0 {
+            TileNode::new(
+                edge_trim,
+                tile_dimensions.width - edge_trim,
+                edge_trim,
+                tile_dimensions.height - edge_trim,
+            )
+        } else {
+            TileNode::from_dimensions(tile_dimensions)
+        };
         root_node.set_external_id(Some(tile_dimensions.id));
         
         Self {
@@ -58,6 +87,8 @@ impl Mosaic {
             material: tile_dimensions.material.clone(),
             orientation: tile_dimensions.orientation,
             stock_id: tile_dimensions.id,
+            edge_trim,
+            keepouts: tile_dimensions.keepouts.clone(),
         }
     }
 }
@@ -70,6 +101,8 @@ impl Default for Mosaic {
             orientation: Orientation::Any,
             root_tile_node: TileNode::default(),
             stock_id: 0,
+            edge_trim: 0,
+            keepouts: Vec::new(),
         }
     }
 }
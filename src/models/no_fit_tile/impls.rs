@@ -1,6 +1,7 @@
 //! NoFitTile implementation
 
 use super::NoFitTile;
+use crate::models::enums::NoFitReason;
 
 impl NoFitTile {
     /// Creates a new NoFitTile with the specified parameters
@@ -18,6 +19,9 @@ impl NoFitTile {
             count,
             label: None,
             material: None,
+            reason: NoFitReason::default(),
+            stock_deficit: None,
+            fits_if_rotated: false,
         }
     }
 
@@ -81,6 +85,16 @@ impl NoFitTile {
         self.material = material;
     }
 
+    /// Gets the reason the tile was not placed
+    pub fn get_reason(&self) -> NoFitReason {
+        self.reason
+    }
+
+    /// Sets the reason the tile was not placed
+    pub fn set_reason(&mut self, reason: NoFitReason) {
+        self.reason = reason;
+    }
+
     /// Calculates the area of the tile
     pub fn area(&self) -> f64 {
         self.width * self.height
@@ -1,5 +1,7 @@
 //! NoFitTile structure definition
 
+use crate::models::enums::NoFitReason;
+
 /// Represents a tile that doesn't fit in the current cutting solution
 /// 
 /// This structure tracks tiles that cannot be placed in the current layout,
@@ -18,6 +20,16 @@ pub struct NoFitTile {
     pub label: Option<String>,
     /// Optional material specification
     pub material: Option<String>,
+    /// Why the tile was left out of the layout
+    #[serde(default)]
+    pub reason: NoFitReason,
+    /// How much wider and taller the closest stock panel of the tile's
+    /// material would have to be to hold it; `None` when some stock is large enough
+    #[serde(default)]
+    pub stock_deficit: Option<(f64, f64)>,
+    /// Whether the tile, kept to its grain, would fit some stock if it were allowed to rotate
+    #[serde(default)]
+    pub fits_if_rotated: bool,
 }
 
 impl Default for NoFitTile {
@@ -29,6 +41,9 @@ impl Default for NoFitTile {
             count: 0,
             label: None,
             material: None,
+            reason: NoFitReason::default(),
+            stock_deficit: None,
+            fits_if_rotated: false,
         }
     }
 }
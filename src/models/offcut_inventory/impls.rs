@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use super::structs::OffcutInventory;
+use crate::models::{CalculationRequest, CalculationResponse, Panel};
+
+impl OffcutInventory {
+    /// Create an empty inventory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an offcut of `width` x `height` request units and return its stock id
+    pub fn add(&mut self, material: impl Into<String>, width: f64, height: f64) -> i32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.offcuts.push(
+            Panel::new()
+                .with_id(id)
+                .with_width(width.to_string())
+                .with_height(height.to_string())
+                .with_count(1)
+                .with_material(material.into())
+                .with_enabled(true)
+                .with_consume_first(true),
+        );
+        id
+    }
+
+    /// Remove the offcut with the given stock id
+    pub fn remove(&mut self, id: i32) -> Option<Panel> {
+        let index = self.offcuts.iter().position(|offcut| offcut.id == id)?;
+        Some(self.offcuts.remove(index))
+    }
+
+    /// Offcuts in the order they were added
+    pub fn list(&self) -> &[Panel] {
+        &self.offcuts
+    }
+
+    /// Number of offcuts held
+    pub fn len(&self) -> usize {
+        self.offcuts.len()
+    }
+
+    /// Whether the inventory holds no offcuts
+    pub fn is_empty(&self) -> bool {
+        self.offcuts.is_empty()
+    }
+
+    /// Offer every offcut as stock to `request`
+    pub fn add_to_request(&self, request: &mut CalculationRequest) {
+        request.stock_panels.extend(self.offcuts.iter().cloned());
+    }
+
+    /// Update the inventory with the result of a job
+    ///
+    /// Offcuts the job cut from are removed and its reusable remainders are
+    /// added. Remainders are only reported when the job's configuration sets
+    /// a reusable offcut threshold.
+    pub fn record_job(&mut self, response: &CalculationResponse) {
+        let used: HashSet<i32> = response.mosaics.iter().map(|mosaic| mosaic.stock_id()).collect();
+        self.offcuts.retain(|offcut| !used.contains(&offcut.id));
+
+        for offcut in &response.reusable_offcuts {
+            self.add(offcut.material.clone(), offcut.real_width(), offcut.real_height());
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod structs;
+pub mod impls;
+
+pub use structs::OffcutInventory;
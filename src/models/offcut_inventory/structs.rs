@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::StockConstants;
+use crate::models::Panel;
+
+/// Reusable offcuts kept from one job to the next
+///
+/// Each offcut is held as a single stock panel in request units, marked to be
+/// consumed before full sheets. Offcuts get ids of their own, counting up from
+/// `StockConstants::FIRST_OFFCUT_INVENTORY_ID`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OffcutInventory {
+    /// Offcuts in the order they were added
+    pub(crate) offcuts: Vec<Panel>,
+
+    /// Id the next added offcut gets
+    pub(crate) next_id: i32,
+}
+
+impl Default for OffcutInventory {
+    fn default() -> Self {
+        Self {
+            offcuts: Vec::new(),
+            next_id: StockConstants::FIRST_OFFCUT_INVENTORY_ID,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::models::edge::Edge;
+use crate::models::{LShape, Tile, TileDimensions};
 use crate::errors::{AppError, Result};
 use crate::models::panel::Panel;
 
@@ -23,6 +24,8 @@ impl Panel {
 
     /// Validate the panel configuration
     /// Returns Ok(true) if valid, Ok(false) if invalid, or Err for parsing errors
+    ///
+    /// Width and height follow the same rule as `TileDimensions::try_new`.
     pub fn is_valid(&self) -> Result<bool> {
         // Check if panel is enabled
         if !self.enabled {
@@ -43,7 +46,7 @@ impl Panel {
         let width_value: f64 = width_str.parse()
             .map_err(|_| AppError::invalid_input(format!("Invalid width value: {}", width_str)))?;
 
-        if width_value <= 0.0 {
+        if !TileDimensions::is_valid_dimension(width_value) {
             return Ok(false);
         }
 
@@ -56,7 +59,7 @@ impl Panel {
         let height_value: f64 = height_str.parse()
             .map_err(|_| AppError::invalid_input(format!("Invalid height value: {}", height_str)))?;
 
-        Ok(height_value > 0.0)
+        Ok(TileDimensions::is_valid_dimension(height_value))
     }
 
     /// Get width as a parsed f64 value
@@ -137,4 +140,109 @@ impl Panel {
         self.edge = Some(edge);
         self
     }
+
+    /// Builder method to set stock preference weight
+    pub fn with_preference_weight(mut self, weight: f64) -> Self {
+        self.preference_weight = Some(weight);
+        self
+    }
+
+    /// Builder method to restrict which stock panels this panel may be cut from
+    pub fn with_allowed_stock_ids(mut self, stock_ids: Vec<i32>) -> Self {
+        self.allowed_stock_ids = Some(stock_ids);
+        self
+    }
+
+    /// Builder method to set the placement priority
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Builder method to set the stock edge trim
+    pub fn with_edge_trim(mut self, edge_trim: i32) -> Self {
+        self.edge_trim = edge_trim;
+        self
+    }
+
+    /// Builder method to set the stock keepout zones
+    pub fn with_keepouts(mut self, keepouts: Vec<Tile>) -> Self {
+        self.keepouts = keepouts;
+        self
+    }
+
+    /// Builder method to set the banding thickness per side
+    pub fn with_banding_thickness(mut self, thickness: [f64; 4]) -> Self {
+        self.banding_thickness = Some(thickness);
+        self
+    }
+
+    /// Builder method to reserve this panel for a single stock panel
+    pub fn with_stock_affinity(mut self, stock_id: i32) -> Self {
+        self.stock_affinity = Some(stock_id);
+        self
+    }
+
+    /// Builder method to mark a stock panel as an offcut to consume first
+    pub fn with_consume_first(mut self, consume_first: bool) -> Self {
+        self.consume_first = consume_first;
+        self
+    }
+
+    /// Builder method to set the fewest copies that must be placed
+    pub fn with_min_count(mut self, min_count: i32) -> Self {
+        self.min_count = Some(min_count);
+        self
+    }
+
+    /// Builder method to set the most copies to place
+    pub fn with_max_count(mut self, max_count: i32) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    /// Builder method to make the panel L-shaped
+    pub fn with_l_shape(mut self, l_shape: LShape) -> Self {
+        self.l_shape = Some(l_shape);
+        self
+    }
+
+    /// Fewest copies the layout has to place
+    pub fn min_quantity(&self) -> i32 {
+        self.min_count.unwrap_or(self.count)
+    }
+
+    /// Copies handed to the optimizer; those beyond `min_quantity` are placed only if they fit
+    pub fn max_quantity(&self) -> i32 {
+        self.max_count.unwrap_or(self.count)
+    }
+
+    /// Whether some copies of this panel are optional
+    pub fn is_flexible(&self) -> bool {
+        self.min_quantity() < self.max_quantity()
+    }
+
+    /// Banding thickness per side scaled to integer units by `factor`
+    pub fn scaled_banding_thickness(&self, factor: f64) -> [i32; 4] {
+        self.banding_thickness
+            .map_or([0; 4], |sides| sides.map(|side| (side * factor).round() as i32))
+    }
+
+    /// Stock panel IDs this panel may be cut from, combining `stock_affinity`
+    /// with `allowed_stock_ids`; `None` allows any stock
+    pub fn permitted_stock_ids(&self) -> Option<Vec<i32>> {
+        let Some(stock_id) = self.stock_affinity else {
+            return self.allowed_stock_ids.clone();
+        };
+        let allowed = self.allowed_stock_ids
+            .as_ref()
+            .is_none_or(|ids| ids.contains(&stock_id));
+        Some(if allowed { vec![stock_id] } else { Vec::new() })
+    }
+
+    /// Check whether this panel may be placed on the given stock panel
+    pub fn allows_stock(&self, stock_id: i32) -> bool {
+        self.permitted_stock_ids()
+            .is_none_or(|ids| ids.contains(&stock_id))
+    }
 }
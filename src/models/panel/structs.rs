@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use crate::models::edge::Edge;
+use crate::models::{LShape, Tile};
 use crate::constants::MaterialConstants;
 
 
@@ -16,6 +17,39 @@ pub struct Panel {
     pub orientation: i32,
     pub label: Option<String>,
     pub edge: Option<Edge>,
+    /// Stock preference weight; lower weights are consumed first
+    #[serde(default)]
+    pub preference_weight: Option<f64>,
+    /// Stock panel IDs this panel may be cut from; `None` allows any stock
+    #[serde(default)]
+    pub allowed_stock_ids: Option<Vec<i32>>,
+    /// Placement priority; higher values are placed first when tiles tie
+    #[serde(default)]
+    pub priority: i32,
+    /// Damaged border trimmed off every side of a stock sheet before cutting
+    #[serde(default)]
+    pub edge_trim: i32,
+    /// Banding thickness per side (top, left, bottom, right) taken off the cut size
+    #[serde(default)]
+    pub banding_thickness: Option<[f64; 4]>,
+    /// Stock panel ID this panel is reserved for; it is only cut from that stock
+    #[serde(default)]
+    pub stock_affinity: Option<i32>,
+    /// Marks a stock panel as an offcut to use up before full sheets
+    #[serde(default)]
+    pub consume_first: bool,
+    /// Defects on a stock sheet, in sheet coordinates, that no panel may cover
+    #[serde(default)]
+    pub keepouts: Vec<Tile>,
+    /// Fewest copies that must be placed; defaults to `count`
+    #[serde(default)]
+    pub min_count: Option<i32>,
+    /// Most copies to place as material allows; defaults to `count`
+    #[serde(default)]
+    pub max_count: Option<i32>,
+    /// Notch making the panel L-shaped; `width` and `height` are its bounding box
+    #[serde(default)]
+    pub l_shape: Option<LShape>,
 }
 
 impl Default for Panel {
@@ -30,6 +64,17 @@ impl Default for Panel {
             orientation: 0,
             label: None,
             edge: None,
+            preference_weight: None,
+            allowed_stock_ids: None,
+            priority: 0,
+            edge_trim: 0,
+            banding_thickness: None,
+            stock_affinity: None,
+            consume_first: false,
+            keepouts: Vec::new(),
+            min_count: None,
+            max_count: None,
+            l_shape: None,
         }
     }
 }
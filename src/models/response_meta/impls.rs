@@ -0,0 +1,53 @@
+//! ResponseMeta implementation
+
+use super::{PermutationStats, ResponseMeta};
+use crate::constants::ResponseConstants;
+use crate::models::CalculationResponse;
+
+impl Default for ResponseMeta {
+    fn default() -> Self {
+        Self {
+            schema_version: ResponseConstants::META_SCHEMA_VERSION,
+            optimization_type: Default::default(),
+            efficiency: 0.0,
+            panel_count: 0,
+            no_fit_count: 0,
+            sheet_count: 0,
+            cut_count: 0,
+            estimated_saw_time: None,
+            permutation_stats: None,
+        }
+    }
+}
+
+impl ResponseMeta {
+    /// Summarize `response` from its top-level fields
+    pub fn from_response(response: &CalculationResponse) -> Self {
+        let configuration = response.request.as_ref().and_then(|request| request.configuration.as_ref());
+        Self {
+            schema_version: ResponseConstants::META_SCHEMA_VERSION,
+            optimization_type: configuration
+                .map(|configuration| configuration.optimization_priority)
+                .unwrap_or_default(),
+            efficiency: response.total_used_area_ratio,
+            panel_count: response.panels.as_ref().map_or(0, |panels| panels.iter().map(|panel| panel.count as usize).sum()),
+            no_fit_count: response.no_fit_panels.iter().map(|panel| panel.count as usize).sum(),
+            sheet_count: response.mosaics.len(),
+            cut_count: response.total_nbr_cuts,
+            estimated_saw_time: configuration.and_then(|configuration| configuration.saw_time).map(|saw_time| {
+                saw_time.time_for(response.total_cut_length, response.total_nbr_cuts.min(u32::MAX as u64) as u32)
+            }),
+            permutation_stats: None,
+        }
+    }
+}
+
+impl PermutationStats {
+    /// Add the counters of another search, such as one for another material
+    pub fn combine(&mut self, other: &PermutationStats) {
+        self.permutations_generated += other.permutations_generated;
+        self.permutations_evaluated += other.permutations_evaluated;
+        self.duplicates_removed += other.duplicates_removed;
+        self.early_exit_triggered |= other.early_exit_triggered;
+    }
+}
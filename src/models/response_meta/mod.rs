@@ -0,0 +1,9 @@
+//! ResponseMeta model module
+//!
+//! Contains the ResponseMeta structure summarizing a calculation response in
+//! typed, versioned fields for downstream parsers.
+
+pub mod structs;
+pub mod impls;
+
+pub use structs::*;
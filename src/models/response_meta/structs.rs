@@ -0,0 +1,48 @@
+//! ResponseMeta structure definition
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::OptimizationPriority;
+
+/// Typed summary of a calculation response
+///
+/// The figures repeat values found elsewhere in the response under stable
+/// names; `schema_version` changes whenever a field is renamed or removed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseMeta {
+    /// Version of this structure's layout
+    pub schema_version: u32,
+    /// Primary goal the layout was optimized for
+    pub optimization_type: OptimizationPriority,
+    /// Fraction of the used sheets covered by panels, as `total_used_area_ratio`
+    pub efficiency: f64,
+    /// Number of panels placed
+    pub panel_count: usize,
+    /// Number of panels that could not be placed
+    pub no_fit_count: usize,
+    /// Number of sheets used
+    pub sheet_count: usize,
+    /// Number of cuts, as `total_nbr_cuts`
+    pub cut_count: u64,
+    /// Machining time estimated from `configuration.saw_time`, when it is set
+    #[serde(default)]
+    pub estimated_saw_time: Option<Duration>,
+    /// How the search pruned its tile orders; `None` when it did not try several
+    #[serde(default)]
+    pub permutation_stats: Option<PermutationStats>,
+}
+
+/// Counters of the tile orders a search generated and how many it skipped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermutationStats {
+    /// Tile orders generated, before duplicates were removed
+    pub permutations_generated: usize,
+    /// Tile orders laid out
+    pub permutations_evaluated: usize,
+    /// Tile orders dropped because an earlier one has the same sequence of sizes
+    pub duplicates_removed: usize,
+    /// Whether the search stopped before the last order because every tile was placed
+    pub early_exit_triggered: bool,
+}
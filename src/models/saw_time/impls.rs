@@ -0,0 +1,30 @@
+//! SawTimeParams implementation
+
+use std::time::Duration;
+
+use super::SawTimeParams;
+
+impl SawTimeParams {
+    /// Create parameters for a saw advancing `feed_rate` units per second
+    pub fn new(feed_rate: f64, setup_time: Duration) -> Self {
+        Self { feed_rate, setup_time }
+    }
+
+    /// Whether the feed rate is a positive, finite number
+    pub fn is_valid(&self) -> bool {
+        self.feed_rate.is_finite() && self.feed_rate > 0.0
+    }
+
+    /// Time to make `nbr_cuts` cuts adding up to `total_length`
+    ///
+    /// Each cut takes its length divided by the feed rate plus the setup
+    /// time. Parameters that are not valid yield zero feed time.
+    pub fn time_for(&self, total_length: f64, nbr_cuts: u32) -> Duration {
+        let feed = if self.is_valid() {
+            Duration::from_secs_f64(total_length.max(0.0) / self.feed_rate)
+        } else {
+            Duration::ZERO
+        };
+        feed + self.setup_time * nbr_cuts
+    }
+}
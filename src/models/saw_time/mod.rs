@@ -0,0 +1,9 @@
+//! SawTimeParams model module
+//!
+//! Contains the SawTimeParams structure describing how fast a saw cuts, used
+//! to estimate the machining time of a layout.
+
+pub mod structs;
+pub mod impls;
+
+pub use structs::*;
@@ -0,0 +1,14 @@
+//! SawTimeParams structure definition
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Speed of the saw used to estimate machining time
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SawTimeParams {
+    /// Cut length the blade advances per second, in the units of the cut lengths
+    pub feed_rate: f64,
+    /// Time to position the piece and blade before every cut
+    pub setup_time: Duration,
+}
@@ -0,0 +1,31 @@
+//! SheetYield implementation
+
+use super::SheetYield;
+use crate::models::Mosaic;
+use crate::utils::math;
+
+impl SheetYield {
+    /// Measure the sheet at `sheet_index`, converting areas back by `factor`
+    pub fn from_mosaic(sheet_index: usize, mosaic: &Mosaic, factor: f64) -> Self {
+        let final_tiles = mosaic.final_tile_nodes();
+        let used_area: f64 = final_tiles
+            .iter()
+            .map(|node| math::area(node.width(), node.height(), factor))
+            .sum();
+        let total_area = math::area(mosaic.width(), mosaic.height(), factor);
+
+        Self {
+            sheet_index,
+            material: mosaic.material().to_string(),
+            used_area,
+            total_area,
+            efficiency: if total_area > 0.0 { used_area / total_area } else { 0.0 },
+            tiles_placed: final_tiles.len(),
+        }
+    }
+
+    /// Area of the sheet not covered by placed tiles
+    pub fn wasted_area(&self) -> f64 {
+        self.total_area - self.used_area
+    }
+}
@@ -0,0 +1,9 @@
+//! SheetYield model module
+//!
+//! Contains the SheetYield structure reporting how well a single stock sheet
+//! of a solution was used.
+
+pub mod structs;
+pub mod impls;
+
+pub use structs::*;
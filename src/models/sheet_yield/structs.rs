@@ -0,0 +1,21 @@
+//! SheetYield structure definition
+
+/// Usage figures for one sheet of a solution
+///
+/// Areas are in real units (the mosaic's scaled units divided by the
+/// solution factor squared).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SheetYield {
+    /// Position of the sheet in the solution's mosaic list
+    pub sheet_index: usize,
+    /// Material of the sheet
+    pub material: String,
+    /// Area covered by placed tiles
+    pub used_area: f64,
+    /// Area of the whole sheet
+    pub total_area: f64,
+    /// Fraction of the sheet covered by placed tiles
+    pub efficiency: f64,
+    /// Number of tiles placed on the sheet
+    pub tiles_placed: usize,
+}
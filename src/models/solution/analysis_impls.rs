@@ -1,5 +1,7 @@
+use std::time::Duration;
+
 use super::structs::Solution;
-use crate::models::TileDimensions;
+use crate::models::{EdgeBandConfig, EdgeBandSummary, SawTimeParams, SheetYield, TileDimensions};
 
 impl Solution {
     /// Get the used area ratio across all mosaics
@@ -17,6 +19,22 @@ impl Solution {
         total_ratio / self.mosaics.len() as f32
     }
     
+    /// Get the variance of the used area ratio across all mosaics
+    pub fn get_utilization_variance(&self) -> f32 {
+        if self.mosaics.is_empty() {
+            return 0.0;
+        }
+
+        let mean = self.get_used_area_ratio();
+        let squared_deviations: f32 = self
+            .mosaics
+            .iter()
+            .map(|mosaic| (mosaic.clone().efficiency() - mean).powi(2))
+            .sum();
+
+        squared_deviations / self.mosaics.len() as f32
+    }
+    
     /// Check if there's an unused base tile
     pub fn has_unused_base_tile(&self) -> bool {
         self.mosaics
@@ -119,6 +137,23 @@ impl Solution {
             .sum()
     }
     
+    /// Get the summed length of every cut across all mosaics
+    pub fn get_total_cut_length(&self) -> i64 {
+        self.mosaics
+            .iter()
+            .flat_map(|m| m.cuts())
+            .map(|cut| cut.length())
+            .sum()
+    }
+    
+    /// Estimated time to make every cut across all mosaics
+    ///
+    /// Each cut takes its length divided by `params.feed_rate` plus
+    /// `params.setup_time`; lengths are in the solution's scaled units.
+    pub fn estimated_saw_time(&self, params: SawTimeParams) -> Duration {
+        params.time_for(self.get_total_cut_length() as f64, self.get_nbr_cuts().max(0) as u32)
+    }
+    
     /// Get the distinct tile set size (maximum across all mosaics)
     pub fn get_distinct_tile_set(&self) -> usize {
         self.mosaics
@@ -128,6 +163,18 @@ impl Solution {
             .unwrap_or(0)
     }
     
+    /// Get an identifier describing the layout geometry of every mosaic
+    ///
+    /// Built from stock IDs and cut coordinates only, so two solutions with the
+    /// same layout share an identifier even if their node IDs differ.
+    pub fn get_structure_identifier(&self) -> String {
+        self.mosaics
+            .iter()
+            .map(|m| format!("{}:{}", m.stock_id(), m.root_tile_node().string_identifier()))
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+    
     /// Get the number of mosaics
     pub fn get_nbr_mosaics(&self) -> usize {
         self.mosaics.len()
@@ -262,6 +309,24 @@ impl Solution {
         (1.0 - self.get_efficiency()) * 100.0
     }
     
+    /// Per-sheet usage figures, with areas converted back by `factor`
+    pub fn get_sheet_yields(&self, factor: f64) -> Vec<SheetYield> {
+        self.mosaics
+            .iter()
+            .enumerate()
+            .map(|(index, mosaic)| SheetYield::from_mosaic(index, mosaic, factor))
+            .collect()
+    }
+    
+    /// Total edge banding needed by the placed tiles, per material and band type
+    pub fn edge_banding_summary(&self, config: &EdgeBandConfig) -> EdgeBandSummary {
+        let mut summary = EdgeBandSummary::default();
+        for mosaic in &self.mosaics {
+            summary.add_mosaic(mosaic, config);
+        }
+        summary
+    }
+    
     /// Check if all panels have been processed (no unused stock panels)
     pub fn is_complete(&self) -> bool {
         self.unused_stock_panels.is_empty()
@@ -1,6 +1,7 @@
 use super::structs::{Solution, ID_COUNTER};
-use crate::models::{Mosaic, TileDimensions, TileNode, Tile};
+use crate::models::{LShape, Mosaic, TileDimensions, TileNode, Tile};
 use crate::engine::stock::StockSolution;
+use crate::errors::{AppError, Result};
 use std::sync::atomic::Ordering;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -18,6 +19,10 @@ impl Solution {
             unused_stock_panels: std::collections::VecDeque::new(),
             aux_info: None,
             creator_thread_group: None,
+            time_bounded: false,
+            permutations_evaluated: 0,
+            grid_packed: false,
+            first_acceptable: None,
         }
     }
     
@@ -34,6 +39,10 @@ impl Solution {
             unused_stock_panels: other.unused_stock_panels.clone(),
             aux_info: other.aux_info.clone(),
             creator_thread_group: other.creator_thread_group.clone(),
+            time_bounded: other.time_bounded,
+            permutations_evaluated: other.permutations_evaluated,
+            grid_packed: other.grid_packed,
+            first_acceptable: other.first_acceptable.clone(),
         };
         
         // Deep copy mosaics
@@ -82,6 +91,10 @@ impl Solution {
             unused_stock_panels: solution.unused_stock_panels.clone(),
             aux_info: solution.aux_info.clone(),
             creator_thread_group: solution.creator_thread_group.clone(),
+            time_bounded: solution.time_bounded,
+            permutations_evaluated: solution.permutations_evaluated,
+            grid_packed: solution.grid_packed,
+            first_acceptable: None,
         };
         
         // Copy all mosaics except the excluded one
@@ -129,6 +142,42 @@ impl Solution {
     pub fn add_no_fit_panel(&mut self, panel: TileDimensions) {
         self.no_fit_panels.push(panel);
     }
+
+    /// Drop unplaced optional copies from the no-fit panels
+    ///
+    /// `min_quantities` holds the fewest copies to place of each panel with
+    /// optional copies, by panel id; see `CalculationRequest::min_quantities`.
+    /// Fails when fewer copies of such a panel were placed.
+    pub fn settle_optional_copies(&mut self, min_quantities: &std::collections::HashMap<i32, i32>) -> Result<()> {
+        for (&id, &min_quantity) in min_quantities {
+            let placed = self
+                .mosaics
+                .iter()
+                .flat_map(|mosaic| mosaic.final_tile_nodes())
+                .filter(|node| node.external_id == Some(id))
+                .count();
+            if placed < min_quantity.max(0) as usize {
+                return Err(AppError::optimization_failed(format!(
+                    "Panel {} needs at least {} copies but only {} fit",
+                    id, min_quantity, placed
+                )));
+            }
+        }
+        self.no_fit_panels.retain(|tile| !min_quantities.contains_key(&tile.id));
+        Ok(())
+    }
+
+    /// Split the placed bounding boxes of L-shaped panels into their rectangles
+    ///
+    /// See `Mosaic::decompose_l_shapes`. Groups are numbered from 1 across
+    /// the whole solution. Returns the number of panels split.
+    pub fn decompose_l_shapes(&mut self, shapes: &std::collections::HashMap<i32, LShape>, scale: f64) -> usize {
+        let mut next_group = 1;
+        self.mosaics
+            .iter_mut()
+            .map(|mosaic| mosaic.decompose_l_shapes(shapes, scale, &mut next_group))
+            .sum()
+    }
     
     /// Get all final tile nodes from all mosaics
     pub fn get_final_tile_nodes(&self) -> Vec<TileNode> {
@@ -153,6 +202,25 @@ impl Solution {
             .map(|m| m.to_tile_dimensions())
             .collect()
     }
+
+    /// Copy this solution with the final tiles whose external ID is in `locked` pinned
+    ///
+    /// Locked tiles keep their position when the copy is used to seed further
+    /// placement; other final tiles are left as they are.
+    pub fn with_locked_tiles(&self, locked: &[i32]) -> Solution {
+        let mut solution = Solution::from_solution(self);
+        for mosaic in solution.get_mosaics_mut() {
+            let mut stack = vec![mosaic.root_tile_node_mut()];
+            while let Some(node) = stack.pop() {
+                if node.is_final && node.external_id.is_some_and(|id| locked.contains(&id)) {
+                    node.is_locked = true;
+                }
+                stack.extend(node.child1.as_deref_mut());
+                stack.extend(node.child2.as_deref_mut());
+            }
+        }
+        solution
+    }
 }
 
 impl Default for Solution {
@@ -0,0 +1,36 @@
+//! CSV export of placed parts
+//!
+//! Lists every placed panel with its position for spreadsheets, one row per
+//! final tile node under the header
+//! `sheet_index,tile_id,label,x,y,width,height,rotated,material`. Sheets are
+//! numbered from 0 in solution order and coordinates are the node corners in
+//! layout units, relative to the sheet's origin.
+
+use super::structs::Solution;
+
+const HEADER: [&str; 9] = ["sheet_index", "tile_id", "label", "x", "y", "width", "height", "rotated", "material"];
+
+/// Render the placed parts of a solution as CSV, header row first
+pub fn parts_to_csv(solution: &Solution) -> String {
+    let mut writer = ::csv::Writer::from_writer(Vec::new());
+    // Writing to a `Vec` cannot fail
+    let _ = writer.write_record(HEADER);
+    for (sheet_index, mosaic) in solution.mosaics.iter().enumerate() {
+        for node in mosaic.final_tile_nodes() {
+            let tile_id = node.external_id.map_or_else(|| node.id().to_string(), |id| id.to_string());
+            let _ = writer.write_record([
+                sheet_index.to_string(),
+                tile_id,
+                node.label().unwrap_or_default().to_string(),
+                node.x1().to_string(),
+                node.y1().to_string(),
+                node.width().to_string(),
+                node.height().to_string(),
+                node.is_rotated().to_string(),
+                mosaic.material().to_string(),
+            ]);
+        }
+    }
+    let bytes = writer.into_inner().unwrap_or_default();
+    String::from_utf8(bytes).unwrap_or_default()
+}
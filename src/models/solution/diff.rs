@@ -0,0 +1,41 @@
+//! Comparing two solutions, e.g. runs with different optimization settings
+
+use std::collections::BTreeMap;
+
+use super::structs::{Solution, SolutionDiff};
+
+impl Solution {
+    /// Report how `other` differs from this solution
+    ///
+    /// Tiles are matched by id. Copies of a panel share an id, so a tile id
+    /// counts as moved when the sheets holding its copies differ between the
+    /// two solutions; ids placed in only one of them are not reported.
+    pub fn diff(&self, other: &Solution) -> SolutionDiff {
+        let sheets = self.sheets_by_tile_id();
+        let other_sheets = other.sheets_by_tile_id();
+        let moved_tile_ids = sheets
+            .iter()
+            .filter(|(id, indices)| other_sheets.get(id).is_some_and(|other| other != *indices))
+            .map(|(&id, _)| id)
+            .collect();
+
+        SolutionDiff {
+            tiles_placed_delta: other.get_nbr_final_tiles() - self.get_nbr_final_tiles(),
+            wasted_area_delta: other.get_unused_area() - self.get_unused_area(),
+            cut_count_delta: other.get_nbr_cuts() - self.get_nbr_cuts(),
+            sheet_count_delta: other.mosaics.len() as i32 - self.mosaics.len() as i32,
+            moved_tile_ids,
+        }
+    }
+
+    /// Sorted sheet indices holding each placed tile id
+    fn sheets_by_tile_id(&self) -> BTreeMap<i32, Vec<usize>> {
+        let mut sheets: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+        for (index, mosaic) in self.mosaics.iter().enumerate() {
+            for node in mosaic.final_tile_nodes() {
+                sheets.entry(node.external_id.unwrap_or(node.id as i32)).or_default().push(index);
+            }
+        }
+        sheets
+    }
+}
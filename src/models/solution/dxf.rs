@@ -0,0 +1,120 @@
+//! DXF export of cut layouts
+//!
+//! Emits an ASCII DXF drawing for CNC software: every placed panel is a
+//! closed LWPOLYLINE and every cut an open two-vertex LWPOLYLINE, on a layer
+//! named after the sheet material. Coordinates are the tile node corners in
+//! layout units; sheets after the first are shifted along X so they don't
+//! overlap.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use super::structs::Solution;
+use crate::models::Mosaic;
+
+/// Horizontal gap between consecutive sheets in layout units
+const SHEET_GAP: i64 = 100;
+
+/// Render a solution as a DXF document
+pub fn to_dxf(solution: &Solution) -> String {
+    let layers: BTreeSet<String> = solution
+        .mosaics
+        .iter()
+        .map(|mosaic| layer_name(mosaic.material()))
+        .collect();
+
+    let mut dxf = String::new();
+    write_header(&mut dxf);
+    write_layer_table(&mut dxf, &layers);
+
+    section(&mut dxf, "ENTITIES");
+    let mut offset_x = 0i64;
+    for mosaic in &solution.mosaics {
+        write_mosaic(&mut dxf, mosaic, offset_x);
+        offset_x += mosaic.width() as i64 + SHEET_GAP;
+    }
+    pair(&mut dxf, 0, "ENDSEC");
+
+    pair(&mut dxf, 0, "EOF");
+    dxf
+}
+
+/// Append one group code / value pair
+fn pair(dxf: &mut String, code: i32, value: impl std::fmt::Display) {
+    let _ = write!(dxf, "{}\n{}\n", code, value);
+}
+
+fn section(dxf: &mut String, name: &str) {
+    pair(dxf, 0, "SECTION");
+    pair(dxf, 2, name);
+}
+
+fn write_header(dxf: &mut String) {
+    section(dxf, "HEADER");
+    pair(dxf, 9, "$ACADVER");
+    pair(dxf, 1, "AC1015");
+    // Millimetres
+    pair(dxf, 9, "$INSUNITS");
+    pair(dxf, 70, 4);
+    pair(dxf, 0, "ENDSEC");
+}
+
+fn write_layer_table(dxf: &mut String, layers: &BTreeSet<String>) {
+    section(dxf, "TABLES");
+    pair(dxf, 0, "TABLE");
+    pair(dxf, 2, "LAYER");
+    pair(dxf, 70, layers.len());
+    for layer in layers {
+        pair(dxf, 0, "LAYER");
+        pair(dxf, 2, layer);
+        pair(dxf, 70, 0);
+        pair(dxf, 62, 7);
+        pair(dxf, 6, "CONTINUOUS");
+    }
+    pair(dxf, 0, "ENDTAB");
+    pair(dxf, 0, "ENDSEC");
+}
+
+fn write_mosaic(dxf: &mut String, mosaic: &Mosaic, offset_x: i64) {
+    let layer = layer_name(mosaic.material());
+    let x = |value: i32| value as i64 + offset_x;
+
+    for node in mosaic.final_tile_nodes() {
+        let corners = [
+            (x(node.x1()), node.y1()),
+            (x(node.x2()), node.y1()),
+            (x(node.x2()), node.y2()),
+            (x(node.x1()), node.y2()),
+        ];
+        write_polyline(dxf, &layer, &corners, true);
+    }
+
+    for cut in mosaic.cuts() {
+        write_polyline(dxf, &layer, &[(x(cut.x1), cut.y1), (x(cut.x2), cut.y2)], false);
+    }
+}
+
+fn write_polyline(dxf: &mut String, layer: &str, vertices: &[(i64, i32)], closed: bool) {
+    pair(dxf, 0, "LWPOLYLINE");
+    pair(dxf, 8, layer);
+    pair(dxf, 90, vertices.len());
+    pair(dxf, 70, if closed { 1 } else { 0 });
+    for &(x, y) in vertices {
+        pair(dxf, 10, x);
+        pair(dxf, 20, y);
+    }
+}
+
+/// Turn a material name into a legal DXF layer name
+fn layer_name(material: &str) -> String {
+    let name: String = material
+        .trim()
+        .chars()
+        .map(|c| if "<>/\\\":;?*|=`".contains(c) { '_' } else { c })
+        .collect();
+    if name.is_empty() {
+        "0".to_string()
+    } else {
+        name
+    }
+}
@@ -0,0 +1,191 @@
+//! Structural integrity check of the cut trees in a solution
+
+use std::fmt;
+
+use super::structs::Solution;
+use crate::models::{Mosaic, TileNode};
+
+/// A node's bounds as `(x1, y1, x2, y2)`
+pub type NodeBounds = (i32, i32, i32, i32);
+
+/// Structural problem found in a mosaic's cut tree
+///
+/// `sheet` is the index of the mosaic in the solution and `node` the id of
+/// the offending node, or of the parent whose children are wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeometryError {
+    /// A node reaches past the edges of its sheet
+    OutOfBounds { sheet: usize, node: u32, bounds: NodeBounds, sheet_size: (i32, i32) },
+    /// A node has no area
+    EmptyNode { sheet: usize, node: u32, bounds: NodeBounds },
+    /// A final tile has been split further
+    SplitFinalTile { sheet: usize, node: u32 },
+    /// Children do not span their parent across the cut, or leave its edges uncovered
+    Gap { sheet: usize, node: u32, bounds: NodeBounds },
+    /// The two children of a node overlap
+    Overlap { sheet: usize, node: u32, child1: NodeBounds, child2: NodeBounds },
+    /// The kerf left by a cut differs from the other cuts along the same axis
+    KerfMismatch { sheet: usize, node: u32, expected: i32, found: i32 },
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rect = |(x1, y1, x2, y2): &NodeBounds| format!("({},{})-({},{})", x1, y1, x2, y2);
+        match self {
+            Self::OutOfBounds { sheet, node, bounds, sheet_size } => write!(
+                f,
+                "sheet {}: node {} at {} exceeds the {}x{} sheet",
+                sheet, node, rect(bounds), sheet_size.0, sheet_size.1
+            ),
+            Self::EmptyNode { sheet, node, bounds } => {
+                write!(f, "sheet {}: node {} at {} has no area", sheet, node, rect(bounds))
+            }
+            Self::SplitFinalTile { sheet, node } => {
+                write!(f, "sheet {}: final tile node {} has children", sheet, node)
+            }
+            Self::Gap { sheet, node, bounds } => write!(
+                f,
+                "sheet {}: children of node {} at {} do not cover it",
+                sheet, node, rect(bounds)
+            ),
+            Self::Overlap { sheet, node, child1, child2 } => write!(
+                f,
+                "sheet {}: children of node {} overlap: {} and {}",
+                sheet, node, rect(child1), rect(child2)
+            ),
+            Self::KerfMismatch { sheet, node, expected, found } => write!(
+                f,
+                "sheet {}: cut in node {} leaves a kerf of {}, other cuts on that axis leave {}",
+                sheet, node, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
+
+/// Axis a node is split along
+#[derive(Clone, Copy)]
+enum SplitAxis {
+    /// Children side by side, divided by a horizontal cut at an x position
+    X,
+    /// Children stacked, divided by a vertical cut at a y position
+    Y,
+}
+
+impl Solution {
+    /// Check that every mosaic's cut tree is geometrically sound
+    ///
+    /// Every node lies within its sheet and has area, final tiles are leaves,
+    /// and the children of a split node tile it: both span the parent across
+    /// the cut, the first starts at the parent's edge, the second ends at the
+    /// opposite one, and they do not overlap. The gap between two children is
+    /// the kerf, which must be the same for every cut along an axis of the
+    /// sheet; a lone child may leave at most that much of its parent unused.
+    pub fn validate_geometry(&self) -> Result<(), GeometryError> {
+        self.mosaics
+            .iter()
+            .enumerate()
+            .try_for_each(|(sheet, mosaic)| validate_mosaic(sheet, mosaic))
+    }
+}
+
+fn bounds(node: &TileNode) -> NodeBounds {
+    (node.x1(), node.y1(), node.x2(), node.y2())
+}
+
+fn validate_mosaic(sheet: usize, mosaic: &Mosaic) -> Result<(), GeometryError> {
+    let nominal = mosaic.to_tile_dimensions();
+    let sheet_size = (nominal.width, nominal.height);
+
+    // Kerf per axis, taken from the first node split into two children
+    let mut kerf: [Option<i32>; 2] = [None, None];
+    // Unused remainders next to lone children, checked once the kerf is known
+    let mut remainders: Vec<(u32, SplitAxis, i32)> = Vec::new();
+
+    for node in mosaic.root_tile_node().iter() {
+        let node_bounds = bounds(node);
+        if node.x1() < 0 || node.y1() < 0 || node.x2() > sheet_size.0 || node.y2() > sheet_size.1 {
+            return Err(GeometryError::OutOfBounds { sheet, node: node.id, bounds: node_bounds, sheet_size });
+        }
+        if node.width() <= 0 || node.height() <= 0 {
+            return Err(GeometryError::EmptyNode { sheet, node: node.id, bounds: node_bounds });
+        }
+    }
+
+    for node in mosaic.root_tile_node().iter() {
+        let node_bounds = bounds(node);
+        let (child1, child2) = (node.child1(), node.child2());
+        if child1.is_none() && child2.is_none() {
+            continue;
+        }
+        if node.is_final {
+            return Err(GeometryError::SplitFinalTile { sheet, node: node.id });
+        }
+
+        let gap = GeometryError::Gap { sheet, node: node.id, bounds: node_bounds };
+        let children: Vec<&TileNode> = child1.into_iter().chain(child2).collect();
+        let axis = if children.iter().all(|child| child.y1() == node.y1() && child.y2() == node.y2()) {
+            SplitAxis::X
+        } else if children.iter().all(|child| child.x1() == node.x1() && child.x2() == node.x2()) {
+            SplitAxis::Y
+        } else {
+            return Err(gap);
+        };
+        // Start and end of a node along the split axis
+        let span = |n: &TileNode| match axis {
+            SplitAxis::X => (n.x1(), n.x2()),
+            SplitAxis::Y => (n.y1(), n.y2()),
+        };
+        let (start, end) = span(node);
+
+        match (child1, child2) {
+            (Some(first), Some(second)) => {
+                let ((first_start, first_end), (second_start, second_end)) = (span(first), span(second));
+                if first_end > second_start {
+                    return Err(GeometryError::Overlap {
+                        sheet,
+                        node: node.id,
+                        child1: bounds(first),
+                        child2: bounds(second),
+                    });
+                }
+                if first_start != start || second_end != end {
+                    return Err(gap);
+                }
+                let found = second_start - first_end;
+                match kerf[axis as usize] {
+                    Some(expected) if expected != found => {
+                        return Err(GeometryError::KerfMismatch { sheet, node: node.id, expected, found });
+                    }
+                    Some(_) => {}
+                    None => kerf[axis as usize] = Some(found),
+                }
+            }
+            (Some(first), None) => {
+                let (first_start, first_end) = span(first);
+                if first_start != start {
+                    return Err(gap);
+                }
+                remainders.push((node.id, axis, end - first_end));
+            }
+            (None, Some(second)) => {
+                let (second_start, second_end) = span(second);
+                if second_end != end {
+                    return Err(gap);
+                }
+                remainders.push((node.id, axis, second_start - start));
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+
+    for (node, axis, unused) in remainders {
+        if let Some(expected) = kerf[axis as usize] {
+            if unused > expected {
+                return Err(GeometryError::KerfMismatch { sheet, node, expected, found: unused });
+            }
+        }
+    }
+    Ok(())
+}
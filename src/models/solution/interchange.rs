@@ -0,0 +1,101 @@
+//! JSON interchange with the Java CutList Optimizer
+//!
+//! Renders a solution with the field names of the Java `CalculationResponse`
+//! this service was ported from, so tools built on its output keep working.
+//! Lengths and areas are in layout units, as in the mosaics.
+
+use serde_json::{json, Value};
+
+use super::structs::Solution;
+use crate::models::{Cut, Mosaic, TileDimensions, TileNode};
+
+/// Render a solution in the Java response's JSON layout
+pub fn to_java_format(solution: &Solution) -> Value {
+    let mosaics: Vec<Value> = solution.mosaics.iter().map(mosaic_to_java).collect();
+    let total_area = solution.get_total_area();
+    let used_area: i64 = solution.mosaics.iter().map(used_area).sum();
+    let cuts = solution.mosaics.iter().flat_map(|mosaic| &mosaic.cuts);
+    json!({
+        "totalUsedArea": used_area,
+        "totalWastedArea": total_area - used_area,
+        "totalUsedAreaRatio": ratio(used_area, total_area),
+        "totalNbrCuts": cuts.clone().count(),
+        "totalCutLength": cuts.map(Cut::length).sum::<i64>(),
+        "mosaics": mosaics,
+        "noFitPanels": solution.no_fit_panels.iter().map(no_fit_to_java).collect::<Vec<_>>(),
+    })
+}
+
+fn mosaic_to_java(mosaic: &Mosaic) -> Value {
+    let total_area = mosaic.total_area();
+    let used_area = used_area(mosaic);
+    let final_nodes = mosaic.final_tile_nodes();
+    let tiles: Vec<Value> = mosaic.root_tile_node.iter().map(tile_to_java).collect();
+    json!({
+        "requestStockId": mosaic.stock_id,
+        "material": mosaic.material,
+        "usedArea": used_area,
+        "wastedArea": total_area - used_area,
+        "usedAreaRatio": ratio(used_area, total_area),
+        "nbrFinalPanels": final_nodes.len(),
+        "nbrWastedPanels": mosaic.unused_tile_count(),
+        "cutLength": mosaic.cuts.iter().map(Cut::length).sum::<i64>(),
+        "panels": final_nodes.into_iter().map(tile_to_java).collect::<Vec<_>>(),
+        "tiles": tiles,
+        "cuts": mosaic.cuts.iter().map(cut_to_java).collect::<Vec<_>>(),
+    })
+}
+
+fn tile_to_java(node: &TileNode) -> Value {
+    json!({
+        "id": node.id(),
+        "requestObjId": node.external_id,
+        "x": node.x1(),
+        "y": node.y1(),
+        "width": node.width(),
+        "height": node.height(),
+        "label": node.label(),
+        "isFinal": node.is_final,
+        "hasChildren": node.has_children(),
+        "isRotated": node.is_rotated,
+    })
+}
+
+fn cut_to_java(cut: &Cut) -> Value {
+    json!({
+        "x1": cut.x1(),
+        "y1": cut.y1(),
+        "x2": cut.x2(),
+        "y2": cut.y2(),
+        "cutCoord": cut.cut_coord(),
+        "isHorizontal": cut.is_horizontal(),
+        "originalTileId": cut.original_tile_id(),
+        "originalWidth": cut.original_width(),
+        "originalHeight": cut.original_height(),
+        "child1TileId": cut.child1_tile_id(),
+        "child2TileId": cut.child2_tile_id(),
+    })
+}
+
+fn no_fit_to_java(tile: &TileDimensions) -> Value {
+    json!({
+        "id": tile.id,
+        "width": tile.width,
+        "height": tile.height,
+        "count": 1,
+        "label": tile.label,
+        "material": tile.material,
+    })
+}
+
+fn used_area(mosaic: &Mosaic) -> i64 {
+    mosaic.final_tile_nodes().iter().map(|node| node.area()).sum()
+}
+
+fn ratio(used_area: i64, total_area: i64) -> f64 {
+    if total_area > 0 {
+        used_area as f64 / total_area as f64
+    } else {
+        0.0
+    }
+}
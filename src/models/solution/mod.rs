@@ -1,5 +1,13 @@
 pub mod structs;
 pub mod analysis_impls;
 pub mod core_impls;
+pub mod waste_impls;
+pub mod svg;
+pub mod dxf;
+pub mod csv;
+pub mod interchange;
+pub mod diff;
+pub mod geometry;
 
-pub use structs::Solution;
+pub use structs::{OffcutClassification, OffcutInfo, Solution, SolutionDiff, WasteBreakdown, WasteByLocation};
+pub use geometry::GeometryError;
@@ -33,4 +33,99 @@ pub struct Solution {
     
     /// Optional creator thread group identifier
     pub creator_thread_group: Option<String>,
+
+    /// Whether the search stopped early because its time budget ran out
+    #[serde(default)]
+    pub time_bounded: bool,
+
+    /// Number of tile orders laid out before this solution was picked; 0 when
+    /// the search does not try several orders
+    #[serde(default)]
+    pub permutations_evaluated: usize,
+
+    /// Whether the layout was packed as a grid without searching, which is
+    /// done for jobs of one part size on sheets of one size
+    #[serde(default)]
+    pub grid_packed: bool,
+
+    /// With `report_first_acceptable`, the layout the search would have
+    /// stopped at on reaching the target efficiency
+    #[serde(default)]
+    pub first_acceptable: Option<Box<Solution>>,
+}
+
+/// Waste of a solution split by cause, in the same scaled units as the mosaics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WasteBreakdown {
+    /// Material turned to dust by the blade along every cut
+    pub kerf_area: i64,
+
+    /// Unused pieces large enough to hold at least one of the placed panels
+    pub offcut_area: i64,
+
+    /// Unused pieces too small to hold any of the placed panels
+    pub scrap_area: i64,
+}
+
+/// Waste of a solution split by where it lies on the sheet, in the same
+/// scaled units as the mosaics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WasteByLocation {
+    /// Trimmed sheet borders and unused pieces along a sheet edge
+    pub edge_trim: i64,
+
+    /// Unused pieces enclosed by parts, and the kerf between parts
+    pub inter_part: i64,
+
+    /// Unused pieces large enough to keep as stock, wherever they lie
+    pub usable_offcut: i64,
+}
+
+/// Unused remainders of a solution split into reusable stock and waste
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct OffcutClassification {
+    /// Remainders worth keeping as stock for a later job
+    pub reusable_offcuts: Vec<TileDimensions>,
+
+    /// Remainders too small to keep
+    pub waste: Vec<TileDimensions>,
+}
+
+/// An unused remainder of a solution and where it lies, in the same scaled
+/// units as the mosaics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffcutInfo {
+    /// Index of the mosaic holding the remainder
+    pub sheet_index: usize,
+
+    /// Left edge of the remainder on its sheet
+    pub x: i32,
+
+    /// Top edge of the remainder on its sheet
+    pub y: i32,
+
+    /// Width of the remainder
+    pub width: i32,
+
+    /// Height of the remainder
+    pub height: i32,
+}
+
+/// Differences between two solutions, each delta taken as other minus self
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SolutionDiff {
+    /// Change in the number of placed tiles
+    pub tiles_placed_delta: i32,
+
+    /// Change in unused area, in scaled units
+    pub wasted_area_delta: i64,
+
+    /// Change in the number of cuts
+    pub cut_count_delta: i32,
+
+    /// Change in the number of sheets used
+    pub sheet_count_delta: i32,
+
+    /// Ids of tiles placed in both solutions but on different sheets, ascending
+    pub moved_tile_ids: Vec<i32>,
 }
@@ -0,0 +1,147 @@
+//! SVG cutting diagrams
+//!
+//! Renders every sheet of a solution as vector graphics: placed panels as
+//! filled rectangles labelled with their panel label and id, unused areas as hatched
+//! rectangles, and optionally the cut lines. Sheets are stacked top to
+//! bottom inside a single standalone SVG document.
+
+use std::fmt::Write;
+
+use super::structs::Solution;
+use crate::models::{Mosaic, TileNode};
+
+/// Vertical gap between stacked sheets in pixels
+const SHEET_GAP_PIXELS: i64 = 20;
+
+const PANEL_FILL: &str = "#f5deb3";
+const OUTLINE: &str = "#282828";
+const CUT_LINE: &str = "#d62728";
+const HATCH_ID: &str = "unused-hatch";
+
+/// Options controlling SVG output
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgOptions {
+    /// Pixels per layout unit; layout units are millimetres scaled by the solution factor
+    pub scale: f64,
+    /// Outline and cut line width in pixels
+    pub stroke_width: f64,
+    /// Whether to draw the cut lines recorded on each mosaic
+    pub draw_cuts: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            stroke_width: 1.0,
+            draw_cuts: true,
+        }
+    }
+}
+
+/// Render a solution as a standalone SVG document
+///
+/// Each mosaic becomes a nested `<svg>` element, stacked vertically in
+/// solution order. All coordinates are rounded to whole pixels.
+pub fn to_svg(solution: &Solution, options: SvgOptions) -> String {
+    let px = |value: i32| (value as f64 * options.scale).round() as i64;
+
+    let total_width = solution.mosaics.iter().map(|mosaic| px(mosaic.width())).max().unwrap_or(0);
+    let total_height = solution.mosaics.iter().map(|mosaic| px(mosaic.height())).sum::<i64>()
+        + SHEET_GAP_PIXELS * solution.mosaics.len().saturating_sub(1) as i64;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = total_width,
+        h = total_height
+    );
+    let _ = writeln!(
+        svg,
+        r#"<defs><pattern id="{}" patternUnits="userSpaceOnUse" width="8" height="8"><path d="M0,8 L8,0" stroke="{}" stroke-width="1"/></pattern></defs>"#,
+        HATCH_ID, OUTLINE
+    );
+
+    let mut offset_y = 0;
+    for mosaic in &solution.mosaics {
+        write_sheet(&mut svg, mosaic, offset_y, &options);
+        offset_y += px(mosaic.height()) + SHEET_GAP_PIXELS;
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Append one sheet as a nested `<svg>` positioned at `offset_y`
+fn write_sheet(svg: &mut String, mosaic: &Mosaic, offset_y: i64, options: &SvgOptions) {
+    let root = mosaic.root_tile_node();
+    let px = |value: i32| (value as f64 * options.scale).round() as i64;
+    let stroke = options.stroke_width;
+
+    let _ = writeln!(
+        svg,
+        r#"<svg y="{}" width="{}" height="{}">"#,
+        offset_y,
+        px(root.width()),
+        px(root.height())
+    );
+
+    let rect = |svg: &mut String, node: &TileNode, fill: &str| {
+        let x = px(node.x1() - root.x1());
+        let y = px(node.y1() - root.y1());
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="{}" stroke-width="{}"/>"#,
+            x,
+            y,
+            px(node.x2() - root.x1()) - x,
+            px(node.y2() - root.y1()) - y,
+            fill,
+            OUTLINE,
+            stroke
+        );
+    };
+
+    for node in root.final_tile_nodes() {
+        rect(svg, node, PANEL_FILL);
+        let id = node.external_id.map_or_else(|| node.id().to_string(), |id| id.to_string());
+        let label = match node.label() {
+            Some(label) => format!("{} ({})", escape_xml(label), id),
+            None => id,
+        };
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle" dominant-baseline="middle" font-family="sans-serif" font-size="12">{}</text>"#,
+            px(node.x1() - root.x1()) + px(node.width()) / 2,
+            px(node.y1() - root.y1()) + px(node.height()) / 2,
+            label
+        );
+    }
+
+    for node in root.unused_tiles() {
+        rect(svg, node, &format!("url(#{})", HATCH_ID));
+    }
+
+    if options.draw_cuts {
+        for cut in mosaic.cuts() {
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+                px(cut.x1 - root.x1()),
+                px(cut.y1 - root.y1()),
+                px(cut.x2 - root.x1()),
+                px(cut.y2 - root.y1()),
+                CUT_LINE,
+                stroke
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+}
+
+/// Escape text for use inside an XML element
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
@@ -0,0 +1,208 @@
+use super::structs::{OffcutClassification, OffcutInfo, Solution, WasteBreakdown, WasteByLocation};
+use std::collections::HashMap;
+
+use crate::models::{CutThickness, TileDimensions, TileNode};
+
+impl WasteBreakdown {
+    /// Total wasted area
+    pub fn total(&self) -> i64 {
+        self.kerf_area + self.offcut_area + self.scrap_area
+    }
+}
+
+impl OffcutInfo {
+    /// Area of the remainder
+    pub fn area(&self) -> i64 {
+        self.width as i64 * self.height as i64
+    }
+}
+
+impl WasteByLocation {
+    /// Total wasted area
+    pub fn total(&self) -> i64 {
+        self.edge_trim + self.inter_part + self.usable_offcut
+    }
+}
+
+impl Solution {
+    /// Split the waste of this solution into kerf, offcut and scrap areas
+    ///
+    /// Kerf is kerf × cut length summed over the cuts of every mosaic, using
+    /// the kerf of the mosaic's material from `material_cut_thicknesses` and
+    /// `cut_thickness` otherwise. A cut whose remainder was narrower than the
+    /// kerf still counts the full blade width. Unused leaves count as offcut
+    /// when at least one placed panel would fit in them, and as scrap otherwise.
+    pub fn waste_breakdown(
+        &self,
+        cut_thickness: impl Into<CutThickness>,
+        material_cut_thicknesses: &HashMap<String, CutThickness>,
+    ) -> WasteBreakdown {
+        let cut_thickness = cut_thickness.into();
+        let panel_sizes = self.placed_panel_sizes();
+        let mut breakdown = WasteBreakdown::default();
+
+        for mosaic in &self.mosaics {
+            let kerf = material_cut_thicknesses
+                .get(mosaic.material())
+                .copied()
+                .unwrap_or(cut_thickness);
+            for cut in &mosaic.cuts {
+                let blade = if cut.is_horizontal { kerf.horizontal() } else { kerf.vertical() };
+                breakdown.kerf_area += blade as i64 * cut.length();
+            }
+
+            for node in mosaic.root_tile_node().iter() {
+                if node.has_children() || node.is_final() {
+                    continue;
+                }
+                let fits_panel = panel_sizes.iter().any(|&(width, height)| {
+                    (node.width() >= width && node.height() >= height)
+                        || (node.width() >= height && node.height() >= width)
+                });
+                if fits_panel {
+                    breakdown.offcut_area += node.area();
+                } else {
+                    breakdown.scrap_area += node.area();
+                }
+            }
+        }
+
+        breakdown
+    }
+
+    /// Split the waste of this solution by where it lies on each sheet
+    ///
+    /// Unused leaves with both sides at least `reusable_offcut_threshold`
+    /// (scaled units) are usable offcuts. Other unused leaves count as edge
+    /// trim when they touch an edge of the sheet's usable area and as
+    /// inter-part waste when they are enclosed. The border removed by a
+    /// sheet's `edge_trim` is edge trim and the kerf of every cut is
+    /// inter-part waste, so the parts add up to the unused area.
+    pub fn waste_by_location(&self, reusable_offcut_threshold: Option<i32>) -> WasteByLocation {
+        let mut waste = WasteByLocation::default();
+
+        for mosaic in &self.mosaics {
+            let root = mosaic.root_tile_node();
+            let sheet = mosaic.to_tile_dimensions();
+            waste.edge_trim += sheet.area() - root.area();
+
+            for node in root.iter() {
+                if node.has_children() {
+                    let children_area: i64 = [node.child1(), node.child2()]
+                        .into_iter()
+                        .flatten()
+                        .map(TileNode::area)
+                        .sum();
+                    waste.inter_part += node.area() - children_area;
+                    continue;
+                }
+                if node.is_final() {
+                    continue;
+                }
+
+                let touches_edge = node.x1() == root.x1()
+                    || node.x2() == root.x2()
+                    || node.y1() == root.y1()
+                    || node.y2() == root.y2();
+                if reusable_offcut_threshold.is_some_and(|threshold| node.width().min(node.height()) >= threshold) {
+                    waste.usable_offcut += node.area();
+                } else if touches_edge {
+                    waste.edge_trim += node.area();
+                } else {
+                    waste.inter_part += node.area();
+                }
+            }
+        }
+
+        waste
+    }
+
+    /// Split the unused leaves of every mosaic into reusable offcuts and waste
+    ///
+    /// A leaf is reusable when both of its sides are at least `threshold`
+    /// (in the same scaled units as the mosaics). Offcuts carry the material
+    /// of their sheet and `factor` as their scale.
+    pub fn classify_offcuts(&self, threshold: i32, factor: f64) -> OffcutClassification {
+        let mut classification = OffcutClassification::default();
+
+        for mosaic in &self.mosaics {
+            for node in mosaic.root_tile_node().iter() {
+                if node.has_children() || node.is_final() || node.area() == 0 {
+                    continue;
+                }
+                let mut offcut = TileDimensions::new(node.id() as i32, node.width(), node.height());
+                offcut.material = mosaic.material().to_string();
+                offcut.scale = factor;
+                if node.width().min(node.height()) >= threshold {
+                    classification.reusable_offcuts.push(offcut);
+                } else {
+                    classification.waste.push(offcut);
+                }
+            }
+        }
+
+        classification
+    }
+
+    /// Count the unused leaves of every mosaic by area
+    ///
+    /// `buckets` are ascending lower bounds in scaled area units: each one
+    /// counts the remainders from its bound up to the next, and the last one
+    /// everything above it. Remainders smaller than the first bound are not
+    /// counted. Returns each bound with its count.
+    pub fn offcut_histogram(&self, buckets: &[i64]) -> Vec<(i64, usize)> {
+        let mut histogram: Vec<(i64, usize)> = buckets.iter().map(|&bound| (bound, 0)).collect();
+
+        for mosaic in &self.mosaics {
+            for node in mosaic.root_tile_node().iter() {
+                if node.has_children() || node.is_final() || node.area() == 0 {
+                    continue;
+                }
+                if let Some(bucket) = histogram.iter_mut().rev().find(|(bound, _)| node.area() >= *bound) {
+                    bucket.1 += 1;
+                }
+            }
+        }
+
+        histogram
+    }
+
+    /// Unused leaves of every mosaic with an area of at least `min_area`
+    ///
+    /// `min_area` is in scaled area units. The remainders are listed largest
+    /// first; ties keep sheet order, then top to bottom and left to right.
+    pub fn offcuts_above(&self, min_area: i64) -> Vec<OffcutInfo> {
+        let mut offcuts: Vec<OffcutInfo> = Vec::new();
+
+        for (sheet_index, mosaic) in self.mosaics.iter().enumerate() {
+            for node in mosaic.root_tile_node().iter() {
+                if node.has_children() || node.is_final() || node.area() == 0 || node.area() < min_area {
+                    continue;
+                }
+                offcuts.push(OffcutInfo {
+                    sheet_index,
+                    x: node.x1(),
+                    y: node.y1(),
+                    width: node.width(),
+                    height: node.height(),
+                });
+            }
+        }
+
+        offcuts.sort_by_key(|offcut| (std::cmp::Reverse(offcut.area()), offcut.sheet_index, offcut.y, offcut.x));
+        offcuts
+    }
+
+    /// Distinct (width, height) of every placed panel
+    fn placed_panel_sizes(&self) -> Vec<(i32, i32)> {
+        let mut sizes: Vec<(i32, i32)> = self
+            .mosaics
+            .iter()
+            .flat_map(|mosaic| mosaic.final_tile_nodes())
+            .map(|node| (node.width(), node.height()))
+            .collect();
+        sizes.sort_unstable();
+        sizes.dedup();
+        sizes
+    }
+}
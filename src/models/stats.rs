@@ -22,6 +22,14 @@ pub struct Stats {
     pub nbr_queued_threads: i32,
     pub nbr_finished_threads: i64,
     
+    // Latency statistics, in milliseconds
+    /// Average time tasks waited in the queue before computation started
+    #[serde(default)]
+    pub avg_queue_wait: f64,
+    /// Average computation time of completed tasks
+    #[serde(default)]
+    pub avg_compute_time: f64,
+    
     // Task reports for detailed monitoring
     pub task_reports: Vec<TaskReport>,
 }
@@ -35,6 +43,10 @@ pub struct TaskReport {
     pub progress_percentage: i32,
     pub start_time: i64,
     pub duration_ms: Option<i64>,
+    #[serde(default)]
+    pub queue_wait_ms: Option<i64>,
+    #[serde(default)]
+    pub compute_time_ms: Option<i64>,
     pub error_message: Option<String>,
 }
 
@@ -63,6 +75,8 @@ impl Stats {
             nbr_running_threads: 0,
             nbr_queued_threads: 0,
             nbr_finished_threads: 0,
+            avg_queue_wait: 0.0,
+            avg_compute_time: 0.0,
             task_reports: Vec::new(),
         }
     }
@@ -107,6 +121,8 @@ impl TaskReport {
             progress_percentage: 0,
             start_time: 0,
             duration_ms: None,
+            queue_wait_ms: None,
+            compute_time_ms: None,
             error_message: None,
         }
     }
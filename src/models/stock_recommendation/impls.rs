@@ -0,0 +1,20 @@
+//! StockRecommendation implementation
+
+use super::StockRecommendation;
+
+impl StockRecommendation {
+    /// Area of the sheet that was used
+    pub fn current_area(&self) -> i64 {
+        self.current_width as i64 * self.current_height as i64
+    }
+
+    /// Area of the suggested catalog size
+    pub fn recommended_area(&self) -> i64 {
+        self.recommended_width as i64 * self.recommended_height as i64
+    }
+
+    /// Area that would be saved by switching to the suggested size
+    pub fn area_saved(&self) -> i64 {
+        self.current_area() - self.recommended_area()
+    }
+}
@@ -0,0 +1,9 @@
+//! StockRecommendation model module
+//!
+//! Contains the StockRecommendation structure used to suggest a smaller
+//! stock size for sheets that were poorly utilized.
+
+pub mod structs;
+pub mod impls;
+
+pub use structs::*;
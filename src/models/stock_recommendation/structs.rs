@@ -0,0 +1,22 @@
+//! StockRecommendation structure definition
+
+/// Suggests a smaller stock size for an underused sheet
+///
+/// Dimensions share the units of the mosaic the recommendation was made for.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StockRecommendation {
+    /// Stock id of the underused sheet
+    pub stock_id: i32,
+    /// Material of the underused sheet
+    pub material: String,
+    /// Width of the sheet that was used
+    pub current_width: i32,
+    /// Height of the sheet that was used
+    pub current_height: i32,
+    /// Width of the suggested catalog size
+    pub recommended_width: i32,
+    /// Height of the suggested catalog size
+    pub recommended_height: i32,
+    /// Fraction of the current sheet covered by placed tiles
+    pub used_area_ratio: f64,
+}
@@ -3,7 +3,7 @@
 //! This module contains simple accessor methods for Task fields.
 
 use std::collections::HashMap;
-use crate::models::{CalculationRequest, CalculationResponse, TileDimensions};
+use crate::models::{CalculationRequest, CalculationResponse, PermutationStats, TileDimensions};
 use super::Task;
 
 impl Task {
@@ -59,6 +59,26 @@ impl Task {
         self.is_min_trim_dimension_influenced = influenced;
     }
 
+    /// Check if the search stopped early because its time budget ran out
+    pub fn is_time_bounded(&self) -> bool {
+        self.is_time_bounded
+    }
+
+    /// Set time bounded flag
+    pub fn set_time_bounded(&mut self, time_bounded: bool) {
+        self.is_time_bounded = time_bounded;
+    }
+
+    /// Counters of the tile orders tried, summed over the materials computed so far
+    pub fn permutation_stats(&self) -> Option<PermutationStats> {
+        self.permutation_stats
+    }
+
+    /// Add the counters of one material's search
+    pub fn add_permutation_stats(&mut self, stats: PermutationStats) {
+        self.permutation_stats.get_or_insert_with(PermutationStats::default).combine(&stats);
+    }
+
     /// Get no material tiles
     pub fn no_material_tiles(&self) -> &Vec<TileDimensions> {
         &self.no_material_tiles
@@ -4,7 +4,12 @@
 
 use std::collections::HashMap;
 use crate::{log_debug, log_info, log_warn};
-use crate::models::{CalculationResponse, FinalTile, NoFitTile, Mosaic, Solution, TileNode};
+use crate::models::{enums::Orientation, CalculationResponse, FinalTile, LShape, NoFitTile, Mosaic, ResponseMeta, Solution, TileDimensions, TileNode};
+use crate::engine::comparator::PriorityListFactory;
+use crate::engine::consolidation;
+use crate::engine::service::computation::material_compute;
+use crate::errors::AppError;
+use crate::utils::math;
 use super::Task;
 
 impl Task {
@@ -27,74 +32,187 @@ impl Task {
     }
 
     /// Build the final solution from all thread solutions
-    /// Returns the built solution or None if no calculation request exists
+    /// Returns the built solution or None if no calculation request exists,
+    /// or if the solution so far places too few copies of a panel
     pub fn build_solution(&self) -> Option<CalculationResponse> {
         let request = self.calculation_request.as_ref()?;
         
         log_debug!("Building solution for task {} with {} materials", 
                self.id, self.solutions.lock().unwrap().len());
         
-        // Collect all solutions from all materials
-        let all_solutions = self.collect_all_solutions();
-        
-        if all_solutions.is_empty() {
+        let bests = self.select_best_solutions();
+        if bests.is_empty() {
             log_warn!("No solutions found for task {}", self.id);
-            return self.build_empty_solution(request);
+            let nothing_placed = self.finish_solution(Solution::new()).ok()?;
+            return self.build_empty_solution(request, &nothing_placed);
         }
-        
-        // Find the best solution using optimization criteria
-        let best_solution = self.select_best_solution(&all_solutions);
-        
+
+        let first_acceptable = self
+            .first_acceptable_solution(&bests)
+            .and_then(|first| self.finish_solution(first).ok());
+        let best_solution = Self::combine_solutions(bests.into_iter().map(|(_, best)| best).collect())?;
+        let best_solution = match self.finish_solution(best_solution) {
+            Ok(best_solution) => best_solution,
+            Err(e) => {
+                log_warn!("No acceptable solution for task {}: {}", self.id, e);
+                return None;
+            }
+        };
+
         // Build the final response from the best solution
-        self.build_response_from_solution(request, &best_solution)
+        let mut response = self.build_response_from_solution(request, &best_solution)?;
+        response.first_acceptable = first_acceptable
+            .and_then(|first| self.build_response_from_solution(request, &first))
+            .map(|mut first| {
+                first.request = None;
+                Box::new(first)
+            });
+        Some(response)
     }
 
-    /// Collect all solutions from all materials
-    fn collect_all_solutions(&self) -> Vec<Solution> {
-        let solutions_map = self.solutions.lock().unwrap();
-        let mut all_solutions = Vec::new();
-        
-        for (material, material_solutions) in solutions_map.iter() {
-            log_debug!("Material '{}' has {} solutions", material, material_solutions.len());
-            all_solutions.extend(material_solutions.iter().cloned());
+    /// The task's result so far: the best solution of every material, combined
+    ///
+    /// Returns `None` while no material has a solution. Fails when the
+    /// solution places too few copies of a panel; see `finish_solution`.
+    pub fn best_solution(&self) -> Result<Option<Solution>, AppError> {
+        let bests = self.select_best_solutions();
+        Self::combine_solutions(bests.into_iter().map(|(_, best)| best).collect())
+            .map(|best| self.finish_solution(best))
+            .transpose()
+    }
+
+    /// The task's result once its computation is over
+    ///
+    /// Like `best_solution`, with the tiles of materials left without a
+    /// solution, as when the placement places nothing, as no-fit panels.
+    /// The result is marked `time_bounded` when the time budget ran out,
+    /// counts the permutations evaluated over every material and, with
+    /// `report_first_acceptable`, keeps the first acceptable solution.
+    pub fn final_solution(&self) -> Result<Solution, AppError> {
+        let bests = self.select_best_solutions();
+        let first_acceptable = self
+            .first_acceptable_solution(&bests)
+            .map(|first| self.finish_solution(first))
+            .transpose()?;
+        let mut best = Self::combine_solutions(bests.iter().map(|(_, best)| best.clone()).collect()).unwrap_or_default();
+        if let Some(tiles_per_material) = &self.tile_dimensions_per_material {
+            let unsolved = tiles_per_material
+                .iter()
+                .filter(|(material, _)| !bests.iter().any(|(solved, _)| solved == *material))
+                .flat_map(|(_, tiles)| tiles)
+                .filter(|tile| !self.no_material_tiles.contains(tile));
+            best.no_fit_panels.extend(unsolved.cloned());
         }
-        
-        log_info!("Collected {} total solutions for task {}", all_solutions.len(), self.id);
-        all_solutions
+        let mut solution = self.finish_solution(best)?;
+        solution.time_bounded |= self.is_time_bounded;
+        if let Some(stats) = self.permutation_stats {
+            solution.permutations_evaluated = stats.permutations_evaluated;
+        }
+        solution.first_acceptable = first_acceptable.map(Box::new);
+        Ok(solution)
     }
 
-    /// Select the best solution based on optimization criteria
-    fn select_best_solution(&self, solutions: &[Solution]) -> Solution {
-        if solutions.is_empty() {
-            panic!("Cannot select best solution from empty list");
+    /// With `report_first_acceptable`, the first acceptable solution of every
+    /// material among `bests`, combined
+    ///
+    /// Materials that never met the target contribute their best solution,
+    /// as a search stopping at the target would have run them to the end.
+    fn first_acceptable_solution(&self, bests: &[(String, Solution)]) -> Option<Solution> {
+        let report_first_acceptable = self
+            .calculation_request
+            .as_ref()
+            .and_then(|request| request.configuration.as_ref())
+            .is_some_and(|configuration| configuration.report_first_acceptable);
+        if !report_first_acceptable {
+            return None;
         }
-        
-        // Find solution with minimum waste (best area utilization)
-        let best = solutions
+        let first_acceptable = self.first_acceptable_solutions.lock().unwrap();
+        let firsts = bests
             .iter()
-            .min_by(|a, b| {
-                let waste_a = self.calculate_solution_waste(a);
-                let waste_b = self.calculate_solution_waste(b);
-                waste_a.partial_cmp(&waste_b).unwrap_or(std::cmp::Ordering::Equal)
-            })
-            .unwrap();
-        
-        log_debug!("Selected best solution {} with waste area: {:.2}", 
-               best.id, self.calculate_solution_waste(best));
-        
-        best.clone()
+            .map(|(material, best)| first_acceptable.get(material).unwrap_or(best).clone())
+            .collect();
+        Self::combine_solutions(firsts)
     }
 
-    /// Calculate total waste area for a solution
-    fn calculate_solution_waste(&self, solution: &Solution) -> f64 {
-        solution.mosaics.iter()
-            .map(|mosaic| {
-                // Calculate waste as stock area minus used area
-                let stock_area = mosaic.root_tile_node.tile.width() as f64 * mosaic.root_tile_node.tile.height() as f64;
-                let used_area = self.calculate_mosaic_used_area(mosaic);
-                stock_area - used_area
+    /// Complete a combined solution into the task's result
+    ///
+    /// Tiles without stock of their material are added as no-fit panels and
+    /// unplaced optional copies are dropped; see `Solution::settle_optional_copies`.
+    /// With `balance_sheets` in the configuration, barely-used sheets are then
+    /// freed; see `consolidation::balance_sheets`. L-shaped panels, laid out
+    /// as their bounding box, are finally split into their two rectangles.
+    fn finish_solution(&self, mut solution: Solution) -> Result<Solution, AppError> {
+        solution.no_fit_panels.extend(self.no_material_tiles.iter().cloned());
+        let tiles: Vec<TileDimensions> = self
+            .tile_dimensions_per_material
+            .iter()
+            .flat_map(HashMap::values)
+            .flatten()
+            .cloned()
+            .collect();
+        if let Some(request) = &self.calculation_request {
+            solution.settle_optional_copies(&request.min_quantities())?;
+            let configuration = request.configuration.clone().unwrap_or_default();
+            if configuration.balance_sheets && solution.mosaics.len() > 1 {
+                let config = material_compute::placement_config(&configuration, self)?;
+                let freed = consolidation::balance_sheets(&mut solution, &tiles, &config);
+                log_debug!("Sheet balancing freed {} sheet(s) of task {}", freed, self.id);
+            }
+        }
+        let l_shapes: HashMap<i32, LShape> = tiles
+            .iter()
+            .filter_map(|tile| tile.l_shape.map(|shape| (tile.id, shape)))
+            .collect();
+        solution.decompose_l_shapes(&l_shapes, self.factor);
+        Ok(solution)
+    }
+
+    /// The best solution of every material, in material name order
+    ///
+    /// Each material's solutions are ranked by the request's final solution
+    /// comparators.
+    fn select_best_solutions(&self) -> Vec<(String, Solution)> {
+        let configuration = self.calculation_request.as_ref()
+            .and_then(|request| request.configuration.clone())
+            .unwrap_or_default();
+        let comparators = PriorityListFactory::get_final_solution_prioritized_comparator_functions(&configuration);
+        let compare = |a: &Solution, b: &Solution| {
+            comparators
+                .iter()
+                .map(|compare| compare(a, b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        };
+
+        let solutions_map = self.solutions.lock().unwrap();
+        let mut materials: Vec<&String> = solutions_map.keys().collect();
+        materials.sort();
+        materials
+            .into_iter()
+            .filter_map(|material| {
+                let material_solutions = &solutions_map[material];
+                log_debug!("Material '{}' has {} solutions", material, material_solutions.len());
+                let best = material_solutions.iter().min_by(|a, b| compare(a, b))?;
+                Some((material.clone(), best.clone()))
             })
-            .sum()
+            .collect()
+    }
+
+    /// Put together one solution per material into a single solution
+    fn combine_solutions(mut solutions: Vec<Solution>) -> Option<Solution> {
+        if solutions.len() <= 1 {
+            return solutions.pop();
+        }
+        let mut combined = Solution::new();
+        combined.grid_packed = solutions.iter().all(|solution| solution.grid_packed);
+        for solution in solutions {
+            combined.mosaics.extend(solution.mosaics);
+            combined.no_fit_panels.extend(solution.no_fit_panels);
+            combined.unused_stock_panels.extend(solution.unused_stock_panels);
+            combined.time_bounded |= solution.time_bounded;
+            combined.permutations_evaluated += solution.permutations_evaluated;
+        }
+        Some(combined)
     }
 
     /// Calculate used area in a mosaic by traversing the tile tree
@@ -106,7 +224,7 @@ impl Task {
     fn calculate_node_used_area(&self, node: &TileNode) -> f64 {
         if node.is_final {
             // This is a final tile, count its area
-            node.tile.width() as f64 * node.tile.height() as f64
+            math::area(node.width(), node.height(), self.factor)
         } else if let (Some(child1), Some(child2)) = (&node.child1, &node.child2) {
             // This node has children, sum their used areas
             self.calculate_node_used_area(child1) + self.calculate_node_used_area(child2)
@@ -129,12 +247,21 @@ impl Task {
             // This is a final tile
             let final_tile = FinalTile {
                 request_obj_id: node.external_id.unwrap_or(node.id as i32),
-                width: node.tile.width() as f64,
-                height: node.tile.height() as f64,
-                label: Some(format!("tile_{}", node.id)), // Generate label since Tile doesn't have one
+                width: node.tile.width() as f64 / self.factor,
+                height: node.tile.height() as f64 / self.factor,
+                label: node.label.clone(),
                 count: 1, // Each node represents one tile
+                ..FinalTile::default()
             };
-            tiles.push(final_tile);
+            let thickness = self.calculation_request.as_ref().and_then(|request| {
+                request.panels.iter()
+                    .find(|panel| panel.id == final_tile.request_obj_id)
+                    .and_then(|panel| panel.banding_thickness)
+            });
+            tiles.push(match thickness {
+                Some(thickness) => final_tile.with_banding(&thickness, node.is_rotated()),
+                None => final_tile,
+            });
         } else if let (Some(child1), Some(child2)) = (&node.child1, &node.child2) {
             // This node has children, recurse into them
             self.extract_tiles_from_node(child1, tiles);
@@ -162,20 +289,12 @@ impl Task {
             total_cuts += mosaic.cuts.len() as u64;
             total_cut_length += self.estimate_cut_length(mosaic);
             total_used_area += self.calculate_mosaic_used_area(mosaic);
-            total_stock_area += mosaic.root_tile_node.tile.width() as f64 * mosaic.root_tile_node.tile.height() as f64;
+            total_stock_area += math::area(mosaic.root_tile_node.width(), mosaic.root_tile_node.height(), self.factor);
         }
         
         // Convert no-fit panels
         let no_fit_panels: Vec<NoFitTile> = solution.no_fit_panels.iter()
-            .chain(self.no_material_tiles.iter())
-            .map(|tile| NoFitTile {
-                id: tile.id,
-                width: tile.width as f64,
-                height: tile.height as f64,
-                count: 1,
-                label: tile.label.clone(),
-                material: Some(tile.material.clone()),
-            })
+            .map(|tile| Self::no_fit_tile(request, tile))
             .collect();
         
         // Calculate ratios and waste
@@ -189,7 +308,7 @@ impl Task {
         log_info!("Built solution for task {}: {} panels, {:.1}% efficiency, {} no-fit panels", 
               self.id, panels.len(), total_used_area_ratio * 100.0, no_fit_panels.len());
         
-        Some(CalculationResponse {
+        let mut response = CalculationResponse {
             version: "1.0.0".to_string(),
             edge_bands: self.calculate_edge_bands(&solution.mosaics),
             elapsed_time,
@@ -206,29 +325,36 @@ impl Task {
             used_stock_panels: None, // Could be populated if needed
             no_fit_panels,
             mosaics: solution.mosaics.clone(),
-        })
+            stock_recommendations: CalculationResponse::stock_recommendations_for(request, solution, self.factor),
+            sheet_yields: solution.get_sheet_yields(self.factor),
+            time_bounded: self.is_time_bounded || solution.time_bounded,
+            grid_packed: solution.grid_packed,
+            reusable_offcuts: CalculationResponse::reusable_offcuts_for(request, solution, self.factor),
+            offcut_histogram: CalculationResponse::offcut_histogram_for(request, solution, self.factor),
+            min_sheets_lower_bound: CalculationResponse::min_sheets_lower_bound_for(request),
+            first_acceptable: None,
+            meta: ResponseMeta::default(),
+        };
+        response.meta = ResponseMeta::from_response(&response);
+        response.meta.permutation_stats = self.permutation_stats;
+        Some(response)
     }
 
     /// Build an empty solution when no solutions are available
-    fn build_empty_solution(&self, request: &crate::models::CalculationRequest) -> Option<CalculationResponse> {
+    ///
+    /// The no-fit panels are those of `nothing_placed`, a finished solution without mosaics.
+    fn build_empty_solution(&self, request: &crate::models::CalculationRequest, nothing_placed: &Solution) -> Option<CalculationResponse> {
         let elapsed_time = self.elapsed_time();
         
         // All panels become no-fit panels
-        let no_fit_panels: Vec<NoFitTile> = self.no_material_tiles.iter()
-            .map(|tile| NoFitTile {
-                id: tile.id,
-                width: tile.width as f64,
-                height: tile.height as f64,
-                count: 1,
-                label: tile.label.clone(),
-                material: Some(tile.material.clone()),
-            })
+        let no_fit_panels: Vec<NoFitTile> = nothing_placed.no_fit_panels.iter()
+            .map(|tile| Self::no_fit_tile(request, tile))
             .collect();
         
         log_warn!("Built empty solution for task {} with {} no-fit panels", 
               self.id, no_fit_panels.len());
         
-        Some(CalculationResponse {
+        let mut response = CalculationResponse {
             version: "1.0.0".to_string(),
             edge_bands: None,
             elapsed_time,
@@ -245,14 +371,41 @@ impl Task {
             used_stock_panels: None,
             no_fit_panels,
             mosaics: Vec::new(),
-        })
+            stock_recommendations: Vec::new(),
+            sheet_yields: Vec::new(),
+            time_bounded: self.is_time_bounded,
+            grid_packed: false,
+            reusable_offcuts: Vec::new(),
+            offcut_histogram: Vec::new(),
+            min_sheets_lower_bound: CalculationResponse::min_sheets_lower_bound_for(request),
+            first_acceptable: None,
+            meta: ResponseMeta::default(),
+        };
+        response.meta = ResponseMeta::from_response(&response);
+        response.meta.permutation_stats = self.permutation_stats;
+        Some(response)
+    }
+
+    /// Report an unplaced tile in real units, with the reason it was left out and what would let it fit
+    fn no_fit_tile(request: &crate::models::CalculationRequest, tile: &TileDimensions) -> NoFitTile {
+        let mut no_fit = NoFitTile {
+            id: tile.id,
+            width: tile.real_width(),
+            height: tile.real_height(),
+            count: 1,
+            label: tile.label.clone(),
+            material: Some(tile.material.clone()),
+            ..NoFitTile::default()
+        };
+        request.classify_no_fit(&mut no_fit, tile.orientation == Orientation::Any);
+        no_fit
     }
 
     /// Estimate cut length for a mosaic based on the number of cuts
     fn estimate_cut_length(&self, mosaic: &Mosaic) -> f64 {
         // Simplified: assume each cut has an average length based on stock dimensions
         let avg_dimension = (mosaic.root_tile_node.tile.width() + mosaic.root_tile_node.tile.height()) as f64 / 2.0;
-        mosaic.cuts.len() as f64 * avg_dimension * 0.5 // Rough estimate
+        mosaic.cuts.len() as f64 * avg_dimension * 0.5 / self.factor // Rough estimate
     }
 
     /// Calculate edge band usage by material (simplified implementation)
@@ -261,9 +414,8 @@ impl Task {
         
         for mosaic in mosaics {
             // Simplified: calculate perimeter of all final tiles
-            let final_tiles = self.extract_final_tiles(mosaic);
-            let total_perimeter: f64 = final_tiles.iter()
-                .map(|tile| 2.0 * (tile.width + tile.height))
+            let total_perimeter: f64 = mosaic.final_tile_nodes().iter()
+                .map(|node| math::perimeter(node.width(), node.height(), self.factor))
                 .sum();
             
             *edge_bands.entry(mosaic.material.clone()).or_insert(0.0) += total_perimeter;
@@ -306,6 +458,15 @@ impl Task {
             .unwrap_or(0)
     }
 
+    /// Get the number of mosaics held by all solutions across all materials
+    pub fn active_mosaic_count(&self) -> usize {
+        self.solutions.lock().unwrap()
+            .values()
+            .flatten()
+            .map(|solution| solution.mosaics.len())
+            .sum()
+    }
+
     /// Get total number of solutions across all materials
     pub fn total_solution_count(&self) -> usize {
         self.solutions.lock().unwrap()
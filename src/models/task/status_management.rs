@@ -2,6 +2,8 @@
 //! 
 //! This module contains methods for managing task status transitions and validation.
 
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+
 use crate::{log_info, log_warn, log_error};
 use crate::models::enums::Status;
 use crate::errors::AppError;
@@ -9,12 +11,11 @@ use super::Task;
 
 /// Helper function to update running tasks counters when status changes
 fn update_running_tasks_counters(task_id: &str, old_status: Status, new_status: Status) {
-    use crate::engine::running_tasks::get_running_tasks_instance;
+    use crate::engine::running_tasks::{get_running_tasks_instance, StatusManager};
     
     if old_status != new_status {
         let running_tasks = get_running_tasks_instance();
-        running_tasks.decrement_status_counter(old_status);
-        running_tasks.increment_status_counter(new_status);
+        let _ = running_tasks.update_task_status(task_id, old_status, new_status);
         log_info!("Updated counters for task {}: {:?} -> {:?}", task_id, old_status, new_status);
     }
 }
@@ -32,6 +33,19 @@ impl Task {
         matches!(self.status(), Status::Running)
     }
 
+    /// Token raised when the task is stopped or terminated
+    ///
+    /// Computation workers hold on to this so they notice cancellation even
+    /// when they only see a clone of the task.
+    pub fn cancellation_token(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.cancellation_token)
+    }
+
+    /// Check whether the task has been stopped or terminated
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.load(Ordering::Acquire)
+    }
+
     /// Set the task status to running
     /// Returns Ok(()) if successful, Err if task is not in a valid state to start
     pub fn set_running_status(&self) -> Result<(), AppError> {
@@ -63,8 +77,13 @@ impl Task {
         }
         *status = Status::Finished;
         drop(status); // Release lock before calling update function
+        self.cancellation_token.store(true, Ordering::Release);
         update_running_tasks_counters(&self.id, old_status, Status::Finished);
         self.set_end_time();
+        // The best solution so far stands
+        if let Some(solution) = self.build_solution() {
+            *self.solution.write().unwrap() = Some(solution);
+        }
         log_info!("Task {} stopped", self.id);
         Ok(())
     }
@@ -82,6 +101,7 @@ impl Task {
         }
         *status = Status::Terminated;
         drop(status); // Release lock before calling update function
+        self.cancellation_token.store(true, Ordering::Release);
         update_running_tasks_counters(&self.id, old_status, Status::Terminated);
         self.set_end_time();
         log_warn!("Task {} terminated", self.id);
@@ -101,7 +121,7 @@ impl Task {
 
     /// Check if all materials are finished and update status accordingly
     pub fn check_if_finished(&self) {
-        if matches!(self.status(), Status::Finished) {
+        if matches!(self.status(), Status::Finished | Status::Error) {
             return;
         }
 
@@ -109,6 +129,14 @@ impl Task {
         let all_finished = percentages.values().all(|&p| p == 100);
 
         if all_finished {
+            drop(percentages);
+            // Too few copies of a panel placed: the task fails rather than finishes
+            if let Err(e) = self.best_solution() {
+                self.append_line_to_log(&e.to_string());
+                self.terminate_error();
+                return;
+            }
+
             let mut status = self.status.write().unwrap();
             let old_status = *status;
             *status = Status::Finished;
@@ -116,11 +144,9 @@ impl Task {
             update_running_tasks_counters(&self.id, old_status, Status::Finished);
             self.set_end_time();
             
-            if self.solution.read().unwrap().is_none() {
-                // Build solution if not already built
-                if let Some(solution) = self.build_solution() {
-                    *self.solution.write().unwrap() = Some(solution);
-                }
+            // Rebuild: a solution built while running may predate the last runs
+            if let Some(solution) = self.build_solution() {
+                *self.solution.write().unwrap() = Some(solution);
             }
             
             log_info!("Task {} finished", self.id);
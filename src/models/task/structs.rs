@@ -5,13 +5,13 @@
 
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex, RwLock},
+    sync::{atomic::AtomicBool, Arc, Mutex, RwLock},
     time::SystemTime,
 };
 
 use crate::{
     models::{
-        CalculationRequest, CalculationResponse, Solution, TileDimensions,
+        CalculationRequest, CalculationResponse, PermutationStats, Solution, TileDimensions,
         enums::Status,
     },
     engine::cut_list_thread::CutListThread,
@@ -29,6 +29,8 @@ pub struct Task {
     
     // Status and timing
     pub(crate) status: Arc<RwLock<Status>>,
+    /// Raised when the task is stopped or terminated; shared by every clone
+    pub(crate) cancellation_token: Arc<AtomicBool>,
     pub(crate) start_time: SystemTime,
     pub(crate) end_time: Arc<Mutex<Option<SystemTime>>>,
     pub(crate) last_queried: Arc<Mutex<SystemTime>>,
@@ -41,6 +43,8 @@ pub struct Task {
     
     // Solutions per material
     pub(crate) solutions: Arc<Mutex<HashMap<String, Vec<Solution>>>>,
+    /// Per material, the best solution at the moment it first met the target efficiency
+    pub(crate) first_acceptable_solutions: Arc<Mutex<HashMap<String, Solution>>>,
     
     // Thread group rankings for optimization
     pub(crate) thread_group_rankings: Arc<Mutex<HashMap<String, HashMap<String, i32>>>>,
@@ -53,6 +57,8 @@ pub struct Task {
     // Configuration
     pub(crate) factor: f64,
     pub(crate) is_min_trim_dimension_influenced: bool,
+    pub(crate) is_time_bounded: bool,
+    pub(crate) permutation_stats: Option<PermutationStats>,
     
     // Logging
     pub(crate) log: Arc<Mutex<String>>,
@@ -68,18 +74,22 @@ impl Task {
             calculation_request: None,
             solution: Arc::new(RwLock::new(None)),
             status: Arc::new(RwLock::new(Status::Queued)), // Java uses IDLE, but our enum uses Queued
+            cancellation_token: Arc::new(AtomicBool::new(false)),
             start_time: now,
             end_time: Arc::new(Mutex::new(None)),
             last_queried: Arc::new(Mutex::new(now)),
             threads: Arc::new(Mutex::new(Vec::new())),
             per_material_percentage_done: Arc::new(Mutex::new(HashMap::new())),
             solutions: Arc::new(Mutex::new(HashMap::new())),
+            first_acceptable_solutions: Arc::new(Mutex::new(HashMap::new())),
             thread_group_rankings: Arc::new(Mutex::new(HashMap::new())),
             tile_dimensions_per_material: None,
             stock_dimensions_per_material: None,
             no_material_tiles: Vec::new(),
             factor: 1.0,
             is_min_trim_dimension_influenced: false,
+            is_time_bounded: false,
+            permutation_stats: None,
             log: Arc::new(Mutex::new(String::new())),
         }
     }
@@ -93,18 +103,22 @@ impl Clone for Task {
             calculation_request: self.calculation_request.clone(),
             solution: Arc::new(RwLock::new(self.solution.read().unwrap().clone())),
             status: Arc::new(RwLock::new(*self.status.read().unwrap())),
+            cancellation_token: Arc::clone(&self.cancellation_token),
             start_time: self.start_time,
             end_time: Arc::clone(&self.end_time),
             last_queried: Arc::new(Mutex::new(*self.last_queried.lock().unwrap())),
             threads: Arc::clone(&self.threads), // Share threads instead of creating empty Vec
             per_material_percentage_done: Arc::clone(&self.per_material_percentage_done),
             solutions: Arc::clone(&self.solutions),
+            first_acceptable_solutions: Arc::clone(&self.first_acceptable_solutions),
             thread_group_rankings: Arc::clone(&self.thread_group_rankings),
             tile_dimensions_per_material: self.tile_dimensions_per_material.clone(),
             stock_dimensions_per_material: self.stock_dimensions_per_material.clone(),
             no_material_tiles: self.no_material_tiles.clone(),
             factor: self.factor,
             is_min_trim_dimension_influenced: self.is_min_trim_dimension_influenced,
+            is_time_bounded: self.is_time_bounded,
+            permutation_stats: self.permutation_stats,
             log: Arc::clone(&self.log),
         }
     }
@@ -0,0 +1,82 @@
+//! TaskCheckpoint implementation
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::TaskCheckpoint;
+use crate::comparator::{compare_by_least_wasted_area, compare_by_most_nbr_tiles};
+use crate::errors::{CoreError, Result};
+use crate::models::task::Task;
+
+impl TaskCheckpoint {
+    /// Snapshot the best solution and progress of every material of `task`
+    pub fn from_task(task: &Task) -> Self {
+        let best_solutions = task
+            .solutions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|(material, solutions)| {
+                solutions
+                    .iter()
+                    .min_by(|a, b| compare_by_most_nbr_tiles(a, b).then_with(|| compare_by_least_wasted_area(a, b)))
+                    .map(|best| (material.clone(), best.clone()))
+            })
+            .collect();
+
+        Self {
+            task_id: task.id.clone(),
+            calculation_request: task.calculation_request.clone(),
+            factor: task.factor,
+            best_solutions,
+            percentage_done: task.per_material_percentage_done.lock().unwrap().clone(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    /// File a task's checkpoint is kept in under `dir`
+    pub fn path_for(dir: &Path, task_id: &str) -> PathBuf {
+        dir.join(format!("{}.checkpoint.json", task_id))
+    }
+
+    /// Write the checkpoint as JSON, replacing any previous file atomically
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string(self).map_err(CoreError::from)?;
+        let temp_path = path.with_extension("json.tmp");
+        std::fs::write(&temp_path, json).map_err(CoreError::from)?;
+        std::fs::rename(&temp_path, path).map_err(CoreError::from)?;
+        Ok(())
+    }
+
+    /// Read a checkpoint written by `write`
+    pub fn read(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path).map_err(CoreError::from)?;
+        Ok(serde_json::from_str(&json).map_err(CoreError::from)?)
+    }
+
+    /// Rebuild a queued task holding the checkpointed solutions and progress
+    pub fn to_task(&self) -> Task {
+        let mut task = Task::new(self.task_id.clone());
+        if let Some(request) = &self.calculation_request {
+            task.set_calculation_request(request.clone());
+        }
+        task.set_factor(self.factor);
+
+        for material in self.percentage_done.keys() {
+            task.add_material_to_compute(material.clone());
+        }
+        for (material, solution) in &self.best_solutions {
+            task.add_solution(material, solution.clone());
+        }
+        // Set directly so a fully computed material does not finish the task on load
+        task.per_material_percentage_done
+            .lock()
+            .unwrap()
+            .extend(self.percentage_done.iter().map(|(material, percentage)| (material.clone(), *percentage)));
+
+        task
+    }
+}
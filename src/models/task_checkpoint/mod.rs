@@ -0,0 +1,9 @@
+//! TaskCheckpoint model module
+//!
+//! Contains the TaskCheckpoint structure, a snapshot of a task's best
+//! solutions and progress that survives a restart of the process.
+
+pub mod structs;
+pub mod impls;
+
+pub use structs::*;
@@ -0,0 +1,22 @@
+//! TaskCheckpoint structure definition
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::models::{CalculationRequest, Solution};
+
+/// Snapshot of a task's progress that can be written to disk and resumed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskCheckpoint {
+    /// Identifier of the checkpointed task
+    pub task_id: String,
+    /// Request the task was computing
+    pub calculation_request: Option<CalculationRequest>,
+    /// Factor the task's dimensions were scaled by
+    pub factor: f64,
+    /// Best solution found so far per material
+    pub best_solutions: HashMap<String, Solution>,
+    /// Progress per material, in percent
+    pub percentage_done: HashMap<String, i32>,
+    /// When the checkpoint was taken, in milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
@@ -105,6 +105,17 @@ impl Tile {
         self.y2 += dy;
     }
 
+    /// Create a new tile with every coordinate multiplied by `factor` and rounded
+    pub fn scaled(&self, factor: f64) -> Self {
+        let scale = |value: i32| (value as f64 * factor).round() as i32;
+        Self {
+            x1: scale(self.x1),
+            x2: scale(self.x2),
+            y1: scale(self.y1),
+            y2: scale(self.y2),
+        }
+    }
+
     /// Create a new tile translated by the specified offset
     pub fn translated(&self, dx: i32, dy: i32) -> Self {
         Self {
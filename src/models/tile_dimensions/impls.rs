@@ -1,9 +1,18 @@
 use super::structs::TileDimensions;
 
-use crate::{constants::MaterialConstants, Orientation};
+use crate::{
+    constants::{EngineConstants, MaterialConstants},
+    errors::{AppError, Result},
+    Orientation,
+};
 
 impl TileDimensions {
     /// Create a new tile with given dimensions
+    ///
+    /// Width and height are taken as given. Every tile the optimizer works
+    /// with must satisfy [`is_valid_dimension`](Self::is_valid_dimension) on
+    /// both sides; use [`try_new`](Self::try_new) for dimensions that come
+    /// from outside.
     pub fn new(id: i32, width: i32, height: i32) -> Self {
         Self {
             id,
@@ -13,12 +22,75 @@ impl TileDimensions {
             material: MaterialConstants::DEFAULT_MATERIAL.to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         }
     }
 
+    /// Create a new tile, rejecting dimensions that are not positive or
+    /// larger than `EngineConstants::MAX_DIMENSION`
+    pub fn try_new(id: i32, width: i32, height: i32) -> Result<Self> {
+        for (name, value) in [("width", width), ("height", height)] {
+            if !Self::is_valid_dimension(value as f64) {
+                return Err(AppError::invalid_input(format!(
+                    "Tile {} {} must be between 1 and {}, got {}",
+                    id, name, EngineConstants::MAX_DIMENSION, value
+                )));
+            }
+        }
+        Ok(Self::new(id, width, height))
+    }
+
+    /// Whether a width or height is positive and no larger than
+    /// `EngineConstants::MAX_DIMENSION`
+    pub fn is_valid_dimension(value: f64) -> bool {
+        value.is_finite() && value > 0.0 && value <= EngineConstants::MAX_DIMENSION as f64
+    }
+
+    /// Width in the units of the original request
+    pub fn real_width(&self) -> f64 {
+        self.width as f64 / self.scale
+    }
+
+    /// Height in the units of the original request
+    pub fn real_height(&self) -> f64 {
+        self.height as f64 / self.scale
+    }
+
+    /// Area in the units of the original request
+    pub fn real_area(&self) -> f64 {
+        self.real_width() * self.real_height()
+    }
+
     /// Calculate the area of the tile
-    pub fn area(&self) -> i32 {
-        self.width.saturating_mul(self.height)
+    ///
+    /// Computed in `i64`, so no valid pair of dimensions can overflow.
+    pub fn area(&self) -> i64 {
+        self.width as i64 * self.height as i64
+    }
+
+    /// Calculate the perimeter of the tile
+    pub fn perimeter(&self) -> i64 {
+        2 * (self.width as i64 + self.height as i64)
+    }
+
+    /// Cut size of this finished tile once banding is applied
+    ///
+    /// `thickness` holds the banding on each side in the order of [`Edge`]:
+    /// top, left, bottom, right. Left and right come off the width, top and
+    /// bottom off the height.
+    ///
+    /// [`Edge`]: crate::models::Edge
+    pub fn with_banding_allowance(&self, thickness: &[i32; 4]) -> TileDimensions {
+        let [top, left, bottom, right] = *thickness;
+        let mut tile = self.clone();
+        tile.width -= left + right;
+        tile.height -= top + bottom;
+        tile
     }
 
     /// Check if this tile can fit within a container
@@ -42,6 +114,23 @@ impl TileDimensions {
         }
     }
 
+    /// Rotate the tile 90 degrees even if its grain forbids it
+    pub fn rotate_90_ignoring_grain(&mut self) {
+        std::mem::swap(&mut self.width, &mut self.height);
+        self.is_rotated = !self.is_rotated;
+    }
+
+    /// Grain direction of the tile as it currently lies
+    ///
+    /// A rotated tile's grain runs across its original direction.
+    pub fn grain_direction(&self) -> Orientation {
+        match (self.orientation, self.is_rotated) {
+            (Orientation::Horizontal, true) => Orientation::Vertical,
+            (Orientation::Vertical, true) => Orientation::Horizontal,
+            (orientation, _) => orientation,
+        }
+    }
+
     /// Get the maximum dimension (width or height)
     pub fn max_dimension(&self) -> i32 {
         self.width.max(self.height)
@@ -52,6 +141,13 @@ impl TileDimensions {
         self.width == self.height
     }
 
+    /// Check if the sides differ by less than `tolerance`
+    ///
+    /// A tolerance of zero or less only matches exact squares.
+    pub fn is_near_square(&self, tolerance: i32) -> bool {
+        (self.width - self.height).abs() < tolerance.max(1)
+    }
+
     /// Check if the tile is horizontally oriented
     pub fn is_horizontal(&self) -> bool {
         self.width >= self.height
@@ -61,6 +157,16 @@ impl TileDimensions {
     pub fn dimensions_string(&self) -> String {
         format!("{}x{}", self.width, self.height)
     }
+
+    /// Key shared by identical tiles: material plus dimensions, ignoring rotation
+    pub fn get_grouping_signature(&self) -> String {
+        let (min_dim, max_dim) = if self.width <= self.height {
+            (self.width, self.height)
+        } else {
+            (self.height, self.width)
+        };
+        format!("{}:{}x{}", self.material, max_dim, min_dim)
+    }
 }
 
 // Java equivalent methods
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::Orientation;
+use crate::models::{Edge, LShape, Tile};
 
 
 
@@ -17,4 +18,26 @@ pub struct TileDimensions {
     pub material: String,
     pub orientation: Orientation,
     pub is_rotated: bool,
+    /// Placement priority; higher-priority tiles are placed first among equals
+    #[serde(default)]
+    pub priority: i32,
+    /// Edge banding spec copied from the originating panel
+    #[serde(default)]
+    pub edge: Option<Edge>,
+    /// Border trimmed off every side when this tile is used as stock
+    #[serde(default)]
+    pub edge_trim: i32,
+    /// Factor the request dimensions were multiplied by to get these integers
+    #[serde(default = "default_scale")]
+    pub scale: f64,
+    /// Areas of the sheet no tile may cover when this tile is used as stock
+    #[serde(default)]
+    pub keepouts: Vec<Tile>,
+    /// Notch of an L-shaped panel laid out as this bounding box
+    #[serde(default)]
+    pub l_shape: Option<LShape>,
+}
+
+fn default_scale() -> f64 {
+    1.0
 }
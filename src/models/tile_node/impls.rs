@@ -10,9 +10,12 @@ impl TileNode {
         Self {
             id: NEXT_ID.fetch_add(1, Ordering::SeqCst),
             external_id: None,
+            label: None,
+            group: None,
             tile: Tile::new(x1, x2, y1, y2),
             is_final: false,
             is_rotated: false,
+            is_locked: false,
             child1: None,
             child2: None,
             is_area_totally_used: false,
@@ -25,9 +28,12 @@ impl TileNode {
         Self {
             id: NEXT_ID.fetch_add(1, Ordering::SeqCst),
             external_id: None,
+            label: None,
+            group: None,
             tile: Tile::from_dimensions(tile_dimensions),
             is_final: false,
             is_rotated: false,
+            is_locked: false,
             child1: None,
             child2: None,
             is_area_totally_used: false,
@@ -40,9 +46,12 @@ impl TileNode {
         Self {
             id: other.id,
             external_id: other.external_id,
+            label: other.label.clone(),
+            group: other.group,
             tile: other.tile.clone(),
             is_final: other.is_final,
             is_rotated: other.is_rotated,
+            is_locked: other.is_locked,
             child1: other.child1.clone(),
             child2: other.child2.clone(),
             is_area_totally_used: false,
@@ -120,6 +129,26 @@ impl TileNode {
         self.child1.is_some() || self.child2.is_some()
     }
 
+    /// Get the panel label
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Set the panel label
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+
+    /// Get the group shared with the other rectangle of an L-shaped panel
+    pub fn group(&self) -> Option<i32> {
+        self.group
+    }
+
+    /// Set the group shared with the other rectangle of an L-shaped panel
+    pub fn set_group(&mut self, group: Option<i32>) {
+        self.group = group;
+    }
+
     /// Check if this tile is rotated
     pub fn is_rotated(&self) -> bool {
         self.is_rotated
@@ -130,23 +159,24 @@ impl TileNode {
         self.is_rotated = is_rotated;
     }
 
-    /// Find a tile node in the tree
-    pub fn find_tile(&self, target: &TileNode) -> Option<&TileNode> {
-        if self == target {
-            return Some(self);
-        }
+    /// Check if this tile is locked in place
+    pub fn is_locked(&self) -> bool {
+        self.is_locked
+    }
 
-        if let Some(child1) = &self.child1 {
-            if let Some(found) = child1.find_tile(target) {
-                return Some(found);
-            }
-        }
+    /// Set the locked status
+    pub fn set_locked(&mut self, is_locked: bool) {
+        self.is_locked = is_locked;
+    }
 
-        if let Some(child2) = &self.child2 {
-            return child2.find_tile(target);
-        }
+    /// Find a tile node in the tree
+    pub fn find_tile(&self, target: &TileNode) -> Option<&TileNode> {
+        self.iter().find(|node| *node == target)
+    }
 
-        None
+    /// Find a tile node in the tree by its unique ID
+    pub fn find_tile_by_id(&self, id: u32) -> Option<&TileNode> {
+        self.iter().find(|node| node.id == id)
     }
 
     /// Replace a tile node in the tree
@@ -198,68 +228,25 @@ impl TileNode {
 
     /// Get all unused tiles (leaf nodes that are not final)
     pub fn unused_tiles(&self) -> Vec<&TileNode> {
-        let mut result = Vec::new();
-        self.collect_unused_tiles(&mut result);
-        result
-    }
-
-    /// Helper method to collect unused tiles
-    fn collect_unused_tiles<'a>(&'a self, result: &mut Vec<&'a TileNode>) {
-        if !self.is_final && self.child1.is_none() && self.child2.is_none() {
-            result.push(self);
-        }
-
-        if let Some(child1) = &self.child1 {
-            child1.collect_unused_tiles(result);
-        }
-
-        if let Some(child2) = &self.child2 {
-            child2.collect_unused_tiles(result);
-        }
+        self.iter().filter(|node| node.is_unused_leaf()).collect()
     }
 
     /// Get all final tiles
     pub fn final_tiles(&self) -> Vec<&Tile> {
-        let mut result = Vec::new();
-        self.collect_final_tiles(&mut result);
-        result
-    }
-
-    /// Helper method to collect final tiles
-    fn collect_final_tiles<'a>(&'a self, result: &mut Vec<&'a Tile>) {
-        if self.is_final {
-            result.push(&self.tile);
-        }
-
-        if let Some(child1) = &self.child1 {
-            child1.collect_final_tiles(result);
-        }
-
-        if let Some(child2) = &self.child2 {
-            child2.collect_final_tiles(result);
-        }
+        self.iter()
+            .filter(|node| node.is_final)
+            .map(|node| &node.tile)
+            .collect()
     }
 
     /// Get all final tile nodes
     pub fn final_tile_nodes(&self) -> Vec<&TileNode> {
-        let mut result = Vec::new();
-        self.collect_final_tile_nodes(&mut result);
-        result
+        self.iter().filter(|node| node.is_final).collect()
     }
 
-    /// Helper method to collect final tile nodes
-    fn collect_final_tile_nodes<'a>(&'a self, result: &mut Vec<&'a TileNode>) {
-        if self.is_final {
-            result.push(self);
-        }
-
-        if let Some(child1) = &self.child1 {
-            child1.collect_final_tile_nodes(result);
-        }
-
-        if let Some(child2) = &self.child2 {
-            child2.collect_final_tile_nodes(result);
-        }
+    /// Check whether this node is a leaf that has not been assigned a panel
+    fn is_unused_leaf(&self) -> bool {
+        !self.is_final && self.child1.is_none() && self.child2.is_none()
     }
 
     /// Get the unused area
@@ -279,147 +266,77 @@ impl TileNode {
 
     /// Check if this node or any child has final tiles
     pub fn has_final(&self) -> bool {
-        if self.is_final {
-            return true;
-        }
-
-        if let Some(child1) = &self.child1 {
-            if child1.has_final() {
-                return true;
-            }
-        }
-
-        if let Some(child2) = &self.child2 {
-            if child2.has_final() {
-                return true;
-            }
-        }
-
-        false
+        self.iter().any(|node| node.is_final)
     }
 
     /// Count the number of unused tiles
     pub fn count_unused_tiles(&self) -> usize {
-        let mut count = 0;
-
-        if !self.is_final && self.child1.is_none() && self.child2.is_none() {
-            count += 1;
-        }
-
-        if let Some(child1) = &self.child1 {
-            count += child1.count_unused_tiles();
-        }
-
-        if let Some(child2) = &self.child2 {
-            count += child2.count_unused_tiles();
-        }
-
-        count
+        self.iter().filter(|node| node.is_unused_leaf()).count()
     }
 
     /// Get the depth of the tree
     pub fn depth(&self) -> usize {
-        let mut depth = 0;
+        let mut max_depth = 0;
+        let mut stack: Vec<(&TileNode, usize)> = vec![(self, 0)];
 
-        if let Some(child1) = &self.child1 {
-            depth = depth.max(1 + child1.depth());
-        }
-
-        if let Some(child2) = &self.child2 {
-            depth = depth.max(1 + child2.depth());
+        while let Some((node, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            for child in [node.child1.as_deref(), node.child2.as_deref()].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
         }
 
-        depth
+        max_depth
     }
 
     /// Count the number of final tiles
     pub fn count_final_tiles(&self) -> usize {
-        let mut count = if self.is_final { 1 } else { 0 };
-
-        if let Some(child1) = &self.child1 {
-            count += child1.count_final_tiles();
-        }
-
-        if let Some(child2) = &self.child2 {
-            count += child2.count_final_tiles();
-        }
-
-        count
+        self.iter().filter(|node| node.is_final).count()
     }
 
     /// Get the biggest unused area
     pub fn biggest_area(&self) -> i64 {
-        let mut area = if self.child1.is_none() && self.child2.is_none() && !self.is_final {
-            self.area()
-        } else {
-            0
-        };
-
-        if let Some(child1) = &self.child1 {
-            area = area.max(child1.biggest_area());
-        }
-
-        if let Some(child2) = &self.child2 {
-            area = area.max(child2.biggest_area());
-        }
-
-        area
+        self.iter()
+            .filter(|node| node.is_unused_leaf())
+            .map(|node| node.area())
+            .max()
+            .unwrap_or(0)
     }
 
     /// Count final horizontal tiles
     pub fn count_final_horizontal(&self) -> usize {
-        let mut count = if self.is_final && self.is_horizontal() { 1 } else { 0 };
-
-        if let Some(child1) = &self.child1 {
-            count += child1.count_final_horizontal();
-        }
-
-        if let Some(child2) = &self.child2 {
-            count += child2.count_final_horizontal();
-        }
-
-        count
+        self.iter()
+            .filter(|node| node.is_final && node.is_horizontal())
+            .count()
     }
 
     /// Count final vertical tiles
     pub fn count_final_vertical(&self) -> usize {
-        let mut count = if self.is_final && self.is_vertical() { 1 } else { 0 };
-
-        if let Some(child1) = &self.child1 {
-            count += child1.count_final_vertical();
-        }
-
-        if let Some(child2) = &self.child2 {
-            count += child2.count_final_vertical();
-        }
-
-        count
+        self.iter()
+            .filter(|node| node.is_final && node.is_vertical())
+            .count()
     }
 
     /// Get distinct tile set using a hash-based approach
-    pub fn distinct_tile_set(&self) -> HashSet<i32> {
+    pub fn distinct_tile_set(&self) -> HashSet<i64> {
         let mut set = HashSet::new();
-        self.collect_distinct_tiles(&mut set);
-        set
-    }
-
-    /// Helper method to collect distinct tiles
-    fn collect_distinct_tiles(&self, set: &mut HashSet<i32>) {
-        if self.is_final {
-            let width = self.width();
-            let height = self.height();
-            let sum = width + height;
-            let hash_value = ((sum * (sum + 1)) / 2) + height;
-            set.insert(hash_value);
-        } else {
-            if let Some(child1) = &self.child1 {
-                child1.collect_distinct_tiles(set);
-            }
-
-            if let Some(child2) = &self.child2 {
-                child2.collect_distinct_tiles(set);
+        let mut stack: Vec<&TileNode> = vec![self];
+
+        // Final nodes are not descended into
+        while let Some(node) = stack.pop() {
+            if node.is_final {
+                let width = node.width() as i64;
+                let height = node.height() as i64;
+                let sum = width + height;
+                let hash_value = ((sum * (sum + 1)) / 2) + height;
+                set.insert(hash_value);
+            } else {
+                stack.extend(node.child2.as_deref());
+                stack.extend(node.child1.as_deref());
             }
         }
+
+        set
     }
 
     /// Convert to TileDimensions
@@ -428,65 +345,57 @@ impl TileNode {
             id: self.external_id.unwrap_or(self.id as i32),
             width: self.width(),
             height: self.height(),
-            label: None,
+            label: self.label.clone(),
             material: String::from("default"),
             orientation: Orientation::Any,
             is_rotated: self.is_rotated,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         }
     }
 
     /// Create a string identifier for this node
     pub fn string_identifier(&self) -> String {
         let mut result = String::new();
-        self.append_string_identifier(&mut result);
-        result
-    }
-
-    /// Helper method to append string identifier
-    fn append_string_identifier(&self, result: &mut String) {
-        result.push_str(&self.x1().to_string());
-        result.push_str(&self.y1().to_string());
-        result.push_str(&self.x2().to_string());
-        result.push_str(&self.y2().to_string());
-        result.push_str(&self.is_final.to_string());
-
-        if let Some(child1) = &self.child1 {
-            child1.append_string_identifier(result);
-        }
-
-        if let Some(child2) = &self.child2 {
-            child2.append_string_identifier(result);
+        for node in self.iter() {
+            result.push_str(&node.x1().to_string());
+            result.push_str(&node.y1().to_string());
+            result.push_str(&node.x2().to_string());
+            result.push_str(&node.y2().to_string());
+            result.push_str(&node.is_final.to_string());
         }
+        result
     }
 
     /// Create a tree representation string
     pub fn tree_string(&self) -> String {
-        self.append_tree_string("")
-    }
-
-    /// Helper method to append tree string with indentation
-    fn append_tree_string(&self, indent: &str) -> String {
-        let mut result = format!(
-            "\n{}({}, {})({}, {})",
-            indent,
-            self.x1(),
-            self.y1(),
-            self.x2(),
-            self.y2()
-        );
-
-        if self.is_final {
-            result.push('*');
-        }
-
-        if let Some(child1) = &self.child1 {
-            let new_indent = format!("{}    ", indent);
-            result.push_str(&child1.append_tree_string(&new_indent));
-        }
+        let mut result = String::new();
+        let mut stack: Vec<(&TileNode, usize)> = vec![(self, 0)];
+
+        while let Some((node, level)) = stack.pop() {
+            result.push_str(&format!(
+                "\n{}({}, {})({}, {})",
+                "    ".repeat(level),
+                node.x1(),
+                node.y1(),
+                node.x2(),
+                node.y2()
+            ));
+
+            if node.is_final {
+                result.push('*');
+            }
 
-        if let Some(child2) = &self.child2 {
-            let new_indent = format!("{}    ", indent);
-            result.push_str(&child2.append_tree_string(&new_indent));
+            if let Some(child2) = node.child2.as_deref() {
+                stack.push((child2, level + 1));
+            }
+            if let Some(child1) = node.child1.as_deref() {
+                stack.push((child1, level + 1));
+            }
         }
 
         result
@@ -539,9 +448,12 @@ impl Default for TileNode {
         Self {
             id: NEXT_ID.fetch_add(1, Ordering::SeqCst),
             external_id: None,
+            label: None,
+            group: None,
             tile: Tile::default(),
             is_final: false,
             is_rotated: false,
+            is_locked: false,
             child1: None,
             child2: None,
             is_area_totally_used: false,
@@ -559,24 +471,28 @@ impl std::fmt::Display for TileNode {
 // Custom equality implementation that matches the Java logic
 impl PartialEq for TileNode {
     fn eq(&self, other: &Self) -> bool {
-        // Check basic properties
-        if self.id != other.id || self.tile != other.tile || self.is_final != other.is_final {
-            return false;
-        }
+        let mut stack: Vec<(&TileNode, &TileNode)> = vec![(self, other)];
 
-        // Check children combinations
-        match (&self.child1, &other.child1, &self.child2, &other.child2) {
-            // Both have no children
-            (None, None, None, None) => true,
-            // Both have only child1
-            (Some(c1), Some(o1), None, None) => c1 == o1,
-            // Both have only child2
-            (None, None, Some(c2), Some(o2)) => c2 == o2,
-            // Both have both children
-            (Some(c1), Some(o1), Some(c2), Some(o2)) => c1 == o1 && c2 == o2,
-            // Any other combination
-            _ => false,
+        while let Some((a, b)) = stack.pop() {
+            // Check basic properties
+            if a.id != b.id || a.tile != b.tile || a.is_final != b.is_final {
+                return false;
+            }
+
+            // Both nodes must have the same children present
+            match (a.child1.as_deref(), b.child1.as_deref()) {
+                (Some(c1), Some(o1)) => stack.push((c1, o1)),
+                (None, None) => {}
+                _ => return false,
+            }
+            match (a.child2.as_deref(), b.child2.as_deref()) {
+                (Some(c2), Some(o2)) => stack.push((c2, o2)),
+                (None, None) => {}
+                _ => return false,
+            }
         }
+
+        true
     }
 }
 
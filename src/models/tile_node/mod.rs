@@ -1,4 +1,7 @@
 pub mod structs;
 pub mod impls;
+pub mod traversal;
+pub mod serde_impls;
 
 pub use structs::TileNode;
+pub use traversal::TileNodeIter;
@@ -0,0 +1,147 @@
+//! Serialization for TileNode trees
+//!
+//! Trees are written as a flat, pre-order list of nodes where children are
+//! referenced by index (`{"nodes": [...]}`, root first). This keeps both
+//! serialization and deserialization iterative, and avoids the nesting limits
+//! that JSON parsers apply to deeply nested documents.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::structs::TileNode;
+use crate::models::Tile;
+
+/// A single node in the flat representation
+#[derive(Serialize, Deserialize)]
+struct FlatTileNode {
+    id: u32,
+    external_id: Option<i32>,
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(default)]
+    group: Option<i32>,
+    tile: Tile,
+    is_final: bool,
+    is_rotated: bool,
+    #[serde(default)]
+    is_locked: bool,
+    child1: Option<usize>,
+    child2: Option<usize>,
+    #[serde(default)]
+    is_area_totally_used: bool,
+    #[serde(default)]
+    totally_used_area: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FlatTileTree {
+    nodes: Vec<FlatTileNode>,
+}
+
+impl TileNode {
+    /// Flatten the tree into pre-order nodes with index-based child links
+    fn to_flat(&self) -> Vec<FlatTileNode> {
+        let mut nodes: Vec<FlatTileNode> = Vec::new();
+        // (node, parent index, is child1 of parent)
+        let mut stack: Vec<(&TileNode, Option<(usize, bool)>)> = vec![(self, None)];
+
+        while let Some((node, parent)) = stack.pop() {
+            let index = nodes.len();
+            nodes.push(FlatTileNode {
+                id: node.id,
+                external_id: node.external_id,
+                label: node.label.clone(),
+                group: node.group,
+                tile: node.tile.clone(),
+                is_final: node.is_final,
+                is_rotated: node.is_rotated,
+                is_locked: node.is_locked,
+                child1: None,
+                child2: None,
+                is_area_totally_used: node.is_area_totally_used,
+                totally_used_area: node.totally_used_area,
+            });
+
+            match parent {
+                Some((parent_index, true)) => nodes[parent_index].child1 = Some(index),
+                Some((parent_index, false)) => nodes[parent_index].child2 = Some(index),
+                None => {}
+            }
+
+            if let Some(child2) = node.child2.as_deref() {
+                stack.push((child2, Some((index, false))));
+            }
+            if let Some(child1) = node.child1.as_deref() {
+                stack.push((child1, Some((index, true))));
+            }
+        }
+
+        nodes
+    }
+
+    /// Rebuild a tree from flat nodes, validating the child links
+    fn from_flat(nodes: Vec<FlatTileNode>) -> Result<TileNode, String> {
+        if nodes.is_empty() {
+            return Err("tile node tree has no nodes".to_string());
+        }
+
+        let count = nodes.len();
+        let mut built: Vec<Option<TileNode>> = (0..count).map(|_| None).collect();
+
+        // Children always follow their parent, so build from the back
+        for (index, flat) in nodes.into_iter().enumerate().rev() {
+            let child1 = Self::take_built_child(&mut built, index, flat.child1)?;
+            let child2 = Self::take_built_child(&mut built, index, flat.child2)?;
+            built[index] = Some(TileNode {
+                id: flat.id,
+                external_id: flat.external_id,
+                label: flat.label,
+                group: flat.group,
+                tile: flat.tile,
+                is_final: flat.is_final,
+                is_rotated: flat.is_rotated,
+                is_locked: flat.is_locked,
+                child1,
+                child2,
+                is_area_totally_used: flat.is_area_totally_used,
+                totally_used_area: flat.totally_used_area,
+            });
+        }
+
+        if built.iter().skip(1).any(Option::is_some) {
+            return Err("tile node tree contains unreachable nodes".to_string());
+        }
+
+        built[0].take().ok_or_else(|| "tile node tree has no root".to_string())
+    }
+
+    fn take_built_child(
+        built: &mut [Option<TileNode>],
+        parent: usize,
+        child: Option<usize>,
+    ) -> Result<Option<Box<TileNode>>, String> {
+        let Some(child) = child else {
+            return Ok(None);
+        };
+        if child <= parent || child >= built.len() {
+            return Err(format!("node {} has invalid child index {}", parent, child));
+        }
+        built[child]
+            .take()
+            .map(|node| Some(Box::new(node)))
+            .ok_or_else(|| format!("node {} is referenced more than once", child))
+    }
+}
+
+impl Serialize for TileNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FlatTileTree { nodes: self.to_flat() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TileNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tree = FlatTileTree::deserialize(deserializer)?;
+        TileNode::from_flat(tree.nodes).map_err(D::Error::custom)
+    }
+}
@@ -1,4 +1,3 @@
-use serde::{Deserialize, Serialize};
 use std::sync::atomic::AtomicU32;
 use crate::models::Tile;
 
@@ -10,7 +9,9 @@ pub(super) static NEXT_ID: AtomicU32 = AtomicU32::new(0);
 /// This structure represents either a leaf node (final tile) or an internal node
 /// that has been split into two child nodes. It maintains spatial information
 /// through the contained Tile and tracks various properties for optimization.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// `Clone`, `Drop` and the serde impls are hand-written and iterative so that
+/// very deep trees cannot overflow the stack (see `traversal` and `serde_impls`).
+#[derive(Debug)]
 pub struct TileNode {
     /// Unique identifier for this node
     pub id: u32,
@@ -18,6 +19,12 @@ pub struct TileNode {
     /// External identifier (can be set by user, defaults to None)
     pub external_id: Option<i32>,
     
+    /// Label of the panel placed on this node, if it has one
+    pub label: Option<String>,
+    
+    /// Group shared by the rectangles of one L-shaped panel, so they can be reassembled
+    pub group: Option<i32>,
+    
     /// The tile representing the spatial bounds of this node
     pub tile: Tile,
     
@@ -27,6 +34,9 @@ pub struct TileNode {
     /// Whether the tile has been rotated from its original orientation
     pub is_rotated: bool,
     
+    /// Whether this final tile is pinned in place and must not be split or reassigned
+    pub is_locked: bool,
+    
     /// First child node (if this node has been split)
     pub child1: Option<Box<TileNode>>,
     
@@ -0,0 +1,96 @@
+//! Stack-safe traversal for TileNode trees
+//!
+//! Long chains of sequential cuts produce trees that are thousands of levels
+//! deep. Walking, cloning or dropping them recursively can exhaust the thread
+//! stack, so the operations here use an explicit heap-allocated stack instead.
+
+use super::structs::TileNode;
+
+/// Pre-order iterator over a tile node and all of its descendants
+///
+/// Nodes are yielded parent first, then the `child1` subtree, then the
+/// `child2` subtree - the same order as the recursive walkers it replaces.
+pub struct TileNodeIter<'a> {
+    stack: Vec<&'a TileNode>,
+}
+
+impl<'a> Iterator for TileNodeIter<'a> {
+    type Item = &'a TileNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        if let Some(child2) = node.child2.as_deref() {
+            self.stack.push(child2);
+        }
+        if let Some(child1) = node.child1.as_deref() {
+            self.stack.push(child1);
+        }
+        Some(node)
+    }
+}
+
+impl TileNode {
+    /// Iterate over this node and all descendants in pre-order without recursion
+    pub fn iter(&self) -> TileNodeIter<'_> {
+        TileNodeIter { stack: vec![self] }
+    }
+
+    /// Copy this node's own fields with the given children attached
+    fn with_children(&self, child1: Option<Box<TileNode>>, child2: Option<Box<TileNode>>) -> TileNode {
+        TileNode {
+            id: self.id,
+            external_id: self.external_id,
+            label: self.label.clone(),
+            group: self.group,
+            tile: self.tile.clone(),
+            is_final: self.is_final,
+            is_rotated: self.is_rotated,
+            is_locked: self.is_locked,
+            child1,
+            child2,
+            is_area_totally_used: self.is_area_totally_used,
+            totally_used_area: self.totally_used_area,
+        }
+    }
+}
+
+impl Clone for TileNode {
+    fn clone(&self) -> Self {
+        // Post-order walk: children are copied before the parent that owns them
+        let mut pending: Vec<(&TileNode, bool)> = vec![(self, false)];
+        let mut copied: Vec<TileNode> = Vec::new();
+
+        while let Some((node, children_copied)) = pending.pop() {
+            if !children_copied {
+                pending.push((node, true));
+                if let Some(child2) = node.child2.as_deref() {
+                    pending.push((child2, false));
+                }
+                if let Some(child1) = node.child1.as_deref() {
+                    pending.push((child1, false));
+                }
+            } else {
+                // child2 finished last, so it sits on top of child1
+                let child2 = node.child2.as_ref().and_then(|_| copied.pop()).map(Box::new);
+                let child1 = node.child1.as_ref().and_then(|_| copied.pop()).map(Box::new);
+                copied.push(node.with_children(child1, child2));
+            }
+        }
+
+        copied.pop().expect("clone always produces the root node")
+    }
+}
+
+impl Drop for TileNode {
+    fn drop(&mut self) {
+        // Detach descendants so each one drops with no children of its own
+        let mut stack: Vec<Box<TileNode>> = Vec::new();
+        stack.extend(self.child1.take());
+        stack.extend(self.child2.take());
+
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.child1.take());
+            stack.extend(node.child2.take());
+        }
+    }
+}
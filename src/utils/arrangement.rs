@@ -152,3 +152,79 @@ pub fn factorial(n: usize) -> Option<usize> {
 pub fn expected_permutation_count(input_size: usize) -> Option<usize> {
     factorial(input_size)
 }
+
+/// Shuffle `list` in place with a deterministic generator seeded by `seed`
+///
+/// The same input and seed always give the same order, independent of
+/// platform or run. Uses a Fisher-Yates shuffle driven by SplitMix64.
+///
+/// # Examples
+/// ```
+/// use cutlist_optimizer_cli::utils::arrangement::seeded_shuffle;
+///
+/// let mut a = vec![1, 2, 3, 4, 5];
+/// let mut b = a.clone();
+/// seeded_shuffle(&mut a, 42);
+/// seeded_shuffle(&mut b, 42);
+/// assert_eq!(a, b);
+/// ```
+pub fn seeded_shuffle<T>(list: &mut [T], seed: u64) {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+
+    for i in (1..list.len()).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        list.swap(i, j);
+    }
+}
+
+/// Up to `max` distinct orders of `list`, starting with `list` as given
+///
+/// When `list` has no more than `max` permutations, all of them are returned.
+/// Otherwise the given order, which callers sort by their own heuristic, is
+/// followed by seeded shuffles of it; attempt `i` shuffles with
+/// `seed + i`, so the same input and seed always give the same orders.
+/// Repeated orders are skipped. Sampling gives up after `max` times a small
+/// factor of attempts, so in rare cases fewer than `max` orders come back.
+///
+/// # Examples
+/// ```
+/// use cutlist_optimizer_cli::utils::arrangement::sample_permutations;
+///
+/// let orders = sample_permutations((0..10).collect::<Vec<_>>(), 20, 7);
+/// assert_eq!(orders.len(), 20);
+/// assert_eq!(orders[0], (0..10).collect::<Vec<_>>());
+/// ```
+pub fn sample_permutations<T: Clone>(list: Vec<T>, max: usize, seed: u64) -> Vec<Vec<T>> {
+    if max == 0 {
+        return Vec::new();
+    }
+    if factorial(list.len()).is_some_and(|count| count <= max) {
+        return generate_permutations(list);
+    }
+
+    const ATTEMPTS_PER_ORDER: usize = 20;
+    let identity: Vec<usize> = (0..list.len()).collect();
+    let mut seen = std::collections::HashSet::from([identity.clone()]);
+    let mut orders = vec![identity.clone()];
+    let mut attempt = 1u64;
+    while orders.len() < max && attempt as usize <= max.saturating_mul(ATTEMPTS_PER_ORDER) {
+        let mut order = identity.clone();
+        seeded_shuffle(&mut order, seed.wrapping_add(attempt));
+        attempt += 1;
+        if seen.insert(order.clone()) {
+            orders.push(order);
+        }
+    }
+
+    orders
+        .into_iter()
+        .map(|order| order.into_iter().map(|index| list[index].clone()).collect())
+        .collect()
+}
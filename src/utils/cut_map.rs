@@ -0,0 +1,334 @@
+//! Printable cut map rendering
+//!
+//! This module rasterizes the layout of each used stock sheet into a PNG image
+//! suitable for shop printouts. Every placed panel is drawn with its label and
+//! its dimensions in real units; the remaining sheet area is shaded as offcut.
+//! Dimensions are bare millimetres unless a `UnitFormat` is given.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::errors::{CoreError, Result};
+use crate::models::{Mosaic, TileNode};
+use crate::utils::math::{self, UnitFormat};
+
+/// Longest side of the drawn sheet in pixels
+const MAX_SHEET_PIXELS: f64 = 1200.0;
+
+/// Blank border around the drawn sheet in pixels
+const MARGIN_PIXELS: usize = 24;
+
+/// Height reserved above the sheet for the title line
+const TITLE_PIXELS: usize = 24;
+
+const BACKGROUND: [u8; 3] = [255, 255, 255];
+const OFFCUT: [u8; 3] = [214, 214, 214];
+const PANEL: [u8; 3] = [245, 222, 179];
+const OUTLINE: [u8; 3] = [40, 40, 40];
+const TEXT: [u8; 3] = [0, 0, 0];
+
+/// Glyph width and height of the built-in bitmap font
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 7;
+
+/// Render a single mosaic (one stock sheet) as a PNG image
+///
+/// # Arguments
+/// * `mosaic` - The sheet layout to draw
+/// * `sheet_number` - 1-based number printed in the title line
+/// * `labels` - Panel labels keyed by request panel id; panels without a label show their id
+/// * `scale_factor` - Factor the integer layout was scaled by, used to print real dimensions
+/// * `units` - Unit dimensions are printed in; bare millimetres when `None`
+///
+/// # Returns
+/// The encoded PNG file contents
+pub fn render_mosaic_png(
+    mosaic: &Mosaic,
+    sheet_number: usize,
+    labels: &HashMap<i32, String>,
+    scale_factor: f64,
+    units: Option<UnitFormat>,
+) -> Vec<u8> {
+    let root = mosaic.root_tile_node();
+    let sheet_width = root.width().max(1) as f64;
+    let sheet_height = root.height().max(1) as f64;
+    let pixels_per_unit = MAX_SHEET_PIXELS / sheet_width.max(sheet_height);
+
+    let drawn_width = (sheet_width * pixels_per_unit).round().max(1.0) as usize;
+    let drawn_height = (sheet_height * pixels_per_unit).round().max(1.0) as usize;
+    let mut canvas = Canvas::new(
+        drawn_width + 2 * MARGIN_PIXELS,
+        drawn_height + 2 * MARGIN_PIXELS + TITLE_PIXELS,
+    );
+
+    let origin_x = MARGIN_PIXELS;
+    let origin_y = MARGIN_PIXELS + TITLE_PIXELS;
+    let to_px = |value: i32, origin: usize| origin + (value as f64 * pixels_per_unit).round() as usize;
+
+    let title = format!(
+        "SHEET {}  {} X {}  {}",
+        sheet_number,
+        format_length(root.width(), scale_factor, units),
+        format_length(root.height(), scale_factor, units),
+        mosaic.material()
+    );
+    canvas.draw_text(origin_x, MARGIN_PIXELS, &title, 2, TEXT);
+
+    canvas.fill_rect(origin_x, origin_y, drawn_width, drawn_height, OFFCUT);
+
+    for node in root.final_tile_nodes() {
+        let x = to_px(node.x1() - root.x1(), origin_x);
+        let y = to_px(node.y1() - root.y1(), origin_y);
+        let w = to_px(node.x2() - root.x1(), origin_x).saturating_sub(x).max(1);
+        let h = to_px(node.y2() - root.y1(), origin_y).saturating_sub(y).max(1);
+
+        canvas.fill_rect(x, y, w, h, PANEL);
+        canvas.stroke_rect(x, y, w, h, 1, OUTLINE);
+        draw_panel_caption(&mut canvas, node, (x, y, w, h), labels, scale_factor, units);
+    }
+
+    canvas.stroke_rect(origin_x, origin_y, drawn_width, drawn_height, 2, OUTLINE);
+
+    encode_png(canvas.width, canvas.height, &canvas.pixels)
+}
+
+/// Write one PNG cut map per mosaic into `output_dir`
+///
+/// The directory is created if needed and files are named `sheet_01.png`,
+/// `sheet_02.png`, ... in mosaic order.
+///
+/// # Returns
+/// The paths of the written files
+pub fn write_cut_map_pngs(
+    mosaics: &[Mosaic],
+    labels: &HashMap<i32, String>,
+    scale_factor: f64,
+    units: Option<UnitFormat>,
+    output_dir: &Path,
+) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(output_dir).map_err(CoreError::from)?;
+
+    let mut written = Vec::with_capacity(mosaics.len());
+    for (index, mosaic) in mosaics.iter().enumerate() {
+        let path = output_dir.join(format!("sheet_{:02}.png", index + 1));
+        let png = render_mosaic_png(mosaic, index + 1, labels, scale_factor, units);
+        fs::write(&path, png).map_err(CoreError::from)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Draw the label and dimension lines centered in a placed panel, if they fit
+fn draw_panel_caption(
+    canvas: &mut Canvas,
+    node: &TileNode,
+    (x, y, w, h): (usize, usize, usize, usize),
+    labels: &HashMap<i32, String>,
+    scale_factor: f64,
+    units: Option<UnitFormat>,
+) {
+    let label = node
+        .external_id()
+        .and_then(|id| labels.get(&id).cloned().or_else(|| Some(format!("#{}", id))))
+        .unwrap_or_default();
+    // Values with a unit suffix need room around the separator
+    let separator = if units.is_some() { " x " } else { "x" };
+    let dimensions = format!(
+        "{}{}{}",
+        format_length(node.width(), scale_factor, units),
+        separator,
+        format_length(node.height(), scale_factor, units)
+    );
+
+    let lines: Vec<&str> = [label.as_str(), dimensions.as_str()]
+        .into_iter()
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    for scale in [2, 1] {
+        let line_height = (GLYPH_HEIGHT + 2) * scale;
+        let block_height = line_height * lines.len();
+        let fits = block_height + 4 <= h
+            && lines.iter().all(|line| Canvas::text_width(line, scale) + 4 <= w);
+        if !fits {
+            continue;
+        }
+
+        let mut line_y = y + (h - block_height) / 2 + scale;
+        for line in &lines {
+            let line_x = x + (w - Canvas::text_width(line, scale)) / 2;
+            canvas.draw_text(line_x, line_y, line, scale, TEXT);
+            line_y += line_height;
+        }
+        return;
+    }
+}
+
+/// Format a scaled integer dimension back into real units
+///
+/// With a unit the millimetre value goes through `math::format_dimension`;
+/// without one it is printed bare, with at most two decimals.
+pub(crate) fn format_length(value: i32, scale_factor: f64, units: Option<UnitFormat>) -> String {
+    let real = value as f64 / scale_factor;
+    if let Some(unit) = units {
+        return math::format_dimension(real, unit);
+    }
+    if (real - real.round()).abs() < 1e-9 {
+        format!("{}", real.round() as i64)
+    } else {
+        let text = format!("{:.2}", real);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// Minimal RGB raster used to build cut map images
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for _ in 0..width * height {
+            pixels.extend_from_slice(&BACKGROUND);
+        }
+        Self { width, height, pixels }
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: [u8; 3]) {
+        if x < self.width && y < self.height {
+            let offset = (y * self.width + x) * 3;
+            self.pixels[offset..offset + 3].copy_from_slice(&color);
+        }
+    }
+
+    fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: [u8; 3]) {
+        for py in y..(y + h).min(self.height) {
+            for px in x..(x + w).min(self.width) {
+                self.set_pixel(px, py, color);
+            }
+        }
+    }
+
+    fn stroke_rect(&mut self, x: usize, y: usize, w: usize, h: usize, thickness: usize, color: [u8; 3]) {
+        let t = thickness.min(w).min(h);
+        self.fill_rect(x, y, w, t, color);
+        self.fill_rect(x, (y + h).saturating_sub(t), w, t, color);
+        self.fill_rect(x, y, t, h, color);
+        self.fill_rect((x + w).saturating_sub(t), y, t, h, color);
+    }
+
+    fn text_width(text: &str, scale: usize) -> usize {
+        let chars = text.chars().count();
+        if chars == 0 {
+            0
+        } else {
+            (chars * (GLYPH_WIDTH + 1) - 1) * scale
+        }
+    }
+
+    fn draw_text(&mut self, x: usize, y: usize, text: &str, scale: usize, color: [u8; 3]) {
+        let mut cursor = x;
+        for ch in text.chars() {
+            let rows = glyph(ch);
+            for (row, bits) in rows.iter().enumerate() {
+                for col in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+                        self.fill_rect(cursor + col * scale, y + row * scale, scale, scale, color);
+                    }
+                }
+            }
+            cursor += (GLYPH_WIDTH + 1) * scale;
+        }
+    }
+}
+
+/// 5x7 bitmap glyphs; lowercase letters other than `x` are drawn as uppercase
+fn glyph(ch: char) -> [u8; GLYPH_HEIGHT] {
+    match ch.to_ascii_uppercase() {
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'X' if ch == 'x' => [0x00, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11],
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '_' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F],
+        '/' => [0x01, 0x01, 0x02, 0x04, 0x08, 0x10, 0x10],
+        ' ' => [0x00; GLYPH_HEIGHT],
+        _ => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+    }
+}
+
+/// Encode an 8-bit RGB buffer as a PNG file
+fn encode_png(width: usize, height: usize, rgb: &[u8]) -> Vec<u8> {
+    let stride = width * 3;
+    let mut raw = Vec::with_capacity((stride + 1) * height);
+    for row in rgb.chunks(stride) {
+        raw.push(0); // filter type: none
+        raw.extend_from_slice(row);
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]); // bit depth, RGB, deflate, no filter, no interlace
+
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &miniz_oxide::deflate::compress_to_vec_zlib(&raw, 6));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in kind.iter().chain(data) {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    png.extend_from_slice(&(crc ^ 0xFFFF_FFFF).to_be_bytes());
+}
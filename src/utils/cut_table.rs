@@ -0,0 +1,136 @@
+//! Plain-text cut lists for the saw
+//!
+//! This module prints the layout of each used stock sheet as aligned ASCII
+//! tables: first the cuts in an order that can be followed at the saw (see
+//! `Mosaic::cut_sequence`, or `Mosaic::cut_sequence_optimized` to turn the
+//! sheet as rarely as possible), then the parts those cuts produce with their
+//! labels, dimensions and positions in real units, bare millimetres unless a
+//! `UnitFormat` is given.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::models::enums::CutDirection;
+use crate::models::Mosaic;
+
+use super::cut_map::format_length;
+use super::math::UnitFormat;
+
+const CUT_HEADERS: [&str; 4] = ["Step", "Piece", "Piece size", "Cut at"];
+const PART_HEADERS: [&str; 6] = ["#", "Part", "Label", "Size", "X", "Y"];
+
+/// Render the cut list of every mosaic as aligned ASCII tables
+///
+/// # Arguments
+/// * `mosaics` - The sheet layouts to list, in print order
+/// * `labels` - Panel labels keyed by request panel id, used when a placed node has no label
+/// * `scale_factor` - Factor the integer layout was scaled by, used to print real dimensions
+/// * `units` - Unit dimensions are printed in; bare millimetres when `None`
+/// * `optimize_cut_order` - List cuts grouped by direction and report how often the sheet is turned
+pub fn render_cut_table(
+    mosaics: &[Mosaic],
+    labels: &HashMap<i32, String>,
+    scale_factor: f64,
+    units: Option<UnitFormat>,
+    optimize_cut_order: bool,
+) -> String {
+    let dimension = |value: i32| format_length(value, scale_factor, units);
+
+    let mut out = String::new();
+    for (index, mosaic) in mosaics.iter().enumerate() {
+        let root = mosaic.root_tile_node();
+        if index > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(
+            out,
+            "Sheet {}: {} x {} {}",
+            index + 1,
+            dimension(root.width()),
+            dimension(root.height()),
+            mosaic.material()
+        );
+
+        let steps = if optimize_cut_order {
+            mosaic.cut_sequence_optimized()
+        } else {
+            mosaic.cut_sequence()
+        };
+        let cuts: Vec<Vec<String>> = steps
+            .iter()
+            .map(|step| {
+                let axis = match step.direction {
+                    CutDirection::Horizontal => "x",
+                    _ => "y",
+                };
+                vec![
+                    (step.index + 1).to_string(),
+                    step.piece_id.to_string(),
+                    format!("{} x {}", dimension(step.piece_width), dimension(step.piece_height)),
+                    format!("{} = {}", axis, dimension(step.position)),
+                ]
+            })
+            .collect();
+        if optimize_cut_order {
+            let _ = writeln!(out, "Cuts ({} direction changes)", Mosaic::direction_changes(&steps));
+        } else {
+            out.push_str("Cuts\n");
+        }
+        out.push_str(&render_table(&CUT_HEADERS, &cuts));
+
+        let parts: Vec<Vec<String>> = root
+            .final_tile_nodes()
+            .iter()
+            .enumerate()
+            .map(|(part_index, node)| {
+                let label = node
+                    .label()
+                    .map(str::to_string)
+                    .or_else(|| node.external_id().and_then(|id| labels.get(&id).cloned()))
+                    .unwrap_or_default();
+                vec![
+                    (part_index + 1).to_string(),
+                    node.external_id().map(|id| id.to_string()).unwrap_or_default(),
+                    label,
+                    format!("{} x {}", dimension(node.width()), dimension(node.height())),
+                    dimension(node.x1() - root.x1()),
+                    dimension(node.y1() - root.y1()),
+                ]
+            })
+            .collect();
+        out.push_str("Parts\n");
+        out.push_str(&render_table(&PART_HEADERS, &parts));
+    }
+
+    out
+}
+
+/// Lay out rows under headers with every column padded to its widest cell
+fn render_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let separator = widths
+        .iter()
+        .fold(String::from("+"), |line, width| line + &"-".repeat(width + 2) + "+");
+    let format_row = |cells: &mut dyn Iterator<Item = &str>| {
+        widths.iter().zip(cells).fold(String::from("|"), |line, (width, cell)| {
+            let padding = width - cell.chars().count();
+            line + " " + cell + &" ".repeat(padding) + " |"
+        })
+    };
+
+    let mut table = String::new();
+    let _ = writeln!(table, "{}", separator);
+    let _ = writeln!(table, "{}", format_row(&mut headers.iter().copied()));
+    let _ = writeln!(table, "{}", separator);
+    for row in rows {
+        let _ = writeln!(table, "{}", format_row(&mut row.iter().map(String::as_str)));
+    }
+    let _ = writeln!(table, "{}", separator);
+    table
+}
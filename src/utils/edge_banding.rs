@@ -4,7 +4,7 @@
 //! for panels based on their placement in tile nodes and edge configurations.
 
 use std::collections::HashMap;
-use crate::models::{TileNode, Panel};
+use crate::models::{Edge, TileNode, Panel};
 
 /// Calculate edge banding requirements for a list of tile nodes and panels
 /// 
@@ -44,24 +44,48 @@ pub fn calc_edge_bands(
         
         // Find the corresponding tile node for this panel
         if let Some(tile_node) = find_tile_node_by_panel_id(tile_nodes, panel.id) {
-            // Calculate dimensions based on rotation
-            let (width_for_horizontal, height_for_vertical) = if tile_node.is_rotated() {
-                (tile_node.height(), tile_node.width())
-            } else {
-                (tile_node.width(), tile_node.height())
-            };
-            
-            // Add edge banding for each side that has a material specified
-            add_edge_banding(&mut edge_map, &edge.top, width_for_horizontal as f64, scale_factor);
-            add_edge_banding(&mut edge_map, &edge.left, height_for_vertical as f64, scale_factor);
-            add_edge_banding(&mut edge_map, &edge.bottom, width_for_horizontal as f64, scale_factor);
-            add_edge_banding(&mut edge_map, &edge.right, height_for_vertical as f64, scale_factor);
+            add_tile_edge_bands(&mut edge_map, tile_node, edge, scale_factor);
         }
     }
     
     edge_map
 }
 
+/// Calculate edge banding requirements for a single placed tile
+/// 
+/// `edge` describes the banded sides in the panel's original orientation;
+/// rotated tiles are swapped back before measuring.
+pub fn calc_tile_edge_bands(
+    tile_node: &TileNode,
+    edge: &Edge,
+    scale_factor: f64
+) -> HashMap<String, f64> {
+    let mut edge_map: HashMap<String, f64> = HashMap::new();
+    add_tile_edge_bands(&mut edge_map, tile_node, edge, scale_factor);
+    edge_map
+}
+
+/// Add the banding of every specified side of a tile to the map
+fn add_tile_edge_bands(
+    edge_map: &mut HashMap<String, f64>,
+    tile_node: &TileNode,
+    edge: &Edge,
+    scale_factor: f64
+) {
+    // Calculate dimensions based on rotation
+    let (width_for_horizontal, height_for_vertical) = if tile_node.is_rotated() {
+        (tile_node.height(), tile_node.width())
+    } else {
+        (tile_node.width(), tile_node.height())
+    };
+    
+    // Add edge banding for each side that has a material specified
+    add_edge_banding(edge_map, &edge.top, width_for_horizontal as f64, scale_factor);
+    add_edge_banding(edge_map, &edge.left, height_for_vertical as f64, scale_factor);
+    add_edge_banding(edge_map, &edge.bottom, width_for_horizontal as f64, scale_factor);
+    add_edge_banding(edge_map, &edge.right, height_for_vertical as f64, scale_factor);
+}
+
 /// Find a tile node by panel ID
 /// 
 /// Searches through the tile nodes to find one with the matching external_id
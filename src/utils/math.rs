@@ -3,6 +3,9 @@
 //! This module provides common mathematical operations and calculations
 //! used throughout the cutting optimization system.
 
+use crate::models::TileDimensions;
+use crate::utils::constants::{INCHES_TO_MM, MM_TO_M};
+
 /// Calculate percentage between two numbers
 /// 
 /// This function calculates what percentage the `part` represents of the `total`.
@@ -162,6 +165,101 @@ pub fn rectangle_perimeter(width: f64, height: f64) -> f64 {
     2.0 * (width + height)
 }
 
+/// Convert an area in scaled units to real mm²
+///
+/// Dimensions are scaled by `factor` before optimization, so areas are
+/// scaled by `factor²`.
+///
+/// # Examples
+/// ```
+/// use cutlist_optimizer_cli::utils::math::scaled_to_mm2;
+///
+/// assert_eq!(scaled_to_mm2(600_000, 100.0), 60.0);
+/// ```
+pub fn scaled_to_mm2(scaled_area: i64, factor: f64) -> f64 {
+    scaled_area as f64 / (factor * factor)
+}
+
+/// Calculate the real area in mm² of scaled dimensions
+///
+/// The product is computed in `i64`, so large sheets at high accuracy
+/// don't overflow.
+///
+/// # Arguments
+/// * `width` - Scaled width
+/// * `height` - Scaled height
+/// * `factor` - Factor the dimensions were scaled by
+pub fn area(width: i32, height: i32, factor: f64) -> f64 {
+    scaled_to_mm2(width as i64 * height as i64, factor)
+}
+
+/// Calculate the real perimeter in mm of scaled dimensions
+///
+/// # Arguments
+/// * `width` - Scaled width
+/// * `height` - Scaled height
+/// * `factor` - Factor the dimensions were scaled by
+pub fn perimeter(width: i32, height: i32, factor: f64) -> f64 {
+    rectangle_perimeter(width as f64, height as f64) / factor
+}
+
+/// Calculate the real area in mm² of scaled tile dimensions
+pub fn tile_area(tile: &TileDimensions, factor: f64) -> f64 {
+    area(tile.width, tile.height, factor)
+}
+
+/// Calculate the real perimeter in mm of scaled tile dimensions
+pub fn tile_perimeter(tile: &TileDimensions, factor: f64) -> f64 {
+    perimeter(tile.width, tile.height, factor)
+}
+
+/// Unit a millimetre dimension is displayed in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnitFormat {
+    #[default]
+    Millimeters,
+    Centimeters,
+    Meters,
+    Inches,
+}
+
+impl UnitFormat {
+    /// Convert a value in millimetres to this unit
+    pub fn convert_mm(self, value: f64) -> f64 {
+        match self {
+            UnitFormat::Millimeters => value,
+            UnitFormat::Centimeters => value * MM_TO_M * 100.0,
+            UnitFormat::Meters => value * MM_TO_M,
+            UnitFormat::Inches => value / INCHES_TO_MM,
+        }
+    }
+
+    /// Suffix printed after a formatted value
+    pub fn suffix(self) -> &'static str {
+        match self {
+            UnitFormat::Millimeters => "mm",
+            UnitFormat::Centimeters => "cm",
+            UnitFormat::Meters => "m",
+            UnitFormat::Inches => "in",
+        }
+    }
+}
+
+/// Format a dimension given in millimetres in the requested unit
+///
+/// The value is converted and printed with two decimals and the unit suffix.
+///
+/// # Examples
+/// ```
+/// use cutlist_optimizer_cli::utils::math::{format_dimension, UnitFormat};
+///
+/// assert_eq!(format_dimension(1000.0, UnitFormat::Meters), "1.00 m");
+/// assert_eq!(format_dimension(1000.0, UnitFormat::Inches), "39.37 in");
+/// ```
+pub fn format_dimension(value: f64, unit: UnitFormat) -> String {
+    format!("{:.2} {}", unit.convert_mm(value), unit.suffix())
+}
+
 /// Statistical functions
 pub mod statistics {
     /// Calculate the mean (average) of a slice of numbers
@@ -10,6 +10,8 @@
 //! - [`edge_banding`] - Edge banding calculation utilities for panel processing
 //! - [`timing`] - Performance measurement and timing utilities
 //! - [`math`] - Mathematical functions and calculations
+//! - [`cut_map`] - Printable PNG cut maps of sheet layouts
+//! - [`cut_table`] - Plain-text cut lists of sheet layouts
 //! 
 //! # Quick Start
 //! 
@@ -40,12 +42,16 @@ pub mod arrangement;
 pub mod edge_banding;
 pub mod timing;
 pub mod math;
+pub mod cut_map;
+pub mod cut_table;
 
 // Re-export commonly used items for convenience
 pub use timing::{Timer, format_duration};
 pub use math::percentage;
 pub use arrangement::generate_permutations;
 pub use edge_banding::{calc_edge_bands, calc_edge_bands_safe};
+pub use cut_map::{render_mosaic_png, write_cut_map_pngs};
+pub use cut_table::render_cut_table;
 
 /// Utility result type for operations that can fail
 pub type UtilResult<T> = Result<T, UtilError>;
@@ -183,6 +183,27 @@ pub mod conversions {
     }
 }
 
+/// Serde support for an optional duration written as whole milliseconds
+///
+/// Use with `#[serde(default, with = "crate::utils::timing::optional_millis")]`;
+/// `None` is written as `null`.
+pub mod optional_millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_u64(duration.as_millis().try_into().unwrap_or(u64::MAX)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+    }
+}
+
 /// Performance measurement utilities
 pub mod performance {
     use super::*;
@@ -0,0 +1,5 @@
+//! Tests for the command-line interface
+
+pub mod validate_command_tests;
+pub mod table_output_tests;
+pub mod search_option_tests;
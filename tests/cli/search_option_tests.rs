@@ -0,0 +1,85 @@
+//! Tests for the `optimize --search` option
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+fn write_job(dir: &tempfile::TempDir) -> std::path::PathBuf {
+    let path = dir.path().join("input.csv");
+    std::fs::write(
+        &path,
+        "1,600,400,2,plywood,Door\n\
+         2,300,200,3,plywood,Shelf\n\
+         # stock\n\
+         100,1000,1000,2,plywood,Sheet\n",
+    )
+    .unwrap();
+    path
+}
+
+#[test]
+fn test_parallel_and_permutation_searches_run_from_the_command_line() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_job(&dir);
+
+    for search in [&["--search", "parallel"][..], &["--search", "permutations", "--max-permutations", "4"][..]] {
+        Command::cargo_bin("cutlist")
+            .unwrap()
+            .args(["--threads", "2", "optimize", "--cut-thickness", "0"])
+            .args(search)
+            .arg("--input")
+            .arg(&path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\"mosaics\""));
+    }
+}
+
+#[test]
+fn test_unknown_search_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_job(&dir);
+
+    Command::cargo_bin("cutlist")
+        .unwrap()
+        .args(["optimize", "--search", "exhaustive", "--input"])
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unsupported search"));
+}
+
+#[test]
+fn test_optimize_prints_only_the_json_response_on_stdout() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_job(&dir);
+
+    let output = Command::cargo_bin("cutlist")
+        .unwrap()
+        .args(["--verbose", "optimize", "--cut-thickness", "0", "--input"])
+        .arg(&path)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let response: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(response["mosaics"].is_array());
+    assert!(!output.stderr.is_empty(), "logs should go to stderr");
+}
+
+#[test]
+fn test_max_permutations_without_permutation_search_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_job(&dir);
+
+    for search in [&[][..], &["--search", "parallel"][..]] {
+        Command::cargo_bin("cutlist")
+            .unwrap()
+            .arg("optimize")
+            .args(search)
+            .args(["--max-permutations", "4", "--input"])
+            .arg(&path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--max-permutations only applies"));
+    }
+}
@@ -0,0 +1,142 @@
+//! Tests for the `optimize --format table` cut list output
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn test_table_format_prints_cuts_and_parts_per_sheet() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.csv");
+    std::fs::write(
+        &path,
+        "1,600,400,2,plywood,Door\n\
+         2,300,200,1,plywood,Shelf\n\
+         # stock\n\
+         100,1000,1000,1,plywood,Sheet\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("cutlist")
+        .unwrap()
+        .args(["optimize", "--format", "table", "--cut-thickness", "0", "--input"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Sheet 1: 1000 x 1000 plywood"))
+        .stdout(predicate::str::contains("| Step | Piece | Piece size  | Cut at  |"))
+        .stdout(predicate::str::contains("| # | Part | Label | Size      | X   | Y |"))
+        .stdout(predicate::str::is_match(r"\| 1    \| \d+ +\| 1000 x 1000 \| [xy] = \d+ +\|").unwrap())
+        .stdout(predicate::str::is_match(r"\| \d \| 1    \| Door  \| (400 x 600|600 x 400) \|").unwrap().count(2))
+        .stdout(predicate::str::is_match(r"\| \d \| 2    \| Shelf \| (200 x 300|300 x 200) \|").unwrap().count(1));
+}
+
+#[test]
+fn test_table_format_prints_dimensions_in_requested_units() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.csv");
+    std::fs::write(
+        &path,
+        "1,600,400,1,plywood,Door\n\
+         # stock\n\
+         100,1000,1000,1,plywood,Sheet\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("cutlist")
+        .unwrap()
+        .args(["optimize", "--format", "table", "--units", "cm", "--cut-thickness", "0", "--input"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Sheet 1: 100.00 cm x 100.00 cm plywood"))
+        .stdout(predicate::str::is_match(r"\| Door  \| (40.00 cm x 60.00 cm|60.00 cm x 40.00 cm) \|").unwrap());
+
+    Command::cargo_bin("cutlist")
+        .unwrap()
+        .args(["optimize", "--units", "in", "--input"])
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--units only applies to the table and png formats"));
+}
+
+#[test]
+fn test_table_format_applies_config_file() {
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.csv");
+    std::fs::write(
+        &path,
+        "1,500,1000,2,plywood,Side\n\
+         # stock\n\
+         100,1000,1000,2,plywood,Sheet\n",
+    )
+    .unwrap();
+    let config = dir.path().join("config.json");
+    let no_kerf = Configuration { cut_removes_material: false, ..Configuration::default() };
+    std::fs::write(&config, serde_json::to_string(&no_kerf).unwrap()).unwrap();
+
+    let run = |config: Option<&std::path::Path>| {
+        let mut command = Command::cargo_bin("cutlist").unwrap();
+        command.args(["optimize", "--format", "table", "--cut-thickness", "10", "--input"]).arg(&path);
+        if let Some(config) = config {
+            command.arg("--config").arg(config);
+        }
+        command.assert().success()
+    };
+
+    // With a kerf the two sides need a sheet each; the config's blade removes nothing
+    run(None).stdout(predicate::str::contains("Sheet 2:"));
+    run(Some(&config)).stdout(predicate::str::contains("Sheet 1:").and(predicate::str::contains("Sheet 2:").not()));
+}
+
+#[test]
+fn test_table_format_keeps_logs_off_stdout() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.csv");
+    std::fs::write(&path, "1,600,400,1,plywood,Door\n# stock\n100,1000,1000,1,plywood,Sheet\n").unwrap();
+
+    Command::cargo_bin("cutlist")
+        .unwrap()
+        .args(["--verbose", "optimize", "--format", "table", "--cut-thickness", "0", "--input"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Sheet 1: 1000 x 1000 plywood"))
+        .stdout(predicate::str::contains("Printing cut list").not())
+        .stderr(predicate::str::contains("Printing cut list"));
+}
+
+#[test]
+fn test_config_file_kerf_applies_without_cut_thickness_flag() {
+    use cutlist_optimizer_cli::models::{Configuration, CutThickness};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.csv");
+    std::fs::write(&path, "1,500,1000,2,plywood,Side\n# stock\n100,1000,1000,2,plywood,Sheet\n").unwrap();
+    let config = dir.path().join("config.json");
+    let thin_blade = Configuration {
+        cut_thickness: CutThickness::Uniform(0),
+        min_trim_dimension: 0,
+        optimization_factor: 2,
+        ..Configuration::default()
+    };
+    std::fs::write(&config, serde_json::to_string(&thin_blade).unwrap()).unwrap();
+
+    let sheets = |config: Option<&std::path::Path>| {
+        let mut command = Command::cargo_bin("cutlist").unwrap();
+        command.args(["optimize", "--input"]).arg(&path);
+        if let Some(config) = config {
+            command.arg("--config").arg(config);
+        }
+        let output = command.output().unwrap();
+        assert!(output.status.success());
+        let response: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        response["mosaics"].as_array().unwrap().len()
+    };
+
+    // The default 3mm kerf parts the two sides; the config's blade removes nothing
+    assert_eq!(sheets(None), 2);
+    assert_eq!(sheets(Some(&config)), 1);
+}
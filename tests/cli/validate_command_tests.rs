@@ -0,0 +1,53 @@
+//! Tests for the `validate` subcommand
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+fn write_input(name: &str, content: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(name);
+    std::fs::write(&path, content).unwrap();
+    (dir, path)
+}
+
+#[test]
+fn test_validate_summarizes_valid_input() {
+    let (_dir, path) = write_input(
+        "input.csv",
+        "1,400,300,2,plywood,Door\n\
+         2,200,100,3,mdf\n\
+         # stock\n\
+         100,2800,2070,1,plywood,Sheet\n",
+    );
+
+    Command::cargo_bin("cutlist")
+        .unwrap()
+        .arg("validate")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Panels: 2 (5 pieces)"))
+        .stdout(predicate::str::contains("Stock panels: 1 (1 sheets)"))
+        .stdout(predicate::str::contains("plywood: 240000 panel area, 5796000 stock area"))
+        .stdout(predicate::str::contains("warning: panels[1].material"));
+}
+
+#[test]
+fn test_validate_fails_on_bad_input() {
+    let (_dir, path) = write_input(
+        "input.csv",
+        "1,400,300,0,plywood\n\
+         # stock\n\
+         100,2800,-5,1,plywood\n",
+    );
+
+    Command::cargo_bin("cutlist")
+        .unwrap()
+        .arg("validate")
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error: panels[0].count: must be at least 1, got 0"))
+        .stderr(predicate::str::contains("error: stock_panels[0].height: must be greater than 0, got -5"))
+        .stderr(predicate::str::contains("2 error(s) found"));
+}
@@ -3,12 +3,16 @@
 //! This module contains comprehensive tests for the factory classes that
 //! convert Java-style string-based comparator selection to Rust's type-safe approach.
 
+use std::cmp::Ordering;
+
 use cutlist_optimizer_cli::comparator::{
     SolutionComparatorFactory, 
     PriorityListFactory, 
     SolutionComparator,
-    ComparatorFactoryError
+    ComparatorFactoryError,
+    MultiCriteriaComparator,
 };
+use cutlist_optimizer_cli::models::{Mosaic, Solution, TileDimensions, TileNode};
 use cutlist_optimizer_cli::models::configuration::Configuration;
 use cutlist_optimizer_cli::models::enums::OptimizationPriority;
 use cutlist_optimizer_cli::models::performance_thresholds::PerformanceThresholds;
@@ -16,7 +20,7 @@ use cutlist_optimizer_cli::models::performance_thresholds::PerformanceThresholds
 /// Helper function to create a test configuration
 fn create_test_configuration(optimization_priority: OptimizationPriority) -> Configuration {
     Configuration {
-        cut_thickness: 3,
+        cut_thickness: 3.into(),
         min_trim_dimension: 10,
         consider_orientation: true,
         optimization_factor: 5,
@@ -24,6 +28,31 @@ fn create_test_configuration(optimization_priority: OptimizationPriority) -> Con
         use_single_stock_unit: false,
         units: "mm".to_string(),
         performance_thresholds: PerformanceThresholds::default(),
+        cut_orientation_preference: 0,
+        material_cut_preferences: Default::default(),
+        material_cut_thickness: Default::default(),
+        max_sheets: None,
+        seed: None,
+        cut_style: Default::default(),
+        first_cut_rule: Default::default(),
+        time_budget: None,
+        target_efficiency: Default::default(),
+        group_identical_tiles: false,
+        reusable_offcut_threshold: None,
+        offcut_histogram_buckets: Default::default(),
+        placement_heuristic: Default::default(),
+        rotation_tolerance: 0,
+        cut_removes_material: true,
+        max_solutions_retained: None,
+        max_cuts_per_sheet: None,
+        max_permutations: None,
+        prefer_fewer_sheets: false,
+        balance_sheets: false,
+        saw_time: None,
+        fit_within_kerf: true,
+        report_first_acceptable: false,
+        grain_mode: Default::default(),
+        stock_catalog: Vec::new(),
     }
 }
 
@@ -43,6 +72,9 @@ mod solution_comparator_factory_tests {
             ("LEAST_NBR_MOSAICS", true),
             ("LEAST_NBR_UNUSED_TILES", true),
             ("MOST_UNUSED_PANEL_AREA", true),
+            ("LEAST_TOTAL_CUT_LENGTH", true),
+            ("MOST_BALANCED_UTILIZATION", true),
+            ("SMALLEST_LARGEST_OFFCUT", true),
         ];
 
         for (priority_str, should_succeed) in test_cases {
@@ -164,6 +196,9 @@ mod solution_comparator_factory_tests {
             (OptimizationPriority::LeastNbrMosaics, SolutionComparator::LeastNbrMosaics),
             (OptimizationPriority::LeastNbrUnusedTiles, SolutionComparator::LeastNbrUnusedTiles),
             (OptimizationPriority::MostUnusedPanelArea, SolutionComparator::MostUnusedPanelArea),
+            (OptimizationPriority::LeastTotalCutLength, SolutionComparator::LeastTotalCutLength),
+            (OptimizationPriority::MostBalancedUtilization, SolutionComparator::MostBalancedUtilization),
+            (OptimizationPriority::SmallestLargestOffcut, SolutionComparator::SmallestLargestOffcut),
         ];
 
         for (optimization_priority, expected_comparator) in test_cases {
@@ -182,6 +217,84 @@ mod solution_comparator_factory_tests {
         let error_string = format!("{}", error);
         assert!(error_string.contains("Invalid input: null or empty string"));
     }
+
+    #[test]
+    fn test_create_comparator_by_name_covers_every_priority() {
+        let test_cases = vec![
+            ("most_tiles", SolutionComparator::MostNbrTiles),
+            ("least_wasted_area", SolutionComparator::LeastWastedArea),
+            ("least_nbr_cuts", SolutionComparator::LeastNbrCuts),
+            ("most_hv_discrepancy", SolutionComparator::HvDiscrepancy),
+            ("biggest_unused_tile_area", SolutionComparator::BiggestUnusedTileArea),
+            ("smallest_center_of_mass_dist_to_origin", SolutionComparator::SmallestCenterOfMassDistToOrigin),
+            ("least_nbr_mosaics", SolutionComparator::LeastNbrMosaics),
+            ("least_nbr_unused_tiles", SolutionComparator::LeastNbrUnusedTiles),
+            ("most_unused_panel_area", SolutionComparator::MostUnusedPanelArea),
+            ("least_total_cut_length", SolutionComparator::LeastTotalCutLength),
+            ("most_balanced_utilization", SolutionComparator::MostBalancedUtilization),
+            ("smallest_largest_offcut", SolutionComparator::SmallestLargestOffcut),
+        ];
+        assert_eq!(test_cases.len(), SolutionComparator::all().len());
+
+        for (name, expected) in test_cases {
+            let comparator = SolutionComparatorFactory::create_comparator_by_name(name)
+                .unwrap_or_else(|e| panic!("Failed for name {}: {}", name, e));
+            assert_eq!(comparator, expected);
+        }
+        assert_eq!(
+            SolutionComparatorFactory::create_comparator_by_name(" MOST_TILES ").unwrap(),
+            SolutionComparator::MostNbrTiles
+        );
+    }
+
+    #[test]
+    fn test_create_comparator_by_name_rejects_unknown_and_blank_names() {
+        let error = SolutionComparatorFactory::create_comparator_by_name("fewest_sheets").unwrap_err();
+        assert!(error.to_string().contains("Unknown optimization priority: fewest_sheets"));
+
+        let error = SolutionComparatorFactory::create_comparator_by_name("  ").unwrap_err();
+        assert!(error.to_string().contains("Invalid input"));
+    }
+
+    /// One 100x100 sheet holding a single 100x`height` panel
+    fn single_panel_solution(height: i32) -> Solution {
+        let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions::new(10, 100, 100));
+        let root = mosaic.root_tile_node_mut();
+        if height == 100 {
+            root.set_final(true);
+        } else {
+            let mut panel = TileNode::new(0, 100, 0, height);
+            panel.set_final(true);
+            root.set_child1(Some(panel));
+            root.set_child2(Some(TileNode::new(0, 100, height, 100)));
+        }
+        let mut solution = Solution::default();
+        solution.add_mosaic(mosaic);
+        solution
+    }
+
+    #[test]
+    fn test_from_names_breaks_ties_with_later_criteria() {
+        let full = single_panel_solution(100);
+        let partial = single_panel_solution(50);
+
+        let tiles_only = SolutionComparatorFactory::from_names(&["most_tiles"]);
+        assert_eq!(tiles_only.compare(&full, &partial), Ordering::Equal);
+
+        let comparator = SolutionComparatorFactory::from_names(&["most_tiles", "bogus", "least_wasted_area"]);
+        assert_eq!(
+            comparator.criteria(),
+            &[SolutionComparator::MostNbrTiles, SolutionComparator::LeastWastedArea]
+        );
+        assert_eq!(comparator.compare(&full, &partial), Ordering::Less);
+        assert_eq!(comparator.compare(&partial, &full), Ordering::Greater);
+
+        let mut solutions = vec![single_panel_solution(50), single_panel_solution(100)];
+        comparator.sort(&mut solutions);
+        assert_eq!(solutions[0].get_unused_area(), 0);
+
+        assert_eq!(SolutionComparatorFactory::from_names(&[]).compare(&full, &partial), Ordering::Equal);
+    }
 }
 
 #[cfg(test)]
@@ -257,6 +370,42 @@ mod priority_list_factory_tests {
         assert_eq!(comparators[5], SolutionComparator::HvDiscrepancy);
     }
 
+    #[test]
+    fn test_enum_list_least_total_cut_length() {
+        let config = create_test_configuration(OptimizationPriority::LeastTotalCutLength);
+        let comparators = PriorityListFactory::get_final_solution_prioritized_comparator_enum_list(&config);
+        let priorities = PriorityListFactory::get_final_solution_prioritized_comparator_list(&config);
+        
+        assert_eq!(comparators.len(), 6);
+        assert_eq!(comparators[1], SolutionComparator::LeastTotalCutLength);
+        assert_eq!(priorities[1], "LEAST_TOTAL_CUT_LENGTH");
+        assert!(!comparators.contains(&SolutionComparator::LeastNbrCuts));
+    }
+
+    #[test]
+    fn test_enum_list_most_balanced_utilization() {
+        let config = create_test_configuration(OptimizationPriority::MostBalancedUtilization);
+        let comparators = PriorityListFactory::get_final_solution_prioritized_comparator_enum_list(&config);
+        let priorities = PriorityListFactory::get_final_solution_prioritized_comparator_list(&config);
+        
+        assert_eq!(comparators.len(), 6);
+        assert_eq!(comparators[1], SolutionComparator::LeastWastedArea);
+        assert_eq!(comparators[2], SolutionComparator::MostBalancedUtilization);
+        assert_eq!(priorities[2], "MOST_BALANCED_UTILIZATION");
+    }
+
+    #[test]
+    fn test_enum_list_smallest_largest_offcut() {
+        let config = create_test_configuration(OptimizationPriority::SmallestLargestOffcut);
+        let comparators = PriorityListFactory::get_final_solution_prioritized_comparator_enum_list(&config);
+        let priorities = PriorityListFactory::get_final_solution_prioritized_comparator_list(&config);
+        
+        assert_eq!(comparators.len(), 6);
+        assert_eq!(comparators[1], SolutionComparator::LeastWastedArea);
+        assert_eq!(comparators[2], SolutionComparator::SmallestLargestOffcut);
+        assert_eq!(priorities[2], "SMALLEST_LARGEST_OFFCUT");
+    }
+
     #[test]
     fn test_function_list_length_and_validity() {
         let config = create_test_configuration(OptimizationPriority::MostTiles);
@@ -347,6 +496,9 @@ mod priority_list_factory_tests {
                 SolutionComparator::LeastNbrMosaics => "LEAST_NBR_MOSAICS",
                 SolutionComparator::LeastNbrUnusedTiles => "LEAST_NBR_UNUSED_TILES",
                 SolutionComparator::MostUnusedPanelArea => "MOST_UNUSED_PANEL_AREA",
+                SolutionComparator::LeastTotalCutLength => "LEAST_TOTAL_CUT_LENGTH",
+                SolutionComparator::MostBalancedUtilization => "MOST_BALANCED_UTILIZATION",
+                SolutionComparator::SmallestLargestOffcut => "SMALLEST_LARGEST_OFFCUT",
             };
             
             assert_eq!(string_priority, enum_as_string, 
@@ -354,6 +506,60 @@ mod priority_list_factory_tests {
                       i, string_priority, enum_as_string);
         }
     }
+
+    /// Sheet of `width`x100 holding one 50x100 panel on its left
+    fn sheet_with_panel(stock_id: i32, width: i32) -> Mosaic {
+        let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions::new(stock_id, width, 100));
+        let root = mosaic.root_tile_node_mut();
+        if width == 50 {
+            root.set_final(true);
+        } else {
+            let mut panel = TileNode::new(0, 50, 0, 100);
+            panel.set_final(true);
+            root.set_child1(Some(panel));
+            root.set_child2(Some(TileNode::new(50, width, 0, 100)));
+        }
+        mosaic
+    }
+
+    #[test]
+    fn test_prefer_fewer_sheets_ranks_single_sheet_above_efficiency() {
+        // Both place two panels: one on a half-used sheet, one on two full sheets
+        let mut one_sheet = Solution::default();
+        let mut single = sheet_with_panel(10, 200);
+        let mut second_panel = TileNode::new(50, 100, 0, 100);
+        second_panel.set_final(true);
+        let remainder = single.root_tile_node_mut().child2_mut().unwrap();
+        remainder.set_child1(Some(second_panel));
+        remainder.set_child2(Some(TileNode::new(100, 200, 0, 100)));
+        one_sheet.add_mosaic(single);
+        let mut two_sheets = Solution::default();
+        two_sheets.add_mosaic(sheet_with_panel(11, 50));
+        two_sheets.add_mosaic(sheet_with_panel(12, 50));
+        assert_eq!(one_sheet.get_nbr_final_tiles(), two_sheets.get_nbr_final_tiles());
+        assert!(one_sheet.get_efficiency() < two_sheets.get_efficiency());
+
+        let mut config = create_test_configuration(OptimizationPriority::MostTiles);
+        let comparator = MultiCriteriaComparator::new(
+            PriorityListFactory::get_final_solution_prioritized_comparator_enum_list(&config),
+        );
+        assert_eq!(comparator.compare(&two_sheets, &one_sheet), Ordering::Less);
+
+        config.prefer_fewer_sheets = true;
+        let comparators = PriorityListFactory::get_final_solution_prioritized_comparator_enum_list(&config);
+        assert_eq!(&comparators[..3], &[
+            SolutionComparator::MostNbrTiles,
+            SolutionComparator::LeastNbrMosaics,
+            SolutionComparator::LeastWastedArea,
+        ]);
+        assert_eq!(comparators.iter().filter(|&&c| c == SolutionComparator::LeastNbrMosaics).count(), 1);
+        let comparator = MultiCriteriaComparator::new(comparators);
+        assert_eq!(comparator.compare(&one_sheet, &two_sheets), Ordering::Less);
+
+        let priorities = PriorityListFactory::get_final_solution_prioritized_comparator_list(&config);
+        assert_eq!(priorities.len(), 6);
+        assert_eq!(priorities[1], "LEAST_NBR_MOSAICS");
+    }
 }
 
 #[cfg(test)]
@@ -399,6 +605,9 @@ mod integration_tests {
                 SolutionComparator::LeastNbrMosaics => OptimizationPriority::LeastNbrMosaics.to_string(),
                 SolutionComparator::LeastNbrUnusedTiles => OptimizationPriority::LeastNbrUnusedTiles.to_string(),
                 SolutionComparator::MostUnusedPanelArea => OptimizationPriority::MostUnusedPanelArea.to_string(),
+                SolutionComparator::LeastTotalCutLength => OptimizationPriority::LeastTotalCutLength.to_string(),
+                SolutionComparator::MostBalancedUtilization => OptimizationPriority::MostBalancedUtilization.to_string(),
+                SolutionComparator::SmallestLargestOffcut => OptimizationPriority::SmallestLargestOffcut.to_string(),
             }
         }).collect();
         
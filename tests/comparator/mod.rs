@@ -5,3 +5,4 @@
 
 pub mod solution_comparator_tests;
 pub mod factory_tests;
+pub mod weighted_comparator_tests;
@@ -30,6 +30,12 @@ fn create_test_solution_with_different_areas(
             orientation: cutlist_optimizer_cli::models::enums::orientation::Orientation::Any,
             label: Some(format!("Test Panel {}", i)),
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
         
         let mosaic = Mosaic::from_tile_dimensions(&tile_dimensions);
@@ -52,6 +58,12 @@ fn create_test_solution_with_no_fit_panels(nbr_no_fit: usize) -> Solution {
         orientation: cutlist_optimizer_cli::models::enums::orientation::Orientation::Any,
         label: Some("Test Panel".to_string()),
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     };
     let mosaic = Mosaic::from_tile_dimensions(&tile_dimensions);
     solution.add_mosaic(mosaic);
@@ -66,6 +78,12 @@ fn create_test_solution_with_no_fit_panels(nbr_no_fit: usize) -> Solution {
             orientation: cutlist_optimizer_cli::models::enums::orientation::Orientation::Any,
             label: Some(format!("No-fit Panel {}", i)),
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
         solution.add_no_fit_panel(tile_dimensions);
     }
@@ -344,4 +362,97 @@ mod tests {
         assert_eq!(compare_by_biggest_unused_tile_area(&solution_small_area, &solution_large_area), std::cmp::Ordering::Greater);
         assert_eq!(compare_by_least_wasted_area(&solution_small_area, &solution_large_area), std::cmp::Ordering::Less);
     }
+
+    #[test]
+    fn test_least_total_cut_length_prefers_shorter_cuts_at_equal_count() {
+        use cutlist_optimizer_cli::models::Cut;
+
+        let solution_with_cuts = |lengths: &[i32]| {
+            let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions::new(1, 1000, 1000));
+            for (i, &length) in lengths.iter().enumerate() {
+                let y = 100 * (i as i32 + 1);
+                mosaic.cuts.push(Cut::new(0, y, length, y, 1000, 1000, true, y, 1, 2, 3));
+            }
+            let mut solution = Solution::new();
+            solution.add_mosaic(mosaic);
+            solution
+        };
+        let long_cuts = solution_with_cuts(&[1000, 1000]);
+        let short_cuts = solution_with_cuts(&[400, 300]);
+
+        assert_eq!(compare_by_least_nbr_cuts(&long_cuts, &short_cuts), std::cmp::Ordering::Equal);
+        assert_eq!(compare_by_least_total_cut_length(&short_cuts, &long_cuts), std::cmp::Ordering::Less);
+
+        let mut solutions = vec![long_cuts, short_cuts];
+        solutions.sort_by_comparator(SolutionComparator::LeastTotalCutLength);
+        assert_eq!(solutions[0].get_total_cut_length(), 700);
+        assert_eq!(solutions[1].get_total_cut_length(), 2000);
+    }
+
+    #[test]
+    fn test_most_balanced_utilization_prefers_even_sheets() {
+        use cutlist_optimizer_cli::models::TileNode;
+
+        // A 1000x1000 sheet with the left `used_width` columns placed as one panel
+        let sheet = |used_width: i32| {
+            let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions::new(1, 1000, 1000));
+            if used_width > 0 {
+                let mut used = TileNode::new(0, used_width, 0, 1000);
+                used.set_final(true);
+                let root = mosaic.root_tile_node_mut();
+                root.set_child1(Some(used));
+                root.set_child2(Some(TileNode::new(used_width, 1000, 0, 1000)));
+            }
+            mosaic
+        };
+        let solution_of = |widths: [i32; 2]| {
+            let mut solution = Solution::new();
+            for width in widths {
+                solution.add_mosaic(sheet(width));
+            }
+            solution
+        };
+        let balanced = solution_of([500, 500]);
+        let lopsided = solution_of([1000, 0]);
+
+        assert_eq!(balanced.get_used_area_ratio(), lopsided.get_used_area_ratio());
+        assert_eq!(compare_by_most_balanced_utilization(&balanced, &lopsided), std::cmp::Ordering::Less);
+
+        let mut solutions = vec![lopsided, balanced];
+        solutions.sort_by_comparator(SolutionComparator::MostBalancedUtilization);
+        assert_eq!(solutions[0].get_utilization_variance(), 0.0);
+        assert_eq!(solutions[1].get_utilization_variance(), 0.25);
+    }
+
+    #[test]
+    fn test_smallest_largest_offcut_prefers_compact_waste() {
+        use cutlist_optimizer_cli::models::TileNode;
+
+        // A 1000x1000 sheet with columns [from, to) placed as one panel
+        let solution_with_used_columns = |from: i32, to: i32| {
+            let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions::new(1, 1000, 1000));
+            let mut used = TileNode::new(from, to, 0, 1000);
+            used.set_final(true);
+            let mut right = TileNode::new(from, 1000, 0, 1000);
+            right.set_child1(Some(used));
+            right.set_child2(Some(TileNode::new(to, 1000, 0, 1000)));
+            let root = mosaic.root_tile_node_mut();
+            root.set_child1(Some(TileNode::new(0, from, 0, 1000)));
+            root.set_child2(Some(right));
+            let mut solution = Solution::new();
+            solution.add_mosaic(mosaic);
+            solution
+        };
+        // Same used area; the waste is one 500x1000 piece or two 250x1000 scraps
+        let one_big_offcut = solution_with_used_columns(0, 500);
+        let two_scraps = solution_with_used_columns(250, 750);
+
+        assert_eq!(one_big_offcut.get_used_area_ratio(), two_scraps.get_used_area_ratio());
+        assert_eq!(compare_by_smallest_largest_offcut(&two_scraps, &one_big_offcut), std::cmp::Ordering::Less);
+
+        let mut solutions = vec![one_big_offcut, two_scraps];
+        solutions.sort_by_comparator(SolutionComparator::SmallestLargestOffcut);
+        assert_eq!(solutions[0].get_biggest_area(), 250_000);
+        assert_eq!(solutions[1].get_biggest_area(), 500_000);
+    }
 }
@@ -0,0 +1,97 @@
+use std::cmp::Ordering;
+
+use cutlist_optimizer_cli::comparator::{WeightedComparator, WeightedComparatorBuilder};
+use cutlist_optimizer_cli::engine::CutListThread;
+use cutlist_optimizer_cli::models::{Mosaic, Solution, TileDimensions};
+use cutlist_optimizer_cli::GrainMode;
+
+/// Place `tile` on a fresh `sheet` and return the first resulting mosaic
+fn placed(sheet: &TileDimensions, tile: &TileDimensions) -> Mosaic {
+    let thread = CutListThread::new();
+    let mut results = Vec::new();
+    thread
+        .add_tile_to_mosaic(tile, &Mosaic::from_tile_dimensions(sheet), &mut results, GrainMode::Free)
+        .unwrap();
+    results.into_iter().next().expect("tile should fit")
+}
+
+/// One sheet holding a half-width panel: fewer sheets, but waste and a cut
+fn compact_solution() -> Solution {
+    let mut solution = Solution::new();
+    solution.add_mosaic(placed(&TileDimensions::new(10, 100, 100), &TileDimensions::new(1, 50, 100)));
+    solution
+}
+
+/// Two sheets each filled exactly: more tiles and no waste, but more sheets
+fn generous_solution() -> Solution {
+    let mut solution = Solution::new();
+    solution.add_mosaic(placed(&TileDimensions::new(10, 100, 100), &TileDimensions::new(1, 100, 100)));
+    solution.add_mosaic(placed(&TileDimensions::new(11, 100, 100), &TileDimensions::new(2, 100, 100)));
+    solution
+}
+
+#[test]
+fn test_fixture_metrics() {
+    let compact = compact_solution();
+    let generous = generous_solution();
+
+    assert_eq!(compact.get_nbr_final_tiles(), 1);
+    assert_eq!(compact.get_nbr_mosaics(), 1);
+    assert!(compact.get_nbr_cuts() > 0);
+    assert!(compact.get_unused_area() > 0);
+
+    assert_eq!(generous.get_nbr_final_tiles(), 2);
+    assert_eq!(generous.get_nbr_mosaics(), 2);
+    assert_eq!(generous.get_nbr_cuts(), 0);
+    assert_eq!(generous.get_unused_area(), 0);
+}
+
+#[test]
+fn test_shifting_weights_changes_winner() {
+    let compact = compact_solution();
+    let generous = generous_solution();
+
+    let favour_yield = WeightedComparator::builder().tiles_placed(1.0).wasted_area(1.0).nbr_mosaics(0.1).build();
+    assert_eq!(favour_yield.compare(&generous, &compact), Ordering::Less);
+
+    let favour_sheets = WeightedComparator::builder().tiles_placed(0.1).nbr_mosaics(1.0).build();
+    assert_eq!(favour_sheets.compare(&compact, &generous), Ordering::Less);
+
+    let mut solutions = vec![compact_solution(), generous_solution()];
+    favour_yield.sort(&mut solutions);
+    assert_eq!(solutions[0].get_nbr_mosaics(), 2);
+    favour_sheets.sort(&mut solutions);
+    assert_eq!(solutions[0].get_nbr_mosaics(), 1);
+}
+
+#[test]
+fn test_scores_are_normalized_per_pair() {
+    let compact = compact_solution();
+    let generous = generous_solution();
+
+    let mosaics_only = WeightedComparator::builder().nbr_mosaics(2.0).build();
+    let (compact_score, generous_score) = mosaics_only.scores(&compact, &generous);
+    assert!((compact_score - 1.0).abs() < 1e-9);
+    assert!((generous_score - 2.0).abs() < 1e-9);
+
+    // Every criterion contributes at most its weight
+    let (a, b) = WeightedComparator::default().scores(&compact, &generous);
+    assert!((0.0..=4.0).contains(&a));
+    assert!((0.0..=4.0).contains(&b));
+}
+
+#[test]
+fn test_identical_solutions_compare_equal() {
+    let comparator = WeightedComparator::default();
+    assert_eq!(comparator.compare(&compact_solution(), &compact_solution()), Ordering::Equal);
+    assert_eq!(comparator.compare(&Solution::new(), &Solution::new()), Ordering::Equal);
+}
+
+#[test]
+fn test_builder_starts_from_zero_weights() {
+    let comparator = WeightedComparatorBuilder::new().nbr_cuts(3.0).build();
+    assert_eq!(comparator.tiles_placed, 0.0);
+    assert_eq!(comparator.wasted_area, 0.0);
+    assert_eq!(comparator.nbr_cuts, 3.0);
+    assert_eq!(comparator.nbr_mosaics, 0.0);
+}
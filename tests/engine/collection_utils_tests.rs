@@ -18,6 +18,12 @@ fn create_test_tile(id: i32, width: i32, height: i32, material: &str) -> TileDim
         material: material.to_string(),
         orientation: Orientation::Any,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     }
 }
 
@@ -30,6 +36,12 @@ fn create_test_tile_with_label(id: i32, width: i32, height: i32, material: &str,
         material: material.to_string(),
         orientation: Orientation::Any,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     }
 }
 
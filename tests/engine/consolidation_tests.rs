@@ -0,0 +1,212 @@
+//! Tests for replanning partly used sheets onto fewer sheets
+
+use cutlist_optimizer_cli::{
+    engine::{
+        consolidation::{balance_sheets, consolidate},
+        service::PlacementConfig,
+    },
+    models::{CutThickness, Mosaic, Solution, TileDimensions, TileNode},
+    Orientation,
+};
+
+fn sheet() -> TileDimensions {
+    TileDimensions {
+        material: "Wood".to_string(),
+        ..TileDimensions::new(100, 1000, 1000)
+    }
+}
+
+fn panel(id: i32, width: i32, height: i32) -> TileDimensions {
+    TileDimensions {
+        material: "Wood".to_string(),
+        ..TileDimensions::new(id, width, height)
+    }
+}
+
+fn kerf(cut_thickness: i32) -> PlacementConfig {
+    PlacementConfig { cut_thickness: CutThickness::from(cut_thickness), ..PlacementConfig::default() }
+}
+
+/// A 1000x1000 sheet with two 500x500 panels in its left half
+fn half_full_sheet(first_id: i32) -> Mosaic {
+    let mut mosaic = Mosaic::from_tile_dimensions(&sheet());
+
+    let mut top = TileNode::new(0, 500, 0, 500);
+    top.set_final(true);
+    top.set_external_id(Some(first_id));
+    let mut bottom = TileNode::new(0, 500, 500, 1000);
+    bottom.set_final(true);
+    bottom.set_external_id(Some(first_id + 1));
+
+    let mut left = TileNode::new(0, 500, 0, 1000);
+    left.set_child1(Some(top));
+    left.set_child2(Some(bottom));
+    let root = mosaic.root_tile_node_mut();
+    root.set_child1(Some(left));
+    root.set_child2(Some(TileNode::new(500, 1000, 0, 1000)));
+    mosaic
+}
+
+/// A 1000x1000 sheet with a single panel of the given size at the origin
+fn sheet_with_panel(panel_id: i32, width: i32, height: i32) -> Mosaic {
+    let mut mosaic = Mosaic::from_tile_dimensions(&sheet());
+
+    let mut panel = TileNode::new(0, width, 0, height);
+    panel.set_final(true);
+    panel.set_external_id(Some(panel_id));
+    let mut strip = TileNode::new(0, 1000, 0, height);
+    strip.set_child1(Some(panel));
+    strip.set_child2(Some(TileNode::new(width, 1000, 0, height)));
+
+    let root = mosaic.root_tile_node_mut();
+    root.set_child1(Some(strip));
+    root.set_child2(Some(TileNode::new(0, 1000, height, 1000)));
+    mosaic
+}
+
+#[test]
+fn test_consolidate_two_half_full_sheets() {
+    let first = half_full_sheet(1);
+    let second = half_full_sheet(3);
+    let tiles: Vec<TileDimensions> = (1..=4).map(|id| panel(id, 500, 500)).collect();
+    assert!(first.can_consolidate_with(&second));
+
+    // All four panels land on one sheet, freeing the second
+    let mut combined = consolidate(&first, &second, &tiles, &kerf(0)).expect("panels should fit on one sheet");
+    assert_eq!(combined.final_tile_count(), 4);
+    assert_eq!(combined.material(), "Wood");
+    assert_eq!(combined.stock_id(), 100);
+    assert_eq!(combined.used_area(), 1_000_000);
+
+    let mut ids: Vec<i32> = combined.final_tile_nodes().iter().filter_map(|node| node.external_id()).collect();
+    ids.sort();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_cannot_consolidate_incompatible_sheets() {
+    let first = half_full_sheet(1);
+    let tiles: Vec<TileDimensions> = (1..=4).map(|id| panel(id, 500, 500)).collect();
+
+    let mut other_material = half_full_sheet(3);
+    other_material.set_material("Steel".to_string());
+    assert!(!first.can_consolidate_with(&other_material));
+    assert!(consolidate(&first, &other_material, &tiles, &kerf(0)).is_none());
+
+    // Any kerf means four 500x500 panels no longer fit a 1000x1000 sheet
+    assert!(first.can_consolidate_with(&half_full_sheet(3)));
+    assert!(consolidate(&first, &half_full_sheet(3), &tiles, &kerf(3)).is_none());
+
+    // Panels that are not among the job's tiles are never replanned
+    let tiles_of_other_job: Vec<TileDimensions> = (1..=3).map(|id| panel(id, 500, 500)).collect();
+    assert!(consolidate(&first, &half_full_sheet(3), &tiles_of_other_job, &kerf(0)).is_none());
+
+    // Stacking these two would leave a 5mm strip, below the minimum trim
+    let tiles = vec![panel(1, 1000, 600), panel(2, 1000, 395)];
+    let (wide, narrow) = (sheet_with_panel(1, 1000, 600), sheet_with_panel(2, 1000, 395));
+    assert!(consolidate(&wide, &narrow, &tiles, &kerf(0)).is_some());
+    let trimmed = PlacementConfig { min_trim_dimension: 10, ..PlacementConfig::default() };
+    assert!(consolidate(&wide, &narrow, &tiles, &trimmed).is_none());
+
+    let empty = Mosaic::from_tile_dimensions(&first.to_tile_dimensions());
+    assert!(!first.can_consolidate_with(&empty));
+}
+
+#[test]
+fn test_consolidate_keeps_panel_labels_and_grain() {
+    let shelf = TileDimensions { orientation: Orientation::Horizontal, ..panel(1, 1000, 600) };
+    let mut door = panel(2, 300, 1000);
+    door.label = Some("Door".to_string());
+    door.orientation = Orientation::Vertical;
+    let tiles = vec![shelf.clone(), door.clone()];
+
+    // The door only fits the 1000x400 left over by turning it, which its grain forbids
+    let mut locked = Solution::default();
+    locked.add_mosaic(sheet_with_panel(1, 1000, 600));
+    locked.add_mosaic(sheet_with_panel(2, 300, 1000));
+    assert_eq!(balance_sheets(&mut locked, &tiles, &kerf(0)), 0);
+    assert_eq!(locked.get_nbr_mosaics(), 2);
+
+    door.orientation = Orientation::Any;
+    let tiles = vec![shelf, door];
+    let mut free = Solution::default();
+    free.add_mosaic(sheet_with_panel(1, 1000, 600));
+    free.add_mosaic(sheet_with_panel(2, 300, 1000));
+    assert_eq!(balance_sheets(&mut free, &tiles, &kerf(0)), 1);
+
+    let nodes = free.get_mosaics()[0].final_tile_nodes();
+    let door = nodes.iter().find(|node| node.external_id() == Some(2)).unwrap();
+    assert_eq!(door.label.as_deref(), Some("Door"));
+    assert!(door.is_rotated());
+    let shelf = nodes.iter().find(|node| node.external_id() == Some(1)).unwrap();
+    assert_eq!(shelf.label, None);
+}
+
+#[test]
+fn test_balance_sheets_frees_barely_used_sheet() {
+    let tiles = vec![panel(1, 1000, 600), panel(2, 200, 200)];
+    let mut solution = Solution::default();
+    solution.add_mosaic(sheet_with_panel(1, 1000, 600));
+    solution.add_mosaic(sheet_with_panel(2, 200, 200));
+    let placed_before = solution.get_nbr_final_tiles();
+    assert_eq!(solution.get_nbr_mosaics(), 2);
+
+    let freed = balance_sheets(&mut solution, &tiles, &kerf(0));
+
+    assert_eq!(freed, 1);
+    assert_eq!(solution.get_nbr_mosaics(), 1);
+    assert_eq!(solution.get_nbr_final_tiles(), placed_before);
+    assert_eq!(solution.get_unused_stock_panels().len(), 1);
+}
+
+#[test]
+fn test_balance_sheets_keeps_sheets_that_cannot_merge() {
+    let tiles = vec![panel(1, 1000, 600), panel(2, 1000, 600)];
+    let mut solution = Solution::default();
+    solution.add_mosaic(sheet_with_panel(1, 1000, 600));
+    solution.add_mosaic(sheet_with_panel(2, 1000, 600));
+
+    assert_eq!(balance_sheets(&mut solution, &tiles, &kerf(0)), 0);
+    assert_eq!(solution.get_nbr_mosaics(), 2);
+    assert_eq!(solution.get_nbr_final_tiles(), 2);
+}
+
+#[test]
+fn test_balance_sheets_moves_grain_locked_labelled_panel_as_given() {
+    let mut door = panel(2, 1000, 300);
+    door.label = Some("Door".to_string());
+    door.orientation = Orientation::Horizontal;
+    let tiles = vec![panel(1, 1000, 600), door];
+
+    let mut solution = Solution::default();
+    solution.add_mosaic(sheet_with_panel(1, 1000, 600));
+    solution.add_mosaic(sheet_with_panel(2, 1000, 300));
+    assert_eq!(balance_sheets(&mut solution, &tiles, &kerf(0)), 1);
+
+    let nodes = solution.get_mosaics()[0].final_tile_nodes();
+    let door = nodes.iter().find(|node| node.external_id() == Some(2)).unwrap();
+    assert_eq!(door.label.as_deref(), Some("Door"));
+    assert!(!door.is_rotated());
+    assert_eq!((door.width(), door.height()), (1000, 300));
+}
+
+#[test]
+fn test_balance_sheets_keeps_l_shape_parts_together() {
+    let tiles = vec![panel(1, 1000, 600), panel(2, 200, 1000)];
+    // One rectangle of an L-shaped panel, split after layout
+    let mut l_shaped = Mosaic::from_tile_dimensions(&sheet());
+    let mut part = TileNode::new(0, 200, 0, 1000);
+    part.set_final(true);
+    part.set_external_id(Some(2));
+    part.set_group(Some(0));
+    let root = l_shaped.root_tile_node_mut();
+    root.set_child1(Some(part));
+    root.set_child2(Some(TileNode::new(200, 1000, 0, 1000)));
+    assert!(!sheet_with_panel(1, 1000, 600).can_consolidate_with(&l_shaped));
+
+    let mut solution = Solution::default();
+    solution.add_mosaic(sheet_with_panel(1, 1000, 600));
+    solution.add_mosaic(l_shaped);
+    assert_eq!(balance_sheets(&mut solution, &tiles, &kerf(0)), 0);
+    assert_eq!(solution.get_nbr_mosaics(), 2);
+}
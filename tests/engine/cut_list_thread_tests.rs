@@ -4,12 +4,13 @@
 
 use cutlist_optimizer_cli::{
     engine::cut_list_thread::{CutListThread, SolutionComparator},
-    models::{Solution, TileDimensions, TileNode, Mosaic},
+    models::{CutThickness, Solution, TileDimensions, TileNode, Mosaic},
     stock::StockSolution,
-    CutDirection, Status, Orientation,
+    CutDirection, FirstCutRule, GrainMode, PlacementHeuristic, Status, Orientation,
     errors::AppError,
 };
 use std::{
+    collections::HashMap,
     sync::{Arc, Mutex},
     thread,
 };
@@ -24,6 +25,12 @@ fn create_test_tile(id: i32, width: i32, height: i32, material: &str) -> TileDim
         orientation: Orientation::Any,
         label: None,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     }
 }
 
@@ -42,7 +49,7 @@ fn test_cut_list_thread_creation() {
     assert_eq!(thread.status(), Status::Queued);
     assert_eq!(thread.percentage_done(), 0);
     assert_eq!(thread.accuracy_factor(), 100);
-    assert_eq!(thread.cut_thickness(), 0);
+    assert_eq!(thread.cut_thickness(), CutThickness::Uniform(0));
     assert_eq!(thread.min_trim_dimension(), 0);
     assert_eq!(thread.first_cut_orientation(), CutDirection::Both);
     assert!(!thread.consider_grain_direction());
@@ -66,7 +73,7 @@ fn test_setters_and_getters() {
     
     // Test cut thickness
     thread.set_cut_thickness(5);
-    assert_eq!(thread.cut_thickness(), 5);
+    assert_eq!(thread.cut_thickness(), CutThickness::Uniform(5));
     
     // Test min trim dimension
     thread.set_min_trim_dimension(10);
@@ -380,6 +387,31 @@ fn test_sort_solutions() {
     assert!(solutions[1].id >= solutions[2].id);
 }
 
+#[test]
+fn test_sort_solutions_breaks_ties_independent_of_input_order() {
+    use cutlist_optimizer_cli::utils::arrangement::seeded_shuffle;
+
+    let thread = CutListThread::new();
+    let comparators: Vec<SolutionComparator> = vec![
+        Box::new(|a, b| a.get_nbr_mosaics().cmp(&b.get_nbr_mosaics()))
+    ];
+    let solutions: Vec<Solution> = (1..=6)
+        .map(|stock_id| Solution::from_tile_dimensions(&create_test_tile(stock_id, 1000, 800, "wood")))
+        .collect();
+
+    let sorted_identifiers = |seed: u64| {
+        let mut shuffled = solutions.clone();
+        seeded_shuffle(&mut shuffled, seed);
+        thread.sort_solutions(&mut shuffled, &comparators);
+        shuffled.iter().map(|solution| solution.get_structure_identifier()).collect::<Vec<_>>()
+    };
+
+    let expected = sorted_identifiers(0);
+    for seed in 1..10 {
+        assert_eq!(sorted_identifiers(seed), expected);
+    }
+}
+
 #[test]
 fn test_all_solutions_thread_safety() {
     let thread = CutListThread::new();
@@ -404,7 +436,7 @@ fn test_default_implementation() {
     let thread = CutListThread::default();
     assert_eq!(thread.status(), Status::Queued);
     assert_eq!(thread.accuracy_factor(), 100);
-    assert_eq!(thread.cut_thickness(), 0);
+    assert_eq!(thread.cut_thickness(), CutThickness::Uniform(0));
     assert_eq!(thread.min_trim_dimension(), 0);
     assert_eq!(thread.first_cut_orientation(), CutDirection::Both);
     assert!(!thread.consider_grain_direction());
@@ -435,6 +467,71 @@ fn test_cutting_strategies() {
     assert!(!cuts_vh.is_empty());
 }
 
+#[test]
+fn test_horizontal_split_remainder_excludes_kerf() {
+    let thread = CutListThread::new();
+    let mut node = TileNode::new(0, 100, 0, 50);
+
+    thread.split_horizontally_with_children(&mut node, 40, 3).unwrap();
+
+    let remainder = node.child2().expect("remainder should exist");
+    assert_eq!(node.child1().unwrap().width(), 40);
+    assert_eq!(remainder.x1(), 43);
+    assert_eq!(remainder.width(), 57);
+    assert_eq!(remainder.height(), 50);
+}
+
+#[test]
+fn test_vertical_split_remainder_excludes_kerf() {
+    let thread = CutListThread::new();
+    let mut node = TileNode::new(0, 50, 0, 100);
+
+    thread.split_vertically_with_children(&mut node, 40, 3).unwrap();
+
+    let remainder = node.child2().expect("remainder should exist");
+    assert_eq!(node.child1().unwrap().height(), 40);
+    assert_eq!(remainder.y1(), 43);
+    assert_eq!(remainder.height(), 57);
+    assert_eq!(remainder.width(), 50);
+}
+
+#[test]
+fn test_split_without_remainder_when_kerf_consumes_it() {
+    let thread = CutListThread::new();
+
+    // Remainder exactly as wide as the kerf
+    let mut node = TileNode::new(0, 100, 0, 50);
+    thread.split_horizontally_with_children(&mut node, 97, 3).unwrap();
+    assert!(node.child1().is_some());
+    assert!(node.child2().is_none());
+
+    // Kerf wider than the remainder
+    let mut node = TileNode::new(0, 50, 0, 100);
+    thread.split_vertically_with_children(&mut node, 98, 3).unwrap();
+    assert!(node.child1().is_some());
+    assert!(node.child2().is_none());
+}
+
+#[test]
+fn test_split_hv_and_vh_cut_positions_with_kerf() {
+    let thread = CutListThread::new();
+    let node = TileNode::new(0, 100, 0, 100);
+    let tile = create_test_tile(1, 40, 30, "Wood");
+
+    let cuts_hv = thread.split_hv(&node, &tile, 3).unwrap();
+    assert_eq!(cuts_hv.len(), 2);
+    assert_eq!(cuts_hv[0].x1, 40);
+    assert_eq!(cuts_hv[1].y1, 30);
+    // The second cut runs across the first piece only, not the kerf
+    assert_eq!(cuts_hv[1].x2, 40);
+
+    let cuts_vh = thread.split_vh(&node, &tile, 3).unwrap();
+    assert_eq!(cuts_vh.len(), 2);
+    assert_eq!(cuts_vh[0].y1, 30);
+    assert_eq!(cuts_vh[1].x1, 40);
+    assert_eq!(cuts_vh[1].y2, 30);
+}
+
 #[test]
 fn test_different_cut_orientations() {
     let mut thread = CutListThread::new();
@@ -496,7 +593,7 @@ fn test_edge_cases() {
     
     // Test with large cut thickness
     thread.set_cut_thickness(100);
-    assert_eq!(thread.cut_thickness(), 100);
+    assert_eq!(thread.cut_thickness(), CutThickness::Uniform(100));
 }
 
 #[test]
@@ -562,3 +659,603 @@ fn test_error_handling() {
     let _result = thread.split_vertically(&invalid_node, 100, 3, 1);
     // The implementation should handle this case appropriately
 }
+
+#[test]
+fn test_asymmetric_cut_thickness_offsets_per_axis() {
+    let mut thread = CutListThread::new();
+    thread.set_tiles(vec![create_test_tile(7, 500, 400, "Wood")]);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(vec![
+        create_test_tile(1, 1000, 1000, "Wood"),
+    ])));
+    thread.set_first_cut_orientation(CutDirection::Horizontal);
+    thread.set_cut_thickness(CutThickness::per_axis(3, 1));
+    thread.run();
+    assert!(!thread.has_error());
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    let root = solutions[0].get_mosaics()[0].root_tile_node();
+
+    // Horizontal split at x=500 consumes 3 units of kerf
+    let right = root.child2().unwrap();
+    assert_eq!(right.x1(), 503);
+
+    // Vertical split at y=400 consumes 1 unit of kerf
+    let bottom = root.child1().unwrap().child2().unwrap();
+    assert_eq!(bottom.y1(), 401);
+}
+
+#[test]
+fn test_cut_thickness_deserializes_scalar_or_per_axis() {
+    let uniform: CutThickness = serde_json::from_str("3").unwrap();
+    assert_eq!(uniform.horizontal(), 3);
+    assert_eq!(uniform.vertical(), 3);
+
+    let per_axis: CutThickness = serde_json::from_str(r#"{"horizontal": 3, "vertical": 1}"#).unwrap();
+    assert_eq!(per_axis, CutThickness::per_axis(3, 1));
+    assert_eq!(per_axis.scaled(10.0), CutThickness::per_axis(30, 10));
+}
+
+#[test]
+fn test_allowed_stock_ids_restrict_placement() {
+    let short = create_test_tile(100, 1200, 600, "Wood");
+    let long = create_test_tile(200, 2800, 600, "Wood");
+
+    let mut thread = CutListThread::new();
+    thread.set_tiles(vec![create_test_tile(1, 1000, 300, "Wood")]);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(vec![short, long])));
+    thread.set_allowed_stock_ids(HashMap::from([(1, vec![200])]));
+    thread.run();
+    assert!(!thread.has_error());
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    assert!(!solutions.is_empty());
+    for solution in solutions.iter() {
+        // The tile would fit the short sheet, but is only allowed on the long one
+        assert!(solution.get_no_fit_panels().is_empty());
+        let used: Vec<i32> = solution
+            .get_mosaics()
+            .iter()
+            .filter(|mosaic| mosaic.has_final_tiles())
+            .map(|mosaic| mosaic.stock_id())
+            .collect();
+        assert_eq!(used, vec![200]);
+    }
+}
+
+#[test]
+fn test_allowed_stock_ids_exhausted_stock_leaves_tile_unplaced() {
+    let stock = vec![
+        create_test_tile(100, 1200, 600, "Wood"),
+        create_test_tile(100, 1200, 600, "Wood"),
+        create_test_tile(200, 2800, 600, "Wood"),
+    ];
+
+    // Only two 2500x300 parts fit on the single long sheet
+    let mut thread = CutListThread::new();
+    thread.set_tiles(vec![
+        create_test_tile(1, 2500, 300, "Wood"),
+        create_test_tile(1, 2500, 300, "Wood"),
+        create_test_tile(1, 2500, 300, "Wood"),
+        create_test_tile(2, 500, 500, "Wood"),
+    ]);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(stock)));
+    thread.set_allowed_stock_ids(HashMap::from([(1, vec![200])]));
+    thread.run();
+    assert!(!thread.has_error());
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    let solution = &solutions[0];
+
+    let no_fit = solution.get_no_fit_panels();
+    assert_eq!(no_fit.len(), 1);
+    assert_eq!(no_fit[0].id, 1);
+
+    for mosaic in solution.get_mosaics() {
+        for node in mosaic.final_tile_nodes() {
+            if node.external_id() == Some(1) {
+                assert_eq!(mosaic.stock_id(), 200);
+            }
+        }
+    }
+}
+
+fn grained_tile(id: i32, width: i32, height: i32, orientation: Orientation) -> TileDimensions {
+    TileDimensions { orientation, ..create_test_tile(id, width, height, "Veneer") }
+}
+
+#[test]
+fn test_default_grain_mode_is_fixed() {
+    let mut thread = CutListThread::new();
+    assert_eq!(thread.grain_mode(), GrainMode::Fixed);
+
+    thread.set_consider_grain_direction(true);
+    assert_eq!(thread.grain_mode(), GrainMode::Alternating);
+
+    thread.set_grain_mode(GrainMode::Free);
+    assert!(!thread.consider_grain_direction());
+}
+
+#[test]
+fn test_fixed_grain_tile_is_not_rotated_to_fit() {
+    let thread = CutListThread::new();
+    let mosaic = Mosaic::from_tile_dimensions(&create_test_tile(10, 100, 50, "Veneer"));
+    // Fits only when turned to 80x40
+    let tile = grained_tile(1, 40, 80, Orientation::Horizontal);
+
+    let mut results = Vec::new();
+    thread.add_tile_to_mosaic(&tile, &mosaic, &mut results, GrainMode::Fixed).unwrap();
+    assert!(results.is_empty());
+
+    thread.add_tile_to_mosaic(&tile, &mosaic, &mut results, GrainMode::Free).unwrap();
+    assert!(!results.is_empty());
+}
+
+#[test]
+fn test_free_grain_tile_rotates_under_fixed_mode() {
+    let thread = CutListThread::new();
+    let mosaic = Mosaic::from_tile_dimensions(&create_test_tile(10, 100, 50, "Veneer"));
+    let tile = grained_tile(2, 40, 80, Orientation::Any);
+
+    let mut results = Vec::new();
+    thread.add_tile_to_mosaic(&tile, &mosaic, &mut results, GrainMode::Fixed).unwrap();
+
+    assert!(!results.is_empty());
+    let placed = results[0].final_tile_nodes();
+    assert_eq!(placed.len(), 1);
+    assert_eq!((placed[0].width(), placed[0].height()), (80, 40));
+    assert!(placed[0].is_rotated);
+}
+
+#[test]
+fn test_alternating_grain_turns_tile_to_follow_stock() {
+    let thread = CutListThread::new();
+    let stock = grained_tile(10, 100, 50, Orientation::Vertical);
+    let mosaic = Mosaic::from_tile_dimensions(&stock);
+    let tile = grained_tile(3, 40, 80, Orientation::Horizontal);
+
+    let mut results = Vec::new();
+    thread.add_tile_to_mosaic(&tile, &mosaic, &mut results, GrainMode::Alternating).unwrap();
+
+    assert!(!results.is_empty());
+    let placed = results[0].final_tile_nodes();
+    assert_eq!((placed[0].width(), placed[0].height()), (80, 40));
+}
+
+/// Best solution after placing `tiles` on a single 1000x1000 sheet
+fn solve_on_single_sheet(tiles: Vec<TileDimensions>) -> Solution {
+    let mut thread = CutListThread::new();
+    thread.set_tiles(tiles);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(vec![
+        create_test_tile(100, 1000, 1000, "Wood"),
+    ])));
+    thread.run();
+    assert!(!thread.has_error());
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    solutions[0].clone()
+}
+
+#[test]
+fn test_with_locked_tiles_marks_only_listed_tiles() {
+    let solution = solve_on_single_sheet(vec![
+        create_test_tile(1, 400, 300, "Wood"),
+        create_test_tile(2, 200, 200, "Wood"),
+    ]);
+    let locked = solution.with_locked_tiles(&[1]);
+
+    let nodes = locked.get_final_tile_nodes();
+    assert_eq!(nodes.len(), 2);
+    for node in nodes {
+        assert_eq!(node.is_locked(), node.external_id() == Some(1));
+    }
+    // The original solution is left untouched
+    assert!(solution.get_final_tile_nodes().iter().all(|node| !node.is_locked()));
+    assert!(locked.get_mosaics()[0].has_locked_tiles());
+}
+
+#[test]
+fn test_incremental_placement_keeps_locked_tile_in_place() {
+    let seed = solve_on_single_sheet(vec![create_test_tile(1, 400, 300, "Wood")])
+        .with_locked_tiles(&[1]);
+    let locked_node = seed.get_final_tile_nodes().into_iter().next().unwrap();
+
+    let mut thread = CutListThread::new();
+    thread.set_tiles(vec![
+        create_test_tile(2, 500, 500, "Wood"),
+        create_test_tile(3, 300, 200, "Wood"),
+    ]);
+    thread.compute_solutions_incremental(&seed).unwrap();
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    assert!(!solutions.is_empty());
+    for solution in solutions.iter() {
+        let nodes = solution.get_final_tile_nodes();
+        let locked: Vec<&TileNode> = nodes.iter().filter(|node| node.external_id() == Some(1)).collect();
+        assert_eq!(locked.len(), 1);
+        assert_eq!(locked[0].id(), locked_node.id());
+        assert_eq!(locked[0].tile(), locked_node.tile());
+        assert!(locked[0].is_locked());
+
+        // Both new tiles were placed around it
+        assert!(solution.get_no_fit_panels().is_empty());
+        assert_eq!(nodes.len(), 3);
+    }
+}
+
+#[test]
+fn test_incremental_placement_moves_unlocked_tiles() {
+    let seed = solve_on_single_sheet(vec![
+        create_test_tile(1, 1000, 500, "Wood"),
+        create_test_tile(2, 300, 300, "Wood"),
+    ])
+    .with_locked_tiles(&[1]);
+    let seed_nodes = seed.get_final_tile_nodes();
+    let unlocked_tile = seed_nodes.iter().find(|node| node.external_id() == Some(2)).unwrap().tile().clone();
+
+    // A larger panel takes the corner the unlocked tile used to hold
+    let mut thread = CutListThread::new();
+    thread.set_tiles(vec![
+        create_test_tile(1, 1000, 500, "Wood"),
+        create_test_tile(3, 500, 500, "Wood"),
+        create_test_tile(2, 300, 300, "Wood"),
+    ]);
+    thread.compute_solutions_incremental(&seed).unwrap();
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    assert!(!solutions.is_empty());
+    for solution in solutions.iter() {
+        assert!(solution.get_no_fit_panels().is_empty());
+        let nodes = solution.get_final_tile_nodes();
+        // The locked panel is not placed a second time
+        assert_eq!(nodes.len(), 3);
+        let moved = nodes.iter().find(|node| node.external_id() == Some(2)).unwrap();
+        assert!(!moved.is_locked());
+        assert_ne!(moved.tile(), &unlocked_tile);
+        let mosaic = &solution.get_mosaics()[0];
+        let splits = mosaic.root_tile_node().iter().filter(|node| node.has_children()).count();
+        assert_eq!(mosaic.cuts().len(), splits);
+    }
+}
+
+#[test]
+fn test_free_unlocked_tiles_keeps_only_locked_tiles() {
+    let solution = solve_on_single_sheet(vec![
+        create_test_tile(1, 400, 300, "Wood"),
+        create_test_tile(2, 200, 200, "Wood"),
+    ])
+    .with_locked_tiles(&[1]);
+    let mut mosaic = solution.get_mosaics()[0].clone();
+
+    mosaic.free_unlocked_tiles();
+
+    let placed = mosaic.final_tile_nodes();
+    assert_eq!(placed.len(), 1);
+    assert_eq!(placed[0].external_id(), Some(1));
+    let free = mosaic.root_tile_node().unused_tiles();
+    assert!(!free.is_empty());
+    assert!(free.iter().all(|node| node.external_id().is_none() && node.label().is_none()));
+    assert_eq!(mosaic.root_tile_node().external_id(), Some(100));
+}
+
+/// Best solution after placing `tiles` on a 1000x800 sheet with a trimmed border
+fn solve_on_trimmed_sheet(tiles: Vec<TileDimensions>, edge_trim: i32) -> Solution {
+    let mut stock = create_test_tile(100, 1000, 800, "Wood");
+    stock.edge_trim = edge_trim;
+
+    let mut thread = CutListThread::new();
+    thread.set_tiles(tiles);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(vec![stock])));
+    thread.run();
+    assert!(!thread.has_error());
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    solutions[0].clone()
+}
+
+#[test]
+fn test_edge_trim_rejects_tile_fitting_only_nominal_sheet() {
+    // Fits the nominal 1000x800 sheet but not the 980x780 usable region
+    let solution = solve_on_trimmed_sheet(vec![create_test_tile(1, 1000, 500, "Wood")], 10);
+    assert_eq!(solution.get_nbr_final_tiles(), 0);
+    let no_fit = solution.get_no_fit_panels();
+    assert_eq!(no_fit.len(), 1);
+    assert_eq!(no_fit[0].id, 1);
+
+    let untrimmed = solve_on_trimmed_sheet(vec![create_test_tile(1, 1000, 500, "Wood")], 0);
+    assert_eq!(untrimmed.get_nbr_final_tiles(), 1);
+}
+
+#[test]
+fn test_edge_trim_places_tiles_inside_usable_region() {
+    let mut solution = solve_on_trimmed_sheet(vec![create_test_tile(1, 980, 780, "Wood")], 10);
+    assert!(solution.get_no_fit_panels().is_empty());
+
+    let mosaic = &mut solution.mosaics[0];
+    let placed = mosaic.final_tile_nodes()[0].clone();
+    assert_eq!((placed.x1(), placed.y1()), (10, 10));
+    assert_eq!((placed.x2(), placed.y2()), (990, 790));
+    // Efficiency is measured against the usable area
+    assert_eq!(mosaic.efficiency(), 1.0);
+}
+
+/// Positions (x1, y1) of tile `id` across every mosaic in `results`
+fn placements_of(results: &[Mosaic], id: i32) -> Vec<(i32, i32)> {
+    results
+        .iter()
+        .flat_map(|mosaic| mosaic.final_tile_nodes())
+        .filter(|node| node.external_id == Some(id))
+        .map(|node| (node.x1(), node.y1()))
+        .collect()
+}
+
+#[test]
+fn test_bottom_left_placement_differs_from_best_area_fit() {
+    let sheet = Mosaic::from_tile_dimensions(&create_test_tile(100, 1000, 800, "Wood"));
+    let first = create_test_tile(1, 400, 300, "Wood");
+    let second = create_test_tile(2, 300, 300, "Wood");
+
+    let place_second = |heuristic: PlacementHeuristic| {
+        let mut thread = CutListThread::new();
+        thread.set_first_cut_orientation(CutDirection::Horizontal);
+        thread.set_placement_heuristic(heuristic);
+
+        // Leaves a 600x800 strip right of the first tile and 400x500 below it
+        let mut after_first = Vec::new();
+        thread.add_tile_to_mosaic(&first, &sheet, &mut after_first, GrainMode::Fixed).unwrap();
+        let mosaic = after_first
+            .into_iter()
+            .find(|mosaic| placements_of(std::slice::from_ref(mosaic), 1) == vec![(0, 0)])
+            .unwrap();
+
+        let mut results = Vec::new();
+        thread.add_tile_to_mosaic(&second, &mosaic, &mut results, GrainMode::Fixed).unwrap();
+        placements_of(&results, 2)
+    };
+
+    let bottom_left = place_second(PlacementHeuristic::BottomLeft);
+    let best_area_fit = place_second(PlacementHeuristic::BestAreaFit);
+
+    assert_eq!(bottom_left, vec![(400, 0)]);
+    assert!(best_area_fit.contains(&(0, 300)));
+    assert_ne!(bottom_left, best_area_fit);
+}
+
+#[test]
+fn test_rotation_tolerance_skips_rotating_near_square_tiles() {
+    let sheet = Mosaic::from_tile_dimensions(&create_test_tile(100, 1000, 800, "Wood"));
+    let tile = create_test_tile(1, 100, 101, "Wood");
+
+    let placements = |tolerance: i32| {
+        let mut thread = CutListThread::new();
+        thread.set_rotation_tolerance(tolerance);
+        let mut results = Vec::new();
+        thread.add_tile_to_mosaic(&tile, &sheet, &mut results, GrainMode::Free).unwrap();
+        results
+            .iter()
+            .flat_map(|mosaic| mosaic.final_tile_nodes())
+            .map(|node| node.is_rotated())
+            .collect::<Vec<_>>()
+    };
+
+    let within_tolerance = placements(2);
+    assert!(!within_tolerance.is_empty());
+    assert!(within_tolerance.iter().all(|&rotated| !rotated));
+
+    let exact_squares_only = placements(0);
+    assert!(exact_squares_only.contains(&false));
+    assert!(exact_squares_only.contains(&true));
+}
+
+#[test]
+fn test_max_solutions_retained_caps_solution_list() {
+    let retained = |limit: Option<usize>| {
+        let mut thread = CutListThread::new();
+        thread.set_accuracy_factor(50);
+        thread.set_max_solutions_retained(limit);
+        thread.set_first_cut_orientation(CutDirection::Both);
+        thread.set_tiles(vec![
+            create_test_tile(1, 400, 300, "Wood"),
+            create_test_tile(2, 300, 200, "Wood"),
+            create_test_tile(3, 250, 150, "Wood"),
+            create_test_tile(4, 200, 100, "Wood"),
+        ]);
+        thread.set_stock_solution(Some(StockSolution::from_tiles(vec![create_test_tile(100, 1000, 800, "Wood")])));
+        thread.run();
+        assert!(thread.is_finished());
+        let solutions = thread.all_solutions();
+        let count = solutions.lock().unwrap().len();
+        count
+    };
+
+    assert!(retained(None) > 3);
+    assert!((1..=3).contains(&retained(Some(3))));
+}
+
+#[test]
+fn test_max_cuts_per_sheet_spills_tiles_to_more_sheets() {
+    let best = |limit: Option<usize>| {
+        let mut thread = CutListThread::new();
+        thread.set_max_cuts_per_sheet(limit);
+        thread.set_first_cut_orientation(CutDirection::Both);
+        thread.set_tiles((1..=4).map(|id| create_test_tile(id, 300, 300, "Wood")).collect());
+        thread.set_stock_solution(Some(StockSolution::from_tiles(
+            (100..104).map(|id| create_test_tile(id, 1000, 800, "Wood")).collect(),
+        )));
+        thread.run();
+        assert!(thread.is_finished());
+        let solutions = thread.all_solutions();
+        let best = solutions.lock().unwrap()[0].clone();
+        best
+    };
+
+    assert_eq!(best(None).get_mosaics().len(), 1);
+
+    let limited = best(Some(2));
+    assert!(limited.get_mosaics().len() > 1);
+    assert!(limited.get_no_fit_panels().is_empty());
+    assert!(limited.get_mosaics().iter().all(|mosaic| mosaic.nbr_cuts() <= 2));
+}
+
+#[test]
+fn test_fixed_grain_tile_is_rejected_on_crosswise_sheet() {
+    let oriented = |id: i32, width: i32, height: i32, orientation: Orientation| {
+        let mut tile = create_test_tile(id, width, height, "Wood");
+        tile.orientation = orientation;
+        tile
+    };
+    let tile = oriented(1, 300, 200, Orientation::Vertical);
+    let horizontal_sheet = oriented(100, 1000, 800, Orientation::Horizontal);
+    let vertical_sheet = oriented(101, 1000, 800, Orientation::Vertical);
+
+    let thread = CutListThread::new();
+    let mut results = Vec::new();
+    thread
+        .add_tile_to_mosaic(&tile, &Mosaic::from_tile_dimensions(&horizontal_sheet), &mut results, GrainMode::Fixed)
+        .unwrap();
+    assert!(results.is_empty());
+    thread
+        .add_tile_to_mosaic(&tile, &Mosaic::from_tile_dimensions(&vertical_sheet), &mut results, GrainMode::Fixed)
+        .unwrap();
+    assert!(!results.is_empty());
+
+    let mut thread = CutListThread::new();
+    thread.set_first_cut_orientation(CutDirection::Both);
+    thread.set_tiles(vec![tile]);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(vec![horizontal_sheet, vertical_sheet])));
+    thread.run();
+    let solutions = thread.all_solutions();
+    let best = solutions.lock().unwrap()[0].clone();
+    assert!(best.get_no_fit_panels().is_empty());
+    assert_eq!(best.get_mosaics().len(), 1);
+    assert_eq!(best.get_mosaics()[0].stock_id(), 101);
+}
+
+#[test]
+fn test_first_cut_rule_picks_cut_along_longest_edge() {
+    let first_cuts = |rule: FirstCutRule| {
+        let mut thread = CutListThread::new();
+        thread.set_first_cut_rule(rule);
+        let wide_sheet = Mosaic::from_tile_dimensions(&create_test_tile(100, 1000, 500, "Wood"));
+        let mut results = Vec::new();
+        thread
+            .add_tile_to_mosaic(&create_test_tile(1, 300, 200, "Wood"), &wide_sheet, &mut results, GrainMode::Free)
+            .unwrap();
+        results.iter().map(|mosaic| mosaic.cuts()[0].is_horizontal).collect::<Vec<_>>()
+    };
+
+    // A vertical cut splits off a full-width strip, ripping along the long edge
+    let longest = first_cuts(FirstCutRule::LongestEdge);
+    assert!(!longest.is_empty() && longest.iter().all(|&is_horizontal| !is_horizontal));
+
+    let shortest = first_cuts(FirstCutRule::ShortestEdge);
+    assert!(!shortest.is_empty() && shortest.iter().all(|&is_horizontal| is_horizontal));
+
+    let both = first_cuts(FirstCutRule::MatchGlobalPreference);
+    assert!(both.contains(&true) && both.contains(&false));
+}
+
+#[test]
+fn test_sheet_sized_tile_takes_whole_sheet_without_cuts() {
+    let mut thread = CutListThread::new();
+    thread.set_cut_thickness(3);
+    let sheet = Mosaic::from_tile_dimensions(&create_test_tile(100, 1000, 600, "Wood"));
+    let mut results = Vec::new();
+    thread
+        .add_tile_to_mosaic(&create_test_tile(1, 1000, 600, "Wood"), &sheet, &mut results, GrainMode::Free)
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    let mut mosaic = results.remove(0);
+    assert_eq!(mosaic.nbr_cuts(), 0);
+    assert!(mosaic.root_tile_node().is_final());
+    assert_eq!(mosaic.root_tile_node().external_id(), Some(1));
+    assert_eq!(mosaic.efficiency(), 1.0);
+}
+
+#[test]
+fn test_sheet_sized_tile_is_placed_by_the_layout_with_kerf() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::{CalculationRequest, Panel};
+
+    let panel = |id: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width("1000".to_string())
+            .with_height("600".to_string())
+            .with_count(1)
+            .with_enabled(true)
+    };
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1)],
+        stock_panels: vec![panel(100)],
+    };
+    let (solution, _) = compute_layout(&request, 3, 10, 5).unwrap();
+
+    assert!(solution.get_no_fit_panels().is_empty());
+    assert_eq!(solution.get_nbr_cuts(), 0);
+    assert_eq!(solution.get_efficiency(), 1.0);
+}
+
+#[test]
+fn test_tile_one_kerf_smaller_than_node_fits_below_min_trim() {
+    let placements = |fit_within_kerf: bool| {
+        let mut thread = CutListThread::new();
+        thread.set_cut_thickness(3);
+        thread.set_min_trim_dimension(10);
+        thread.set_fit_within_kerf(fit_within_kerf);
+        let sheet = Mosaic::from_tile_dimensions(&create_test_tile(100, 1000, 600, "Wood"));
+        let mut results = Vec::new();
+        thread
+            .add_tile_to_mosaic(&create_test_tile(1, 997, 600, "Wood"), &sheet, &mut results, GrainMode::Fixed)
+            .unwrap();
+        results
+    };
+
+    // The kerf consumes the rest of the sheet, so no offcut is left to trim
+    let results = placements(true);
+    assert!(!results.is_empty());
+    assert!(results.iter().all(|mosaic| mosaic.nbr_cuts() == 1));
+    let placed = results[0].final_tile_nodes()[0].clone();
+    assert_eq!((placed.width(), placed.height()), (997, 600));
+
+    assert!(placements(false).is_empty());
+}
+
+#[test]
+fn test_layout_takes_the_kerf_from_the_configuration_unless_overridden() {
+    use cutlist_optimizer_cli::engine::layout::{compute_layout_with_options, OptimizeOptions};
+    use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, CutThickness, Panel};
+
+    let panel = |id: i32, width: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height("1000".to_string())
+            .with_count(count)
+            .with_enabled(true)
+    };
+    // Two halves fill the sheet only when the cut between them removes nothing
+    let request = CalculationRequest {
+        configuration: Some(Configuration {
+            cut_thickness: CutThickness::per_axis(0, 0),
+            ..Configuration::default()
+        }),
+        panels: vec![panel(1, "500", 2)],
+        stock_panels: vec![panel(100, "1000", 2)],
+    };
+
+    let (solution, _) = compute_layout_with_options(&request, &OptimizeOptions::default()).unwrap();
+    assert_eq!(solution.get_mosaics().len(), 1);
+
+    let options = OptimizeOptions { cut_thickness: Some(3), ..OptimizeOptions::default() };
+    let (solution, _) = compute_layout_with_options(&request, &options).unwrap();
+    assert_eq!(solution.get_mosaics().len(), 2);
+}
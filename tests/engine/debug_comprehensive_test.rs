@@ -30,6 +30,7 @@ fn test_debug_high_precision_decimals() {
             orientation: 0,
             label: Some("High Precision Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 2,
@@ -41,6 +42,7 @@ fn test_debug_high_precision_decimals() {
             orientation: 0,
             label: Some("Precision Panel 2".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -55,6 +57,7 @@ fn test_debug_high_precision_decimals() {
             orientation: 0,
             label: Some("Precision Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -104,6 +107,7 @@ fn test_debug_large_panel_count() {
             orientation: 0,
             label: Some(format!("Panel {}", i)),
             edge: None,
+            ..Default::default()
         });
     }
 
@@ -118,6 +122,7 @@ fn test_debug_large_panel_count() {
             orientation: 0,
             label: Some("Wood Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 102,
@@ -129,6 +134,7 @@ fn test_debug_large_panel_count() {
             orientation: 0,
             label: Some("Metal Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -181,6 +187,7 @@ fn test_debug_edge_cases() {
             orientation: 0,
             label: Some("Tiny Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
         // Large panel (but reasonable size)
         Panel {
@@ -193,6 +200,7 @@ fn test_debug_edge_cases() {
             orientation: 0,
             label: Some("Large Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
         // Square panel
         Panel {
@@ -205,6 +213,7 @@ fn test_debug_edge_cases() {
             orientation: 0,
             label: Some("Square Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
         // Very thin panel
         Panel {
@@ -217,6 +226,7 @@ fn test_debug_edge_cases() {
             orientation: 0,
             label: Some("Thin Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -231,6 +241,7 @@ fn test_debug_edge_cases() {
             orientation: 0,
             label: Some("Large Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -277,6 +288,7 @@ fn test_debug_algorithm_consistency() {
             orientation: 0,
             label: Some("Test Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -291,6 +303,7 @@ fn test_debug_algorithm_consistency() {
             orientation: 0,
             label: Some("Test Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -353,6 +366,7 @@ fn test_debug_performance_measurement() {
             orientation: 0,
             label: Some("Performance Test Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 2,
@@ -364,6 +378,7 @@ fn test_debug_performance_measurement() {
             orientation: 0,
             label: Some("Metal Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -378,6 +393,7 @@ fn test_debug_performance_measurement() {
             orientation: 0,
             label: Some("Wood Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 102,
@@ -389,6 +405,7 @@ fn test_debug_performance_measurement() {
             orientation: 0,
             label: Some("Metal Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
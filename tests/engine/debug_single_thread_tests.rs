@@ -106,6 +106,7 @@ fn test_debug_compute_empty_panels() {
                 orientation: 0,
                 label: Some("Stock".to_string()),
                 edge: None,
+                ..Default::default()
             },
         ],
     };
@@ -137,6 +138,7 @@ fn test_debug_compute_empty_stock() {
                 orientation: 0,
                 label: Some("Panel".to_string()),
                 edge: None,
+                ..Default::default()
             },
         ],
         stock_panels: vec![],  // Empty stock
@@ -167,6 +169,7 @@ fn test_debug_compute_mixed_materials() {
             orientation: 0,
             label: Some("Wood Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 2,
@@ -178,6 +181,7 @@ fn test_debug_compute_mixed_materials() {
             orientation: 0,
             label: Some("Metal Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 3,
@@ -189,6 +193,7 @@ fn test_debug_compute_mixed_materials() {
             orientation: 0,
             label: Some("Plastic Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -203,6 +208,7 @@ fn test_debug_compute_mixed_materials() {
             orientation: 0,
             label: Some("Wood Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 102,
@@ -214,6 +220,7 @@ fn test_debug_compute_mixed_materials() {
             orientation: 0,
             label: Some("Metal Stock".to_string()),
             edge: None,
+            ..Default::default()
         },
         // Note: No plastic stock - should be handled gracefully
     ];
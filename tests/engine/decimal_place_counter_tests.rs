@@ -21,6 +21,7 @@ mod decimal_place_counter_tests {
             orientation: 0,
             label: None,
             edge: None,
+            ..Default::default()
         }
     }
 
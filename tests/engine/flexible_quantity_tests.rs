@@ -0,0 +1,101 @@
+//! Tests for panels ordered as a range of quantities
+
+use cutlist_optimizer_cli::engine::layout::compute_layout;
+use cutlist_optimizer_cli::models::{CalculationRequest, Panel, Solution};
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_enabled(true)
+}
+
+fn request_with(panel: Panel) -> CalculationRequest {
+    CalculationRequest {
+        configuration: None,
+        panels: vec![panel],
+        stock_panels: vec![create_panel(100, "1000", "1000", 1)],
+    }
+}
+
+fn placed_count(solution: &Solution, id: i32) -> usize {
+    solution
+        .mosaics
+        .iter()
+        .flat_map(|mosaic| mosaic.final_tile_nodes())
+        .filter(|node| node.external_id() == Some(id))
+        .count()
+}
+
+#[test]
+fn test_flexible_panel_fills_stock_between_min_and_max() {
+    let shelf = create_panel(1, "300", "300", 1).with_min_count(5).with_max_count(20);
+    assert_eq!((shelf.min_quantity(), shelf.max_quantity()), (5, 20));
+    assert!(shelf.is_flexible());
+
+    let (solution, _) = compute_layout(&request_with(shelf), 0, 0, 5).unwrap();
+    let placed = placed_count(&solution, 1);
+    assert!((5..=20).contains(&placed), "placed {}", placed);
+    // As many as the single sheet holds
+    assert_eq!(placed, 9);
+    // Copies beyond the minimum that did not fit are not reported as missing
+    assert!(solution.no_fit_panels.is_empty());
+}
+
+#[test]
+fn test_flexible_panel_fails_when_minimum_does_not_fit() {
+    let shelf = create_panel(1, "300", "300", 1).with_min_count(10).with_max_count(20);
+    let error = compute_layout(&request_with(shelf), 0, 0, 5).unwrap_err();
+    assert!(error.to_string().contains("at least 10"), "{}", error);
+}
+
+#[test]
+fn test_validate_rejects_max_count_below_min_count() {
+    let shelf = create_panel(1, "300", "300", 1).with_min_count(10).with_max_count(5);
+    let errors = request_with(shelf).validate_semantic().unwrap_err();
+    assert_eq!(errors[0].path, "panels[0].max_count");
+}
+
+/// Submit `request` to a fresh service and return the task's final status
+async fn compute_with_service(request: CalculationRequest) -> cutlist_optimizer_cli::models::TaskStatusResponse {
+    use cutlist_optimizer_cli::engine::service::{CutListOptimizerService, CutListOptimizerServiceImpl};
+    use cutlist_optimizer_cli::models::enums::Status;
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    service.init(2).await.unwrap();
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+    for _ in 0..600 {
+        let status = service.get_task_status(&task_id).await.unwrap().unwrap();
+        if matches!(status.status, Status::Finished | Status::Error) {
+            return status;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    panic!("task {} did not finish", task_id);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_service_places_flexible_panel_between_min_and_max() {
+    use cutlist_optimizer_cli::models::enums::Status;
+
+    let shelf = create_panel(1, "300", "300", 1).with_min_count(5).with_max_count(20);
+    let status = compute_with_service(request_with(shelf)).await;
+
+    assert_eq!(status.status, Status::Finished);
+    let response = status.solution.unwrap();
+    let placed = response.panels.unwrap().iter().filter(|panel| panel.request_obj_id == 1).count();
+    assert!((5..=20).contains(&placed), "placed {}", placed);
+    assert!(response.no_fit_panels.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_service_task_fails_when_minimum_does_not_fit() {
+    use cutlist_optimizer_cli::models::enums::Status;
+
+    let shelf = create_panel(1, "300", "300", 1).with_min_count(10).with_max_count(20);
+    let status = compute_with_service(request_with(shelf)).await;
+
+    assert_eq!(status.status, Status::Error);
+}
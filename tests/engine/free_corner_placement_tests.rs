@@ -0,0 +1,155 @@
+//! Tests for the free-corner placement and the cut style choosing it
+
+use cutlist_optimizer_cli::engine::layout::{compute_layout_with_options, OptimizeOptions};
+use cutlist_optimizer_cli::engine::service::{
+    FreeCornerPlacement, GuillotinePlacement, PlacementAlgorithm, PlacementConfig,
+};
+use cutlist_optimizer_cli::models::{
+    CalculationRequest, Configuration, CutThickness, Panel, Solution, Tile, TileDimensions,
+};
+use cutlist_optimizer_cli::stock::StockSolution;
+use cutlist_optimizer_cli::CutStyle;
+
+fn config_with_kerf(kerf: i32) -> PlacementConfig {
+    PlacementConfig {
+        cut_thickness: CutThickness::from(kerf),
+        ..PlacementConfig::default()
+    }
+}
+
+/// Best solution of `placement` for `tiles` on one `width` x `height` sheet
+fn place_on_one_sheet(
+    placement: &dyn PlacementAlgorithm,
+    tiles: &[TileDimensions],
+    width: i32,
+    height: i32,
+    kerf: i32,
+) -> Solution {
+    let stock_solution = StockSolution::from_tiles(vec![TileDimensions::new(100, width, height)]);
+    let solutions = placement.place(tiles, &stock_solution, &config_with_kerf(kerf)).unwrap();
+    solutions.into_iter().next().unwrap()
+}
+
+fn squares(count: i32, side: i32) -> Vec<TileDimensions> {
+    (1..=count).map(|id| TileDimensions::new(id, side, side)).collect()
+}
+
+fn free_tiles(solution: &Solution) -> Vec<Tile> {
+    solution
+        .get_mosaics()
+        .iter()
+        .flat_map(|mosaic| mosaic.root_tile_node().unused_tiles())
+        .map(|node| node.tile().clone())
+        .collect()
+}
+
+#[test]
+fn test_free_corner_fits_tile_in_l_shaped_remainder() {
+    // After the first square the sheet's remainder is an L; a guillotine cut
+    // past the first square's kerf leaves no room for the second one
+    let tiles = squares(2, 50);
+
+    let guillotine = place_on_one_sheet(&GuillotinePlacement, &tiles, 100, 100, 10);
+    assert_eq!(guillotine.get_nbr_final_tiles(), 1);
+    assert_eq!(guillotine.get_no_fit_panels().len(), 1);
+
+    let free_corner = place_on_one_sheet(&FreeCornerPlacement, &tiles, 100, 100, 10);
+    assert_eq!(free_corner.get_nbr_final_tiles(), 2);
+    assert!(free_corner.get_no_fit_panels().is_empty());
+
+    let mut placed: Vec<Tile> = free_corner.get_final_tile_nodes().iter().map(|node| node.tile().clone()).collect();
+    placed.sort_by_key(|tile| (tile.y1, tile.x1));
+    assert_eq!(placed, vec![Tile::new(0, 50, 0, 50), Tile::new(50, 100, 50, 100)]);
+}
+
+#[test]
+fn test_free_corner_takes_kerf_from_free_nodes_across_tile_edges() {
+    let solution = place_on_one_sheet(&FreeCornerPlacement, &squares(2, 50), 100, 100, 10);
+
+    // The first square's own edges, then the second square's edges facing the free nodes
+    let mosaic = &solution.get_mosaics()[0];
+    assert_eq!(mosaic.nbr_cuts(), 4);
+    assert_eq!(mosaic.cut_sequence().len(), 4);
+
+    let mut free = free_tiles(&solution);
+    free.sort_by_key(|tile| (tile.y1, tile.x1));
+    assert_eq!(free, vec![Tile::new(60, 100, 0, 40), Tile::new(0, 40, 60, 100)]);
+}
+
+#[test]
+fn test_free_corner_places_as_many_tiles_as_guillotine_on_a_grid() {
+    let tiles = squares(4, 45);
+
+    let guillotine = place_on_one_sheet(&GuillotinePlacement, &tiles, 100, 100, 10);
+    let free_corner = place_on_one_sheet(&FreeCornerPlacement, &tiles, 100, 100, 10);
+    assert_eq!(guillotine.get_nbr_final_tiles(), 4);
+    assert_eq!(free_corner.get_nbr_final_tiles(), 4);
+    assert_eq!(free_corner.get_mosaics().len(), 1);
+}
+
+#[test]
+fn test_free_corner_keeps_a_kerf_between_neighbouring_tiles() {
+    let kerf = 4;
+    let tiles: Vec<TileDimensions> = [(720, 560), (564, 460), (564, 100), (715, 396), (400, 300), (1200, 250), (350, 350)]
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &(width, height))| {
+            (0..2).map(move |copy| TileDimensions::new((index * 2 + copy) as i32 + 1, width, height))
+        })
+        .collect();
+    let solution = place_on_one_sheet(&FreeCornerPlacement, &tiles, 2440, 1220, kerf);
+    let placed: Vec<Tile> = solution.get_final_tile_nodes().iter().map(|node| node.tile().clone()).collect();
+    assert!(placed.len() > 1);
+
+    for (index, tile) in placed.iter().enumerate() {
+        for other in &placed[index + 1..] {
+            let rows_overlap = tile.y1 < other.y2 && other.y1 < tile.y2;
+            let columns_overlap = tile.x1 < other.x2 && other.x1 < tile.x2;
+            assert!(!(rows_overlap && columns_overlap), "{:?} overlaps {:?}", tile, other);
+            if rows_overlap {
+                assert!(tile.x2 + kerf <= other.x1 || other.x2 + kerf <= tile.x1, "{:?} touches {:?}", tile, other);
+            }
+            if columns_overlap {
+                assert!(tile.y2 + kerf <= other.y1 || other.y2 + kerf <= tile.y1, "{:?} touches {:?}", tile, other);
+            }
+        }
+    }
+
+    let free = free_tiles(&solution);
+    for (index, tile) in free.iter().enumerate() {
+        assert!(placed.iter().all(|placed| !placed.overlaps_with(tile)));
+        assert!(free[index + 1..].iter().all(|other| !other.overlaps_with(tile)));
+    }
+}
+
+#[test]
+fn test_layout_uses_free_corner_placement_for_the_cut_style() {
+    let panel = |id: i32, size: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(size.to_string())
+            .with_height(size.to_string())
+            .with_count(count)
+            .with_enabled(true)
+    };
+    let mut request = CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "50", 2)],
+        stock_panels: vec![panel(100, "100", 1)],
+    };
+    let options = OptimizeOptions {
+        cut_thickness: Some(10),
+        ..OptimizeOptions::default()
+    };
+
+    let (guillotine, _) = compute_layout_with_options(&request, &options).unwrap();
+    assert_eq!(guillotine.get_nbr_final_tiles(), 1);
+
+    request.configuration = Some(Configuration {
+        cut_style: CutStyle::FreeCorner,
+        ..Configuration::default()
+    });
+    let (free_corner, _) = compute_layout_with_options(&request, &options).unwrap();
+    assert_eq!(free_corner.get_nbr_final_tiles(), 2);
+    assert!(free_corner.get_no_fit_panels().is_empty());
+}
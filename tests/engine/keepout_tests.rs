@@ -0,0 +1,90 @@
+//! Tests for keepout zones on stock sheets
+
+use cutlist_optimizer_cli::engine::layout::compute_layout;
+use cutlist_optimizer_cli::engine::CutListThread;
+use cutlist_optimizer_cli::models::{CalculationRequest, Mosaic, Panel, Tile, TileDimensions};
+use cutlist_optimizer_cli::GrainMode;
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_enabled(true)
+}
+
+/// Final tiles of a mosaic as rectangles
+fn placed_tiles(mosaic: &Mosaic) -> Vec<Tile> {
+    mosaic
+        .final_tile_nodes()
+        .into_iter()
+        .map(|node| Tile::new(node.x1(), node.x2(), node.y1(), node.y2()))
+        .collect()
+}
+
+#[test]
+fn test_central_keepout_rejects_overlapping_tile() {
+    let mut stock = TileDimensions::new(100, 100, 100);
+    stock.keepouts = vec![Tile::new(40, 60, 40, 60)];
+    let mosaic = Mosaic::from_tile_dimensions(&stock);
+    assert_eq!(mosaic.keepouts(), &[Tile::new(40, 60, 40, 60)]);
+
+    let thread = CutListThread::new();
+    let mut results = Vec::new();
+    thread
+        .add_tile_to_mosaic(&TileDimensions::new(1, 50, 50), &mosaic, &mut results, GrainMode::Free)
+        .unwrap();
+    assert!(results.is_empty(), "a 50x50 tile in the corner would cover the keepout");
+
+    thread
+        .add_tile_to_mosaic(&TileDimensions::new(2, 30, 30), &mosaic, &mut results, GrainMode::Free)
+        .unwrap();
+    assert!(!results.is_empty());
+    for result in &results {
+        assert!(placed_tiles(result).iter().all(|tile| result.is_clear_of_keepouts(tile)));
+    }
+
+    // The same tile fits on a sheet without keepouts
+    thread
+        .add_tile_to_mosaic(
+            &TileDimensions::new(1, 50, 50),
+            &Mosaic::from_tile_dimensions(&TileDimensions::new(101, 100, 100)),
+            &mut results,
+            GrainMode::Free,
+        )
+        .unwrap();
+    assert!(results.iter().any(|result| result.keepouts().is_empty()));
+}
+
+#[test]
+fn test_layout_places_parts_around_scaled_keepout() {
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![create_panel(1, "40.5", "40.5", 4)],
+        stock_panels: vec![create_panel(100, "100", "100", 1).with_keepouts(vec![Tile::new(45, 55, 45, 55)])],
+    };
+
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+    assert_eq!(factor, 10.0);
+    let mosaic = &solution.mosaics[0];
+    assert_eq!(mosaic.keepouts(), &[Tile::new(450, 550, 450, 550)]);
+
+    let placed = placed_tiles(mosaic);
+    assert!(!placed.is_empty());
+    for tile in &placed {
+        assert!(mosaic.is_clear_of_keepouts(tile), "{:?} covers the keepout", tile);
+    }
+}
+
+#[test]
+fn test_validate_rejects_empty_keepout() {
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![create_panel(1, "40", "40", 1)],
+        stock_panels: vec![create_panel(100, "100", "100", 1).with_keepouts(vec![Tile::new(60, 50, 10, 20)])],
+    };
+
+    let errors = request.validate_semantic().unwrap_err();
+    assert_eq!(errors[0].path, "stock_panels[0].keepouts[0]");
+}
@@ -0,0 +1,116 @@
+//! Tests for L-shaped panels laid out as their bounding box
+
+use cutlist_optimizer_cli::engine::layout::compute_layout;
+use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, LShape, Panel, TileDimensions, TileNode};
+
+fn create_panel(id: i32, width: &str, height: &str) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(1)
+        .with_enabled(true)
+}
+
+fn request_with(panel: Panel) -> CalculationRequest {
+    CalculationRequest {
+        configuration: Some(Configuration::default()),
+        panels: vec![panel],
+        stock_panels: vec![create_panel(100, "1000", "1000")],
+    }
+}
+
+#[test]
+fn test_parts_split_bounding_box() {
+    let shape = LShape::new(200.0, 150.0);
+    let [base, leg] = shape.parts(&TileDimensions::new(1, 600, 400), 7);
+
+    assert_eq!((base.width(), base.height()), (600, 250));
+    assert_eq!((leg.width(), leg.height()), (400, 150));
+    assert_eq!(base.get_group(), 7);
+    assert_eq!(leg.get_group(), 7);
+    assert_eq!(leg.id(), 1);
+
+    assert!(shape.is_valid_for(600.0, 400.0));
+    assert!(!shape.is_valid_for(200.0, 400.0));
+    assert!(!LShape::new(0.0, 150.0).is_valid_for(600.0, 400.0));
+}
+
+#[test]
+fn test_l_shaped_panel_is_placed_as_two_adjacent_parts() {
+    let request = request_with(create_panel(1, "600", "400").with_l_shape(LShape::new(200.0, 150.0)));
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+    assert!(solution.get_no_fit_panels().is_empty());
+
+    let mosaic = &solution.get_mosaics()[0];
+    let parts: Vec<&TileNode> = mosaic.final_tile_nodes();
+    assert_eq!(parts.len(), 2);
+    assert!(parts.iter().all(|part| part.external_id() == Some(1)));
+    assert!(parts[0].group().is_some());
+    assert_eq!(parts[0].group(), parts[1].group());
+
+    let scale = |length: i32| length as f64 / factor;
+    let mut sizes: Vec<(f64, f64)> = parts
+        .iter()
+        .map(|part| {
+            if part.is_rotated() {
+                (scale(part.height()), scale(part.width()))
+            } else {
+                (scale(part.width()), scale(part.height()))
+            }
+        })
+        .collect();
+    sizes.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    assert_eq!(sizes, vec![(600.0, 250.0), (400.0, 150.0)]);
+
+    // The parts share an edge, so the notch is left as the only gap in the box
+    let (base, leg) = if parts[0].area() > parts[1].area() { (parts[0], parts[1]) } else { (parts[1], parts[0]) };
+    if base.is_rotated() {
+        assert_eq!(leg.x2(), base.x1());
+        assert_eq!(leg.y1(), base.y1());
+    } else {
+        assert_eq!(leg.y1(), base.y2());
+        assert_eq!(leg.x1(), base.x1());
+    }
+}
+
+#[test]
+fn test_request_conversion_carries_the_notch_on_the_bounding_box() {
+    use cutlist_optimizer_cli::engine::service::computation::DimensionUtils;
+
+    let request = request_with(create_panel(1, "600", "400").with_l_shape(LShape::new(200.0, 150.0)));
+    let (tiles, stock_tiles, _) = DimensionUtils::convert_request_to_tiles(&request, 6).unwrap();
+
+    assert_eq!((tiles[0].width, tiles[0].height), (600, 400));
+    assert_eq!(tiles[0].l_shape, Some(LShape::new(200.0, 150.0)));
+    assert_eq!(stock_tiles[0].l_shape, None);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_service_splits_l_shaped_panel_into_its_parts() {
+    use cutlist_optimizer_cli::engine::service::{CutListOptimizerService, CutListOptimizerServiceImpl};
+    use cutlist_optimizer_cli::models::enums::Status;
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    service.init(2).await.unwrap();
+    let request = request_with(create_panel(1, "600", "400").with_l_shape(LShape::new(200.0, 150.0)));
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+
+    let mut status = service.get_task_status(&task_id).await.unwrap().unwrap();
+    for _ in 0..600 {
+        if status.status == Status::Finished {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        status = service.get_task_status(&task_id).await.unwrap().unwrap();
+    }
+    assert_eq!(status.status, Status::Finished);
+
+    let response = status.solution.unwrap();
+    let parts: Vec<&TileNode> = response.mosaics[0].final_tile_nodes();
+    assert_eq!(parts.len(), 2);
+    assert!(parts.iter().all(|part| part.external_id() == Some(1)));
+    assert!(parts[0].group().is_some());
+    assert_eq!(parts[0].group(), parts[1].group());
+    assert_eq!(response.panels.unwrap().len(), 2);
+}
@@ -0,0 +1,79 @@
+//! Tests for per-material first-cut preferences
+
+use std::collections::HashMap;
+
+use cutlist_optimizer_cli::engine::layout::compute_layout;
+use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, Panel};
+use cutlist_optimizer_cli::CutDirection;
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32, material: &str) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material(material.to_string())
+        .with_enabled(true)
+}
+
+fn configuration_with(preferences: &[(&str, i32)]) -> Configuration {
+    Configuration {
+        material_cut_preferences: preferences
+            .iter()
+            .map(|&(material, preference)| (material.to_string(), preference))
+            .collect(),
+        ..Configuration::default()
+    }
+}
+
+#[test]
+fn test_cut_direction_for_falls_back_to_global_preference() {
+    let mut configuration = configuration_with(&[("plywood", 1)]);
+    configuration.cut_orientation_preference = 2;
+
+    assert_eq!(configuration.cut_direction_for("plywood"), CutDirection::Horizontal);
+    assert_eq!(configuration.cut_direction_for("melamine"), CutDirection::Vertical);
+    assert_eq!(Configuration::default().cut_direction_for("plywood"), CutDirection::Both);
+}
+
+#[test]
+fn test_validate_rejects_unknown_material_preference() {
+    assert!(configuration_with(&[("plywood", 1), ("melamine", 2)]).validate().is_ok());
+
+    let error = configuration_with(&[("plywood", 3)]).validate().unwrap_err();
+    assert!(error.to_string().contains("plywood"));
+
+    let request = CalculationRequest {
+        configuration: Some(configuration_with(&[("plywood", -1)])),
+        panels: vec![create_panel(1, "100", "100", 1, "plywood")],
+        stock_panels: vec![create_panel(100, "1000", "1000", 1, "plywood")],
+    };
+    let errors = request.validate_semantic().unwrap_err();
+    assert_eq!(errors[0].path, "configuration.material_cut_preferences.plywood");
+}
+
+#[test]
+fn test_layout_honors_each_material_cut_preference() {
+    let request = CalculationRequest {
+        configuration: Some(configuration_with(&[("plywood", 1), ("melamine", 2)])),
+        panels: vec![
+            create_panel(1, "400", "300", 2, "plywood"),
+            create_panel(2, "400", "300", 2, "melamine"),
+        ],
+        stock_panels: vec![
+            create_panel(100, "1000", "800", 1, "plywood"),
+            create_panel(200, "1000", "800", 1, "melamine"),
+        ],
+    };
+
+    let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+    let first_cuts: HashMap<&str, bool> = solution
+        .mosaics
+        .iter()
+        .map(|mosaic| (mosaic.material(), mosaic.cuts()[0].is_horizontal))
+        .collect();
+
+    assert_eq!(first_cuts.len(), 2);
+    assert!(first_cuts["plywood"]);
+    assert!(!first_cuts["melamine"]);
+}
@@ -0,0 +1,95 @@
+//! Tests for per-material cut thickness
+
+use std::collections::HashMap;
+
+use cutlist_optimizer_cli::engine::layout::compute_layout;
+use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, CutThickness, Mosaic, Panel};
+
+fn create_panel(id: i32, width: &str, height: &str, material: &str) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(1)
+        .with_material(material.to_string())
+        .with_enabled(true)
+}
+
+fn configuration_with(thicknesses: &[(&str, &str)]) -> Configuration {
+    Configuration {
+        material_cut_thickness: thicknesses
+            .iter()
+            .map(|&(material, thickness)| (material.to_string(), thickness.to_string()))
+            .collect(),
+        ..Configuration::default()
+    }
+}
+
+/// Gap between the first placed piece and the remainder next to it
+fn first_cut_kerf(mosaic: &Mosaic) -> i32 {
+    let root = mosaic.root_tile_node();
+    let (piece, remainder) = (root.child1().unwrap(), root.child2().unwrap());
+    if remainder.x1() > piece.x1() {
+        remainder.x1() - piece.x2()
+    } else {
+        remainder.y1() - piece.y2()
+    }
+}
+
+#[test]
+fn test_material_cut_thicknesses_scale_and_respect_cut_removes_material() {
+    let mut configuration = configuration_with(&[("Wood", "4"), ("Metal", "0.5")]);
+    let thicknesses = configuration.material_cut_thicknesses(10.0).unwrap();
+    assert_eq!(thicknesses["Wood"], CutThickness::from(40));
+    assert_eq!(thicknesses["Metal"], CutThickness::from(5));
+
+    configuration.cut_removes_material = false;
+    let thicknesses = configuration.material_cut_thicknesses(10.0).unwrap();
+    assert_eq!(thicknesses["Wood"], CutThickness::default());
+}
+
+#[test]
+fn test_validate_rejects_invalid_material_cut_thickness() {
+    assert!(configuration_with(&[("Wood", "4"), ("Metal", "1.5")]).validate().is_ok());
+
+    let error = configuration_with(&[("Wood", "-4")]).validate().unwrap_err();
+    assert!(error.to_string().contains("Wood"));
+
+    let request = CalculationRequest {
+        configuration: Some(configuration_with(&[("Metal", "thin")])),
+        panels: vec![create_panel(1, "100", "100", "Metal")],
+        stock_panels: vec![create_panel(100, "1000", "1000", "Metal")],
+    };
+    let errors = request.validate_semantic().unwrap_err();
+    assert_eq!(errors[0].path, "configuration.material_cut_thickness.Metal");
+    assert!(!request.validate().unwrap().is_valid());
+}
+
+#[test]
+fn test_layout_uses_each_material_cut_thickness() {
+    let request = CalculationRequest {
+        configuration: Some(configuration_with(&[("Wood", "4"), ("Metal", "1")])),
+        panels: vec![
+            create_panel(1, "500", "400", "Wood"),
+            create_panel(2, "500", "400", "Metal"),
+            create_panel(3, "500", "400", "Plastic"),
+        ],
+        stock_panels: vec![
+            create_panel(100, "1000", "1000", "Wood"),
+            create_panel(200, "1000", "1000", "Metal"),
+            create_panel(300, "1000", "1000", "Plastic"),
+        ],
+    };
+
+    let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+    let kerfs: HashMap<&str, i32> = solution
+        .mosaics
+        .iter()
+        .map(|mosaic| (mosaic.material(), first_cut_kerf(mosaic)))
+        .collect();
+
+    assert_eq!(kerfs["Wood"], 4);
+    assert_eq!(kerfs["Metal"], 1);
+    // Materials without an override keep the global kerf
+    assert_eq!(kerfs["Plastic"], 3);
+}
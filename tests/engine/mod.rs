@@ -1,6 +1,7 @@
 //! Engine tests module
 
 pub mod cut_list_thread_tests;
+pub mod tile_placement_tests;
 pub mod service;
 pub mod service_tests;
 pub mod service_impl_tests;
@@ -19,4 +20,18 @@ pub mod watch_dog_monitoring_tests;
 pub mod watch_dog_statistics_tests;
 pub mod debug_single_thread_tests;
 pub mod debug_comprehensive_test;
+pub mod parallel_layout_tests;
+pub mod consolidation_tests;
 
+pub mod material_cut_preference_tests;
+pub mod keepout_tests;
+pub mod flexible_quantity_tests;
+pub mod offcut_inventory_tests;
+pub mod placement_algorithm_tests;
+pub mod free_corner_placement_tests;
+pub mod material_cut_thickness_tests;
+pub mod panel_priority_tests;
+pub mod stock_affinity_tests;
+pub mod task_checkpoint_tests;
+pub mod tile_grouping_tests;
+pub mod l_shape_tests;
@@ -0,0 +1,125 @@
+//! Tests for carrying reusable offcuts from one job to the next
+
+use cutlist_optimizer_cli::constants::{MaterialConstants, StockConstants};
+use cutlist_optimizer_cli::engine::running_tasks::{get_running_tasks_instance, TaskManager};
+use cutlist_optimizer_cli::engine::service::{CutListOptimizerService, CutListOptimizerServiceImpl};
+use cutlist_optimizer_cli::models::enums::Status;
+use cutlist_optimizer_cli::models::{CalculationRequest, CalculationResponse, Configuration, CutThickness, OffcutInventory, Panel};
+
+fn create_panel(id: i32, width: &str, height: &str) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(1)
+        .with_enabled(true)
+}
+
+fn request_with(panel: Panel, inventory: &OffcutInventory) -> CalculationRequest {
+    let mut request = CalculationRequest {
+        configuration: Some(Configuration {
+            cut_thickness: CutThickness::from(3),
+            reusable_offcut_threshold: Some("50".to_string()),
+            ..Configuration::default()
+        }),
+        panels: vec![panel],
+        stock_panels: vec![create_panel(100, "1000", "1000")],
+    };
+    inventory.add_to_request(&mut request);
+    request
+}
+
+/// Submit a job for `panel` to the service and wait for its solution
+///
+/// Seeing the task finished records it in the service's offcut inventory.
+async fn run_job(service: &CutListOptimizerServiceImpl, panel: Panel) -> CalculationResponse {
+    let request = request_with(panel, &OffcutInventory::new());
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+    for _ in 0..600 {
+        let status = service.get_task_status(&task_id).await.unwrap().unwrap();
+        if status.status == Status::Finished {
+            return status.solution.expect("finished task has a solution");
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    panic!("task {} did not finish", task_id);
+}
+
+#[test]
+fn test_add_remove_and_list_offcuts() {
+    let mut inventory = OffcutInventory::new();
+    assert!(inventory.is_empty());
+
+    let first = inventory.add("Wood", 400.0, 250.5);
+    let second = inventory.add("Metal", 300.0, 300.0);
+    assert_eq!(first, StockConstants::FIRST_OFFCUT_INVENTORY_ID);
+    assert_eq!(second, first + 1);
+
+    let offcut = &inventory.list()[0];
+    assert_eq!(offcut.width.as_deref(), Some("400"));
+    assert_eq!(offcut.height.as_deref(), Some("250.5"));
+    assert_eq!(offcut.material, "Wood");
+    assert_eq!(offcut.count, 1);
+    assert!(offcut.enabled && offcut.consume_first);
+
+    assert_eq!(inventory.remove(first).map(|offcut| offcut.id), Some(first));
+    assert!(inventory.remove(first).is_none());
+    assert_eq!(inventory.len(), 1);
+    // Ids are not handed out twice
+    assert_eq!(inventory.add("Wood", 100.0, 100.0), second + 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_second_task_consumes_offcut_from_first() {
+    let mut service = CutListOptimizerServiceImpl::new();
+    service.init(2).await.unwrap();
+    service.set_offcut_inventory(OffcutInventory::new());
+
+    let first = run_job(&service, create_panel(1, "1000", "600")).await;
+    assert_eq!(first.mosaics[0].stock_id(), 100);
+    let inventory = service.offcut_inventory().unwrap();
+    assert_eq!(inventory.len(), 1);
+    let offcut = inventory.list()[0].clone();
+    assert_eq!(offcut.width.as_deref(), Some("1000"));
+    assert_eq!(offcut.height.as_deref(), Some("397"));
+
+    // The offcut is used before a fresh sheet
+    let second = run_job(&service, create_panel(2, "900", "300")).await;
+    assert!(second.no_fit_panels.is_empty());
+    assert_eq!(second.mosaics.len(), 1);
+    assert_eq!(second.mosaics[0].stock_id(), offcut.id);
+
+    // The used offcut is gone; what is left of it comes back as new offcuts
+    let inventory = service.offcut_inventory().unwrap();
+    assert!(inventory.list().iter().all(|panel| panel.id != offcut.id));
+    assert!(!inventory.is_empty());
+    assert!(inventory.list().iter().all(|panel| panel.id > offcut.id));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_service_offers_inventory_offcuts_as_stock() {
+    let mut inventory = OffcutInventory::new();
+    let offcut_id = inventory.add(MaterialConstants::DEFAULT_MATERIAL, 500.0, 400.0);
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    service.init(2).await.unwrap();
+    assert!(service.offcut_inventory().is_none());
+    service.set_offcut_inventory(inventory.clone());
+    assert_eq!(service.offcut_inventory(), Some(inventory));
+
+    let request = request_with(create_panel(1, "300", "200"), &OffcutInventory::new());
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+
+    let task = get_running_tasks_instance().get_task(&task_id).expect("submitted task");
+    let stock_ids: Vec<i32> = task
+        .read()
+        .calculation_request()
+        .as_ref()
+        .unwrap()
+        .stock_panels
+        .iter()
+        .map(|panel| panel.id)
+        .collect();
+    assert_eq!(stock_ids, vec![100, offcut_id]);
+    service.stop_task(&task_id).await.unwrap();
+}
@@ -0,0 +1,85 @@
+//! Tests for panel placement priority
+
+use cutlist_optimizer_cli::engine::layout::{compute_layout, compute_layout_parallel};
+use cutlist_optimizer_cli::engine::service::computation::DimensionUtils;
+use cutlist_optimizer_cli::models::{CalculationRequest, Panel, TileDimensions};
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material("wood".to_string())
+        .with_enabled(true)
+}
+
+/// Two identical panels competing for a sheet that holds only one of them
+///
+/// The low-priority panel is listed first so input order alone would place it.
+fn competing_request() -> CalculationRequest {
+    CalculationRequest {
+        configuration: None,
+        panels: vec![
+            create_panel(1, "600", "400", 1),
+            create_panel(2, "600", "400", 1).with_priority(5),
+        ],
+        stock_panels: vec![create_panel(100, "700", "500", 1)],
+    }
+}
+
+fn placed_ids(request: &CalculationRequest, parallel: bool) -> (Vec<i32>, Vec<i32>) {
+    let (solution, _) = if parallel {
+        compute_layout_parallel(request, 0, 0, 5).unwrap()
+    } else {
+        compute_layout(request, 0, 0, 5).unwrap()
+    };
+    let placed = solution
+        .get_final_tile_nodes()
+        .iter()
+        .filter_map(|node| node.external_id())
+        .collect();
+    let unplaced = solution.get_no_fit_panels().iter().map(|tile| tile.id).collect();
+    (placed, unplaced)
+}
+
+#[test]
+fn test_priority_defaults_to_zero() {
+    let panel: Panel = serde_json::from_str(
+        r#"{"id": 1, "width": "10", "height": "10", "count": 1, "material": "wood",
+            "enabled": true, "orientation": 0, "label": null, "edge": null}"#,
+    )
+    .unwrap();
+    assert_eq!(panel.priority, 0);
+    assert_eq!(TileDimensions::new(1, 10, 10).priority, 0);
+}
+
+#[test]
+fn test_priority_is_carried_onto_tiles() {
+    let panels = vec![create_panel(1, "600", "400", 2).with_priority(3)];
+    let stock = vec![create_panel(100, "700", "500", 1).with_priority(9)];
+    let (tiles, stock_tiles, _) = DimensionUtils::convert_panels_to_tiles(&panels, &stock, 6).unwrap();
+
+    assert!(tiles.iter().all(|tile| tile.priority == 3));
+    assert_eq!(stock_tiles[0].priority, 0);
+}
+
+#[test]
+fn test_higher_priority_panel_is_placed_first_among_equal_areas() {
+    let request = competing_request();
+
+    for parallel in [false, true] {
+        let (placed, unplaced) = placed_ids(&request, parallel);
+        assert_eq!(placed, vec![2], "parallel = {}", parallel);
+        assert_eq!(unplaced, vec![1], "parallel = {}", parallel);
+    }
+}
+
+#[test]
+fn test_without_priority_input_order_wins() {
+    let mut request = competing_request();
+    request.panels[1].priority = 0;
+
+    let (placed, _) = placed_ids(&request, false);
+    assert_eq!(placed, vec![1]);
+}
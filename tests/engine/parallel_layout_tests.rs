@@ -0,0 +1,362 @@
+//! Tests for the parallel layout path
+//!
+//! The parallel optimizer must pick the same solution no matter how the
+//! runtime schedules the individual permutations. The speedup is measured in
+//! `benches/permutation_search.rs`.
+
+use std::time::{Duration, Instant};
+
+use cutlist_optimizer_cli::engine::layout::{
+    compute_layout, compute_layout_parallel, compute_layout_permutations, compute_layout_with_options, LayoutSearch,
+    OptimizeOptions,
+};
+use cutlist_optimizer_cli::models::{CalculationRequest, CalculationResponse, Configuration, Panel};
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material("wood".to_string())
+        .with_enabled(true)
+}
+
+/// A medium job with several equal-sized parts, so strategies often tie
+fn create_medium_request() -> CalculationRequest {
+    CalculationRequest {
+        configuration: None,
+        panels: vec![
+            create_panel(1, "720", "560", 3),
+            create_panel(2, "564", "560", 2),
+            create_panel(3, "564", "100", 3),
+            create_panel(4, "715", "396", 2),
+                    ],
+        stock_panels: vec![create_panel(100, "2440", "1220", 3)],
+    }
+}
+
+#[test]
+fn test_parallel_layout_is_deterministic() {
+    let request = create_medium_request();
+    let (reference, _) = compute_layout_parallel(&request, 3, 0, 5).unwrap();
+    let reference_id = reference.get_structure_identifier();
+    let reference_efficiency = reference.get_efficiency();
+    assert!(!reference_id.is_empty());
+
+    for run in 0..50 {
+        let (solution, _) = compute_layout_parallel(&request, 3, 0, 5).unwrap();
+        assert_eq!(solution.get_structure_identifier(), reference_id, "run {} picked a different layout", run);
+        assert_eq!(solution.get_efficiency(), reference_efficiency, "run {} changed efficiency", run);
+    }
+}
+
+#[test]
+fn test_parallel_layout_is_at_least_as_good_as_sequential() {
+    let request = create_medium_request();
+    let (sequential, _) = compute_layout(&request, 3, 0, 5).unwrap();
+    let (parallel, _) = compute_layout_parallel(&request, 3, 0, 5).unwrap();
+
+    assert!(parallel.get_nbr_final_tiles() >= sequential.get_nbr_final_tiles());
+}
+
+#[test]
+fn test_parallel_layout_without_budget_is_not_time_bounded() {
+    let request = create_medium_request();
+    let (solution, _) = compute_layout_parallel(&request, 3, 0, 5).unwrap();
+
+    assert!(!solution.time_bounded);
+}
+
+#[test]
+fn test_parallel_layout_returns_best_so_far_when_budget_runs_out() {
+    let mut request = create_medium_request();
+    request.configuration = Some(Configuration {
+        time_budget: Some(Duration::ZERO),
+        ..Configuration::default()
+    });
+
+    let start = Instant::now();
+    let (solution, factor) = compute_layout_parallel(&request, 3, 0, 5).unwrap();
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    assert!(solution.time_bounded);
+    assert!(solution.get_nbr_final_tiles() > 0);
+    assert!(!solution.get_structure_identifier().is_empty());
+
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+    assert!(response.time_bounded);
+}
+
+#[test]
+fn test_permutation_layout_stops_when_budget_runs_out() {
+    let mut request = create_medium_request();
+    request.configuration = Some(Configuration {
+        time_budget: Some(Duration::ZERO),
+        ..Configuration::default()
+    });
+    let options = OptimizeOptions { cut_thickness: Some(3), ..OptimizeOptions::default() };
+
+    // The first batch, an order per thread, always runs
+    let (solution, _) = compute_layout_permutations(&request, &options, 12, 2).unwrap();
+    assert!(solution.time_bounded);
+    assert_eq!(solution.permutations_evaluated, 2);
+    assert!(solution.get_nbr_final_tiles() > 0);
+
+    request.configuration = None;
+    let (solution, _) = compute_layout_permutations(&request, &options, 12, 2).unwrap();
+    assert!(!solution.time_bounded);
+    assert_eq!(solution.permutations_evaluated, 12);
+}
+
+#[test]
+fn test_options_select_parallel_search() {
+    let request = create_medium_request();
+    let options = OptimizeOptions {
+        cut_thickness: Some(3),
+        min_trim: Some(0),
+        accuracy: Some(5),
+        search: LayoutSearch::Parallel,
+        threads: 2,
+        ..OptimizeOptions::default()
+    };
+
+    let (solution, _) = compute_layout_with_options(&request, &options).unwrap();
+    let (reference, _) = compute_layout_parallel(&request, 3, 0, 5).unwrap();
+    assert_eq!(solution.get_structure_identifier(), reference.get_structure_identifier());
+}
+
+/// Eight distinct panels, so every permutation is a different tile order
+fn create_eight_panel_request(seed: Option<u64>) -> CalculationRequest {
+    CalculationRequest {
+        configuration: Some(Configuration {
+            seed,
+            ..Configuration::default()
+        }),
+        panels: vec![
+            create_panel(1, "720", "560", 2),
+            create_panel(2, "564", "460", 2),
+            create_panel(3, "564", "100", 3),
+            create_panel(4, "715", "396", 2),
+            create_panel(5, "400", "300", 2),
+            create_panel(6, "1200", "250", 1),
+            create_panel(7, "350", "350", 2),
+            create_panel(8, "900", "150", 2),
+        ],
+        stock_panels: vec![create_panel(100, "2440", "1220", 4)],
+    }
+}
+
+#[test]
+fn test_permutation_search_matches_serial_result() {
+    let request = create_eight_panel_request(Some(7));
+    let options = OptimizeOptions::default();
+
+    let (serial, _) = compute_layout_permutations(&request, &options, 12, 1).unwrap();
+    let (parallel, _) = compute_layout_permutations(&request, &options, 12, 4).unwrap();
+
+    assert_eq!(parallel.get_nbr_final_tiles(), serial.get_nbr_final_tiles());
+    assert_eq!(parallel.get_structure_identifier(), serial.get_structure_identifier());
+}
+
+#[test]
+fn test_options_select_permutation_search() {
+    let request = create_eight_panel_request(Some(7));
+    let options = OptimizeOptions {
+        search: LayoutSearch::Permutations(Some(12)),
+        threads: 4,
+        ..OptimizeOptions::default()
+    };
+
+    let (solution, _) = compute_layout_with_options(&request, &options).unwrap();
+    let (serial, _) = compute_layout_permutations(&request, &OptimizeOptions::default(), 12, 1).unwrap();
+    assert_eq!(solution.get_structure_identifier(), serial.get_structure_identifier());
+    assert_eq!(solution.permutations_evaluated, 12);
+}
+
+#[test]
+fn test_permutation_search_is_deterministic_with_seed() {
+    let request = create_eight_panel_request(Some(42));
+    let options = OptimizeOptions::default();
+    let (reference, _) = compute_layout_permutations(&request, &options, 12, 4).unwrap();
+
+    for run in 0..5 {
+        let (solution, _) = compute_layout_permutations(&request, &options, 12, 4).unwrap();
+        assert_eq!(
+            solution.get_structure_identifier(),
+            reference.get_structure_identifier(),
+            "run {} picked a different layout",
+            run
+        );
+    }
+}
+
+#[test]
+fn test_permutation_search_stops_at_target_efficiency() {
+    let options = OptimizeOptions::default();
+    let (full, _) = compute_layout_permutations(&create_eight_panel_request(Some(7)), &options, 12, 1).unwrap();
+    assert_eq!(full.permutations_evaluated, 12);
+
+    let mut request = create_eight_panel_request(Some(7));
+    if let Some(configuration) = request.configuration.as_mut() {
+        configuration.target_efficiency = Some(0.1);
+    }
+    let (early, _) = compute_layout_permutations(&request, &options, 12, 1).unwrap();
+
+    assert_eq!(early.permutations_evaluated, 1);
+    assert!(early.get_no_fit_panels().is_empty());
+    assert_eq!(early.get_nbr_final_tiles(), full.get_nbr_final_tiles());
+    assert!(f64::from(early.get_efficiency()) >= 0.1);
+}
+
+#[test]
+fn test_first_acceptable_layout_reported_next_to_best() {
+    let options = OptimizeOptions::default();
+    let request_with_target = |target_efficiency: Option<f64>| {
+        let mut request = create_eight_panel_request(Some(7));
+        if let Some(configuration) = request.configuration.as_mut() {
+            configuration.report_first_acceptable = true;
+            configuration.target_efficiency = target_efficiency;
+        }
+        request
+    };
+
+    // The first order already meets a low target, but a later one needs fewer cuts
+    let request = request_with_target(Some(0.1));
+    let (best, factor) = compute_layout_permutations(&request, &options, 12, 1).unwrap();
+    let first = best.first_acceptable.as_deref().unwrap();
+    assert_eq!(best.permutations_evaluated, 12);
+    assert_eq!(first.permutations_evaluated, 1);
+    assert!(first.get_no_fit_panels().is_empty());
+    assert!(first.get_nbr_cuts() > best.get_nbr_cuts());
+
+    let response = CalculationResponse::from_solution(&request, &best, factor);
+    let first_response = response.first_acceptable.as_deref().unwrap();
+    assert_eq!(first_response.total_nbr_cuts, first.get_nbr_cuts() as u64);
+    assert!(first_response.request.is_none());
+
+    // Without a target the search never stops early, so both are the best layout
+    let (best, _) = compute_layout_permutations(&request_with_target(None), &options, 12, 1).unwrap();
+    let first = best.first_acceptable.as_deref().unwrap();
+    assert_eq!(first.id, best.id);
+    assert_eq!(first.permutations_evaluated, 12);
+    assert_eq!(first.get_nbr_cuts(), best.get_nbr_cuts());
+}
+
+#[test]
+fn test_configured_max_permutations_caps_the_search() {
+    let options = OptimizeOptions::default();
+    let mut request = create_eight_panel_request(Some(7));
+    if let Some(configuration) = request.configuration.as_mut() {
+        configuration.max_permutations = Some(3);
+    }
+
+    let (solution, _) = compute_layout_permutations(&request, &options, 12, 1).unwrap();
+    assert_eq!(solution.permutations_evaluated, 3);
+    assert!(solution.get_no_fit_panels().is_empty());
+}
+
+#[test]
+fn test_adaptive_permutation_search_tries_every_order_of_few_sizes() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout_adaptive_permutations;
+
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![
+            create_panel(1, "600", "400", 2),
+            create_panel(2, "300", "250", 1),
+            create_panel(3, "900", "150", 1),
+        ],
+        stock_panels: vec![create_panel(100, "2440", "1220", 1)],
+    };
+
+    let (solution, _) = compute_layout_adaptive_permutations(&request, &OptimizeOptions::default(), 2).unwrap();
+    assert_eq!(solution.permutations_evaluated, 6);
+    assert!(solution.get_no_fit_panels().is_empty());
+}
+
+#[test]
+fn test_identical_parts_on_one_sheet_size_are_grid_packed() {
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![create_panel(1, "400", "300", 20)],
+        stock_panels: vec![create_panel(100, "2440", "1220", 1)],
+    };
+    let options = OptimizeOptions { cut_thickness: Some(3), ..OptimizeOptions::default() };
+
+    // Restricting the parts to the one sheet changes nothing but forces the search
+    let mut restricted = request.clone();
+    restricted.panels[0].allowed_stock_ids = Some(vec![100]);
+    let (general, _) = compute_layout(&restricted, 3, 0, 5).unwrap();
+    assert!(!general.grid_packed);
+
+    let start = Instant::now();
+    let (grid, _) = compute_layout_permutations(&request, &options, 32, 1).unwrap();
+    println!("grid packed in {:?}", start.elapsed());
+    assert!(grid.grid_packed);
+    assert_eq!(grid.permutations_evaluated, 0);
+    assert_eq!(grid.get_nbr_final_tiles(), general.get_nbr_final_tiles());
+    assert!(grid.get_no_fit_panels().is_empty());
+
+    // Six parts per row, with the kerf between neighbours
+    let mosaic = &grid.get_mosaics()[0];
+    let mut corners: Vec<(i32, i32)> = mosaic.final_tile_nodes().iter().map(|node| (node.y1(), node.x1())).collect();
+    corners.sort();
+    assert_eq!(corners[..7], [(0, 0), (0, 403), (0, 806), (0, 1209), (0, 1612), (0, 2015), (303, 0)]);
+    // A cut per row and per part, as every row and part leaves an offcut
+    assert_eq!(mosaic.cuts().len(), 4 + 3 * 6 + 2);
+
+    let (parallel, _) = compute_layout_parallel(&request, 3, 0, 5).unwrap();
+    assert!(parallel.grid_packed);
+    let (single, _) = compute_layout_with_options(&request, &options).unwrap();
+    assert!(single.grid_packed);
+    let (mixed, _) = compute_layout_parallel(&create_medium_request(), 3, 0, 5).unwrap();
+    assert!(!mixed.grid_packed);
+}
+
+#[test]
+fn test_grid_packing_only_applies_with_default_placement_options() {
+    use cutlist_optimizer_cli::models::enums::PlacementHeuristic;
+
+    let request_with = |configure: fn(&mut Configuration), panel: fn(Panel) -> Panel| CalculationRequest {
+        configuration: Some({
+            let mut configuration = Configuration::default();
+            configure(&mut configuration);
+            configuration
+        }),
+        panels: vec![panel(create_panel(1, "400", "300", 20))],
+        stock_panels: vec![create_panel(100, "2440", "1220", 1)],
+    };
+    let grid_packed = |request: CalculationRequest| {
+        let (solution, _) = compute_layout_with_options(&request, &OptimizeOptions::default()).unwrap();
+        solution.grid_packed
+    };
+
+    assert!(grid_packed(request_with(|_| {}, |panel| panel)));
+    assert!(!grid_packed(request_with(|configuration| configuration.rotation_tolerance = 5, |panel| panel)));
+    assert!(!grid_packed(request_with(
+        |configuration| configuration.placement_heuristic = PlacementHeuristic::BottomLeft,
+        |panel| panel
+    )));
+    assert!(!grid_packed(request_with(|configuration| configuration.fit_within_kerf = false, |panel| panel)));
+    assert!(!grid_packed(request_with(|_| {}, |panel| panel.with_banding_thickness([1.0, 0.0, 1.0, 0.0]))));
+}
+
+#[test]
+fn test_layout_scales_by_the_request_precision() {
+    use cutlist_optimizer_cli::constants::EngineConstants;
+    use cutlist_optimizer_cli::engine::service::computation::DimensionUtils;
+
+    // Four integer and four decimal places exceed the six digits allowed, so
+    // dimensions are scaled by two decimal places, as for submitted tasks
+    let request = CalculationRequest {
+        configuration: Some(Configuration::default()),
+        panels: vec![create_panel(1, "600.1234", "400.5", 2)],
+        stock_panels: vec![create_panel(100, "2000", "1000", 1)],
+    };
+    let (solution, factor) = compute_layout_with_options(&request, &OptimizeOptions::default()).unwrap();
+
+    assert_eq!(factor, 100.0);
+    assert_eq!(DimensionUtils::get_request_decimal_places(&request, EngineConstants::MAX_ALLOWED_DIGITS), 2);
+    assert_eq!(solution.get_nbr_final_tiles(), 2);
+}
@@ -11,6 +11,12 @@ fn create_test_tile(id: i32, width: i32, height: i32, material: &str) -> TileDim
         material: material.to_string(),
         orientation: Orientation::Any,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     }
 }
 
@@ -156,6 +162,12 @@ fn test_different_orientations() {
         material: "Wood".to_string(),
         orientation: Orientation::Horizontal,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     };
 
     let tile_vertical = TileDimensions {
@@ -166,6 +178,12 @@ fn test_different_orientations() {
         material: "Wood".to_string(),
         orientation: Orientation::Vertical,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     };
 
     // Should not be equivalent due to different orientations
@@ -176,3 +194,38 @@ fn test_different_orientations() {
     let groups = PermutationUtils::group_equivalent_tiles(tiles);
     assert_eq!(groups.len(), 2); // Should create separate groups
 }
+
+#[test]
+fn test_adaptive_permutation_count_scales_with_diversity() {
+    let diverse = vec![
+        create_test_tile(1, 600, 400, "Wood"),
+        create_test_tile(2, 300, 250, "Wood"),
+        create_test_tile(3, 900, 150, "Wood"),
+    ];
+    let identical: Vec<TileDimensions> = (0..50).map(|id| create_test_tile(id, 300, 200, "Wood")).collect();
+
+    let statistics = PermutationUtils::calculate_statistics(&diverse);
+    assert_eq!(statistics.tile_count, 3);
+    assert_eq!(statistics.unique_sizes, 3);
+    assert_eq!(statistics.elongated_count, 1);
+    assert_eq!(PermutationUtils::calculate_statistics(&identical).unique_sizes, 1);
+
+    let diverse_count = PermutationUtils::adaptive_permutation_count(&diverse);
+    let identical_count = PermutationUtils::adaptive_permutation_count(&identical);
+    assert!(diverse_count > identical_count, "{} <= {}", diverse_count, identical_count);
+    // Three sizes allow 3! orders; fifty identical tiles only one
+    assert_eq!(diverse_count, 6);
+    assert_eq!(identical_count, 1);
+    assert_eq!(PermutationUtils::adaptive_permutation_count(&[]), 1);
+}
+
+#[test]
+fn test_adaptive_permutation_count_caps_total_work() {
+    use cutlist_optimizer_cli::constants::EngineConstants;
+
+    // Many distinct sizes would allow far more orders than the work cap
+    let tiles: Vec<TileDimensions> = (0..2000).map(|id| create_test_tile(id, 100 + id, 200, "Wood")).collect();
+    let count = PermutationUtils::adaptive_permutation_count(&tiles);
+    assert!(count * tiles.len() <= EngineConstants::ADAPTIVE_PERMUTATION_WORK);
+    assert!(count >= 1);
+}
@@ -0,0 +1,100 @@
+//! Tests for plugging a custom placement algorithm into the layout
+
+use std::sync::Arc;
+
+use cutlist_optimizer_cli::engine::layout::{compute_layout_with_options, OptimizeOptions};
+use cutlist_optimizer_cli::engine::service::{
+    CutListOptimizerServiceImpl, GuillotinePlacement, PlacementAlgorithm, PlacementConfig,
+};
+use cutlist_optimizer_cli::errors::Result;
+use cutlist_optimizer_cli::models::{CalculationRequest, CalculationResponse, Panel, Solution, TileDimensions};
+use cutlist_optimizer_cli::stock::StockSolution;
+
+/// Never places a tile
+#[derive(Debug)]
+struct PlacesNothing;
+
+impl PlacementAlgorithm for PlacesNothing {
+    fn place(
+        &self,
+        _tiles: &[TileDimensions],
+        _stock_solution: &StockSolution,
+        _config: &PlacementConfig,
+    ) -> Result<Vec<Solution>> {
+        Ok(Vec::new())
+    }
+}
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_enabled(true)
+}
+
+fn create_request() -> CalculationRequest {
+    CalculationRequest {
+        configuration: None,
+        panels: vec![create_panel(1, "300", "200", 2), create_panel(2, "100", "100", 1)],
+        stock_panels: vec![create_panel(100, "1000", "1000", 1)],
+    }
+}
+
+#[test]
+fn test_service_placement_algorithm_is_used_by_layout() {
+    let service = CutListOptimizerServiceImpl::new().with_placement_algorithm(Box::new(PlacesNothing));
+    let options = OptimizeOptions {
+        placement: service.placement_algorithm(),
+        ..OptimizeOptions::default()
+    };
+
+    let request = create_request();
+    let (solution, factor) = compute_layout_with_options(&request, &options).unwrap();
+    assert!(solution.get_mosaics().is_empty());
+    assert_eq!(solution.get_no_fit_panels().len(), 3);
+
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+    assert!(response.mosaics.is_empty());
+    let unplaced: i32 = response.no_fit_panels.iter().map(|panel| panel.count).sum();
+    assert_eq!(unplaced, 3);
+}
+
+#[test]
+fn test_default_placement_is_guillotine() {
+    let request = create_request();
+    let (solution, _) = compute_layout_with_options(&request, &OptimizeOptions::default()).unwrap();
+    assert!(solution.get_no_fit_panels().is_empty());
+
+    let explicit = OptimizeOptions {
+        placement: Arc::new(GuillotinePlacement),
+        ..OptimizeOptions::default()
+    };
+    let (same, _) = compute_layout_with_options(&request, &explicit).unwrap();
+    assert_eq!(same.get_mosaics().len(), solution.get_mosaics().len());
+    assert_eq!(same.get_used_area(), solution.get_used_area());
+}
+
+#[test]
+fn test_guillotine_placement_follows_configured_grain_mode() {
+    use cutlist_optimizer_cli::models::Configuration;
+    use cutlist_optimizer_cli::GrainMode;
+
+    // Grain-locked along its length, the panel only fits the sheet turned
+    let mut request = CalculationRequest {
+        configuration: None,
+        panels: vec![create_panel(1, "400", "900", 1).with_orientation(1)],
+        stock_panels: vec![create_panel(100, "1000", "500", 1)],
+    };
+    let (fixed, _) = compute_layout_with_options(&request, &OptimizeOptions::default()).unwrap();
+    assert_eq!(fixed.get_nbr_final_tiles(), 0);
+
+    request.configuration = Some(Configuration {
+        grain_mode: GrainMode::Free,
+        ..Configuration::default()
+    });
+    let (free, _) = compute_layout_with_options(&request, &OptimizeOptions::default()).unwrap();
+    assert_eq!(free.get_nbr_final_tiles(), 1);
+    assert!(free.get_no_fit_panels().is_empty());
+}
@@ -80,3 +80,67 @@ fn test_singleton_instance() {
     // Should be the same instance
     assert!(Arc::ptr_eq(instance1, instance2));
 }
+
+#[test]
+fn test_queue_wait_and_compute_time_with_one_busy_worker() {
+    let running_tasks = Arc::new(RunningTasks::new());
+    running_tasks.add_task(create_test_task("latency_first")).unwrap();
+    running_tasks.add_task(create_test_task("latency_second")).unwrap();
+
+    // A single worker takes tasks in submission order; the second one waits
+    // in the queue while the first is being computed
+    let worker = {
+        let running_tasks = Arc::clone(&running_tasks);
+        std::thread::spawn(move || {
+            for task_id in ["latency_first", "latency_second"] {
+                running_tasks.update_task_status(task_id, Status::Queued, Status::Running).unwrap();
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                running_tasks.update_task_status(task_id, Status::Running, Status::Finished).unwrap();
+            }
+        })
+    };
+    worker.join().unwrap();
+
+    let first = running_tasks.task_info("latency_first").unwrap();
+    let second = running_tasks.task_info("latency_second").unwrap();
+
+    assert!(second.compute_started_at.unwrap() >= first.completed_at.unwrap());
+    assert!(second.queue_wait_time().unwrap() > first.queue_wait_time().unwrap());
+    assert!(first.compute_time().unwrap() >= 50);
+    assert!(second.compute_time().unwrap() >= 50);
+
+    let stats = running_tasks.get_stats();
+    assert!(stats.avg_queue_wait > 0.0);
+    assert!(stats.avg_compute_time >= 50.0);
+    let second_report = stats
+        .task_reports
+        .iter()
+        .find(|report| report.task_id == "latency_second")
+        .unwrap();
+    assert_eq!(second_report.queue_wait_ms, second.queue_wait_time().map(|ms| ms as i64));
+}
+
+#[test]
+fn test_queued_task_has_no_queue_wait_or_compute_time() {
+    let running_tasks = RunningTasks::new();
+    running_tasks.add_task(create_test_task("latency_queued")).unwrap();
+
+    let info = running_tasks.task_info("latency_queued").unwrap();
+    assert_eq!(info.queue_wait_time(), None);
+    assert_eq!(info.compute_time(), None);
+    assert_eq!(running_tasks.task_info("latency_missing"), None);
+}
+
+#[test]
+fn test_task_status_changes_are_timed_by_the_shared_instance() {
+    let running_tasks = RunningTasks::get_instance();
+    running_tasks.add_task(create_test_task("latency_shared")).unwrap();
+
+    let task = running_tasks.get_task("latency_shared").unwrap();
+    task.read().set_running_status().unwrap();
+    assert!(running_tasks.task_info("latency_shared").unwrap().compute_started_at.is_some());
+
+    task.read().stop().unwrap();
+    assert!(running_tasks.task_info("latency_shared").unwrap().compute_time().is_some());
+    running_tasks.remove_task("latency_shared").unwrap();
+}
@@ -23,6 +23,12 @@ fn create_test_tile(id: i32, width: i32, height: i32, material: &str) -> TileDim
         material: material.to_string(),
         orientation: Orientation::Any,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     }
 }
 
@@ -36,6 +42,12 @@ fn create_test_tile_with_label(id: i32, width: i32, height: i32, material: &str,
         material: material.to_string(),
         orientation: Orientation::Any,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     }
 }
 
@@ -450,3 +462,83 @@ mod performance_tests {
         assert_eq!(groups.len(), 1000, "Should process all 1000 tiles");
     }
 }
+
+/// Groups of equal area but different shapes, so area sorting leaves ties
+fn equal_area_groups() -> Vec<GroupedTileDimensions> {
+    let shapes = [(300, 200), (200, 300), (600, 100), (100, 600), (150, 400), (400, 150), (240, 250)];
+    shapes
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &(width, height))| {
+            (0..2).map(move |_| GroupedTileDimensions {
+                tile_dimensions: create_test_tile(index as i32 + 1, width, height, "Wood"),
+                group: 0,
+            })
+        })
+        .collect()
+}
+
+/// Order groups the way the service does with a seed, then lay them out
+fn seeded_layout_identifier(seed: u64) -> String {
+    use cutlist_optimizer_cli::engine::cut_list_thread::CutListThread;
+    use cutlist_optimizer_cli::stock::StockSolution;
+
+    let grouped = equal_area_groups();
+    // A fresh HashMap each run, so iteration order differs between runs
+    let distinct = CollectionUtils::get_distinct_grouped_tile_dimensions(&grouped).unwrap();
+    let mut order = CollectionUtils::seeded_group_order(&distinct, seed);
+    order.sort_by_key(|group| std::cmp::Reverse(group.tile_dimensions.area()));
+
+    let tiles: Vec<TileDimensions> = order
+        .iter()
+        .flat_map(|group| grouped.iter().filter(move |g| *g == group).map(|g| g.tile_dimensions.clone()))
+        .collect();
+
+    let mut thread = CutListThread::new();
+    thread.set_tiles(tiles);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(vec![create_test_tile(100, 1000, 1000, "Wood")])));
+    thread.run();
+    assert!(!thread.has_error());
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    solutions[0].get_structure_identifier()
+}
+
+#[test]
+fn test_seeded_group_order_ignores_hash_map_order() {
+    let grouped = equal_area_groups();
+    let reference = CollectionUtils::seeded_group_order(
+        &CollectionUtils::get_distinct_grouped_tile_dimensions(&grouped).unwrap(),
+        11,
+    );
+    assert_eq!(reference.len(), 7);
+
+    for _ in 0..10 {
+        let distinct = CollectionUtils::get_distinct_grouped_tile_dimensions(&grouped).unwrap();
+        assert_eq!(CollectionUtils::seeded_group_order(&distinct, 11), reference);
+    }
+}
+
+#[test]
+fn test_seeded_runs_produce_identical_structure() {
+    let reference = seeded_layout_identifier(2024);
+    assert!(!reference.is_empty());
+    for _ in 0..5 {
+        assert_eq!(seeded_layout_identifier(2024), reference);
+    }
+}
+
+#[test]
+fn test_configuration_seed_defaults_to_none() {
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut value = serde_json::to_value(Configuration::default()).unwrap();
+    value.as_object_mut().unwrap().remove("seed");
+    let configuration: Configuration = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(configuration.seed, None);
+
+    value["seed"] = serde_json::json!(99);
+    let configuration: Configuration = serde_json::from_value(value).unwrap();
+    assert_eq!(configuration.seed, Some(99));
+}
@@ -32,6 +32,12 @@ fn create_test_tiles(material: &str, count: usize) -> Vec<TileDimensions> {
             orientation: Orientation::Any,
             label: Some(format!("Tile_{}", i)),
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         })
         .collect()
 }
@@ -47,6 +53,12 @@ fn create_test_stock_tiles(material: &str, count: usize) -> Vec<TileDimensions>
             orientation: Orientation::Any,
             label: Some(format!("Stock_{}", i)),
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         })
         .collect()
 }
@@ -253,3 +265,144 @@ fn create_test_stock_tiles(material: &str, count: usize) -> Vec<TileDimensions>
     
 //     Ok(())
 // }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_compute_material_reports_progress_until_done() {
+    use cutlist_optimizer_cli::engine::service::{
+        computation::{material_compute::compute_material, ComputeHooks}, ProgressCallback, ProgressUpdate,
+    };
+    use std::sync::Mutex;
+
+    let updates: Arc<Mutex<Vec<ProgressUpdate>>> = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&updates);
+    let progress = ProgressCallback::new(Box::new(move |update| collected.lock().unwrap().push(update)));
+
+    let task_id = Uuid::new_v4().to_string();
+    let task_arc = Arc::new(RwLock::new(Task::new(task_id.clone())));
+
+    compute_material(
+        create_test_tiles("Wood", 4),
+        create_test_stock_tiles("Wood", 2),
+        &Configuration::default(),
+        task_arc,
+        "Wood",
+        &ComputeHooks { progress: Some(progress), ..ComputeHooks::default() },
+    )
+    .await
+    .unwrap();
+
+    let updates = updates.lock().unwrap();
+    assert!(updates.len() > 1);
+    assert!(updates.iter().all(|update| update.task_id == task_id && update.material == "Wood"));
+    assert!(updates.windows(2).all(|pair| pair[0].percentage <= pair[1].percentage));
+    assert!(updates.windows(2).all(|pair| pair[0].permutation_index <= pair[1].permutation_index));
+    assert_eq!(updates.last().unwrap().percentage, 100);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_compute_material_reports_intermediate_progress() {
+    use cutlist_optimizer_cli::engine::service::{
+        computation::{material_compute::compute_material, ComputeHooks}, ProgressCallback, ProgressUpdate,
+    };
+    use std::sync::Mutex;
+
+    let percentages: Arc<Mutex<Vec<i32>>> = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&percentages);
+    let progress = ProgressCallback::new(Box::new(move |update| collected.lock().unwrap().push(update.percentage)));
+
+    let task_arc = Arc::new(RwLock::new(Task::new(Uuid::new_v4().to_string())));
+    compute_material(
+        create_test_tiles("Wood", 4),
+        create_test_stock_tiles("Wood", 2),
+        &Configuration::default(),
+        task_arc,
+        "Wood",
+        &ComputeHooks { progress: Some(progress), ..ComputeHooks::default() },
+    )
+    .await
+    .unwrap();
+
+    let percentages = percentages.lock().unwrap();
+    assert!(percentages.iter().any(|&percentage| 10 < percentage && percentage < 100), "{:?}", percentages);
+    assert!(percentages.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(percentages.last(), Some(&100));
+
+    // Halfway through the third of four permutations, each with two stock solutions
+    assert_eq!(ProgressUpdate::percentage_of_work(2, 4, 1, 2), 62);
+    assert_eq!(ProgressUpdate::percentage_of_work(3, 4, 2, 2), 99);
+    assert_eq!(ProgressUpdate::percentage_of_work(4, 4, 0, 1), 100);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_compute_material_exits_when_task_is_stopped() {
+    use cutlist_optimizer_cli::engine::service::{
+        computation::{material_compute::compute_material, ComputeHooks}, ProgressCallback,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let task_id = Uuid::new_v4().to_string();
+    let task_arc = Arc::new(RwLock::new(Task::new(task_id)));
+
+    // Count finished permutations and stop the task after the first one
+    let permutations_done = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&permutations_done);
+    let stopping_task = Arc::clone(&task_arc);
+    let progress = ProgressCallback::new(Box::new(move |update| {
+        if update.percentage < 100 && counter.fetch_add(1, Ordering::SeqCst) == 0 {
+            stopping_task.read().stop().unwrap();
+        }
+    }));
+
+    // Six distinct tiles give hundreds of permutations to work through
+    compute_material(
+        create_test_tiles("Wood", 6),
+        create_test_stock_tiles("Wood", 2),
+        &Configuration::default(),
+        Arc::clone(&task_arc),
+        "Wood",
+        &ComputeHooks { progress: Some(progress), ..ComputeHooks::default() },
+    )
+    .await
+    .unwrap();
+
+    let task = task_arc.read();
+    assert!(task.is_cancelled());
+    assert_eq!(task.status(), Status::Finished);
+    assert_eq!(permutations_done.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_compute_material_records_duplicate_permutations() {
+    use cutlist_optimizer_cli::engine::service::{
+        computation::{material_compute::compute_material, ComputeHooks},
+    };
+
+    // Copies of one panel are split into several groups of the same size, so
+    // reordering those groups gives the same tile order
+    let tiles: Vec<TileDimensions> = (0..8)
+        .map(|_| {
+            let mut tile = TileDimensions::new(1, 100, 200);
+            tile.material = "Wood".to_string();
+            tile
+        })
+        .collect();
+    let task_arc = Arc::new(RwLock::new(Task::new(Uuid::new_v4().to_string())));
+    assert!(task_arc.read().permutation_stats().is_none());
+
+    compute_material(
+        tiles,
+        create_test_stock_tiles("Wood", 2),
+        &Configuration::default(),
+        Arc::clone(&task_arc),
+        "Wood",
+        &ComputeHooks::default(),
+    )
+    .await
+    .unwrap();
+
+    let stats = task_arc.read().permutation_stats().expect("stats are recorded");
+    assert!(stats.permutations_generated > 1);
+    assert!(stats.duplicates_removed > 0);
+    assert_eq!(stats.permutations_evaluated, stats.permutations_generated - stats.duplicates_removed);
+    assert!(!stats.early_exit_triggered);
+}
@@ -77,6 +77,7 @@ mod decimal_places_tests {
             orientation: 0,
             label: None,
             edge: None,
+            ..Default::default()
         }
     }
 
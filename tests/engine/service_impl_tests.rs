@@ -87,6 +87,7 @@ async fn test_submit_valid_request() {
         orientation: 0,
         label: Some("Test Panel".to_string()),
         edge: None,
+        ..Default::default()
     };
 
     let valid_stock_panel = Panel {
@@ -99,6 +100,7 @@ async fn test_submit_valid_request() {
         orientation: 0,
         label: Some("Stock Panel".to_string()),
         edge: None,
+        ..Default::default()
     };
 
     let request = CalculationRequest {
@@ -134,6 +136,7 @@ async fn test_submit_invalid_panels() {
         orientation: 0,
         label: Some("Invalid Panel".to_string()),
         edge: None,
+        ..Default::default()
     };
 
     let valid_stock_panel = Panel {
@@ -146,6 +149,7 @@ async fn test_submit_invalid_panels() {
         orientation: 0,
         label: Some("Stock Panel".to_string()),
         edge: None,
+        ..Default::default()
     };
 
     let request = CalculationRequest {
@@ -436,3 +440,488 @@ async fn test_terminate_task_invalid_status() {
 //     let all_tasks = service.get_tasks(None).await.unwrap();
 //     assert_eq!(all_tasks.len(), 4);
 // }
+
+#[test]
+fn test_progress_callback_is_registered() {
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.progress_callback().is_none());
+
+    service.set_progress_callback(Box::new(|_| {}));
+    assert!(service.progress_callback().is_some());
+}
+
+fn feasibility_request(panels: &[(&str, &str, i32)], stock: &[(&str, &str, i32)]) -> CalculationRequest {
+    use cutlist_optimizer_cli::models::Panel;
+
+    let panel = |id: usize, (width, height, count): &(&str, &str, i32)| {
+        Panel::new()
+            .with_id(id as i32)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(*count)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    CalculationRequest {
+        configuration: None,
+        panels: panels.iter().enumerate().map(|(id, spec)| panel(id + 1, spec)).collect(),
+        stock_panels: stock.iter().enumerate().map(|(id, spec)| panel(id + 100, spec)).collect(),
+    }
+}
+
+#[test]
+fn test_feasibility_check_reports_insufficient_area() {
+    use cutlist_optimizer_cli::models::FeasibilityConstraint;
+
+    let service = CutListOptimizerServiceImpl::new();
+    let request = feasibility_request(&[("500", "500", 5)], &[("1000", "1000", 1)]);
+
+    let report = service.feasibility_check(&request);
+    assert!(!report.feasible);
+    assert_eq!(
+        report.binding_constraint,
+        Some(FeasibilityConstraint::InsufficientArea {
+            material: "wood".to_string(),
+            panel_area: 1_250_000.0,
+            stock_area: 1_000_000.0,
+        })
+    );
+
+    let report = service.feasibility_check(&feasibility_request(&[("500", "500", 4)], &[("1000", "1000", 1)]));
+    assert!(report.feasible);
+    assert!(report.binding_constraint.is_none());
+}
+
+#[test]
+fn test_feasibility_check_reports_oversized_panel() {
+    use cutlist_optimizer_cli::models::FeasibilityConstraint;
+
+    let service = CutListOptimizerServiceImpl::new();
+    let request = feasibility_request(
+        &[("300", "200", 1), ("1200", "100", 1), ("900", "100", 1)],
+        &[("1000", "800", 2)],
+    );
+
+    let report = service.feasibility_check(&request);
+    assert!(!report.feasible);
+    assert_eq!(
+        report.binding_constraint,
+        Some(FeasibilityConstraint::OversizedPanel { panel_id: 2, width: 1200.0, height: 100.0 })
+    );
+}
+
+#[test]
+fn test_feasibility_check_handles_thousands_of_panels() {
+    let panels: Vec<(String, String, i32)> = (1..=5000)
+        .map(|i| ((10 + i % 90).to_string(), (10 + i % 70).to_string(), 1))
+        .collect();
+    let panels: Vec<(&str, &str, i32)> = panels.iter().map(|(w, h, c)| (w.as_str(), h.as_str(), *c)).collect();
+    let stock = [("2800", "2070", 20), ("2440", "1220", 20)];
+
+    let start = std::time::Instant::now();
+    let report = CutListOptimizerServiceImpl::new().feasibility_check(&feasibility_request(&panels, &stock));
+    assert!(report.feasible);
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+}
+
+/// Poll a submitted task until it finishes
+async fn wait_until_finished(
+    service: &CutListOptimizerServiceImpl,
+    task_id: &str,
+) -> cutlist_optimizer_cli::models::TaskStatusResponse {
+    use cutlist_optimizer_cli::models::enums::Status;
+
+    for _ in 0..600 {
+        let status = service.get_task_status(task_id).await.unwrap().unwrap();
+        if status.status == Status::Finished {
+            return status;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    panic!("task {} did not finish", task_id);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_is_computed() {
+    use cutlist_optimizer_cli::engine::service::ProgressUpdate;
+    use std::sync::{Arc, Mutex};
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+    let updates: Arc<Mutex<Vec<ProgressUpdate>>> = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&updates);
+    service.set_progress_callback(Box::new(move |update| collected.lock().unwrap().push(update)));
+
+    // Without a configuration the defaults apply
+    let request = feasibility_request(&[("400", "300", 2), ("200", "100", 3)], &[("1000", "1000", 1)]);
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+
+    let response = wait_until_finished(&service, &task_id).await.solution.expect("finished task has a solution");
+    assert_eq!(response.panels.as_ref().map(Vec::len), Some(5));
+    assert!(response.no_fit_panels.is_empty());
+    assert_eq!(response.mosaics.len(), 1);
+
+    let updates = updates.lock().unwrap();
+    assert!(updates.iter().all(|update| update.task_id == task_id));
+    assert_eq!(updates.last().map(|update| update.percentage), Some(100));
+    assert!(updates.last().unwrap().best_efficiency > 0.0);
+    assert_eq!(updates.last().unwrap().tiles_placed, 5);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_reports_first_acceptable_solution() {
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+    let submit = |target_efficiency: Option<f64>| {
+        let mut request = feasibility_request(
+            &[("400", "300", 2), ("200", "100", 3)],
+            &[("1000", "1000", 1), ("600", "700", 1)],
+        );
+        request.configuration = Some(Configuration {
+            report_first_acceptable: true,
+            target_efficiency,
+            ..Configuration::default()
+        });
+        request
+    };
+
+    // A low target is met by the first solution placing every panel, whatever the best ends up being
+    let task_id = service.submit_task(submit(Some(0.05))).await.unwrap().task_id.unwrap();
+    let best = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    let first = best.first_acceptable.as_deref().expect("first acceptable solution is reported");
+    assert!(first.request.is_none());
+    assert!(first.no_fit_panels.is_empty());
+    assert!(first.total_used_area_ratio >= 0.05);
+    assert!(first.total_wasted_area >= best.total_wasted_area - 1e-6);
+
+    // Without a target the search never stops early, so both are the best solution
+    let task_id = service.submit_task(submit(None)).await.unwrap().task_id.unwrap();
+    let best = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    let first = best.first_acceptable.as_deref().expect("first acceptable solution is reported");
+    assert_eq!(first.mosaics.len(), best.mosaics.len());
+    assert_eq!(first.total_nbr_cuts, best.total_nbr_cuts);
+    assert_eq!(first.total_wasted_area, best.total_wasted_area);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_stops_when_time_budget_runs_out() {
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+    let mut request = feasibility_request(&[("400", "300", 2), ("200", "100", 3), ("150", "120", 2)], &[("1000", "1000", 1)]);
+    request.configuration = Some(Configuration {
+        time_budget: Some(std::time::Duration::ZERO),
+        ..Configuration::default()
+    });
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+
+    // The first batch, a permutation per thread of the pool, always runs
+    let response = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    assert!(response.time_bounded);
+    assert_eq!(response.meta.permutation_stats.map(|stats| stats.permutations_evaluated), Some(4));
+    assert_eq!(response.panels.as_ref().map(Vec::len), Some(7));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stopped_task_keeps_its_best_solution_and_stops_computing() {
+    use cutlist_optimizer_cli::engine::running_tasks::{get_running_tasks_instance, TaskManager};
+    use cutlist_optimizer_cli::models::enums::Status;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+    let tiles_placed = Arc::new(AtomicUsize::new(0));
+    let placed = Arc::clone(&tiles_placed);
+    service.set_progress_callback(Box::new(move |update| {
+        placed.fetch_max(update.tiles_placed, Ordering::SeqCst);
+    }));
+
+    // Nine distinct sizes give a thousand permutations to work through
+    let panels: Vec<(String, String, i32)> = (0..9).map(|i| ((300 + 40 * i).to_string(), (200 + 10 * i).to_string(), 2)).collect();
+    let panels: Vec<(&str, &str, i32)> = panels.iter().map(|(w, h, c)| (w.as_str(), h.as_str(), *c)).collect();
+    let request = feasibility_request(&panels, &[("1000", "800", 4)]);
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+
+    for _ in 0..600 {
+        if tiles_placed.load(Ordering::SeqCst) > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let stopped = service.stop_task(&task_id).await.unwrap().expect("task exists");
+    assert_eq!(stopped.status, Status::Finished);
+    let response = stopped.solution.expect("stopped task keeps its best solution");
+    assert!(!response.panels.unwrap_or_default().is_empty());
+
+    // The worker records its statistics when it leaves the permutation loop
+    let task_arc = get_running_tasks_instance().get_task(&task_id).unwrap();
+    let mut stats = None;
+    for _ in 0..600 {
+        stats = task_arc.read().permutation_stats();
+        if stats.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let stats = stats.expect("worker exits after the stop");
+    assert!(task_arc.read().is_cancelled());
+    assert!(stats.permutations_evaluated < stats.permutations_generated - stats.duplicates_removed);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_reports_intermediate_progress() {
+    use std::sync::{Arc, Mutex};
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+    let percentages: Arc<Mutex<Vec<i32>>> = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&percentages);
+    service.set_progress_callback(Box::new(move |update| collected.lock().unwrap().push(update.percentage)));
+
+    let request = feasibility_request(&[("400", "300", 1), ("300", "200", 1), ("200", "100", 1)], &[("1000", "1000", 1)]);
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+    wait_until_finished(&service, &task_id).await;
+
+    let percentages = percentages.lock().unwrap();
+    assert!(percentages.iter().any(|&percentage| 10 < percentage && percentage < 100), "{:?}", percentages);
+    assert!(percentages.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_reports_permutation_stats() {
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+
+    // Copies of one panel split into groups of the same size, so reordering
+    // those groups repeats tile orders; two sheet sizes keep the job from
+    // being packed as a grid
+    let request = feasibility_request(&[("200", "100", 8)], &[("1000", "1000", 1), ("1000", "900", 1)]);
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+    let response = wait_until_finished(&service, &task_id).await.solution.unwrap();
+
+    let stats = response.meta.permutation_stats.expect("stats are reported");
+    assert!(stats.duplicates_removed > 0);
+    assert_eq!(stats.permutations_evaluated, stats.permutations_generated - stats.duplicates_removed);
+    assert_eq!(response.panels.map(|panels| panels.len()), Some(8));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_packs_identical_parts_as_a_grid() {
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+
+    let request = feasibility_request(&[("400", "300", 20)], &[("2440", "1220", 1)]);
+    let task_id = service.submit_task(request.clone()).await.unwrap().task_id.unwrap();
+    let grid = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    assert!(grid.grid_packed);
+    assert!(grid.meta.permutation_stats.is_none());
+    assert!(grid.no_fit_panels.is_empty());
+
+    // Restricting the parts to the one sheet changes nothing but forces the search
+    let mut restricted = request;
+    restricted.panels[0].allowed_stock_ids = Some(vec![100]);
+    let task_id = service.submit_task(restricted).await.unwrap().task_id.unwrap();
+    let general = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    assert!(!general.grid_packed);
+    assert!(general.meta.permutation_stats.is_some());
+    assert_eq!(grid.panels.map(|panels| panels.len()), general.panels.map(|panels| panels.len()));
+    assert_eq!(grid.mosaics.len(), general.mosaics.len());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_tries_at_most_max_permutations() {
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+
+    let panels: Vec<(String, String, i32)> = (0..12).map(|i| ((100 + 20 * i).to_string(), (50 + 10 * i).to_string(), 1)).collect();
+    let panels: Vec<(&str, &str, i32)> = panels.iter().map(|(w, h, c)| (w.as_str(), h.as_str(), *c)).collect();
+    let mut request = feasibility_request(&panels, &[("2000", "2000", 1)]);
+    request.configuration = Some(Configuration { max_permutations: Some(50), ..Configuration::default() });
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+    let response = wait_until_finished(&service, &task_id).await.solution.unwrap();
+
+    let stats = response.meta.permutation_stats.expect("stats are reported");
+    assert!(stats.permutations_generated <= 50);
+    assert!(stats.permutations_evaluated <= 50);
+    assert_eq!(response.panels.map(|panels| panels.len()), Some(12));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_stops_at_target_efficiency() {
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+    let panels: Vec<(String, String, i32)> = (0..8).map(|i| ((100 + 20 * i).to_string(), (50 + 10 * i).to_string(), 1)).collect();
+    let panels: Vec<(&str, &str, i32)> = panels.iter().map(|(w, h, c)| (w.as_str(), h.as_str(), *c)).collect();
+    let submit = |target_efficiency: Option<f64>| {
+        let mut request = feasibility_request(&panels, &[("1000", "1000", 1)]);
+        request.configuration = Some(Configuration { target_efficiency, ..Configuration::default() });
+        request
+    };
+
+    // Any layout placing every panel meets a low target, so the first batch,
+    // a permutation per thread of the pool, ends the search
+    let task_id = service.submit_task(submit(Some(0.05))).await.unwrap().task_id.unwrap();
+    let early = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    let early_stats = early.meta.permutation_stats.expect("stats are reported");
+    assert!(early_stats.early_exit_triggered);
+    assert_eq!(early_stats.permutations_evaluated, 4);
+    assert!(early.no_fit_panels.is_empty());
+    assert!(early.total_used_area_ratio >= 0.05);
+
+    let task_id = service.submit_task(submit(None)).await.unwrap().task_id.unwrap();
+    let full = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    let full_stats = full.meta.permutation_stats.expect("stats are reported");
+    assert!(full_stats.permutations_evaluated > early_stats.permutations_evaluated);
+    assert_eq!(full.panels.map(|panels| panels.len()), early.panels.map(|panels| panels.len()));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_submitted_task_tries_an_adaptive_number_of_permutations() {
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+
+    // Five sizes allow 5! = 120 orders, but five sizes only earn fifty
+    let panels: Vec<(String, String, i32)> = (0..5).map(|i| ((100 + 20 * i).to_string(), (80 + 10 * i).to_string(), 1)).collect();
+    let panels: Vec<(&str, &str, i32)> = panels.iter().map(|(w, h, c)| (w.as_str(), h.as_str(), *c)).collect();
+    let request = feasibility_request(&panels, &[("1000", "1000", 1)]);
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+    let response = wait_until_finished(&service, &task_id).await.solution.unwrap();
+
+    let stats = response.meta.permutation_stats.expect("stats are reported");
+    assert_eq!(stats.permutations_generated, 120);
+    assert_eq!(stats.permutations_evaluated, 50);
+    assert_eq!(response.panels.map(|panels| panels.len()), Some(5));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_submitted_task_layout_is_deterministic() {
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+
+    // Sizes of equal area give the permutation order nothing but ties to go on
+    let request = feasibility_request(
+        &[("400", "300", 2), ("600", "200", 2), ("200", "150", 3), ("150", "200", 2)],
+        &[("1000", "800", 2)],
+    );
+    let mut layouts = Vec::new();
+    for _ in 0..4 {
+        let task_id = service.submit_task(request.clone()).await.unwrap().task_id.unwrap();
+        let response = wait_until_finished(&service, &task_id).await.solution.unwrap();
+        let layout: Vec<Vec<(i32, i32, i32, i32)>> = response
+            .mosaics
+            .iter()
+            .map(|mosaic| {
+                let mut tiles: Vec<_> = mosaic
+                    .final_tile_nodes()
+                    .iter()
+                    .map(|node| (node.x1(), node.y1(), node.width(), node.height()))
+                    .collect();
+                tiles.sort();
+                tiles
+            })
+            .collect();
+        layouts.push((layout, response.total_wasted_area));
+    }
+    assert!(layouts.windows(2).all(|pair| pair[0] == pair[1]), "{:?}", layouts);
+}
+
+/// Guillotine placement slowed down, so a search runs long enough to checkpoint
+#[derive(Debug)]
+struct SlowPlacement;
+
+impl cutlist_optimizer_cli::engine::service::PlacementAlgorithm for SlowPlacement {
+    fn place(
+        &self,
+        tiles: &[cutlist_optimizer_cli::models::TileDimensions],
+        stock_solution: &cutlist_optimizer_cli::stock::StockSolution,
+        config: &cutlist_optimizer_cli::engine::service::PlacementConfig,
+    ) -> cutlist_optimizer_cli::errors::Result<Vec<cutlist_optimizer_cli::models::Solution>> {
+        use cutlist_optimizer_cli::engine::service::{GuillotinePlacement, PlacementAlgorithm};
+
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        GuillotinePlacement.place(tiles, stock_solution, config)
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_service_watch_dog_checkpoints_running_tasks() {
+    use cutlist_optimizer_cli::engine::WatchDogConfig;
+    use cutlist_optimizer_cli::models::TaskCheckpoint;
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir().unwrap();
+    let config = WatchDogConfig::default()
+        .with_check_interval(Duration::from_millis(20))
+        .with_checkpoint_dir(dir.path())
+        .with_checkpoint_interval(Duration::from_millis(20));
+    let mut service = CutListOptimizerServiceImpl::new()
+        .with_watch_dog_config(config)
+        .with_placement_algorithm(Box::new(SlowPlacement));
+    assert!(service.init(4).await.is_ok());
+
+    let panels: Vec<(String, String, i32)> = (0..9).map(|i| ((300 + 40 * i).to_string(), (200 + 10 * i).to_string(), 2)).collect();
+    let panels: Vec<(&str, &str, i32)> = panels.iter().map(|(w, h, c)| (w.as_str(), h.as_str(), *c)).collect();
+    let request = feasibility_request(&panels, &[("1000", "800", 4)]);
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+
+    let path = TaskCheckpoint::path_for(dir.path(), &task_id);
+    for _ in 0..1000 {
+        if path.exists() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    service.stop_task(&task_id).await.unwrap();
+
+    let checkpoint = TaskCheckpoint::read(&path).expect("the service's watch dog checkpoints running tasks");
+    assert_eq!(checkpoint.task_id, task_id);
+    assert!(checkpoint.calculation_request.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_resumed_task_continues_from_checkpoint() {
+    use cutlist_optimizer_cli::engine::running_tasks::{get_running_tasks_instance, TaskManager};
+    use cutlist_optimizer_cli::models::TaskCheckpoint;
+    use std::sync::{Arc, Mutex};
+
+    let dir = tempfile::tempdir().unwrap();
+    let mut service = CutListOptimizerServiceImpl::new();
+    assert!(service.init(4).await.is_ok());
+
+    // Five sizes earn fifty permutations
+    let panels: Vec<(String, String, i32)> = (0..5).map(|i| ((100 + 20 * i).to_string(), (80 + 10 * i).to_string(), 1)).collect();
+    let panels: Vec<(&str, &str, i32)> = panels.iter().map(|(w, h, c)| (w.as_str(), h.as_str(), *c)).collect();
+    let request = feasibility_request(&panels, &[("1000", "1000", 1)]);
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+    wait_until_finished(&service, &task_id).await;
+
+    // A checkpoint taken halfway, then the in-memory state is lost, as after a crash
+    let task_arc = get_running_tasks_instance().get_task(&task_id).unwrap();
+    let mut checkpoint = TaskCheckpoint::from_task(&task_arc.read());
+    checkpoint.percentage_done.insert("wood".to_string(), 50);
+    let path = TaskCheckpoint::path_for(dir.path(), &task_id);
+    checkpoint.write(&path).unwrap();
+    get_running_tasks_instance().remove_task(&task_id).unwrap();
+
+    let permutations: Arc<Mutex<Vec<usize>>> = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&permutations);
+    service.set_progress_callback(Box::new(move |update| collected.lock().unwrap().push(update.permutation_index)));
+    let resumed = service.resume_task(&path).await.unwrap();
+    assert_eq!(resumed.task_id.as_deref(), Some(task_id.as_str()));
+
+    let response = wait_until_finished(&service, &task_id).await.solution.unwrap();
+    assert_eq!(response.panels.map(|panels| panels.len()), Some(5));
+    // Only the second half of the fifty permutations is searched again
+    let permutations = permutations.lock().unwrap();
+    assert!(!permutations.is_empty());
+    assert!(permutations.iter().all(|&index| index >= 25), "{:?}", permutations);
+}
@@ -28,6 +28,7 @@ fn create_test_request() -> CalculationRequest {
             orientation: 0,
             label: Some("Test Panel 1".to_string()),
             edge: None,
+            ..Default::default()
         },
         Panel {
             id: 2,
@@ -39,6 +40,7 @@ fn create_test_request() -> CalculationRequest {
             orientation: 0,
             label: Some("Test Panel 2".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -53,6 +55,7 @@ fn create_test_request() -> CalculationRequest {
             orientation: 0,
             label: Some("Stock Panel".to_string()),
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -76,6 +79,7 @@ fn create_invalid_request() -> CalculationRequest {
             orientation: 0,
             label: None,
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -90,6 +94,7 @@ fn create_invalid_request() -> CalculationRequest {
             orientation: 0,
             label: None,
             edge: None,
+            ..Default::default()
         },
     ];
 
@@ -0,0 +1,81 @@
+//! Tests for reserving stock sheets for specific panels
+
+use cutlist_optimizer_cli::engine::layout::{compute_layout, compute_layout_parallel};
+use cutlist_optimizer_cli::engine::CutListThread;
+use cutlist_optimizer_cli::models::{CalculationRequest, Panel};
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material("wood".to_string())
+        .with_enabled(true)
+}
+
+/// Stock #1 has room for every panel, stock #2 only for the reserved one
+fn reserved_request() -> CalculationRequest {
+    CalculationRequest {
+        configuration: None,
+        panels: vec![
+            create_panel(1, "400", "300", 1).with_stock_affinity(2),
+            create_panel(2, "500", "500", 1),
+        ],
+        stock_panels: vec![
+            create_panel(1, "2000", "1000", 1),
+            create_panel(2, "450", "350", 1),
+        ],
+    }
+}
+
+#[test]
+fn test_stock_affinity_defaults_to_none() {
+    let panel: Panel = serde_json::from_str(
+        r#"{"id": 1, "width": "10", "height": "10", "count": 1, "material": "wood",
+            "enabled": true, "orientation": 0, "label": null, "edge": null}"#,
+    )
+    .unwrap();
+    assert_eq!(panel.stock_affinity, None);
+    assert_eq!(panel.permitted_stock_ids(), None);
+}
+
+#[test]
+fn test_stock_affinity_narrows_allowed_stock_ids() {
+    let reserved = Panel::default().with_stock_affinity(2);
+    assert_eq!(reserved.permitted_stock_ids(), Some(vec![2]));
+    assert!(reserved.allows_stock(2));
+    assert!(!reserved.allows_stock(1));
+
+    let conflicting = Panel::default().with_allowed_stock_ids(vec![1]).with_stock_affinity(2);
+    assert_eq!(conflicting.permitted_stock_ids(), Some(vec![]));
+
+    let restrictions = CutListThread::allowed_stock_ids_from_panels(&reserved_request().panels);
+    assert_eq!(restrictions.len(), 1);
+    assert_eq!(restrictions[&1], vec![2]);
+}
+
+#[test]
+fn test_panel_with_affinity_is_never_placed_on_other_stock() {
+    let request = reserved_request();
+
+    for parallel in [false, true] {
+        let (solution, _) = if parallel {
+            compute_layout_parallel(&request, 0, 0, 5).unwrap()
+        } else {
+            compute_layout(&request, 0, 0, 5).unwrap()
+        };
+        assert!(solution.get_no_fit_panels().is_empty(), "parallel = {}", parallel);
+
+        let mut reserved_found = false;
+        for mosaic in solution.get_mosaics() {
+            for node in mosaic.final_tile_nodes() {
+                if node.external_id() == Some(1) {
+                    reserved_found = true;
+                    assert_eq!(mosaic.stock_id(), 2, "parallel = {}", parallel);
+                }
+            }
+        }
+        assert!(reserved_found, "parallel = {}", parallel);
+    }
+}
@@ -0,0 +1,112 @@
+//! Tests for task checkpointing and resuming
+
+use cutlist_optimizer_cli::{
+    engine::{CutListThread, RunningTasks, TaskCheckpointing, TaskManager, WatchDog, WatchDogConfig},
+    models::{task::Task, Solution, TaskCheckpoint, TileDimensions},
+    stock::StockSolution,
+    Status,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Best solution for a handful of tiles on one sheet
+fn computed_solution() -> Solution {
+    let tile = |id: i32, width: i32, height: i32| {
+        let mut tile = TileDimensions::new(id, width, height);
+        tile.material = "Wood".to_string();
+        tile
+    };
+
+    let mut thread = CutListThread::new();
+    thread.set_tiles(vec![tile(1, 400, 300), tile(2, 300, 200), tile(3, 200, 200)]);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(vec![tile(100, 1000, 800)])));
+    thread.run();
+
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    solutions[0].clone()
+}
+
+/// A running task that has found `solution` and is `percentage` done
+fn running_task(task_id: &str, solution: Solution, percentage: i32) -> Task {
+    let mut task = Task::new(task_id.to_string());
+    task.set_factor(10.0);
+    task.add_material_to_compute("Wood".to_string());
+    task.add_solution("Wood", solution);
+    task.set_material_percentage_done("Wood".to_string(), percentage);
+    task.set_running_status().unwrap();
+    task
+}
+
+#[test]
+fn test_checkpoint_round_trips_through_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let solution = computed_solution();
+    let task = running_task("checkpoint-file", solution.clone(), 25);
+
+    let checkpoint = TaskCheckpoint::from_task(&task);
+    let path = TaskCheckpoint::path_for(dir.path(), "checkpoint-file");
+    checkpoint.write(&path).unwrap();
+
+    let restored = TaskCheckpoint::read(&path).unwrap();
+    assert_eq!(restored.task_id, "checkpoint-file");
+    assert_eq!(restored.factor, 10.0);
+    assert_eq!(restored.percentage_done.get("Wood"), Some(&25));
+    assert_eq!(
+        restored.best_solutions["Wood"].get_structure_identifier(),
+        solution.get_structure_identifier()
+    );
+}
+
+#[test]
+fn test_resume_from_missing_checkpoint_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let running_tasks = RunningTasks::new();
+    assert!(running_tasks.resume_from_checkpoint(&dir.path().join("missing.json")).is_err());
+}
+
+#[tokio::test]
+async fn test_watchdog_checkpoint_survives_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let solution = computed_solution();
+    let tiles_placed = solution.get_nbr_final_tiles();
+    assert!(tiles_placed > 0);
+
+    {
+        let running_tasks = Arc::new(RunningTasks::new());
+        running_tasks.add_task(running_task("long-task", solution, 40)).unwrap();
+
+        let config = WatchDogConfig::default()
+            .with_checkpoint_dir(dir.path())
+            .with_checkpoint_interval(Duration::from_secs(60));
+        let watchdog = WatchDog::with_config(running_tasks.clone(), config);
+        watchdog.check_once().await.unwrap();
+        // In-memory state is dropped here, as after a crash
+    }
+
+    let path = TaskCheckpoint::path_for(dir.path(), "long-task");
+    assert!(path.exists());
+
+    let running_tasks = RunningTasks::new();
+    let task_id = running_tasks.resume_from_checkpoint(&path).unwrap();
+    assert_eq!(task_id, "long-task");
+
+    let task = running_tasks.get_task(&task_id).unwrap();
+    let task = task.read();
+    assert_eq!(task.status(), Status::Queued);
+    assert_eq!(task.percentage_done(), 40);
+    assert_eq!(task.factor(), 10.0);
+
+    let resumed = task.solutions("Wood").unwrap();
+    assert_eq!(resumed.len(), 1);
+    assert_eq!(resumed[0].get_nbr_final_tiles(), tiles_placed);
+}
+
+#[tokio::test]
+async fn test_watchdog_without_checkpoint_dir_writes_nothing() {
+    let running_tasks = Arc::new(RunningTasks::new());
+    running_tasks.add_task(running_task("no-checkpoint", computed_solution(), 10)).unwrap();
+
+    let watchdog = WatchDog::new(running_tasks);
+    assert_eq!(watchdog.write_checkpoints().unwrap(), 0);
+}
@@ -0,0 +1,87 @@
+//! Tests for keeping identical tiles together
+
+use cutlist_optimizer_cli::engine::layout::compute_layout;
+use cutlist_optimizer_cli::engine::cut_list_thread::CutListThread;
+use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, Panel, Solution, TileDimensions};
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material("wood".to_string())
+        .with_enabled(true)
+}
+
+/// Four identical 300x300 parts that the default layout spreads over two sheets
+fn batch_request(group_identical_tiles: bool) -> CalculationRequest {
+    CalculationRequest {
+        configuration: Some(Configuration {
+            group_identical_tiles,
+            ..Configuration::default()
+        }),
+        panels: vec![
+            create_panel(1, "600", "700", 2),
+            create_panel(2, "300", "300", 4),
+            create_panel(3, "400", "250", 3),
+        ],
+        stock_panels: vec![create_panel(100, "1000", "1000", 3)],
+    }
+}
+
+/// For each sheet holding a tile with `id`, whether those tiles form one run
+fn runs_of(solution: &Solution, id: i32) -> Vec<bool> {
+    solution
+        .mosaics
+        .iter()
+        .filter_map(|mosaic| {
+            let hits: Vec<bool> = mosaic
+                .final_tile_nodes()
+                .iter()
+                .map(|node| node.external_id() == Some(id))
+                .collect();
+            let first = hits.iter().position(|&hit| hit)?;
+            let last = hits.iter().rposition(|&hit| hit)?;
+            Some(hits[first..=last].iter().all(|&hit| hit))
+        })
+        .collect()
+}
+
+#[test]
+fn test_identical_tiles_scatter_without_grouping() {
+    let (solution, _) = compute_layout(&batch_request(false), 0, 0, 5).unwrap();
+    assert!(solution.get_no_fit_panels().is_empty());
+    assert!(runs_of(&solution, 2).len() > 1);
+}
+
+#[test]
+fn test_group_identical_tiles_places_copies_as_one_run() {
+    let (solution, _) = compute_layout(&batch_request(true), 0, 0, 5).unwrap();
+    assert!(solution.get_no_fit_panels().is_empty());
+    assert_eq!(solution.get_nbr_final_tiles(), 9);
+
+    assert_eq!(runs_of(&solution, 2), vec![true]);
+    assert!(solution.mosaics.iter().all(CutListThread::has_contiguous_groups));
+}
+
+#[test]
+fn test_cluster_identical_tiles_keeps_first_appearance_order() {
+    let tile = |id: i32, width: i32, height: i32| TileDimensions::new(id, width, height);
+    let mut thread = CutListThread::new();
+    thread.set_tiles(vec![tile(1, 300, 300), tile(2, 200, 100), tile(3, 300, 300), tile(4, 100, 200)]);
+    thread.cluster_identical_tiles();
+
+    let ids: Vec<i32> = thread.tiles().iter().map(|tile| tile.id).collect();
+    assert_eq!(ids, vec![1, 3, 2, 4]);
+}
+
+#[test]
+fn test_grouping_signature_ignores_rotation() {
+    let mut tile = TileDimensions::new(1, 200, 100);
+    let rotated = TileDimensions::new(2, 100, 200);
+    assert_eq!(tile.get_grouping_signature(), rotated.get_grouping_signature());
+
+    tile.material = "oak".to_string();
+    assert_ne!(tile.get_grouping_signature(), rotated.get_grouping_signature());
+}
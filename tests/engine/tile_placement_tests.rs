@@ -0,0 +1,89 @@
+//! Tests for tile placement in CutListThread
+//!
+//! These run the thread end to end and check the placed tiles on the
+//! resulting mosaics.
+
+use cutlist_optimizer_cli::{
+    engine::cut_list_thread::{CutListThread, SolutionComparator},
+    models::{Solution, TileDimensions},
+    stock::StockSolution,
+};
+
+fn run_thread(tiles: Vec<TileDimensions>, stock_tiles: Vec<TileDimensions>) -> Vec<Solution> {
+    run_thread_with(tiles, stock_tiles, Vec::new)
+}
+
+fn run_thread_with(
+    tiles: Vec<TileDimensions>,
+    stock_tiles: Vec<TileDimensions>,
+    comparators: impl Fn() -> Vec<SolutionComparator>,
+) -> Vec<Solution> {
+    let mut thread = CutListThread::new();
+    thread.set_thread_prioritized_comparators(comparators());
+    thread.set_final_solution_prioritized_comparators(comparators());
+    thread.set_tiles(tiles);
+    thread.set_stock_solution(Some(StockSolution::from_tiles(stock_tiles)));
+    thread.run();
+    let solutions = thread.all_solutions();
+    let solutions = solutions.lock().unwrap();
+    solutions.clone()
+}
+
+/// (id, width, height) of every final node of a solution
+fn placed_tiles(solution: &Solution) -> Vec<(i32, i32, i32)> {
+    solution
+        .get_mosaics()
+        .iter()
+        .flat_map(|mosaic| mosaic.final_tile_nodes())
+        .map(|node| (node.external_id().unwrap_or_default(), node.width(), node.height()))
+        .collect()
+}
+
+#[test]
+fn test_placed_tile_is_written_into_mosaic_tree() {
+    let solutions = run_thread(vec![TileDimensions::new(1, 400, 300)], vec![TileDimensions::new(100, 1000, 1000)]);
+
+    assert!(!solutions.is_empty());
+    for solution in &solutions {
+        let placed = placed_tiles(solution);
+        assert!(placed == vec![(1, 400, 300)] || placed == vec![(1, 300, 400)], "{:?}", placed);
+        assert!(solution.get_no_fit_panels().is_empty());
+    }
+}
+
+#[test]
+fn test_tiles_too_large_to_share_a_sheet_open_identical_sheets() {
+    let tiles = (1..=3).map(|id| TileDimensions::new(id, 800, 800)).collect();
+    let sheets = (0..3).map(|_| TileDimensions::new(100, 1000, 1000)).collect();
+    let solutions = run_thread(tiles, sheets);
+
+    let best = solutions.first().unwrap();
+    assert!(best.get_no_fit_panels().is_empty());
+    assert_eq!(best.get_mosaics().len(), 3);
+    let mut ids: Vec<i32> = placed_tiles(best).iter().map(|&(id, _, _)| id).collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_solutions_are_ordered_by_comparators() {
+    // Cutting the length or the width first leaves a different largest remainder
+    let run = |largest_first: bool| {
+        run_thread_with(vec![TileDimensions::new(1, 600, 400)], vec![TileDimensions::new(100, 1000, 1000)], || {
+            let comparator: SolutionComparator = Box::new(move |a: &Solution, b: &Solution| {
+                let order = a.get_biggest_area().cmp(&b.get_biggest_area());
+                if largest_first { order.reverse() } else { order }
+            });
+            vec![comparator]
+        })
+    };
+
+    let largest_first = run(true);
+    let smallest_first = run(false);
+    let areas = |solutions: &[Solution]| -> Vec<i64> { solutions.iter().map(Solution::get_biggest_area).collect() };
+    let largest = *areas(&largest_first).iter().max().unwrap();
+    let smallest = *areas(&smallest_first).iter().min().unwrap();
+    assert!(largest > smallest);
+    assert_eq!(largest_first[0].get_biggest_area(), largest);
+    assert_eq!(smallest_first[0].get_biggest_area(), smallest);
+}
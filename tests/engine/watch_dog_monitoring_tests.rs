@@ -31,3 +31,32 @@ fn test_task_monitor_config_update() {
     assert_eq!(monitor.get_config().check_interval, new_config.check_interval);
     assert_eq!(monitor.get_config().task_timeout, new_config.task_timeout);
 }
+
+#[tokio::test]
+async fn test_task_monitor_terminates_task_over_mosaic_limit() {
+    use cutlist_optimizer_cli::engine::running_tasks::TaskManager;
+    use cutlist_optimizer_cli::models::{enums::Status, Mosaic, Solution, Task, TileDimensions};
+
+    let mut solution = Solution::new();
+    for id in 0..3 {
+        solution.mosaics.push(Mosaic::from_tile_dimensions(&TileDimensions::new(id, 1000, 800)));
+    }
+    let running_task = |task_id: &str| {
+        let task = Task::new(task_id.to_string());
+        task.add_solution("Wood", solution.clone());
+        task.set_running_status().unwrap();
+        task
+    };
+
+    let running_tasks = Arc::new(RunningTasks::new());
+    running_tasks.add_task(running_task("mosaic-heavy")).unwrap();
+
+    let within_limit = TaskMonitor::new(running_tasks.clone(), WatchDogConfig::default().with_max_active_mosaics(3));
+    within_limit.check_tasks().await.unwrap();
+    let task = running_tasks.get_task("mosaic-heavy").unwrap();
+    assert_eq!(task.read().status(), Status::Running);
+
+    let over_limit = TaskMonitor::new(running_tasks.clone(), WatchDogConfig::default().with_max_active_mosaics(2));
+    over_limit.check_tasks().await.unwrap();
+    assert_eq!(task.read().status(), Status::Terminated);
+}
@@ -13,6 +13,7 @@ mod status_code_integration_tests {
         assert_eq!(StatusCode::ServerUnavailable.description(), "Server is unavailable");
         assert_eq!(StatusCode::TooManyPanels.description(), "Too many panels specified");
         assert_eq!(StatusCode::TooManyStockPanels.description(), "Too many stock panels specified");
+        assert_eq!(StatusCode::NotEnoughStock.description(), "Panels cannot fit within the allowed number of sheets");
     }
 
     #[test]
@@ -29,6 +30,7 @@ mod status_code_integration_tests {
             StatusCode::ServerUnavailable,
             StatusCode::TooManyPanels,
             StatusCode::TooManyStockPanels,
+            StatusCode::NotEnoughStock,
         ];
 
         for status in error_statuses {
@@ -77,6 +79,7 @@ mod status_code_integration_tests {
             StatusCode::ServerUnavailable,
             StatusCode::TooManyPanels,
             StatusCode::TooManyStockPanels,
+            StatusCode::NotEnoughStock,
         ];
 
         // Проверяем, что все варианты имеют уникальные значения
@@ -87,7 +90,7 @@ mod status_code_integration_tests {
 
         // Проверяем, что все значения находятся в ожидаемом диапазоне
         for variant in &all_variants {
-            assert!(variant.value() <= 6);
+            assert!(variant.value() <= 7);
         }
 
         // Проверяем, что каждый вариант имеет описание
@@ -99,7 +102,8 @@ mod status_code_integration_tests {
     #[test]
     fn test_status_code_boundary_values() {
         // Тестируем граничные случаи для u8
-        assert_eq!(StatusCode::from_value(7), None);
+        assert_eq!(StatusCode::from_value(7), Some(StatusCode::NotEnoughStock));
+        assert_eq!(StatusCode::from_value(8), None);
         assert_eq!(StatusCode::from_value(255), None);
         assert_eq!(StatusCode::from_value(u8::MAX), None);
     }
@@ -5,6 +5,8 @@ pub mod enums;
 mod logging;
 mod engine;
 mod comparator;
+mod regression;
+mod cli;
 // Re-export test modules for easier access
 pub use models::*;
 pub use utils::*;
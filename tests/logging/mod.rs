@@ -4,3 +4,4 @@ pub mod enums_tests;
 pub mod init_tests;
 pub mod macros_tests;
 pub mod structs_tests;
+pub mod spans_tests;
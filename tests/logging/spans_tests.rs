@@ -0,0 +1,136 @@
+//! Тесты для спанов tracing задач оптимизации
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use cutlist_optimizer_cli::engine::service::computation::material_compute::compute_material;
+use cutlist_optimizer_cli::engine::service::computation::task_compute::compute_task_complete;
+use cutlist_optimizer_cli::engine::service::computation::ComputeHooks;
+use cutlist_optimizer_cli::logging::task_span;
+use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, Panel, Task, TileDimensions};
+use parking_lot::RwLock;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Instrument, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// A span as seen by the recorder: name, parent name and fields
+#[derive(Debug, Clone, Default)]
+struct RecordedSpan {
+    name: String,
+    parent: Option<String>,
+    fields: HashMap<String, String>,
+}
+
+/// Layer that keeps every span opened while it is the default subscriber
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<HashMap<u64, RecordedSpan>>>,
+}
+
+impl SpanRecorder {
+    fn named(&self, name: &str) -> Vec<RecordedSpan> {
+        let spans = self.spans.lock().unwrap();
+        let mut named: Vec<(u64, RecordedSpan)> = spans
+            .iter()
+            .filter(|(_, span)| span.name == name)
+            .map(|(id, span)| (*id, span.clone()))
+            .collect();
+        named.sort_by_key(|(id, _)| *id);
+        named.into_iter().map(|(_, span)| span).collect()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanRecorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut span = RecordedSpan {
+            name: attrs.metadata().name().to_string(),
+            parent: ctx.span(id).and_then(|span| span.parent()).map(|parent| parent.name().to_string()),
+            fields: HashMap::new(),
+        };
+        attrs.record(&mut FieldVisitor(&mut span.fields));
+        self.spans.lock().unwrap().insert(id.into_u64(), span);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        if let Some(span) = self.spans.lock().unwrap().get_mut(&id.into_u64()) {
+            values.record(&mut FieldVisitor(&mut span.fields));
+        }
+    }
+}
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material("wood".to_string())
+        .with_enabled(true)
+}
+
+#[tokio::test]
+async fn test_each_task_opens_a_span_with_its_id() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let request = CalculationRequest {
+        configuration: Some(Configuration::default()),
+        panels: vec![create_panel(1, "400", "300", 2)],
+        stock_panels: vec![create_panel(100, "1000", "1000", 1)],
+    };
+    let task_ids = ["span-task-1", "span-task-2"];
+    for task_id in task_ids {
+        compute_task_complete(request.clone(), task_id.to_string()).await.unwrap();
+    }
+
+    let task_spans = recorder.named("task");
+    let recorded_ids: Vec<&str> = task_spans
+        .iter()
+        .map(|span| span.fields.get("task_id").map(String::as_str).unwrap_or_default())
+        .collect();
+    assert_eq!(recorded_ids, task_ids);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_permutations_open_spans_inside_the_task_span() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let tiles = vec![TileDimensions::new(1, 400, 300), TileDimensions::new(2, 200, 100)];
+    let stock = vec![TileDimensions::new(100, 1000, 1000)];
+    let task_arc = Arc::new(RwLock::new(Task::new("span-permutations".to_string())));
+
+    compute_material(
+        tiles,
+        stock,
+        &Configuration::default(),
+        task_arc,
+        "DEFAULT_MATERIAL",
+        &ComputeHooks::default(),
+    )
+    .instrument(task_span("span-permutations"))
+    .await
+    .unwrap();
+
+    let permutation_spans = recorder.named("permutation");
+    assert!(!permutation_spans.is_empty());
+    for (index, span) in permutation_spans.iter().enumerate() {
+        assert_eq!(span.parent.as_deref(), Some("task"));
+        assert_eq!(span.fields.get("permutation_index"), Some(&index.to_string()));
+        assert!(span.fields.contains_key("best_efficiency"));
+    }
+    let best_efficiency: f64 = permutation_spans.last().unwrap().fields["best_efficiency"].parse().unwrap();
+    assert!(best_efficiency > 0.0);
+}
@@ -15,7 +15,7 @@ fn test_with_configuration() {
     use cutlist_optimizer_cli::models::PerformanceThresholds;
     
     let config = Configuration {
-        cut_thickness: 3,
+        cut_thickness: 3.into(),
         min_trim_dimension: 10,
         consider_orientation: true,
         optimization_factor: 5,
@@ -23,6 +23,31 @@ fn test_with_configuration() {
         use_single_stock_unit: false,
         units: "mm".to_string(),
         performance_thresholds: PerformanceThresholds::default(),
+        cut_orientation_preference: 0,
+        material_cut_preferences: Default::default(),
+        material_cut_thickness: Default::default(),
+        max_sheets: None,
+        seed: None,
+        cut_style: Default::default(),
+        first_cut_rule: Default::default(),
+        time_budget: None,
+        target_efficiency: Default::default(),
+        group_identical_tiles: false,
+        reusable_offcut_threshold: None,
+        offcut_histogram_buckets: Default::default(),
+        placement_heuristic: Default::default(),
+        rotation_tolerance: 0,
+        cut_removes_material: true,
+        max_solutions_retained: None,
+        max_cuts_per_sheet: None,
+        max_permutations: None,
+        prefer_fewer_sheets: false,
+        balance_sheets: false,
+        saw_time: None,
+        fit_within_kerf: true,
+        report_first_acceptable: false,
+        grain_mode: Default::default(),
+        stock_catalog: Vec::new(),
     };
     
     let request = CalculationRequest::with_configuration(config);
@@ -151,7 +176,7 @@ fn test_take_configuration() {
     use cutlist_optimizer_cli::models::PerformanceThresholds;
     
     let config = Configuration {
-        cut_thickness: 3,
+        cut_thickness: 3.into(),
         min_trim_dimension: 10,
         consider_orientation: true,
         optimization_factor: 5,
@@ -159,6 +184,31 @@ fn test_take_configuration() {
         use_single_stock_unit: false,
         units: "mm".to_string(),
         performance_thresholds: PerformanceThresholds::default(),
+        cut_orientation_preference: 0,
+        material_cut_preferences: Default::default(),
+        material_cut_thickness: Default::default(),
+        max_sheets: None,
+        seed: None,
+        cut_style: Default::default(),
+        first_cut_rule: Default::default(),
+        time_budget: None,
+        target_efficiency: Default::default(),
+        group_identical_tiles: false,
+        reusable_offcut_threshold: None,
+        offcut_histogram_buckets: Default::default(),
+        placement_heuristic: Default::default(),
+        rotation_tolerance: 0,
+        cut_removes_material: true,
+        max_solutions_retained: None,
+        max_cuts_per_sheet: None,
+        max_permutations: None,
+        prefer_fewer_sheets: false,
+        balance_sheets: false,
+        saw_time: None,
+        fit_within_kerf: true,
+        report_first_acceptable: false,
+        grain_mode: Default::default(),
+        stock_catalog: Vec::new(),
     };
     
     let mut request = CalculationRequest::with_configuration(config);
@@ -168,3 +218,235 @@ fn test_take_configuration() {
     assert!(taken_config.is_some());
     assert!(request.configuration().is_none());
 }
+
+fn enabled_panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel {
+        id,
+        width: Some(width.to_string()),
+        height: Some(height.to_string()),
+        count,
+        enabled: true,
+        ..Panel::default()
+    }
+}
+
+#[test]
+fn test_validate_semantic_accepts_valid_request() {
+    let mut request = CalculationRequest::new();
+    request.add_panel(enabled_panel(1, "100", "50", 2));
+    request.add_stock_panel(enabled_panel(10, "1000", "500", 1));
+
+    assert!(request.validate_semantic().is_ok());
+}
+
+#[test]
+fn test_validate_semantic_reports_every_error_with_path() {
+    let mut request = CalculationRequest::new();
+    request.add_panel(enabled_panel(1, "100", "50", 1));
+    request.add_panel(enabled_panel(2, "100", "50", 1));
+    request.add_panel(enabled_panel(3, "100", "50", 0));
+
+    let errors = request.validate_semantic().unwrap_err();
+    let paths: Vec<&str> = errors.iter().map(|error| error.path.as_str()).collect();
+
+    assert_eq!(paths, vec!["stock_panels", "panels[2].count"]);
+    assert_eq!(errors[1].to_string(), "panels[2].count: must be at least 1, got 0");
+}
+
+#[test]
+fn test_validate_semantic_checks_dimensions_and_stock_references() {
+    let mut request = CalculationRequest::new();
+    let mut panel = enabled_panel(1, "abc", "-5", 1);
+    panel.allowed_stock_ids = Some(vec![10, 99]);
+    request.add_panel(panel);
+    // Disabled panels are not checked
+    request.add_panel(Panel::default());
+    request.add_stock_panel(enabled_panel(10, "1000", "500", 1));
+
+    let errors = request.validate_semantic().unwrap_err();
+    let paths: Vec<&str> = errors.iter().map(|error| error.path.as_str()).collect();
+
+    assert_eq!(
+        paths,
+        vec!["panels[0].width", "panels[0].height", "panels[0].allowed_stock_ids[1]"]
+    );
+}
+
+#[test]
+fn test_validate_semantic_checks_stock_affinity() {
+    let mut request = CalculationRequest::new();
+    request.add_panel(enabled_panel(1, "100", "50", 1).with_stock_affinity(99));
+    request.add_panel(
+        enabled_panel(2, "100", "50", 1)
+            .with_allowed_stock_ids(vec![10])
+            .with_stock_affinity(20),
+    );
+    request.add_panel(enabled_panel(3, "100", "50", 1).with_stock_affinity(20));
+    request.add_stock_panel(enabled_panel(10, "1000", "500", 1));
+    request.add_stock_panel(enabled_panel(20, "1000", "500", 1));
+
+    let errors = request.validate_semantic().unwrap_err();
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "panels[0].stock_affinity: no stock panel has id 99",
+            "panels[1].stock_affinity: stock id 20 is not in allowed_stock_ids",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_warns_about_oversized_panel() {
+    let mut request = CalculationRequest::new();
+    request.add_panel(enabled_panel(1, "100", "50", 2));
+    request.add_panel(enabled_panel(2, "1200", "400", 1));
+    // Fits only when rotated
+    request.add_panel(enabled_panel(3, "400", "900", 1));
+    request.add_stock_panel(enabled_panel(10, "1000", "500", 1));
+
+    let report = request.validate().unwrap();
+
+    assert!(report.is_valid());
+    assert_eq!(report.warnings.len(), 1);
+    assert_eq!(report.warnings[0].path, "panels[1]");
+}
+
+#[test]
+fn test_validate_compares_against_usable_sheet_area() {
+    use cutlist_optimizer_cli::models::Tile;
+
+    let mut request = CalculationRequest::new();
+    // Fits the raw 1000x500 sheet but not the 980x480 left by the edge trim
+    request.add_panel(enabled_panel(1, "990", "300", 1));
+    // Fits the trimmed sheet once its banding is taken off
+    let mut banded = enabled_panel(2, "985", "300", 1);
+    banded.banding_thickness = Some([0.0, 5.0, 0.0, 5.0]);
+    request.add_panel(banded);
+    // Fits below the keepout but not beside it
+    request.add_panel(enabled_panel(3, "900", "300", 1));
+    // Needs the full height, which the keepout blocks everywhere it is wide enough
+    request.add_panel(enabled_panel(4, "600", "480", 1));
+    let mut stock = enabled_panel(10, "1000", "500", 1);
+    stock.edge_trim = 10;
+    stock.keepouts = vec![Tile::new(300, 400, 10, 100)];
+    request.add_stock_panel(stock);
+
+    let report = request.validate().unwrap();
+
+    let paths: Vec<&str> = report.warnings.iter().map(|warning| warning.path.as_str()).collect();
+    assert_eq!(paths, vec!["panels[0]", "panels[3]"]);
+}
+
+#[test]
+fn test_validate_warns_about_panel_without_stock_material() {
+    let mut request = CalculationRequest::new();
+    let mut panel = enabled_panel(1, "100", "50", 1);
+    panel.material = "Metal".to_string();
+    request.add_panel(panel);
+    request.add_stock_panel(enabled_panel(10, "1000", "500", 1));
+
+    let report = request.validate().unwrap();
+
+    assert!(report.is_valid());
+    assert_eq!(report.warnings.len(), 1);
+    assert_eq!(report.warnings[0].path, "panels[0].material");
+}
+
+#[test]
+fn test_validate_reports_duplicate_panel_ids() {
+    let mut request = CalculationRequest::new();
+    request.add_panel(enabled_panel(1, "100", "50", 1));
+    request.add_panel(enabled_panel(1, "200", "80", 1));
+    request.add_panel(enabled_panel(2, "100", "50", 1));
+    // Disabled panels do not take part in the layout
+    request.add_panel(Panel { id: 2, ..Panel::default() });
+    request.add_stock_panel(enabled_panel(10, "1000", "500", 1));
+    request.add_stock_panel(enabled_panel(10, "800", "500", 1));
+
+    let report = request.validate().unwrap();
+
+    assert!(!report.is_valid());
+    let messages: Vec<String> = report.errors.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "panels: ids must be unique, duplicated: 1",
+            "stock_panels: ids must be unique, duplicated: 10",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_reports_empty_panels_as_error() {
+    let mut request = CalculationRequest::new();
+    request.add_stock_panel(enabled_panel(10, "1000", "500", 1));
+
+    let report = request.validate().unwrap();
+
+    assert!(!report.is_valid());
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].path, "panels");
+    assert!(report.warnings.is_empty());
+}
+
+#[test]
+fn test_suggest_stock_picks_smallest_sheet_holding_every_panel() {
+    use cutlist_optimizer_cli::engine::service::PlacementConfig;
+    use cutlist_optimizer_cli::models::calculation_request::feasibility::suggest_stock;
+    use cutlist_optimizer_cli::models::TileDimensions;
+
+    let tiles = vec![
+        TileDimensions::new(1, 600, 400),
+        TileDimensions::new(1, 600, 400),
+        TileDimensions::new(2, 900, 300),
+    ];
+    let config = PlacementConfig::default();
+    let given = TileDimensions::new(100, 500, 500);
+    assert_eq!(suggest_stock(&tiles, std::slice::from_ref(&given), 3, &config), None);
+
+    // 700x700 is smaller than 1000x700 but too narrow for the 900 long panel
+    let candidates = vec![
+        TileDimensions::new(101, 2440, 1220),
+        given,
+        TileDimensions::new(102, 1000, 700),
+        TileDimensions::new(103, 700, 700),
+    ];
+    let suggested = suggest_stock(&tiles, &candidates, 3, &config).unwrap();
+    assert_eq!((suggested.id, suggested.width, suggested.height), (102, 1000, 700));
+    assert_eq!(suggest_stock(&[], &candidates, 3, &config), None);
+
+    // With a single sheet in stock the panels no longer spread over several
+    let suggested = suggest_stock(&tiles, &candidates, 1, &config).unwrap();
+    assert_eq!(suggested.id, 101);
+}
+
+#[test]
+fn test_suggest_stock_uses_request_sheet_count_and_kerf() {
+    use cutlist_optimizer_cli::engine::service::PlacementConfig;
+    use cutlist_optimizer_cli::models::calculation_request::feasibility::suggest_stock;
+    use cutlist_optimizer_cli::models::{CutThickness, TileDimensions};
+
+    let mut request = CalculationRequest::new();
+    request.add_stock_panel(enabled_panel(100, "500", "500", 1));
+    let mut disabled = enabled_panel(101, "500", "500", 4);
+    disabled.enabled = false;
+    request.add_stock_panel(disabled);
+    assert_eq!(request.stock_sheet_count(), 1);
+
+    let tiles = vec![TileDimensions::new(1, 500, 500), TileDimensions::new(1, 500, 500)];
+    // 1000x500 holds both panels only when the cut takes no material
+    let candidates = vec![TileDimensions::new(102, 1000, 500), TileDimensions::new(103, 1010, 500)];
+
+    let no_kerf = PlacementConfig::default();
+    let suggested = suggest_stock(&tiles, &candidates, request.stock_sheet_count(), &no_kerf).unwrap();
+    assert_eq!(suggested.id, 102);
+
+    let with_kerf = PlacementConfig {
+        cut_thickness: CutThickness::Uniform(5),
+        ..PlacementConfig::default()
+    };
+    let suggested = suggest_stock(&tiles, &candidates, request.stock_sheet_count(), &with_kerf).unwrap();
+    assert_eq!(suggested.id, 103);
+}
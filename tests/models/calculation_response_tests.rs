@@ -1,6 +1,6 @@
 //! Tests for CalculationResponse model
 
-use cutlist_optimizer_cli::models::{CalculationResponse, CalculationRequest, FinalTile, NoFitTile, Mosaic};
+use cutlist_optimizer_cli::models::{CalculationResponse, CalculationRequest, FinalTile, NoFitTile, Mosaic, TileDimensions, TileNode};
 use std::collections::HashMap;
 
 #[test]
@@ -93,6 +93,8 @@ fn test_panels_operations() {
         height: 50.0,
         label: Some("Panel 1".to_string()),
         count: 2,
+        finished_width: None,
+        finished_height: None,
     };
     
     let panel2 = FinalTile {
@@ -101,6 +103,8 @@ fn test_panels_operations() {
         height: 60.0,
         label: Some("Panel 2".to_string()),
         count: 1,
+        finished_width: None,
+        finished_height: None,
     };
     
     let panels = vec![panel1.clone(), panel2.clone()];
@@ -122,6 +126,8 @@ fn test_used_stock_panels_operations() {
         height: 100.0,
         label: Some("Stock Panel".to_string()),
         count: 1,
+        finished_width: None,
+        finished_height: None,
     };
     
     let stock_panels = vec![stock_panel.clone()];
@@ -159,6 +165,8 @@ fn test_no_fit_panels_operations() {
         count: 1,
         label: Some("No Fit 1".to_string()),
         material: Some("Wood".to_string()),
+        reason: Default::default(),
+        ..NoFitTile::default()
     };
     
     let no_fit_panel2 = NoFitTile {
@@ -168,6 +176,8 @@ fn test_no_fit_panels_operations() {
         count: 2,
         label: Some("No Fit 2".to_string()),
         material: Some("MDF".to_string()),
+        reason: Default::default(),
+        ..NoFitTile::default()
     };
     
     // Test adding individual panels
@@ -297,3 +307,750 @@ fn test_debug() {
     assert!(debug_str.contains("CalculationResponse"));
     assert!(debug_str.contains("version"));
 }
+
+/// A 1000x1000 sheet with a single 800x500 panel placed at the origin (40% used)
+fn underused_mosaic() -> Mosaic {
+    let mut root = TileNode::new(0, 1000, 0, 1000);
+    let mut placed = TileNode::new(0, 800, 0, 500);
+    placed.set_final(true);
+    root.set_child1(Some(placed));
+    root.set_child2(Some(TileNode::new(0, 1000, 500, 1000)));
+    Mosaic::from_tile_node(&root, "wood".to_string())
+}
+
+fn catalog_size(width: i32, height: i32, material: &str) -> TileDimensions {
+    TileDimensions {
+        material: material.to_string(),
+        ..TileDimensions::new(0, width, height)
+    }
+}
+
+#[test]
+fn test_stock_recommendation_for_underused_sheet() {
+    let mosaic = underused_mosaic();
+    assert_eq!(mosaic.placements_bounding_box(), Some((800, 500)));
+
+    let mut response = CalculationResponse::new();
+    response.add_mosaic(mosaic);
+    response.recommend_stock(&[
+        catalog_size(1000, 1000, "wood"),
+        catalog_size(900, 600, "wood"),
+        catalog_size(550, 850, "wood"),
+        catalog_size(700, 500, "wood"),
+        catalog_size(810, 510, "metal"),
+    ]);
+
+    let recommendations = response.stock_recommendations();
+    assert_eq!(recommendations.len(), 1);
+    let recommendation = &recommendations[0];
+    assert_eq!((recommendation.current_width, recommendation.current_height), (1000, 1000));
+    assert_eq!((recommendation.recommended_width, recommendation.recommended_height), (850, 550));
+    assert!((recommendation.used_area_ratio - 0.4).abs() < 1e-9);
+    assert!(recommendation.area_saved() > 0);
+}
+
+#[test]
+fn test_no_stock_recommendation_for_well_used_sheet() {
+    let mut root = TileNode::new(0, 1000, 0, 1000);
+    let mut placed = TileNode::new(0, 1000, 0, 900);
+    placed.set_final(true);
+    root.set_child1(Some(placed));
+    root.set_child2(Some(TileNode::new(0, 1000, 900, 1000)));
+
+    let mut response = CalculationResponse::new();
+    response.add_mosaic(Mosaic::from_tile_node(&root, "wood".to_string()));
+    response.recommend_stock(&[catalog_size(1000, 900, "wood")]);
+
+    assert!(response.stock_recommendations().is_empty());
+}
+
+fn solved_request() -> CalculationRequest {
+    use cutlist_optimizer_cli::models::Panel;
+
+    let panel = |id: i32, width: &str, height: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "400.5", "300", 3), panel(2, "250", "120", 2)],
+        stock_panels: vec![panel(100, "1000", "800", 2)],
+    }
+}
+
+#[test]
+fn test_from_solution_json_round_trip() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let request = solved_request();
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    assert_eq!(response.panels().unwrap().len(), 5);
+    assert!(response.panels().unwrap().iter().any(|panel| panel.width == 400.5 || panel.height == 400.5));
+    assert!(response.total_used_area_ratio() > 0.0 && response.total_used_area_ratio() <= 1.0);
+    assert!(response.total_nbr_cuts() > 0);
+
+    let json = serde_json::to_string(&response).unwrap();
+    let restored: CalculationResponse = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.mosaics(), response.mosaics());
+    assert_eq!(restored.panels(), response.panels());
+    assert_eq!(
+        serde_json::to_value(&restored).unwrap(),
+        serde_json::to_value(&response).unwrap()
+    );
+}
+
+#[test]
+fn test_from_solution_reports_min_sheets_lower_bound() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let request = solved_request();
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    assert_eq!(response.min_sheets_lower_bound().len(), 1);
+    assert_eq!(response.min_sheets_lower_bound()["wood"], 1);
+    assert!(response.mosaics().len() >= response.min_sheets_lower_bound()["wood"]);
+}
+
+#[tokio::test]
+async fn test_optimize_command_writes_json_response() {
+    use cutlist_optimizer_cli::{cli::commands::optimize_command, engine::layout::OptimizeOptions};
+
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("job.json");
+    let output = dir.path().join("response.json");
+    std::fs::write(&input, serde_json::to_string(&solved_request()).unwrap()).unwrap();
+
+    optimize_command(input, Some(output.clone()), None, OptimizeOptions::default())
+        .await
+        .unwrap();
+
+    let response: CalculationResponse =
+        serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+    assert_eq!(response.panels().unwrap().len(), 5);
+    assert!(!response.mosaics().is_empty());
+    assert!(response.request().is_some());
+}
+
+#[test]
+fn test_sheet_yields_sum_to_aggregate_used_area() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let request = solved_request();
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let yields = response.sheet_yields();
+    assert_eq!(yields.len(), response.mosaics().len());
+
+    let used_area: f64 = yields.iter().map(|sheet| sheet.used_area).sum();
+    assert!((used_area - response.total_used_area()).abs() < 1e-6);
+
+    let tiles_placed: usize = yields.iter().map(|sheet| sheet.tiles_placed).sum();
+    assert_eq!(tiles_placed, response.panels().unwrap().len());
+
+    for (index, sheet) in yields.iter().enumerate() {
+        assert_eq!(sheet.sheet_index, index);
+        assert_eq!(sheet.material, "wood");
+        assert!((sheet.total_area - 800_000.0).abs() < 1e-6);
+        assert!((sheet.efficiency - sheet.used_area / sheet.total_area).abs() < 1e-12);
+        assert!(sheet.wasted_area() >= 0.0);
+    }
+}
+
+#[test]
+fn test_sheet_yields_default_when_missing_from_json() {
+    let mut value = serde_json::to_value(CalculationResponse::new()).unwrap();
+    value.as_object_mut().unwrap().remove("sheet_yields");
+
+    let restored: CalculationResponse = serde_json::from_value(value).unwrap();
+    assert!(restored.sheet_yields().is_empty());
+}
+
+fn decimal_request() -> CalculationRequest {
+    use cutlist_optimizer_cli::models::Panel;
+
+    let panel = |id: i32, width: &str, height: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "100.5", "200.25", 2)],
+        stock_panels: vec![panel(100, "1000", "800", 1)],
+    }
+}
+
+fn has_decimal_dimensions(tile: &FinalTile) -> bool {
+    (tile.width == 100.5 && tile.height == 200.25) || (tile.width == 200.25 && tile.height == 100.5)
+}
+
+#[test]
+fn test_decimal_tiles_carry_their_scale() {
+    use cutlist_optimizer_cli::engine::service::computation::dimension_utils::DimensionUtils;
+
+    let request = decimal_request();
+    let (tiles, stock_tiles, factor) =
+        DimensionUtils::convert_panels_to_tiles(&request.panels, &request.stock_panels, 6).unwrap();
+
+    assert_eq!(factor, 100.0);
+    assert_eq!((tiles[0].width, tiles[0].height), (10050, 20025));
+    assert_eq!(tiles[0].scale, factor);
+    assert_eq!((tiles[0].real_width(), tiles[0].real_height()), (100.5, 200.25));
+    assert!((tiles[0].real_area() - 100.5 * 200.25).abs() < 1e-9);
+    assert_eq!(stock_tiles[0].real_width(), 1000.0);
+}
+
+#[test]
+fn test_from_solution_reports_decimal_dimensions() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let request = decimal_request();
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let panels = response.panels().unwrap();
+    assert_eq!(panels.len(), 2);
+    assert!(panels.iter().all(has_decimal_dimensions));
+    assert!((response.total_used_area() - 2.0 * 100.5 * 200.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_task_response_reports_decimal_dimensions() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::Task;
+
+    let request = decimal_request();
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+
+    let mut task = Task::new("decimal-task".to_string());
+    task.set_factor(factor);
+    task.set_calculation_request(request);
+    task.add_solution("wood", solution);
+
+    let response = task.build_solution().unwrap();
+    let panels = response.panels().unwrap();
+    assert_eq!(panels.len(), 2);
+    assert!(panels.iter().all(has_decimal_dimensions));
+    assert!((response.total_used_area() - 2.0 * 100.5 * 200.25).abs() < 1e-6);
+}
+
+fn mixed_material_request(tile_material: &str, stock_materials: &[&str]) -> CalculationRequest {
+    use cutlist_optimizer_cli::models::Panel;
+
+    let panel = |id: i32, width: &str, height: &str, count: i32, material: &str| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_material(material.to_string())
+            .with_enabled(true)
+    };
+    CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "300", "200", 3, tile_material)],
+        stock_panels: stock_materials
+            .iter()
+            .enumerate()
+            .map(|(index, material)| panel(100 + index as i32, "1000", "800", 2, material))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_panels_without_matching_stock_are_material_unavailable() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::enums::NoFitReason;
+
+    let request = mixed_material_request("Wood", &["Metal"]);
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    assert!(response.panels().unwrap().is_empty());
+    assert!(solution.mosaics.is_empty());
+    assert_eq!(response.no_fit_panels.len(), 3);
+    assert!(response
+        .no_fit_panels
+        .iter()
+        .all(|tile| tile.reason == NoFitReason::MaterialUnavailable));
+}
+
+#[test]
+fn test_panels_only_land_on_stock_of_their_material() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let request = mixed_material_request("Wood", &["Metal", "Wood"]);
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    assert_eq!(response.panels().unwrap().len(), 3);
+    assert!(response.no_fit_panels.is_empty());
+    assert!(solution.mosaics.iter().all(|mosaic| mosaic.material() == "Wood"));
+}
+
+#[test]
+fn test_max_sheets_picks_stock_per_material() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut request = mixed_material_request("Wood", &["Metal", "Wood"]);
+    request.configuration = Some(Configuration {
+        max_sheets: Some(1),
+        ..Configuration::default()
+    });
+    let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+
+    assert_eq!(solution.mosaics.len(), 1);
+    assert_eq!(solution.mosaics[0].material(), "Wood");
+    assert!(solution.no_fit_panels.is_empty());
+}
+
+fn offcut_request(threshold: Option<&str>) -> CalculationRequest {
+    use cutlist_optimizer_cli::models::{Configuration, Panel};
+
+    let panel = |id: i32, width: &str, height: &str| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(1)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    CalculationRequest {
+        configuration: Some(Configuration {
+            reusable_offcut_threshold: threshold.map(str::to_string),
+            ..Configuration::default()
+        }),
+        panels: vec![panel(1, "990", "700")],
+        stock_panels: vec![panel(100, "1000", "800")],
+    }
+}
+
+#[test]
+fn test_only_large_remainders_are_reusable_offcuts() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let request = offcut_request(Some("50"));
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+
+    // One 100-wide strip and one 10-wide sliver are left over
+    let classification = solution.classify_offcuts((50.0 * factor) as i32, factor);
+    assert_eq!(classification.reusable_offcuts.len(), 1);
+    assert_eq!(classification.waste.len(), 1);
+    let sliver = &classification.waste[0];
+    assert_eq!(sliver.real_width().min(sliver.real_height()), 10.0);
+
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+    let offcuts = response.reusable_offcuts();
+    assert_eq!(offcuts.len(), 1);
+    assert_eq!(offcuts[0].real_width().min(offcuts[0].real_height()), 100.0);
+    assert_eq!(offcuts[0].material, "wood");
+}
+
+#[test]
+fn test_no_reusable_offcuts_without_threshold() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let request = offcut_request(None);
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    assert!(response.reusable_offcuts().is_empty());
+}
+
+#[test]
+fn test_invalid_reusable_offcut_threshold_is_rejected() {
+    let errors = offcut_request(Some("wide")).validate_semantic().unwrap_err();
+    assert!(errors.iter().any(|error| error.path == "configuration.reusable_offcut_threshold"));
+}
+
+fn scoring_request(cut_removes_material: bool) -> CalculationRequest {
+    let mut request = offcut_request(None);
+    request.panels[0].width = Some("300".to_string());
+    request.panels[0].height = Some("800".to_string());
+    if let Some(configuration) = request.configuration.as_mut() {
+        configuration.cut_removes_material = cut_removes_material;
+    }
+    request
+}
+
+#[test]
+fn test_scoring_cuts_leave_full_size_remainders() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+
+    let remainder_and_cuts = |cut_removes_material: bool| {
+        let (solution, factor) = compute_layout(&scoring_request(cut_removes_material), 3, 0, 5).unwrap();
+        let offcuts = solution.classify_offcuts(0, factor).reusable_offcuts;
+        assert_eq!(offcuts.len(), 1);
+        ((offcuts[0].real_width(), offcuts[0].real_height()), solution.get_nbr_cuts())
+    };
+
+    let (scored, scored_cuts) = remainder_and_cuts(false);
+    let (sawn, sawn_cuts) = remainder_and_cuts(true);
+
+    assert_eq!(scored, (700.0, 800.0));
+    assert_eq!(sawn, (697.0, 800.0));
+    assert_eq!(scored_cuts, 1);
+    assert_eq!(scored_cuts, sawn_cuts);
+}
+
+#[test]
+fn test_cut_removes_material_defaults_to_true() {
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut value = serde_json::to_value(Configuration::default()).unwrap();
+    value.as_object_mut().unwrap().remove("cut_removes_material");
+
+    let configuration: Configuration = serde_json::from_value(value).unwrap();
+    assert!(configuration.cut_removes_material);
+}
+
+#[test]
+fn test_no_fit_panels_explain_why_they_failed() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::{enums::NoFitReason, Panel};
+
+    let panel = |id: i32, width: &str, height: &str, material: &str| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(1)
+            .with_material(material.to_string())
+            .with_enabled(true)
+    };
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![
+            panel(1, "2000", "300", "wood"),
+            panel(2, "300", "200", "metal"),
+            panel(3, "1000", "800", "wood"),
+            panel(4, "1000", "800", "wood"),
+        ],
+        stock_panels: vec![panel(100, "1000", "800", "wood")],
+    };
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let reason_of = |id: i32| {
+        response
+            .no_fit_panels
+            .iter()
+            .find(|tile| tile.id == id)
+            .map(|tile| tile.reason)
+    };
+    assert_eq!(response.panels().unwrap().len(), 1);
+    assert_eq!(reason_of(1), Some(NoFitReason::OversizedForAllStock));
+    assert_eq!(reason_of(2), Some(NoFitReason::MaterialUnavailable));
+    let full_sheet_reasons: Vec<_> = [3, 4].into_iter().filter_map(reason_of).collect();
+    assert_eq!(full_sheet_reasons, vec![NoFitReason::NoRemainingSpace]);
+}
+
+#[test]
+fn test_no_fit_panels_suggest_rotation_or_larger_stock() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::{enums::NoFitReason, Panel};
+
+    let panel = |id: i32, width: &str, height: &str| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(1)
+            .with_enabled(true)
+    };
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![
+            // Kept to its grain, it only fits the sheet turned sideways
+            panel(1, "900", "400").with_orientation(1),
+            // Too long either way; rotated it is 200 short of the sheet's height
+            panel(2, "1200", "300"),
+        ],
+        stock_panels: vec![panel(100, "500", "1000")],
+    };
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let no_fit = |id: i32| response.no_fit_panels.iter().find(|tile| tile.id == id).unwrap();
+    assert_eq!(no_fit(1).reason, NoFitReason::OversizedForAllStock);
+    assert!(no_fit(1).fits_if_rotated);
+    assert_eq!(no_fit(1).stock_deficit, Some((400.0, 0.0)));
+
+    assert_eq!(no_fit(2).reason, NoFitReason::OversizedForAllStock);
+    assert!(!no_fit(2).fits_if_rotated);
+    assert_eq!(no_fit(2).stock_deficit, Some((0.0, 200.0)));
+}
+
+#[test]
+fn test_from_solution_reports_cut_and_finished_banded_size() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::Panel;
+
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![Panel::new()
+            .with_id(1)
+            .with_width("100".to_string())
+            .with_height("100".to_string())
+            .with_count(1)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+            .with_banding_thickness([1.0; 4])],
+        stock_panels: vec![Panel::new()
+            .with_id(100)
+            .with_width("1000".to_string())
+            .with_height("800".to_string())
+            .with_count(1)
+            .with_material("wood".to_string())
+            .with_enabled(true)],
+    };
+    let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let panels = response.panels().unwrap();
+    assert_eq!(panels.len(), 1);
+    assert_eq!((panels[0].width, panels[0].height), (98.0, 98.0));
+    assert_eq!((panels[0].finished_width, panels[0].finished_height), (Some(100.0), Some(100.0)));
+}
+
+#[test]
+fn test_merge_recomputes_efficiency_from_combined_areas() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::Panel;
+
+    let single_sheet = |panel_size: &str, stock_size: &str| {
+        let panel = |id: i32, size: &str| {
+            Panel::new()
+                .with_id(id)
+                .with_width(size.to_string())
+                .with_height(size.to_string())
+                .with_count(1)
+                .with_material("wood".to_string())
+                .with_enabled(true)
+        };
+        let request = CalculationRequest {
+            configuration: None,
+            panels: vec![panel(1, panel_size)],
+            stock_panels: vec![panel(100, stock_size)],
+        };
+        let (solution, factor) = compute_layout(&request, 0, 0, 5).unwrap();
+        CalculationResponse::from_solution(&request, &solution, factor)
+    };
+
+    let mut merged = single_sheet("300", "1000");
+    let other = single_sheet("400", "500");
+    let (first_ratio, second_ratio) = (merged.total_used_area_ratio(), other.total_used_area_ratio());
+    let (first_area, second_area) = (1000.0 * 1000.0, 500.0 * 500.0);
+
+    merged.merge(other);
+
+    let weighted = (first_ratio * first_area + second_ratio * second_area) / (first_area + second_area);
+    assert!((merged.total_used_area_ratio() - weighted).abs() < 1e-9);
+    assert_eq!(merged.total_used_area(), 300.0 * 300.0 + 400.0 * 400.0);
+    assert_eq!(merged.panels().unwrap().len(), 2);
+    assert_eq!(merged.mosaics().len(), 2);
+    let indices: Vec<usize> = merged.sheet_yields().iter().map(|sheet| sheet.sheet_index).collect();
+    assert_eq!(indices, vec![0, 1]);
+}
+
+#[test]
+fn test_merge_combines_permutation_stats() {
+    use cutlist_optimizer_cli::models::PermutationStats;
+
+    let with_stats = |stats: Option<PermutationStats>| {
+        let mut response = CalculationResponse::new();
+        response.meta.permutation_stats = stats;
+        response
+    };
+    let first = PermutationStats {
+        permutations_generated: 6,
+        permutations_evaluated: 1,
+        duplicates_removed: 5,
+        early_exit_triggered: false,
+    };
+    let second = PermutationStats {
+        permutations_generated: 2,
+        permutations_evaluated: 1,
+        duplicates_removed: 0,
+        early_exit_triggered: true,
+    };
+
+    let mut merged = with_stats(Some(first));
+    merged.merge(with_stats(Some(second)));
+    assert_eq!(
+        merged.meta.permutation_stats,
+        Some(PermutationStats {
+            permutations_generated: 8,
+            permutations_evaluated: 2,
+            duplicates_removed: 5,
+            early_exit_triggered: true,
+        })
+    );
+
+    let mut merged = with_stats(None);
+    merged.merge(with_stats(Some(first)));
+    assert_eq!(merged.meta.permutation_stats, Some(first));
+}
+
+#[test]
+fn test_panel_labels_survive_placement() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::Task;
+
+    let mut request = solved_request();
+    request.panels[0].label = Some("Door".to_string());
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+
+    let placed: Vec<_> = solution.mosaics.iter().flat_map(|mosaic| mosaic.final_tile_nodes()).collect();
+    assert!(placed.iter().filter(|node| node.external_id == Some(1)).all(|node| node.label() == Some("Door")));
+    assert!(placed.iter().filter(|node| node.external_id == Some(2)).all(|node| node.label().is_none()));
+
+    // Labels come from the placed nodes, not from looking the panel up again
+    let mut unlabeled = request.clone();
+    unlabeled.panels[0].label = None;
+    let response = CalculationResponse::from_solution(&unlabeled, &solution, factor);
+    let door_labels = |panels: &[FinalTile]| {
+        panels.iter().filter(|panel| panel.label.as_deref() == Some("Door")).count()
+    };
+    assert_eq!(door_labels(response.panels().unwrap()), 3);
+
+    let mut task = Task::new("labeled-task".to_string());
+    task.set_factor(factor);
+    task.set_calculation_request(request);
+    task.add_solution("wood", solution);
+    let response = task.build_solution().unwrap();
+    assert_eq!(door_labels(response.panels().unwrap()), 3);
+}
+
+#[test]
+fn test_from_solution_reports_offcut_histogram() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::Configuration;
+
+    let mut request = solved_request();
+    request.configuration = Some(
+        Configuration::builder()
+            .offcut_histogram_buckets(vec![0.0, 10_000.0])
+            .build()
+            .unwrap(),
+    );
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let histogram = response.offcut_histogram();
+    assert_eq!(histogram.iter().map(|(bound, _)| *bound).collect::<Vec<_>>(), vec![0.0, 10_000.0]);
+    let remainders = solution
+        .mosaics
+        .iter()
+        .flat_map(|mosaic| mosaic.root_tile_node().unused_tiles())
+        .filter(|node| node.area() > 0)
+        .count();
+    assert_eq!(histogram.iter().map(|(_, count)| count).sum::<usize>(), remainders);
+
+    assert!(CalculationResponse::from_solution(&solved_request(), &solution, factor)
+        .offcut_histogram()
+        .is_empty());
+}
+
+#[test]
+fn test_from_solution_reports_typed_meta() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::constants::ResponseConstants;
+    use cutlist_optimizer_cli::models::enums::OptimizationPriority;
+
+    let request = solved_request();
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["meta"]["schema_version"], ResponseConstants::META_SCHEMA_VERSION);
+
+    // The typed fields repeat the top-level figures
+    let meta = &response.meta;
+    assert_eq!(meta.optimization_type, OptimizationPriority::LeastWastedArea);
+    assert_eq!(meta.efficiency, response.total_used_area_ratio());
+    assert_eq!(meta.panel_count, response.panels().unwrap().len());
+    assert_eq!(meta.no_fit_count, response.no_fit_panels().len());
+    assert_eq!(meta.sheet_count, response.mosaics().len());
+    assert_eq!(meta.cut_count, response.total_nbr_cuts());
+    assert_eq!(json["meta"]["efficiency"], json["total_used_area_ratio"]);
+
+    // Responses written before `meta` existed still load
+    let mut legacy = json.clone();
+    legacy.as_object_mut().unwrap().remove("meta");
+    let restored: CalculationResponse = serde_json::from_value(legacy).unwrap();
+    assert_eq!(restored.meta.schema_version, ResponseConstants::META_SCHEMA_VERSION);
+    assert_eq!(restored.meta.panel_count, 0);
+}
+
+#[test]
+fn test_from_solution_estimates_saw_time_when_configured() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::{Configuration, SawTimeParams};
+    use std::time::Duration;
+
+    let mut request = solved_request();
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    assert_eq!(CalculationResponse::from_solution(&request, &solution, factor).meta.estimated_saw_time, None);
+
+    let params = SawTimeParams::new(50.0, Duration::from_millis(1500));
+    request.configuration = Some(Configuration::builder().saw_time(params).build().unwrap());
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+
+    let expected = response.total_cut_length() / 50.0 + 1.5 * response.total_nbr_cuts() as f64;
+    let estimated = response.meta.estimated_saw_time.unwrap().as_secs_f64();
+    assert!((estimated - expected).abs() < 1e-6, "{} != {}", estimated, expected);
+
+    assert!(Configuration::builder()
+        .saw_time(SawTimeParams::new(0.0, Duration::ZERO))
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_from_solution_recommends_stock_from_request_catalog() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::{Configuration, Panel};
+
+    let size = |id: i32, width: &str, height: &str| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_material("wood".to_string())
+    };
+    let mut request = solved_request();
+    request.panels = vec![size(1, "400", "300").with_count(1).with_enabled(true)];
+    request.stock_panels = vec![size(100, "1000", "800").with_count(1).with_enabled(true)];
+
+    let (solution, factor) = compute_layout(&request, 3, 0, 5).unwrap();
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+    assert!(response.stock_recommendations().is_empty());
+
+    request.configuration = Some(Configuration {
+        stock_catalog: vec![size(200, "2440", "1220"), size(201, "600", "400"), size(202, "500", "350")],
+        ..Configuration::default()
+    });
+    let response = CalculationResponse::from_solution(&request, &solution, factor);
+    let recommendations = response.stock_recommendations();
+    assert_eq!(recommendations.len(), 1);
+    assert_eq!(recommendations[0].stock_id, 100);
+    let recommended = (recommendations[0].recommended_width, recommendations[0].recommended_height);
+    assert!(recommended == (500, 350) || recommended == (350, 500));
+}
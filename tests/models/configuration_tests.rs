@@ -0,0 +1,62 @@
+//! Tests for building configurations
+
+use cutlist_optimizer_cli::models::{Configuration, CutThickness};
+use cutlist_optimizer_cli::{CutStyle, GrainMode, OptimizationPriority};
+
+#[test]
+fn test_builder_with_only_cut_thickness_keeps_defaults() {
+    let configuration = Configuration::builder().cut_thickness(4).build().unwrap();
+
+    assert_eq!(configuration.cut_thickness, CutThickness::from(4));
+    let mut expected = serde_json::to_value(Configuration::default()).unwrap();
+    expected["cut_thickness"] = serde_json::to_value(CutThickness::from(4)).unwrap();
+    assert_eq!(serde_json::to_value(&configuration).unwrap(), expected);
+}
+
+#[test]
+fn test_builder_sets_fields() {
+    let configuration = Configuration::builder()
+        .optimization_priority(OptimizationPriority::LeastNbrCuts)
+        .grain_mode(GrainMode::Free)
+        .cut_style(CutStyle::FreeCorner)
+        .max_sheets(3)
+        .reusable_offcut_threshold("150")
+        .build()
+        .unwrap();
+
+    assert_eq!(configuration.optimization_priority, OptimizationPriority::LeastNbrCuts);
+    assert_eq!(configuration.grain_mode, GrainMode::Free);
+    assert_eq!(configuration.cut_style, CutStyle::FreeCorner);
+    assert_eq!(configuration.max_sheets, Some(3));
+    assert_eq!(configuration.reusable_offcut_threshold.as_deref(), Some("150"));
+}
+
+#[test]
+fn test_builder_rejects_invalid_configuration() {
+    assert!(Configuration::builder().cut_thickness(-1).build().is_err());
+    assert!(Configuration::builder().optimization_factor(0).build().is_err());
+    assert!(Configuration::builder().max_solutions_retained(0).build().is_err());
+    assert!(Configuration::builder().target_efficiency(0.0).build().is_err());
+    assert!(Configuration::builder().target_efficiency(1.5).build().is_err());
+    assert!(Configuration::builder().target_efficiency(1.0).build().is_ok());
+    assert!(Configuration::builder().max_permutations(0).build().is_err());
+    assert!(Configuration::builder().max_permutations(50).build().is_ok());
+}
+
+#[test]
+fn test_time_budget_serializes_as_milliseconds() {
+    use std::time::Duration;
+
+    let configuration = Configuration::builder().time_budget(Duration::from_millis(1500)).build().unwrap();
+    let json = serde_json::to_value(&configuration).unwrap();
+    assert_eq!(json["time_budget"], serde_json::json!(1500));
+
+    let restored: Configuration = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.time_budget, Some(Duration::from_millis(1500)));
+
+    let mut json = serde_json::to_value(Configuration::default()).unwrap();
+    assert!(json["time_budget"].is_null());
+    json.as_object_mut().unwrap().remove("time_budget");
+    let restored: Configuration = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.time_budget, None);
+}
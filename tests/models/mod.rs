@@ -1,6 +1,7 @@
 pub mod calculation_request_tests;
 pub mod calculation_response_tests;
 pub mod calculation_submission_result_tests;
+pub mod configuration_tests;
 pub mod cut_tests;
 pub mod edge_tests;
 pub mod final_tile_tests;
@@ -11,6 +12,7 @@ pub mod panel_struct_tests;
 pub mod panel_tests;
 pub mod performance_thresholds_tests;
 pub mod solution_tests;
+pub mod solution_geometry_tests;
 pub mod task_tests;
 pub mod task_status_response_tests;
 pub mod tile_dimensions_tests;
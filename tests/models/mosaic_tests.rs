@@ -16,6 +16,12 @@ mod mosaic_tests {
             material: "Wood".to_string(),
             orientation: Orientation::Horizontal,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let mosaic = Mosaic::from_tile_dimensions(&tile_dimensions);
@@ -53,6 +59,12 @@ mod mosaic_tests {
             material: "Plastic".to_string(),
             orientation: Orientation::Vertical,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let original = Mosaic::from_tile_dimensions(&tile_dimensions);
@@ -113,6 +125,12 @@ mod mosaic_tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let mut mosaic = Mosaic::from_tile_dimensions(&tile_dimensions);
@@ -173,6 +191,12 @@ mod mosaic_tests {
             material: "Aluminum".to_string(),
             orientation: Orientation::Vertical,
             is_rotated: true,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let mosaic = Mosaic::from_tile_dimensions(&original_dimensions);
@@ -197,6 +221,12 @@ mod mosaic_tests {
             material: "TestMaterial".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let mosaic = Mosaic::from_tile_dimensions(&tile_dimensions);
@@ -218,6 +248,12 @@ mod mosaic_tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let mosaic1 = Mosaic::from_tile_dimensions(&tile_dimensions);
@@ -235,9 +271,100 @@ mod mosaic_tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let mosaic3 = Mosaic::from_tile_dimensions(&different_dimensions);
         assert_ne!(mosaic1, mosaic3);
     }
 }
+
+#[test]
+fn test_mosaic_from_trimmed_stock_insets_root() {
+    let mut stock = TileDimensions::new(5, 1000, 800);
+    stock.edge_trim = 15;
+
+    let mosaic = Mosaic::from_tile_dimensions(&stock);
+    let root = mosaic.root_tile_node();
+    assert_eq!((root.x1(), root.y1()), (15, 15));
+    assert_eq!((mosaic.width(), mosaic.height()), (970, 770));
+    assert_eq!(mosaic.total_area(), 970 * 770);
+
+    // The nominal sheet is reported back unchanged
+    let nominal = mosaic.to_tile_dimensions();
+    assert_eq!((nominal.width, nominal.height, nominal.edge_trim), (1000, 800, 15));
+}
+
+#[test]
+fn test_cut_sequence_divides_parents_before_children() {
+    use cutlist_optimizer_cli::engine::cut_list_thread::CutListThread;
+    use cutlist_optimizer_cli::{CutDirection, GrainMode};
+
+    let sheet = Mosaic::from_tile_dimensions(&TileDimensions::new(100, 1000, 800));
+    let mut thread = CutListThread::new();
+    thread.set_first_cut_orientation(CutDirection::Horizontal);
+    let mut results = Vec::new();
+    thread
+        .add_tile_to_mosaic(&TileDimensions::new(1, 400, 300), &sheet, &mut results, GrainMode::Fixed)
+        .unwrap();
+    let mosaic = results.into_iter().find(|mosaic| mosaic.nbr_cuts() == 2).unwrap();
+
+    let steps = mosaic.cut_sequence();
+    assert_eq!(steps.len(), 2);
+
+    assert_eq!(steps[0].index, 0);
+    assert_eq!(steps[0].piece_id, mosaic.root_tile_node().id() as i32);
+    assert_eq!((steps[0].piece_width, steps[0].piece_height), (1000, 800));
+    assert_eq!(steps[0].direction, CutDirection::Horizontal);
+    assert_eq!(steps[0].position, 400);
+
+    assert_eq!(steps[1].index, 1);
+    assert_eq!(steps[1].piece_id, steps[0].child1_id);
+    assert_eq!((steps[1].piece_width, steps[1].piece_height), (400, 800));
+    assert_eq!(steps[1].direction, CutDirection::Vertical);
+    assert_eq!(steps[1].position, 300);
+}
+
+#[test]
+fn test_optimized_cut_sequence_turns_sheet_no_more_often() {
+    use cutlist_optimizer_cli::engine::cut_list_thread::CutListThread;
+    use cutlist_optimizer_cli::{CutDirection, GrainMode};
+    use std::collections::HashSet;
+
+    let thread = CutListThread::new();
+    let mut mosaics = vec![Mosaic::from_tile_dimensions(&TileDimensions::new(100, 1000, 800))];
+    for (id, (width, height)) in [(400, 300), (250, 500), (300, 200), (150, 150)].into_iter().enumerate() {
+        let mut results = Vec::new();
+        for mosaic in &mosaics {
+            thread
+                .add_tile_to_mosaic(&TileDimensions::new(id as i32, width, height), mosaic, &mut results, GrainMode::Fixed)
+                .unwrap();
+        }
+        mosaics = results;
+    }
+    let mosaic = mosaics
+        .into_iter()
+        .max_by_key(|mosaic| Mosaic::direction_changes(&mosaic.cut_sequence()))
+        .unwrap();
+
+    let naive = mosaic.cut_sequence();
+    let optimized = mosaic.cut_sequence_optimized();
+    assert!(naive.iter().any(|step| step.direction == CutDirection::Horizontal));
+    assert!(naive.iter().any(|step| step.direction == CutDirection::Vertical));
+    assert!(Mosaic::direction_changes(&optimized) <= Mosaic::direction_changes(&naive));
+
+    // Same cuts, re-indexed, each piece divided only after the cut producing it
+    assert_eq!(optimized.len(), naive.len());
+    let mut produced = HashSet::from([mosaic.root_tile_node().id() as i32]);
+    for (index, step) in optimized.iter().enumerate() {
+        assert_eq!(step.index, index);
+        assert!(naive.iter().any(|cut| cut.piece_id == step.piece_id && cut.position == step.position));
+        assert!(produced.contains(&step.piece_id), "piece {} cut before it exists", step.piece_id);
+        produced.extend([step.child1_id, step.child2_id]);
+    }
+}
@@ -143,6 +143,17 @@ mod panel_tests {
         assert_eq!(panel.is_valid().unwrap(), false);
     }
 
+    #[test]
+    fn test_panel_is_valid_oversized_width() {
+        let panel = Panel::new()
+            .with_enabled(true)
+            .with_count(5)
+            .with_width("2e9".to_string())
+            .with_height("200.0".to_string());
+
+        assert_eq!(panel.is_valid().unwrap(), false);
+    }
+
     #[test]
     fn test_panel_is_valid_success() {
         let panel = Panel::new()
@@ -225,6 +236,21 @@ mod panel_tests {
         let panel_no_dimensions = Panel::new().with_count(2).with_enabled(true);
         assert_eq!(format!("{}", panel_no_dimensions), "[?x?]*2");
     }
+
+    #[test]
+    fn test_panel_allowed_stock_ids() {
+        let unrestricted = Panel::default();
+        assert!(unrestricted.allows_stock(100));
+
+        let restricted = Panel::default().with_allowed_stock_ids(vec![200]);
+        assert!(restricted.allows_stock(200));
+        assert!(!restricted.allows_stock(100));
+
+        // Older requests without the field still deserialize as unrestricted
+        let json = r#"{"id":1,"width":"10","height":"10","count":1,"material":"wood","enabled":true,"orientation":0,"label":null,"edge":null}"#;
+        let panel: Panel = serde_json::from_str(json).unwrap();
+        assert_eq!(panel.allowed_stock_ids, None);
+    }
 }
 
 #[cfg(test)]
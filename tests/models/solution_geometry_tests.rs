@@ -0,0 +1,99 @@
+use cutlist_optimizer_cli::engine::layout::compute_layout;
+use cutlist_optimizer_cli::models::{CalculationRequest, GeometryError, Mosaic, Panel, Solution, TileDimensions, TileNode};
+
+fn final_node(x1: i32, x2: i32, y1: i32, y2: i32) -> TileNode {
+    let mut node = TileNode::new(x1, x2, y1, y2);
+    node.set_final(true);
+    node
+}
+
+/// A 100x50 sheet cut at x=40 with a kerf of 3, the right part cut at y=20
+fn valid_mosaic() -> Mosaic {
+    let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions::new(1, 100, 50));
+    let mut right = TileNode::new(43, 100, 0, 50);
+    right.set_child1(Some(final_node(43, 100, 0, 20)));
+    right.set_child2(Some(TileNode::new(43, 100, 23, 50)));
+
+    let root = mosaic.root_tile_node_mut();
+    root.set_child1(Some(final_node(0, 40, 0, 50)));
+    root.set_child2(Some(right));
+    mosaic
+}
+
+fn solution_with(mosaic: Mosaic) -> Solution {
+    let mut solution = Solution::new();
+    solution.add_mosaic(mosaic);
+    solution
+}
+
+#[test]
+fn test_hand_built_mosaic_passes() {
+    assert_eq!(solution_with(valid_mosaic()).validate_geometry(), Ok(()));
+}
+
+#[test]
+fn test_overlapping_children_fail() {
+    let mut mosaic = valid_mosaic();
+    mosaic.root_tile_node_mut().set_child2(Some(TileNode::new(30, 100, 0, 50)));
+
+    let error = solution_with(mosaic).validate_geometry().unwrap_err();
+    assert!(matches!(error, GeometryError::Overlap { sheet: 0, .. }));
+    let message = error.to_string();
+    assert!(message.starts_with("sheet 0: children of node"));
+    assert!(message.ends_with("overlap: (0,0)-(40,50) and (30,0)-(100,50)"));
+}
+
+#[test]
+fn test_node_beyond_sheet_fails() {
+    let mut mosaic = valid_mosaic();
+    mosaic.root_tile_node_mut().child2_mut().unwrap().set_child2(Some(TileNode::new(43, 100, 23, 60)));
+
+    let error = solution_with(mosaic).validate_geometry().unwrap_err();
+    assert!(matches!(error, GeometryError::OutOfBounds { sheet: 0, bounds: (43, 23, 100, 60), .. }));
+    assert!(error.to_string().contains("exceeds the 100x50 sheet"));
+}
+
+#[test]
+fn test_inconsistent_kerf_fails() {
+    let mut mosaic = valid_mosaic();
+    // Kerf of 5 on the y axis, against 3 on the x axis; then 5 again on a second y cut
+    let right = mosaic.root_tile_node_mut().child2_mut().unwrap();
+    let mut lower = TileNode::new(43, 100, 23, 50);
+    lower.set_child1(Some(final_node(43, 100, 23, 30)));
+    lower.set_child2(Some(TileNode::new(43, 100, 35, 50)));
+    right.set_child2(Some(lower));
+
+    let error = solution_with(mosaic).validate_geometry().unwrap_err();
+    assert!(matches!(error, GeometryError::KerfMismatch { expected: 3, found: 5, .. }));
+}
+
+#[test]
+fn test_split_final_tile_fails() {
+    let mut mosaic = valid_mosaic();
+    mosaic.root_tile_node_mut().child1_mut().unwrap().set_child1(Some(TileNode::new(0, 20, 0, 50)));
+
+    let error = solution_with(mosaic).validate_geometry().unwrap_err();
+    assert!(matches!(error, GeometryError::SplitFinalTile { sheet: 0, .. }));
+}
+
+#[test]
+fn test_computed_layout_passes() {
+    let panel = |id: i32, width: &str, height: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "400.5", "300", 3), panel(2, "250", "120", 4), panel(3, "90", "700", 2)],
+        stock_panels: vec![panel(100, "1000", "800", 3)],
+    };
+
+    let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+    assert!(solution.get_nbr_final_tiles() > 0);
+    assert_eq!(solution.validate_geometry(), Ok(()));
+}
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use cutlist_optimizer_cli::{
-        models::{Mosaic, Solution, TileDimensions},
+        models::{Cut, CutThickness, Mosaic, Solution, TileDimensions, TileNode},
         Orientation,
     };
 
@@ -14,6 +16,12 @@ mod tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         }
     }
 
@@ -75,6 +83,12 @@ mod tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
         let tile_dims2 = TileDimensions {
             id: 2,
@@ -84,6 +98,12 @@ mod tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         let mosaics = vec![
@@ -156,6 +176,12 @@ mod tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
         let tile_dims2 = TileDimensions {
             id: 2,
@@ -165,6 +191,12 @@ mod tests {
             material: "Wood".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         };
 
         solution.add_mosaic(Mosaic::from_tile_dimensions(&tile_dims1));
@@ -204,4 +236,712 @@ mod tests {
         assert_eq!(solution.get_nbr_mosaics(), 0);
         assert!(solution.is_empty());
     }
+
+    /// A 1000x1000 sheet with a single panel of the given size at the origin
+    fn sheet_with_panel(panel_id: i32, width: i32, height: i32) -> Mosaic {
+        let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions {
+            id: 100,
+            width: 1000,
+            height: 1000,
+            label: None,
+            material: "Wood".to_string(),
+            orientation: Orientation::Any,
+            is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
+        });
+
+        let mut panel = TileNode::new(0, width, 0, height);
+        panel.set_final(true);
+        panel.set_external_id(Some(panel_id));
+        let mut strip = TileNode::new(0, 1000, 0, height);
+        strip.set_child1(Some(panel));
+        strip.set_child2(Some(TileNode::new(width, 1000, 0, height)));
+
+        let root = mosaic.root_tile_node_mut();
+        root.set_child1(Some(strip));
+        root.set_child2(Some(TileNode::new(0, 1000, height, 1000)));
+        mosaic
+    }
+
+    fn waste_test_cut(x1: i32, y1: i32, x2: i32, y2: i32, is_horizontal: bool) -> Cut {
+        Cut::builder()
+            .set_x1(x1)
+            .set_y1(y1)
+            .set_x2(x2)
+            .set_y2(y2)
+            .set_horizontal(is_horizontal)
+            .build()
+    }
+
+    #[test]
+    fn test_waste_breakdown_known_layout() {
+        // 1000x1000 sheet with a 4-wide kerf: a 1000x600 panel on top, then the
+        // remaining 1000x396 strip is split at 990 (leaving a 6x396 sliver) and
+        // the 990x396 part holds a 300x396 panel next to a 686x396 offcut
+        let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions {
+            id: 100,
+            width: 1000,
+            height: 1000,
+            label: None,
+            material: "Wood".to_string(),
+            orientation: Orientation::Any,
+            is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
+        });
+
+        let mut top_panel = TileNode::new(0, 1000, 0, 600);
+        top_panel.set_final(true);
+        let mut small_panel = TileNode::new(0, 300, 604, 1000);
+        small_panel.set_final(true);
+        let mut usable = TileNode::new(0, 990, 604, 1000);
+        usable.set_child1(Some(small_panel));
+        usable.set_child2(Some(TileNode::new(304, 990, 604, 1000)));
+        let mut rest = TileNode::new(0, 1000, 604, 1000);
+        rest.set_child1(Some(usable));
+        rest.set_child2(Some(TileNode::new(994, 1000, 604, 1000)));
+        let root = mosaic.root_tile_node_mut();
+        root.set_child1(Some(top_panel));
+        root.set_child2(Some(rest));
+        mosaic.cuts = vec![
+            waste_test_cut(0, 600, 1000, 600, false),
+            waste_test_cut(990, 604, 990, 1000, true),
+            waste_test_cut(300, 604, 300, 1000, true),
+        ];
+
+        let mut solution = Solution::default();
+        solution.add_mosaic(mosaic);
+        let breakdown = solution.waste_breakdown(4, &HashMap::new());
+
+        assert_eq!(breakdown.kerf_area, 4 * 1000 + 4 * 396 + 4 * 396);
+        assert_eq!(breakdown.offcut_area, 686 * 396);
+        assert_eq!(breakdown.scrap_area, 6 * 396);
+        assert_eq!(
+            breakdown.total() + solution.get_used_area(),
+            solution.get_total_area()
+        );
+    }
+
+    #[test]
+    fn test_waste_breakdown_charges_full_kerf_on_narrow_remainder() {
+        // A 998x1000 panel on a 1000x1000 sheet with a 4-wide kerf: the cut
+        // leaves only 2 units of material but the blade still removes 4 along
+        // its whole length
+        let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions {
+            id: 100,
+            width: 1000,
+            height: 1000,
+            label: None,
+            material: "Wood".to_string(),
+            orientation: Orientation::Any,
+            is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
+        });
+        let mut panel = TileNode::new(0, 998, 0, 1000);
+        panel.set_final(true);
+        mosaic.root_tile_node_mut().set_child1(Some(panel));
+        mosaic.cuts = vec![waste_test_cut(998, 0, 998, 1000, true)];
+
+        let mut solution = Solution::default();
+        solution.add_mosaic(mosaic);
+
+        assert_eq!(solution.waste_breakdown(4, &HashMap::new()).kerf_area, 4 * 1000);
+        let thicker = HashMap::from([("Wood".to_string(), CutThickness::Uniform(6))]);
+        assert_eq!(solution.waste_breakdown(4, &thicker).kerf_area, 6 * 1000);
+    }
+
+    #[test]
+    fn test_waste_breakdown_accounts_for_whole_stock() {
+        use cutlist_optimizer_cli::engine::layout::compute_layout;
+        use cutlist_optimizer_cli::models::{CalculationRequest, Panel};
+
+        let panel = |id: i32, width: &str, height: &str, count: i32| {
+            Panel::new()
+                .with_id(id)
+                .with_width(width.to_string())
+                .with_height(height.to_string())
+                .with_count(count)
+                .with_material("wood".to_string())
+                .with_enabled(true)
+        };
+        let request = CalculationRequest {
+            configuration: None,
+            panels: vec![panel(1, "400", "300", 3), panel(2, "250", "120", 4)],
+            stock_panels: vec![panel(100, "1000", "800", 2)],
+        };
+
+        let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+        let breakdown = solution.waste_breakdown(3, &HashMap::new());
+
+        assert!(breakdown.kerf_area > 0);
+        assert!(breakdown.offcut_area >= 0 && breakdown.scrap_area >= 0);
+        assert_eq!(
+            breakdown.kerf_area + breakdown.offcut_area + solution.get_used_area() + breakdown.scrap_area,
+            solution.get_total_area()
+        );
+    }
+
+    /// 200x100 sheet without kerf holding four panels, with a 10x60 strip along
+    /// the left edge, a 90x10 gap enclosed by panels and a 50x50 corner offcut
+    fn sheet_with_corner_offcut_and_interior_gap() -> Solution {
+        let final_node = |x1, x2, y1, y2| {
+            let mut node = TileNode::new(x1, x2, y1, y2);
+            node.set_final(true);
+            node
+        };
+        let mut mosaic = Mosaic::from_tile_dimensions(&TileDimensions::new(100, 200, 100));
+
+        let mut between = TileNode::new(10, 100, 40, 100);
+        between.set_child1(Some(TileNode::new(10, 100, 40, 50)));
+        between.set_child2(Some(final_node(10, 100, 50, 100)));
+        let mut lower_left = TileNode::new(0, 100, 40, 100);
+        lower_left.set_child1(Some(TileNode::new(0, 10, 40, 100)));
+        lower_left.set_child2(Some(between));
+        let mut left = TileNode::new(0, 100, 0, 100);
+        left.set_child1(Some(final_node(0, 100, 0, 40)));
+        left.set_child2(Some(lower_left));
+
+        let mut upper_right = TileNode::new(100, 200, 0, 50);
+        upper_right.set_child1(Some(final_node(100, 150, 0, 50)));
+        upper_right.set_child2(Some(TileNode::new(150, 200, 0, 50)));
+        let mut right = TileNode::new(100, 200, 0, 100);
+        right.set_child1(Some(upper_right));
+        right.set_child2(Some(final_node(100, 200, 50, 100)));
+
+        let root = mosaic.root_tile_node_mut();
+        root.set_child1(Some(left));
+        root.set_child2(Some(right));
+
+        let mut solution = Solution::default();
+        solution.add_mosaic(mosaic);
+        solution
+    }
+
+    #[test]
+    fn test_waste_by_location_separates_corner_offcut_and_interior_gap() {
+        let solution = sheet_with_corner_offcut_and_interior_gap();
+
+        let waste = solution.waste_by_location(Some(30));
+        assert_eq!(waste.usable_offcut, 50 * 50);
+        assert_eq!(waste.inter_part, 90 * 10);
+        assert_eq!(waste.edge_trim, 10 * 60);
+        assert_eq!(waste.total() + solution.get_used_area(), solution.get_total_area());
+
+        // Without a threshold the corner offcut is waste along the sheet edge
+        let waste = solution.waste_by_location(None);
+        assert_eq!(waste.usable_offcut, 0);
+        assert_eq!(waste.inter_part, 90 * 10);
+        assert_eq!(waste.edge_trim, 10 * 60 + 50 * 50);
+    }
+
+    #[test]
+    fn test_waste_by_location_counts_trimmed_border_and_kerf() {
+        let mut stock = TileDimensions::new(100, 120, 100);
+        stock.edge_trim = 10;
+        let mut mosaic = Mosaic::from_tile_dimensions(&stock);
+        // 100x80 usable area cut at x=50 with a 4-wide kerf
+        let mut panel = TileNode::new(10, 60, 10, 90);
+        panel.set_final(true);
+        let root = mosaic.root_tile_node_mut();
+        root.set_child1(Some(panel));
+        root.set_child2(Some(TileNode::new(64, 110, 10, 90)));
+
+        let mut solution = Solution::default();
+        solution.add_mosaic(mosaic);
+        let waste = solution.waste_by_location(Some(40));
+
+        assert_eq!(waste.edge_trim, 120 * 100 - 100 * 80);
+        assert_eq!(waste.inter_part, 4 * 80);
+        assert_eq!(waste.usable_offcut, 46 * 80);
+    }
+
+    #[test]
+    fn test_to_svg_draws_one_rect_per_final_and_unused_node() {
+        use cutlist_optimizer_cli::models::solution::svg::{to_svg, SvgOptions};
+
+        let mut solution = Solution::new();
+        solution.mosaics.push(sheet_with_panel(7, 400, 300));
+        solution.mosaics.push(sheet_with_panel(8, 250, 250));
+
+        let svg = to_svg(&solution, SvgOptions { scale: 0.5, ..SvgOptions::default() });
+
+        let expected: usize = solution
+            .mosaics
+            .iter()
+            .map(|mosaic| mosaic.final_tile_count() + mosaic.root_tile_node().unused_tiles().len())
+            .sum();
+        assert_eq!(svg.matches("<rect ").count(), expected);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert_eq!(svg.matches("<svg").count(), svg.matches("</svg>").count());
+        assert_eq!(svg.matches("<svg y=").count(), 2);
+        assert!(svg.contains(">7</text>") && svg.contains(">8</text>"));
+        // Second sheet starts below the first: 1000 * 0.5 + 20px gap
+        assert!(svg.contains("<svg y=\"520\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"200\" height=\"150\""));
+    }
+
+    #[test]
+    fn test_offcut_histogram_counts_remainders_per_bucket() {
+        // Remainders: 600x300 beside the first panel, 1000x700 below each panel
+        let mut solution = Solution::new();
+        solution.mosaics.push(sheet_with_panel(1, 400, 300));
+        solution.mosaics.push(sheet_with_panel(2, 1000, 300));
+
+        assert_eq!(
+            solution.offcut_histogram(&[0, 100_000, 500_000]),
+            vec![(0, 0), (100_000, 1), (500_000, 2)]
+        );
+        // Remainders below the first bound are left out
+        assert_eq!(solution.offcut_histogram(&[200_000, 800_000]), vec![(200_000, 2), (800_000, 0)]);
+        assert!(solution.offcut_histogram(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_offcuts_above_lists_large_remainders_largest_first() {
+        // Remainders: 600x300 and 1000x700 on the first sheet, 1000x700 on the second,
+        // 750x1000 on the third
+        let mut solution = Solution::new();
+        solution.mosaics.push(sheet_with_panel(1, 400, 300));
+        solution.mosaics.push(sheet_with_panel(2, 1000, 300));
+        solution.mosaics.push(sheet_with_panel(3, 250, 1000));
+
+        let offcuts = solution.offcuts_above(200_000);
+        let found: Vec<(usize, i32, i32, i32, i32)> = offcuts
+            .iter()
+            .map(|offcut| (offcut.sheet_index, offcut.x, offcut.y, offcut.width, offcut.height))
+            .collect();
+        assert_eq!(
+            found,
+            vec![(2, 250, 0, 750, 1000), (0, 0, 300, 1000, 700), (1, 0, 300, 1000, 700)]
+        );
+        assert_eq!(offcuts[0].area(), 750_000);
+        assert_eq!(solution.offcuts_above(0).len(), 4);
+        assert!(solution.offcuts_above(1_000_000).is_empty());
+    }
+
+    #[test]
+    fn test_diff_lists_single_relocated_tile() {
+        // A 400x300 panel plus, when given, a 600x300 panel in the rest of the strip
+        let sheet = |first_id: i32, second_id: Option<i32>| {
+            let mut mosaic = sheet_with_panel(first_id, 400, 300);
+            if let Some(second_id) = second_id {
+                let rest = mosaic.root_tile_node_mut().child1_mut().and_then(TileNode::child2_mut).unwrap();
+                rest.set_final(true);
+                rest.set_external_id(Some(second_id));
+            }
+            mosaic
+        };
+        let mut before = Solution::new();
+        before.mosaics.push(sheet(1, Some(2)));
+        before.mosaics.push(sheet(3, None));
+        let mut after = Solution::new();
+        after.mosaics.push(sheet(1, None));
+        after.mosaics.push(sheet(3, Some(2)));
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.moved_tile_ids, vec![2]);
+        assert_eq!(diff.tiles_placed_delta, 0);
+        assert_eq!(diff.wasted_area_delta, 0);
+        assert_eq!(diff.cut_count_delta, 0);
+        assert_eq!(diff.sheet_count_delta, 0);
+        assert!(before.diff(&before).moved_tile_ids.is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_deltas_as_other_minus_self() {
+        let mut one_sheet = Solution::new();
+        one_sheet.mosaics.push(sheet_with_panel(1, 400, 300));
+        let mut two_sheets = one_sheet.clone();
+        two_sheets.mosaics.push(sheet_with_panel(2, 250, 250));
+
+        let diff = one_sheet.diff(&two_sheets);
+
+        assert_eq!(diff.tiles_placed_delta, 1);
+        assert_eq!(diff.sheet_count_delta, 1);
+        assert_eq!(diff.wasted_area_delta, 1000 * 1000 - 250 * 250);
+        assert!(diff.moved_tile_ids.is_empty());
+        assert_eq!(two_sheets.diff(&one_sheet).sheet_count_delta, -1);
+    }
+
+    #[test]
+    fn test_to_svg_labels_panels_with_label_and_id() {
+        use cutlist_optimizer_cli::models::solution::svg::{to_svg, SvgOptions};
+
+        let mut sheet = sheet_with_panel(7, 400, 300);
+        let panel = sheet.root_tile_node_mut().child1_mut().and_then(TileNode::child1_mut).unwrap();
+        panel.set_label(Some("Door <left>".to_string()));
+        let mut solution = Solution::new();
+        solution.mosaics.push(sheet);
+
+        let svg = to_svg(&solution, SvgOptions::default());
+        assert!(svg.contains(">Door &lt;left&gt; (7)</text>"));
+    }
+
+    #[test]
+    fn test_to_svg_cut_lines_are_optional() {
+        use cutlist_optimizer_cli::engine::layout::compute_layout;
+        use cutlist_optimizer_cli::models::solution::svg::{to_svg, SvgOptions};
+        use cutlist_optimizer_cli::models::{CalculationRequest, Panel};
+
+        let panel = |id: i32, width: &str, height: &str, count: i32| {
+            Panel::new()
+                .with_id(id)
+                .with_width(width.to_string())
+                .with_height(height.to_string())
+                .with_count(count)
+                .with_material("wood".to_string())
+                .with_enabled(true)
+        };
+        let request = CalculationRequest {
+            configuration: None,
+            panels: vec![panel(1, "400", "300", 2)],
+            stock_panels: vec![panel(100, "1000", "800", 1)],
+        };
+        let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+        let cuts: usize = solution.mosaics.iter().map(|mosaic| mosaic.nbr_cuts()).sum();
+        assert!(cuts > 0);
+
+        let with_cuts = to_svg(&solution, SvgOptions::default());
+        let without_cuts = to_svg(&solution, SvgOptions { draw_cuts: false, ..SvgOptions::default() });
+
+        assert_eq!(with_cuts.matches("<line ").count(), cuts);
+        assert_eq!(without_cuts.matches("<line ").count(), 0);
+    }
+
+    /// Polylines read back from DXF text: (layer, declared vertex count, vertices, closed)
+    fn parse_dxf_polylines(dxf: &str) -> Vec<(String, usize, Vec<(i64, i64)>, bool)> {
+        let lines: Vec<&str> = dxf.lines().collect();
+        let pairs: Vec<(i32, &str)> = lines
+            .chunks(2)
+            .map(|chunk| (chunk[0].trim().parse().unwrap(), chunk[1]))
+            .collect();
+
+        let mut polylines = Vec::new();
+        let mut i = 0;
+        while i < pairs.len() {
+            if pairs[i] != (0, "LWPOLYLINE") {
+                i += 1;
+                continue;
+            }
+            let (mut layer, mut declared, mut closed) = (String::new(), 0, false);
+            let mut vertices = Vec::new();
+            i += 1;
+            while i < pairs.len() && pairs[i].0 != 0 {
+                match pairs[i] {
+                    (8, value) => layer = value.to_string(),
+                    (90, value) => declared = value.parse().unwrap(),
+                    (70, value) => closed = value == "1",
+                    (10, value) => vertices.push((value.parse().unwrap(), 0)),
+                    (20, value) => vertices.last_mut().unwrap().1 = value.parse().unwrap(),
+                    _ => {}
+                }
+                i += 1;
+            }
+            polylines.push((layer, declared, vertices, closed));
+        }
+        polylines
+    }
+
+    #[test]
+    fn test_to_dxf_round_trips_panels_and_cuts() {
+        use cutlist_optimizer_cli::engine::layout::compute_layout;
+        use cutlist_optimizer_cli::models::solution::dxf::to_dxf;
+        use cutlist_optimizer_cli::models::{CalculationRequest, Panel};
+
+        let panel = |id: i32, width: &str, height: &str, count: i32| {
+            Panel::new()
+                .with_id(id)
+                .with_width(width.to_string())
+                .with_height(height.to_string())
+                .with_count(count)
+                .with_material("wood".to_string())
+                .with_enabled(true)
+        };
+        let request = CalculationRequest {
+            configuration: None,
+            panels: vec![panel(1, "400", "300", 3), panel(2, "250", "120", 2)],
+            stock_panels: vec![panel(100, "1000", "800", 1)],
+        };
+        let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+
+        let dxf = to_dxf(&solution);
+        assert!(dxf.starts_with("0\nSECTION\n2\nHEADER\n"));
+        assert!(dxf.ends_with("0\nEOF\n"));
+
+        let polylines = parse_dxf_polylines(&dxf);
+        let finals: usize = solution.mosaics.iter().map(|mosaic| mosaic.final_tile_count()).sum();
+        let cuts: usize = solution.mosaics.iter().map(|mosaic| mosaic.nbr_cuts()).sum();
+        assert_eq!(polylines.iter().filter(|polyline| polyline.3).count(), finals);
+        assert_eq!(polylines.iter().filter(|polyline| !polyline.3).count(), cuts);
+        for (layer, declared, vertices, closed) in &polylines {
+            assert_eq!(layer, "wood");
+            assert_eq!(*declared, vertices.len());
+            assert_eq!(vertices.len(), if *closed { 4 } else { 2 });
+        }
+    }
+
+    #[test]
+    fn test_to_dxf_uses_node_corners_and_material_layers() {
+        use cutlist_optimizer_cli::models::solution::dxf::to_dxf;
+
+        let mut oak = sheet_with_panel(2, 250, 250);
+        oak.set_material("Oak/Veneer".to_string());
+        let mut solution = Solution::new();
+        solution.mosaics.push(sheet_with_panel(1, 400, 300));
+        solution.mosaics.push(oak);
+
+        let dxf = to_dxf(&solution);
+        assert!(dxf.contains("0\nLAYER\n2\nOak_Veneer\n"));
+        assert!(dxf.contains("0\nLAYER\n2\nWood\n"));
+
+        let polylines = parse_dxf_polylines(&dxf);
+        assert_eq!(polylines.len(), 2);
+        assert_eq!(polylines[0].0, "Wood");
+        assert_eq!(polylines[0].2, vec![(0, 0), (400, 0), (400, 300), (0, 300)]);
+        // Second sheet is shifted past the first one
+        assert_eq!(polylines[1].0, "Oak_Veneer");
+        assert!(polylines[1].2.iter().all(|&(x, _)| x >= 1000));
+    }
+
+    /// Parse the rows of a parts CSV, header excluded
+    fn parse_parts_csv(csv: &str) -> Vec<Vec<String>> {
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        assert_eq!(
+            reader.headers().unwrap().iter().collect::<Vec<_>>(),
+            ["sheet_index", "tile_id", "label", "x", "y", "width", "height", "rotated", "material"]
+        );
+        reader
+            .records()
+            .map(|record| record.unwrap().iter().map(str::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_parts_to_csv_lists_every_placed_tile() {
+        use cutlist_optimizer_cli::engine::layout::compute_layout;
+        use cutlist_optimizer_cli::models::solution::csv::parts_to_csv;
+        use cutlist_optimizer_cli::models::{CalculationRequest, Panel};
+
+        let panel = |id: i32, width: &str, height: &str, count: i32| {
+            Panel::new()
+                .with_id(id)
+                .with_width(width.to_string())
+                .with_height(height.to_string())
+                .with_count(count)
+                .with_enabled(true)
+        };
+        let request = CalculationRequest {
+            configuration: None,
+            panels: vec![panel(1, "400", "300", 3), panel(2, "250", "120", 2)],
+            stock_panels: vec![panel(100, "1000", "800", 1), panel(101, "600", "400", 1)],
+        };
+        let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+
+        let rows = parse_parts_csv(&parts_to_csv(&solution));
+        let finals: usize = solution.mosaics.iter().map(|mosaic| mosaic.final_tile_count()).sum();
+        assert_eq!(rows.len(), finals);
+        assert_eq!(rows.len(), 5);
+        for row in &rows {
+            let sheet_index: usize = row[0].parse().unwrap();
+            assert!(sheet_index < solution.mosaics.len());
+            assert!(row[1] == "1" || row[1] == "2");
+        }
+    }
+
+    #[test]
+    fn test_parts_to_csv_known_placement() {
+        use cutlist_optimizer_cli::models::solution::csv::parts_to_csv;
+
+        let mut first = sheet_with_panel(7, 400, 300);
+        let panel = first.root_tile_node_mut().child1_mut().and_then(TileNode::child1_mut).unwrap();
+        panel.set_label(Some("Door, left".to_string()));
+        let mut second = sheet_with_panel(8, 250, 250);
+        second.set_material("Oak".to_string());
+        let mut solution = Solution::new();
+        solution.mosaics.push(first);
+        solution.mosaics.push(second);
+
+        let rows = parse_parts_csv(&parts_to_csv(&solution));
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], ["0", "7", "Door, left", "0", "0", "400", "300", "false", "Wood"]);
+        assert_eq!(rows[1], ["1", "8", "", "0", "0", "250", "250", "false", "Oak"]);
+    }
+
+    #[test]
+    fn test_to_java_format_uses_java_field_names() {
+        use cutlist_optimizer_cli::engine::layout::compute_layout;
+        use cutlist_optimizer_cli::models::solution::interchange::to_java_format;
+        use cutlist_optimizer_cli::models::{CalculationRequest, Panel};
+
+        let panel = |id: i32, width: &str, height: &str, count: i32| {
+            Panel::new()
+                .with_id(id)
+                .with_width(width.to_string())
+                .with_height(height.to_string())
+                .with_count(count)
+                .with_enabled(true)
+        };
+        let request = CalculationRequest {
+            configuration: None,
+            panels: vec![panel(1, "400", "300", 3), panel(2, "250", "120", 2)],
+            stock_panels: vec![panel(100, "1000", "800", 1)],
+        };
+        let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+
+        let json = to_java_format(&solution);
+        assert!(json["totalUsedArea"].is_i64());
+        assert!(json["totalUsedAreaRatio"].is_f64());
+        assert!(json["noFitPanels"].is_array());
+        let mosaics = json["mosaics"].as_array().unwrap();
+        assert_eq!(mosaics.len(), solution.mosaics.len());
+
+        let mosaic = &mosaics[0];
+        assert_eq!(mosaic["requestStockId"], 100);
+        assert!(mosaic["usedArea"].is_i64());
+        assert!(mosaic["wastedArea"].is_i64());
+        assert_eq!(mosaic["panels"].as_array().unwrap().len(), 5);
+        let cuts = mosaic["cuts"].as_array().unwrap();
+        assert_eq!(cuts.len(), solution.mosaics[0].cuts.len());
+        for cut in cuts {
+            for key in ["x1", "y1", "x2", "y2", "cutCoord", "originalTileId", "child1TileId", "child2TileId"] {
+                assert!(cut[key].is_i64(), "{} is not an integer", key);
+            }
+            assert!(cut["isHorizontal"].is_boolean());
+        }
+        let tiles = mosaic["tiles"].as_array().unwrap();
+        assert!(tiles.iter().all(|tile| tile["isFinal"].is_boolean() && tile["hasChildren"].is_boolean()));
+    }
+
+    #[test]
+    fn test_to_java_format_known_placement() {
+        use cutlist_optimizer_cli::models::solution::interchange::to_java_format;
+
+        let mut solution = Solution::new();
+        solution.mosaics.push(sheet_with_panel(7, 400, 300));
+
+        let json = to_java_format(&solution);
+        let mosaic = &json["mosaics"][0];
+        assert_eq!(mosaic["usedArea"], 400 * 300);
+        assert_eq!(mosaic["wastedArea"], 1000 * 1000 - 400 * 300);
+        assert_eq!(mosaic["material"], "Wood");
+        assert_eq!(mosaic["nbrFinalPanels"], 1);
+        assert_eq!(mosaic["nbrWastedPanels"], 2);
+        let panel = &mosaic["panels"][0];
+        assert_eq!(panel["requestObjId"], 7);
+        assert_eq!((panel["x"].as_i64(), panel["y"].as_i64()), (Some(0), Some(0)));
+        assert_eq!((panel["width"].as_i64(), panel["height"].as_i64()), (Some(400), Some(300)));
+        assert_eq!(mosaic["tiles"].as_array().unwrap().len(), 5);
+        assert_eq!(json["totalUsedArea"], 400 * 300);
+    }
+
+    #[test]
+    fn test_edge_banding_summary_totals_requested_edges() {
+        use cutlist_optimizer_cli::engine::layout::compute_layout;
+        use cutlist_optimizer_cli::engine::service::computation::dimension_utils::DimensionUtils;
+        use cutlist_optimizer_cli::models::{CalculationRequest, Edge, EdgeBandConfig, Panel};
+
+        let panel = |id: i32, width: &str, height: &str, edge: Edge| {
+            Panel::new()
+                .with_id(id)
+                .with_width(width.to_string())
+                .with_height(height.to_string())
+                .with_count(1)
+                .with_material("wood".to_string())
+                .with_enabled(true)
+                .with_edge(edge)
+        };
+        let request = CalculationRequest {
+            configuration: None,
+            panels: vec![
+                panel(1, "300", "200", Edge {
+                    top: Some("abs".to_string()),
+                    bottom: Some("abs".to_string()),
+                    ..Edge::default()
+                }),
+                panel(2, "150", "100", Edge {
+                    left: Some("pvc".to_string()),
+                    right: Some("abs".to_string()),
+                    ..Edge::default()
+                }),
+            ],
+            stock_panels: vec![Panel::new()
+                .with_id(100)
+                .with_width("1000".to_string())
+                .with_height("800".to_string())
+                .with_count(1)
+                .with_material("wood".to_string())
+                .with_enabled(true)],
+        };
+
+        let (tiles, _, factor) =
+            DimensionUtils::convert_panels_to_tiles(&request.panels, &request.stock_panels, 6).unwrap();
+        assert!(tiles.iter().all(|tile| tile.edge.is_some()));
+
+        let (solution, layout_factor) = compute_layout(&request, 3, 0, 5).unwrap();
+        assert_eq!(solution.get_nbr_final_tiles(), 2);
+        assert_eq!(factor, layout_factor);
+
+        let summary = solution.edge_banding_summary(&EdgeBandConfig::from_tiles(&tiles, factor));
+        // 300 + 300 on panel 1, 100 + 100 on panel 2
+        assert!((summary.total_length - 800.0).abs() < 1e-9);
+        assert!((summary.band_type_length("abs") - 700.0).abs() < 1e-9);
+        assert!((summary.band_type_length("pvc") - 100.0).abs() < 1e-9);
+        assert!((summary.material_length("wood") - 800.0).abs() < 1e-9);
+        assert_eq!(summary.band_type_length("missing"), 0.0);
+    }
+
+    #[test]
+    fn test_edge_banding_summary_ignores_unbanded_tiles() {
+        use cutlist_optimizer_cli::models::EdgeBandConfig;
+
+        let mut root = TileNode::new(0, 1000, 0, 800);
+        root.set_external_id(Some(7));
+        root.set_final(true);
+        let mut solution = Solution::new();
+        solution.add_mosaic(Mosaic::from_tile_node(&root, "Wood".to_string()));
+
+        let summary = solution.edge_banding_summary(&EdgeBandConfig::new(1.0));
+        assert_eq!(summary.total_length, 0.0);
+        assert!(summary.by_band_type.is_empty());
+    }
+
+    #[test]
+    fn test_estimated_saw_time_sums_feed_and_setup_per_cut() {
+        use cutlist_optimizer_cli::engine::cut_list_thread::CutListThread;
+        use cutlist_optimizer_cli::models::SawTimeParams;
+        use cutlist_optimizer_cli::{CutDirection, GrainMode};
+        use std::time::Duration;
+
+        let sheet = Mosaic::from_tile_dimensions(&TileDimensions::new(100, 1000, 800));
+        let mut thread = CutListThread::new();
+        thread.set_first_cut_orientation(CutDirection::Horizontal);
+        let mut results = Vec::new();
+        thread
+            .add_tile_to_mosaic(&TileDimensions::new(1, 400, 300), &sheet, &mut results, GrainMode::Fixed)
+            .unwrap();
+        let mut solution = Solution::new();
+        solution.add_mosaic(results.into_iter().find(|mosaic| mosaic.nbr_cuts() == 2).unwrap());
+
+        // A full-height cut of 800 at x = 400, then a 400 long cut across the strip
+        assert_eq!(solution.get_total_cut_length(), 1200);
+        let params = SawTimeParams::new(100.0, Duration::from_secs(2));
+        // 1200 / 100 s of feed plus 2 s of setup for each of the two cuts
+        assert_eq!(solution.estimated_saw_time(params), Duration::from_secs(16));
+        assert_eq!(Solution::new().estimated_saw_time(params), Duration::ZERO);
+    }
 }
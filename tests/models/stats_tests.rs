@@ -23,6 +23,8 @@ fn test_stats_calculations() {
         nbr_running_threads: 5,
         nbr_queued_threads: 3,
         nbr_finished_threads: 100,
+        avg_queue_wait: 0.0,
+        avg_compute_time: 0.0,
         task_reports: vec![],
     };
 
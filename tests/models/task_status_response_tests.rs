@@ -328,5 +328,14 @@ fn create_mock_calculation_response() -> CalculationResponse {
         used_stock_panels: None,
         no_fit_panels: vec![],
         mosaics: vec![],
+        stock_recommendations: Vec::new(),
+        sheet_yields: Vec::new(),
+        time_bounded: false,
+        grid_packed: false,
+        reusable_offcuts: Vec::new(),
+        offcut_histogram: Vec::new(),
+        min_sheets_lower_bound: Default::default(),
+        first_acceptable: None,
+        meta: Default::default(),
     }
 }
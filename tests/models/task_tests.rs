@@ -70,6 +70,29 @@ fn test_status_transitions_terminate() {
     ));
 }
 
+#[test]
+fn test_cancellation_token_is_shared_by_clones() {
+    let task = Task::new("cancel-test".to_string());
+    let worker_copy = task.clone();
+    let token = task.cancellation_token();
+    assert!(!task.is_cancelled());
+
+    task.set_running_status().unwrap();
+    task.stop().unwrap();
+
+    assert!(task.is_cancelled());
+    assert!(worker_copy.is_cancelled());
+    assert!(token.load(std::sync::atomic::Ordering::Acquire));
+}
+
+#[test]
+fn test_failed_stop_does_not_cancel() {
+    let task = Task::new("queued-cancel-test".to_string());
+
+    assert!(task.stop().is_err());
+    assert!(!task.is_cancelled());
+}
+
 #[test]
 fn test_error_status() {
     let task = Task::new("error-test".to_string());
@@ -244,6 +267,12 @@ fn test_getters_and_setters() {
             material: "wood".to_string(),
             orientation: Orientation::Vertical,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         },
         TileDimensions {
             id: 2,
@@ -253,6 +282,12 @@ fn test_getters_and_setters() {
             material: "wood".to_string(),
             orientation: Orientation::Vertical,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         },
     ];
     task.set_no_material_tiles(tiles.clone());
@@ -360,6 +395,54 @@ fn test_solution_building() {
     // Note: has_solution_all_fit() will be false because panels is empty
 }
 
+#[test]
+fn test_best_solution_balances_sheets_when_configured() {
+    use cutlist_optimizer_cli::models::{Configuration, Mosaic, Solution, TileNode};
+    use std::collections::HashMap;
+
+    let tile = |id: i32, width: i32, height: i32| TileDimensions {
+        material: "wood".to_string(),
+        ..TileDimensions::new(id, width, height)
+    };
+    // A 1000x1000 sheet with a single panel at the origin
+    let sheet_with = |panel: &TileDimensions| {
+        let mut mosaic = Mosaic::from_tile_dimensions(&tile(100, 1000, 1000));
+        let mut placed = TileNode::new(0, panel.width, 0, panel.height);
+        placed.set_final(true);
+        placed.set_external_id(Some(panel.id));
+        let mut strip = TileNode::new(0, 1000, 0, panel.height);
+        strip.set_child1(Some(placed));
+        strip.set_child2(Some(TileNode::new(panel.width, 1000, 0, panel.height)));
+        let root = mosaic.root_tile_node_mut();
+        root.set_child1(Some(strip));
+        root.set_child2(Some(TileNode::new(0, 1000, panel.height, 1000)));
+        mosaic
+    };
+    let tiles = vec![tile(1, 1000, 600), tile(2, 200, 200)];
+    let task_with = |balance_sheets: bool| {
+        let mut task = Task::new("balance-test".to_string());
+        task.set_calculation_request(CalculationRequest {
+            configuration: Some(Configuration { balance_sheets, ..Configuration::default() }),
+            panels: vec![],
+            stock_panels: vec![],
+        });
+        task.set_tile_dimensions_per_material(HashMap::from([("wood".to_string(), tiles.clone())]));
+        let mut solution = Solution::default();
+        solution.add_mosaic(sheet_with(&tiles[0]));
+        solution.add_mosaic(sheet_with(&tiles[1]));
+        task.add_solution("wood", solution);
+        task
+    };
+
+    let kept = task_with(false).best_solution().unwrap().unwrap();
+    assert_eq!(kept.get_nbr_mosaics(), 2);
+
+    let balanced = task_with(true).best_solution().unwrap().unwrap();
+    assert_eq!(balanced.get_nbr_mosaics(), 1);
+    assert_eq!(balanced.get_nbr_final_tiles(), kept.get_nbr_final_tiles());
+    assert_eq!(balanced.get_unused_stock_panels().len(), 1);
+}
+
 #[test]
 fn test_thread_counting_placeholders() {
     let task = Task::new("thread-count-test".to_string());
@@ -1,4 +1,4 @@
-use cutlist_optimizer_cli::{models::tile_dimensions::TileDimensions, Orientation};
+use cutlist_optimizer_cli::{constants::EngineConstants, models::tile_dimensions::TileDimensions, Orientation};
 
 #[test]
 fn test_tile_creation() {
@@ -8,6 +8,26 @@ fn test_tile_creation() {
     assert!(!tile.is_horizontal());
 }
 
+#[test]
+fn test_try_new_accepts_positive_dimensions() {
+    let tile = TileDimensions::try_new(1, 100, 200).unwrap();
+    assert_eq!(tile, TileDimensions::new(1, 100, 200));
+    assert!(TileDimensions::try_new(2, 1, EngineConstants::MAX_DIMENSION).is_ok());
+}
+
+#[test]
+fn test_try_new_rejects_non_positive_and_oversized_dimensions() {
+    let error = TileDimensions::try_new(1, 0, 100).unwrap_err();
+    assert!(error.to_string().contains("width"));
+    let error = TileDimensions::try_new(1, 100, -5).unwrap_err();
+    assert!(error.to_string().contains("height"));
+    assert!(TileDimensions::try_new(1, EngineConstants::MAX_DIMENSION + 1, 100).is_err());
+
+    assert!(!TileDimensions::is_valid_dimension(f64::NAN));
+    assert!(!TileDimensions::is_valid_dimension(f64::INFINITY));
+    assert!(TileDimensions::is_valid_dimension(0.5));
+}
+
 #[test]
 fn test_tile_rotation() {
     let mut tile = TileDimensions::new(1, 100, 200);
@@ -35,6 +55,12 @@ fn test_orientation_constraints() {
         material: "Wood".to_string(),
         orientation: Orientation::Horizontal,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     };
     
     // Tile with horizontal orientation should not be able to rotate
@@ -99,6 +125,12 @@ fn test_orientation_any_allows_rotation() {
         material: "Wood".to_string(),
         orientation: Orientation::Any,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     };
     
     assert!(tile.can_rotate());
@@ -118,6 +150,12 @@ fn test_vertical_orientation_no_rotation() {
         material: "Wood".to_string(),
         orientation: Orientation::Vertical,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     };
     
     assert!(!tile.can_rotate());
@@ -127,3 +165,35 @@ fn test_vertical_orientation_no_rotation() {
     assert_eq!(tile.height, 200);
     assert!(!tile.is_rotated);
 }
+
+#[test]
+fn test_with_banding_allowance_shrinks_cut_size() {
+    let tile = TileDimensions::new(1, 100, 100);
+    let cut = tile.with_banding_allowance(&[1, 1, 1, 1]);
+    assert_eq!((cut.width, cut.height), (98, 98));
+    assert_eq!((tile.width, tile.height), (100, 100));
+
+    let cut = TileDimensions::new(1, 100, 60).with_banding_allowance(&[2, 1, 0, 0]);
+    assert_eq!((cut.width, cut.height), (99, 58));
+}
+
+#[test]
+fn test_area_and_perimeter_of_large_tiles_do_not_overflow() {
+    let tile = TileDimensions::new(1, 50_000, 30_000);
+    assert_eq!(tile.area(), 1_500_000_000);
+    assert_eq!(tile.perimeter(), 160_000);
+
+    let largest = TileDimensions::new(2, i32::MAX, i32::MAX);
+    assert_eq!(largest.area(), i32::MAX as i64 * i32::MAX as i64);
+    assert_eq!(largest.perimeter(), 4 * i32::MAX as i64);
+}
+
+#[test]
+fn test_scale_dimension_rejects_values_beyond_i32() {
+    use cutlist_optimizer_cli::engine::service::computation::dimension_utils::DimensionUtils;
+
+    assert_eq!(DimensionUtils::scale_dimension(50_000.0, 10_000.0).unwrap(), 500_000_000);
+    let error = DimensionUtils::scale_dimension(50_000.0, 100_000.0).unwrap_err();
+    assert!(error.to_string().contains("too large"));
+    assert!(DimensionUtils::scale_dimension(f64::INFINITY, 1.0).is_err());
+}
@@ -25,6 +25,12 @@ fn test_tile_node_from_dimensions() {
         material: "Wood".to_string(),
         orientation: Orientation::Any,
         is_rotated: false,
+        priority: 0,
+        edge: None,
+        edge_trim: 0,
+        scale: 1.0,
+        keepouts: Vec::new(),
+        l_shape: None,
     };
     
     let node = TileNode::from_dimensions(&dimensions);
@@ -279,3 +285,78 @@ fn test_tile_node_default() {
     assert!(!node.is_rotated());
     assert!(!node.has_children());
 }
+
+/// Build a chain where every level splits off one final strip and continues
+/// in `child2`, giving a tree that is `depth` levels deep
+fn build_deep_chain(depth: i32) -> TileNode {
+    let mut node = TileNode::new(0, 10, depth, depth + 1);
+    node.set_final(true);
+
+    for level in (0..depth).rev() {
+        let mut strip = TileNode::new(0, 10, level, level + 1);
+        strip.set_final(true);
+
+        let mut parent = TileNode::new(0, 10, level, depth + 1);
+        parent.set_child1(Some(strip));
+        parent.set_child2(Some(node));
+        node = parent;
+    }
+
+    node
+}
+
+#[test]
+fn test_tile_node_deep_tree_walkers_do_not_overflow() {
+    const DEPTH: i32 = 10_000;
+    let root = build_deep_chain(DEPTH);
+
+    assert_eq!(root.depth(), DEPTH as usize);
+    assert_eq!(root.final_tile_nodes().len(), DEPTH as usize + 1);
+    assert_eq!(root.count_final_tiles(), DEPTH as usize + 1);
+    assert_eq!(root.count_unused_tiles(), 0);
+    assert_eq!(root.iter().count(), 2 * DEPTH as usize + 1);
+
+    let deepest = root.iter().last().unwrap();
+    assert_eq!(deepest.y1(), DEPTH);
+    assert_eq!(root.find_tile_by_id(deepest.id()).map(|n| n.y1()), Some(DEPTH));
+    assert!(root.find_tile_by_id(u32::MAX).is_none());
+
+    let copy = root.clone();
+    assert_eq!(copy, root);
+}
+
+#[test]
+fn test_tile_node_deep_tree_serde_round_trip() {
+    let root = build_deep_chain(10_000);
+
+    let json = serde_json::to_string(&root).unwrap();
+    let restored: TileNode = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored, root);
+    assert_eq!(restored.final_tile_nodes().len(), 10_001);
+}
+
+#[test]
+fn test_tile_node_deserialize_rejects_invalid_child_index() {
+    let mut node = TileNode::new(0, 10, 0, 10);
+    node.set_child1(Some(TileNode::new(0, 5, 0, 10)));
+    let json = serde_json::to_string(&node).unwrap();
+
+    // Point the root's child back at itself
+    let broken = json.replacen("\"child1\":1", "\"child1\":0", 1);
+    assert!(serde_json::from_str::<TileNode>(&broken).is_err());
+}
+
+#[test]
+fn test_tile_node_distinct_tile_set_with_large_tiles() {
+    let mut root = TileNode::new(0, 100_000, 0, 60_000);
+    let mut left = TileNode::new(0, 50_000, 0, 60_000);
+    let mut right = TileNode::new(50_000, 100_000, 0, 60_000);
+    left.set_final(true);
+    right.set_final(true);
+    root.set_child1(Some(left));
+    root.set_child2(Some(right));
+
+    assert_eq!(root.area(), 6_000_000_000);
+    assert_eq!(root.distinct_tile_set().len(), 1);
+}
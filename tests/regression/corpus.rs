@@ -0,0 +1,247 @@
+//! Regression corpus loader and baseline store
+//!
+//! Each file in `tests/regression/corpus/` is a representative job: a
+//! `CalculationRequest` plus the kerf and trim it is cut with. Cases are
+//! measured through one optimizer service shared by the whole run, the path
+//! submitted tasks take, on a reduced search budget.
+//! Recorded results live in `tests/regression/baselines.json`, keyed by case
+//! name (the file stem). Set `CUTLIST_UPDATE_BASELINES=1` to re-record them.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use cutlist_optimizer_cli::engine::service::{CutListOptimizerService, CutListOptimizerServiceImpl};
+use cutlist_optimizer_cli::models::{CalculationRequest, CalculationResponse, CutThickness};
+use cutlist_optimizer_cli::Status;
+use serde::{Deserialize, Serialize};
+
+/// Accuracy used for every corpus run so results are comparable over time
+pub const CORPUS_ACCURACY: i32 = 4;
+
+/// Tile orders tried per case, enough to tell a regression from noise
+pub const CORPUS_MAX_PERMUTATIONS: usize = 8;
+
+/// Allowed efficiency drop (absolute, 0.0-1.0) before a case counts as regressed
+pub const EFFICIENCY_TOLERANCE: f32 = 0.005;
+
+/// Environment variable that switches the harness into re-recording mode
+pub const UPDATE_BASELINES_ENV: &str = "CUTLIST_UPDATE_BASELINES";
+
+/// A single job in the corpus
+#[derive(Debug, Deserialize)]
+pub struct CorpusCase {
+    #[serde(skip)]
+    pub name: String,
+    #[serde(default)]
+    pub cut_thickness: i32,
+    #[serde(default)]
+    pub min_trim: i32,
+    pub request: CalculationRequest,
+}
+
+/// Quality figures recorded for one case
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Used area divided by the area of the sheets opened
+    pub efficiency: f32,
+    /// Number of panels placed on sheets
+    pub placed_panels: i32,
+    /// Number of sheets opened
+    pub sheets: usize,
+}
+
+/// Outcome of comparing a run against its baseline
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Improved,
+    Unchanged,
+    Regressed(String),
+    MissingBaseline,
+}
+
+/// Result of running one corpus case
+#[derive(Debug)]
+pub struct CaseResult {
+    pub name: String,
+    pub baseline: Option<Baseline>,
+    pub actual: Baseline,
+    pub verdict: Verdict,
+}
+
+pub fn corpus_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/regression/corpus")
+}
+
+pub fn baselines_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/regression/baselines.json")
+}
+
+/// Load every `*.json` case in the corpus directory, sorted by name
+pub fn load_corpus(dir: &Path) -> Vec<CorpusCase> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("cannot read corpus dir {}: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let text = std::fs::read_to_string(&path).unwrap();
+            let mut case: CorpusCase = serde_json::from_str(&text)
+                .unwrap_or_else(|e| panic!("invalid corpus case {}: {}", path.display(), e));
+            case.name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            case
+        })
+        .collect()
+}
+
+/// Load the baseline store; a missing file is an empty store
+pub fn load_baselines(path: &Path) -> BTreeMap<String, Baseline> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text)
+            .unwrap_or_else(|e| panic!("invalid baselines file {}: {}", path.display(), e)),
+        Err(_) => BTreeMap::new(),
+    }
+}
+
+pub fn save_baselines(path: &Path, baselines: &BTreeMap<String, Baseline>) {
+    let text = serde_json::to_string_pretty(baselines).unwrap();
+    std::fs::write(path, text + "\n").unwrap();
+}
+
+/// Runtime and optimizer service every case of a run is submitted to
+///
+/// Starting the service once for the whole corpus keeps a run's time down to
+/// the cases themselves.
+pub struct CorpusService {
+    runtime: tokio::runtime::Runtime,
+    service: CutListOptimizerServiceImpl,
+}
+
+impl CorpusService {
+    pub fn start() -> Self {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .unwrap();
+        let mut service = CutListOptimizerServiceImpl::new();
+        runtime
+            .block_on(service.init(4))
+            .unwrap_or_else(|e| panic!("cannot start the optimizer service: {}", e));
+        Self { runtime, service }
+    }
+
+    /// Run the optimizer on a case and measure the result
+    pub fn measure(&self, case: &CorpusCase) -> Baseline {
+        let response = self
+            .runtime
+            .block_on(self.optimize(case))
+            .unwrap_or_else(|e| panic!("case {} failed to optimize: {}", case.name, e));
+
+        Baseline {
+            efficiency: response.total_used_area_ratio as f32,
+            placed_panels: response.panels.as_ref().map_or(0, Vec::len) as i32,
+            sheets: response.mosaics.len(),
+        }
+    }
+
+    /// Submit a case to the optimizer service and wait for its solution
+    async fn optimize(&self, case: &CorpusCase) -> Result<CalculationResponse, String> {
+        let mut request = case.request.clone();
+        let mut configuration = request.configuration.take().unwrap_or_default();
+        configuration.cut_thickness = CutThickness::from(case.cut_thickness);
+        configuration.min_trim_dimension = case.min_trim;
+        configuration.optimization_factor = CORPUS_ACCURACY;
+        configuration.max_permutations = Some(CORPUS_MAX_PERMUTATIONS);
+        request.configuration = Some(configuration);
+
+        let submitted = self.service.submit_task(request).await.map_err(|e| e.to_string())?;
+        let task_id = submitted.task_id.ok_or_else(|| format!("request rejected: {:?}", submitted.status_code))?;
+        loop {
+            let status = self
+                .service
+                .get_task_status(&task_id)
+                .await
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("task {} disappeared", task_id))?;
+            match status.status {
+                Status::Finished => return status.solution.ok_or_else(|| "finished without a solution".to_string()),
+                Status::Error | Status::Terminated => return Err(format!("task ended with status {:?}", status.status)),
+                _ => tokio::time::sleep(std::time::Duration::from_millis(20)).await,
+            }
+        }
+    }
+}
+
+/// Compare a run against its baseline
+///
+/// Placing fewer panels is always a regression; otherwise efficiency may drop
+/// by at most `tolerance`.
+pub fn compare(baseline: Option<&Baseline>, actual: &Baseline, tolerance: f32) -> Verdict {
+    let Some(baseline) = baseline else {
+        return Verdict::MissingBaseline;
+    };
+
+    if actual.placed_panels < baseline.placed_panels {
+        return Verdict::Regressed(format!(
+            "placed {} panels, baseline {}",
+            actual.placed_panels, baseline.placed_panels
+        ));
+    }
+    if actual.efficiency < baseline.efficiency - tolerance {
+        return Verdict::Regressed(format!(
+            "efficiency {:.2}%, baseline {:.2}%",
+            actual.efficiency * 100.0,
+            baseline.efficiency * 100.0
+        ));
+    }
+
+    if actual.placed_panels > baseline.placed_panels || actual.efficiency > baseline.efficiency + tolerance {
+        Verdict::Improved
+    } else {
+        Verdict::Unchanged
+    }
+}
+
+/// Run every case and compare it against the store
+pub fn run_corpus(cases: &[CorpusCase], baselines: &BTreeMap<String, Baseline>, tolerance: f32) -> Vec<CaseResult> {
+    let service = CorpusService::start();
+    cases
+        .iter()
+        .map(|case| {
+            let baseline = baselines.get(&case.name).copied();
+            let actual = service.measure(case);
+            let verdict = compare(baseline.as_ref(), &actual, tolerance);
+            CaseResult { name: case.name.clone(), baseline, actual, verdict }
+        })
+        .collect()
+}
+
+impl fmt::Display for CaseResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match &self.verdict {
+            Verdict::Improved => "IMPROVED".to_string(),
+            Verdict::Unchanged => "ok".to_string(),
+            Verdict::Regressed(reason) => format!("REGRESSED ({})", reason),
+            Verdict::MissingBaseline => "NO BASELINE".to_string(),
+        };
+        let baseline = self
+            .baseline
+            .map(|b| format!("{:.2}%", b.efficiency * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        write!(
+            f,
+            "{:<28} baseline {:>8}  actual {:>7.2}%  panels {:>3}  sheets {:>2}  {}",
+            self.name,
+            baseline,
+            self.actual.efficiency * 100.0,
+            self.actual.placed_panels,
+            self.actual.sheets,
+            status
+        )
+    }
+}
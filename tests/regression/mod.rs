@@ -0,0 +1,4 @@
+//! Solution-quality regression tests
+
+pub mod corpus;
+pub mod regression_tests;
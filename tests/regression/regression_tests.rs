@@ -0,0 +1,75 @@
+//! Solution-quality regression tests
+//!
+//! Runs every corpus case through the optimizer and fails if any case places
+//! fewer panels or loses more than the tolerated efficiency against its
+//! recorded baseline.
+
+use super::corpus::{
+    baselines_path, compare, corpus_dir, load_baselines, load_corpus, run_corpus, save_baselines,
+    Baseline, Verdict, EFFICIENCY_TOLERANCE, UPDATE_BASELINES_ENV,
+};
+
+fn baseline(efficiency: f32, placed_panels: i32) -> Baseline {
+    Baseline { efficiency, placed_panels, sheets: 1 }
+}
+
+#[test]
+fn test_corpus_quality_against_baselines() {
+    let cases = load_corpus(&corpus_dir());
+    assert!(!cases.is_empty(), "regression corpus is empty");
+
+    let path = baselines_path();
+    let mut baselines = load_baselines(&path);
+    let results = run_corpus(&cases, &baselines, EFFICIENCY_TOLERANCE);
+
+    println!("Solution quality vs baseline (tolerance {:.1}%):", EFFICIENCY_TOLERANCE * 100.0);
+    for result in &results {
+        println!("  {}", result);
+    }
+
+    if std::env::var_os(UPDATE_BASELINES_ENV).is_some() {
+        for result in &results {
+            baselines.insert(result.name.clone(), result.actual);
+        }
+        save_baselines(&path, &baselines);
+        println!("Baselines written to {}", path.display());
+        return;
+    }
+
+    let failures: Vec<String> = results
+        .iter()
+        .filter(|result| matches!(result.verdict, Verdict::Regressed(_) | Verdict::MissingBaseline))
+        .map(|result| result.to_string())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} corpus case(s) regressed or lack a baseline (set {}=1 to re-record):\n{}",
+        failures.len(),
+        UPDATE_BASELINES_ENV,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_compare_within_tolerance_is_unchanged() {
+    let verdict = compare(Some(&baseline(0.80, 10)), &baseline(0.797, 10), 0.005);
+    assert_eq!(verdict, Verdict::Unchanged);
+}
+
+#[test]
+fn test_compare_efficiency_drop_is_regression() {
+    let verdict = compare(Some(&baseline(0.80, 10)), &baseline(0.75, 10), 0.005);
+    assert!(matches!(verdict, Verdict::Regressed(reason) if reason.contains("efficiency")));
+}
+
+#[test]
+fn test_compare_fewer_panels_is_regression_even_if_more_efficient() {
+    let verdict = compare(Some(&baseline(0.80, 10)), &baseline(0.90, 9), 0.005);
+    assert!(matches!(verdict, Verdict::Regressed(reason) if reason.contains("placed")));
+}
+
+#[test]
+fn test_compare_better_result_is_improvement() {
+    assert_eq!(compare(Some(&baseline(0.80, 10)), &baseline(0.85, 10), 0.005), Verdict::Improved);
+    assert_eq!(compare(None, &baseline(0.85, 10), 0.005), Verdict::MissingBaseline);
+}
@@ -0,0 +1,47 @@
+use cutlist_optimizer_cli::models::TileDimensions;
+use cutlist_optimizer_cli::stock::{min_sheets_lower_bound, min_sheets_lower_bounds};
+
+fn tile(id: i32, width: i32, height: i32, material: &str) -> TileDimensions {
+    let mut tile = TileDimensions::new(id, width, height);
+    tile.material = material.to_string();
+    tile
+}
+
+#[test]
+fn test_three_tiles_fit_one_sheet_by_area() {
+    let tiles = vec![tile(1, 400, 400, "wood"), tile(2, 400, 400, "wood"), tile(3, 400, 400, "wood")];
+    let sheet = tile(100, 800, 800, "wood");
+
+    assert_eq!(min_sheets_lower_bound(&tiles, &sheet), 1);
+}
+
+#[test]
+fn test_lower_bound_rounds_up_and_ignores_other_materials() {
+    let mut tiles: Vec<TileDimensions> = (1..=5).map(|id| tile(id, 400, 400, "wood")).collect();
+    tiles.push(tile(6, 800, 800, "metal"));
+    let sheet = tile(100, 800, 800, "wood");
+
+    assert_eq!(min_sheets_lower_bound(&tiles, &sheet), 2);
+    assert_eq!(min_sheets_lower_bound(&[], &sheet), 0);
+    assert_eq!(min_sheets_lower_bound(&tiles, &tile(101, 0, 800, "wood")), 0);
+}
+
+#[test]
+fn test_lower_bounds_per_material_use_largest_sheet() {
+    let tiles = vec![
+        tile(1, 600, 600, "wood"),
+        tile(2, 600, 600, "wood"),
+        tile(3, 500, 500, "metal"),
+    ];
+    let stock = vec![
+        tile(100, 600, 600, "wood"),
+        tile(101, 1200, 600, "wood"),
+        tile(200, 400, 400, "metal"),
+        tile(300, 1000, 1000, "glass"),
+    ];
+
+    let bounds = min_sheets_lower_bounds(&tiles, &stock);
+    assert_eq!(bounds.len(), 2);
+    assert_eq!(bounds["wood"], 1);
+    assert_eq!(bounds["metal"], 2);
+}
@@ -2,3 +2,5 @@ pub mod stock_solution_tests;
 pub mod stock_solution_generator_tests;
 pub mod stock_panel_picker_tests;
 pub mod stock_panel_picker_compatibility_tests;
+pub mod stock_provider_tests;
+pub mod lower_bound_tests;
@@ -0,0 +1,107 @@
+//! Tests for plugging a custom stock solution provider into the optimizer
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cutlist_optimizer_cli::engine::service::{
+    CutListOptimizerService, CutListOptimizerServiceImpl, GeneratedStockSolutionProvider, StockSolutionProvider,
+};
+use cutlist_optimizer_cli::errors::Result;
+use cutlist_optimizer_cli::models::{enums::Status, CalculationRequest, Panel, Task, TileDimensions};
+use cutlist_optimizer_cli::stock::{StockPanelPicker, StockSolution, StockSolutionSource};
+
+/// Offers a single stock solution: the first stock tile with the given id
+#[derive(Debug)]
+struct SingleStockProvider {
+    stock_id: i32,
+}
+
+impl StockSolutionProvider for SingleStockProvider {
+    fn stock_solutions(
+        &self,
+        _tiles_to_fit: &[TileDimensions],
+        stock_tiles: &[TileDimensions],
+        _task: &Task,
+        _max_stock_solution_length_hint: Option<usize>,
+    ) -> Result<StockSolutionSource> {
+        let stock = stock_tiles.iter().filter(|tile| tile.id == self.stock_id).take(1).cloned().collect();
+        Ok(StockSolutionSource::new(std::iter::once(StockSolution::from_tiles(stock))))
+    }
+}
+
+fn tiles_to_fit() -> Vec<TileDimensions> {
+    vec![TileDimensions::new(1, 300, 200), TileDimensions::new(2, 300, 200)]
+}
+
+fn stock_tiles() -> Vec<TileDimensions> {
+    vec![
+        TileDimensions::new(10, 1000, 1000),
+        TileDimensions::new(11, 800, 600),
+        TileDimensions::new(12, 600, 400),
+    ]
+}
+
+/// Build a picker the same way the material computation does
+fn picker_for(provider: &dyn StockSolutionProvider) -> StockPanelPicker {
+    let task = Arc::new(Task::new("provider".to_string()));
+    let source = provider.stock_solutions(&tiles_to_fit(), &stock_tiles(), &task, None).unwrap();
+    StockPanelPicker::new(tiles_to_fit(), stock_tiles(), task, None)
+        .unwrap()
+        .with_solution_source(source)
+}
+
+fn panel(id: i32, width: &str, height: &str, count: i32) -> Panel {
+    Panel::new()
+        .with_id(id)
+        .with_width(width.to_string())
+        .with_height(height.to_string())
+        .with_count(count)
+        .with_material("wood".to_string())
+        .with_enabled(true)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_custom_provider_supplies_only_its_stock() {
+    let mut service = CutListOptimizerServiceImpl::new()
+        .with_stock_solution_provider(Box::new(SingleStockProvider { stock_id: 11 }));
+    service.init(2).await.unwrap();
+
+    // The generator would pick the smallest sheet that holds both panels
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "300", "200", 2)],
+        stock_panels: vec![panel(10, "1000", "1000", 1), panel(11, "800", "600", 1), panel(12, "600", "400", 1)],
+    };
+    let task_id = service.submit_task(request).await.unwrap().task_id.unwrap();
+
+    let mut response = None;
+    for _ in 0..600 {
+        let status = service.get_task_status(&task_id).await.unwrap().unwrap();
+        if status.status == Status::Finished {
+            response = status.solution;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let response = response.expect("task finishes with a solution");
+
+    assert_eq!(response.panels.map(|panels| panels.len()), Some(2));
+    let stock_ids: Vec<i32> = response.mosaics.iter().map(|mosaic| mosaic.stock_id).collect();
+    assert_eq!(stock_ids, vec![11]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_default_provider_uses_generator() {
+    let service = CutListOptimizerServiceImpl::new();
+    let picker = picker_for(service.stock_solution_provider().as_ref());
+    picker.init().await.unwrap();
+
+    let first = picker.get_stock_solution(0).unwrap().expect("generated solution");
+    assert!(!first.get_stock_tile_dimensions().is_empty());
+
+    // The default provider is the built-in generator
+    let generated = picker_for(&GeneratedStockSolutionProvider);
+    generated.init().await.unwrap();
+    let expected = generated.get_stock_solution(0).unwrap().expect("generated solution");
+    assert_eq!(first.get_total_area(), expected.get_total_area());
+}
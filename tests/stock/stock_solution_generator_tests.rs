@@ -1,4 +1,7 @@
-use cutlist_optimizer_cli::models::TileDimensions;
+use std::collections::{HashMap, HashSet};
+
+use cutlist_optimizer_cli::constants::StockConstants;
+use cutlist_optimizer_cli::models::{Panel, TileDimensions};
 use cutlist_optimizer_cli::stock::{StockSolution, StockSolutionGenerator};
 use cutlist_optimizer_cli::models::enums::StockSolutionResult;
 use cutlist_optimizer_cli::errors::{AppError, StockError};
@@ -232,3 +235,375 @@ fn test_with_length_hint() {
         }
     }
 }
+
+#[test]
+fn test_lower_preference_weight_stock_chosen_first() {
+    let tiles_to_fit = vec![TileDimensions::new(1, 50, 30)];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 100, 80), // premium sheet
+        TileDimensions::new(11, 100, 80), // old inventory
+    ];
+    let weights = HashMap::from([(10, 5.0), (11, 1.0)]);
+
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, Some(1))
+        .unwrap()
+        .with_preference_weights(weights);
+
+    match generator.generate_stock_solution() {
+        StockSolutionResult::Solution(solution) => {
+            assert_eq!(solution.len(), 1);
+            assert_eq!(solution.get_stock_tile_dimensions()[0].id, 11);
+            assert_eq!(solution.get_preference_weight(), 1.0);
+        }
+        other => panic!("Expected a solution, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_all_panel_solution_orders_equal_sizes_by_preference_weight() {
+    let tiles_to_fit = vec![TileDimensions::new(1, 50, 30)];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 100, 80),
+        TileDimensions::new(11, 100, 80),
+    ];
+    let weights = HashMap::from([(10, 2.0), (11, 0.5)]);
+
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, None)
+        .unwrap()
+        .with_preference_weights(weights);
+
+    match generator.generate_stock_solution() {
+        StockSolutionResult::Solution(solution) => {
+            let ids: Vec<i32> = solution.iter().map(|tile| tile.id).collect();
+            assert_eq!(ids, vec![11, 10]);
+            assert_eq!(solution.get_preference_weight(), 2.5);
+        }
+        other => panic!("Expected a solution, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_default_preference_weights_keep_area_order() {
+    let tiles_to_fit = vec![TileDimensions::new(1, 50, 30)];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 200, 100),
+        TileDimensions::new(11, 100, 80),
+    ];
+
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, Some(1)).unwrap();
+
+    match generator.generate_stock_solution() {
+        StockSolutionResult::Solution(solution) => {
+            assert_eq!(solution.get_stock_tile_dimensions()[0].id, 11);
+            assert_eq!(solution.get_preference_weight(), StockConstants::DEFAULT_PREFERENCE_WEIGHT);
+        }
+        other => panic!("Expected a solution, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_preference_weights_from_panels() {
+    let panels = vec![
+        Panel::new().with_id(10).with_preference_weight(3.0),
+        Panel::new().with_id(11),
+    ];
+
+    let weights = StockSolutionGenerator::preference_weights_from_panels(&panels);
+    assert_eq!(weights.len(), 1);
+    assert_eq!(weights.get(&10), Some(&3.0));
+}
+
+#[test]
+fn test_consume_first_offcut_chosen_before_full_sheet() {
+    let tiles_to_fit = vec![TileDimensions::new(1, 80, 80)];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 300, 300),  // full sheet
+        TileDimensions::new(11, 1000, 100), // offcut strip, larger by area
+    ];
+
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit.clone(), stock_tiles.clone(), Some(1)).unwrap();
+    match generator.generate_stock_solution() {
+        StockSolutionResult::Solution(solution) => assert_eq!(solution.get_stock_tile_dimensions()[0].id, 10),
+        other => panic!("Expected a solution, got {:?}", other),
+    }
+
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, Some(1))
+        .unwrap()
+        .with_consume_first(HashSet::from([11]));
+    match generator.generate_stock_solution() {
+        StockSolutionResult::Solution(solution) => {
+            assert_eq!(solution.len(), 1);
+            assert_eq!(solution.get_stock_tile_dimensions()[0].id, 11);
+        }
+        other => panic!("Expected a solution, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_consume_first_ids_from_panels() {
+    let panels = vec![
+        Panel::new().with_id(10),
+        Panel::new().with_id(11).with_consume_first(true),
+    ];
+
+    let ids = StockSolutionGenerator::consume_first_ids_from_panels(&panels);
+    assert_eq!(ids, HashSet::from([11]));
+}
+
+#[test]
+fn test_compute_layout_uses_consume_first_offcut() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::CalculationRequest;
+
+    let panel = |id: i32, width: &str, height: &str| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(1)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "80", "80")],
+        stock_panels: vec![panel(10, "300", "300"), panel(11, "1000", "100").with_consume_first(true)],
+    };
+
+    let (solution, _) = compute_layout(&request, 0, 0, 5).unwrap();
+    assert!(solution.no_fit_panels.is_empty());
+    assert_eq!(solution.mosaics.len(), 1);
+    assert_eq!(solution.mosaics[0].stock_id(), 11);
+}
+
+#[test]
+fn test_rotated_stock_is_one_candidate() {
+    let tiles_to_fit = vec![TileDimensions::new(1, 500, 500)];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 1000, 600),
+        TileDimensions::new(11, 600, 1000),
+    ];
+
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, Some(1))
+        .unwrap()
+        .with_consider_rotation(true);
+
+    match generator.generate_stock_solution() {
+        StockSolutionResult::Solution(solution) => assert_eq!(solution.len(), 1),
+        other => panic!("Expected a solution, got {:?}", other),
+    }
+    // The rotated sheet is the same candidate, so nothing else is left to try
+    assert!(!matches!(generator.generate_stock_solution(), StockSolutionResult::Solution(_)));
+}
+
+#[test]
+fn test_rotated_stock_pairs_are_one_candidate() {
+    let tiles_to_fit = vec![TileDimensions::new(1, 900, 550), TileDimensions::new(2, 900, 550)];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 1000, 600),
+        TileDimensions::new(11, 750, 800),
+        TileDimensions::new(12, 600, 1000),
+        TileDimensions::new(13, 1000, 600),
+    ];
+
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, Some(2))
+        .unwrap()
+        .with_consider_rotation(true);
+
+    let mut candidates = Vec::new();
+    while let StockSolutionResult::Solution(solution) = generator.generate_stock_solution() {
+        candidates.push(solution);
+    }
+    // Two 1000x600 sheets, or one of them with the 750x800 sheet
+    assert_eq!(candidates.len(), 2);
+    assert!(candidates.iter().all(|solution| solution.len() == 2));
+}
+
+/// Three panels whose combined area needs two of the available sheets
+fn three_panels_two_sheets() -> (Vec<TileDimensions>, Vec<TileDimensions>) {
+    let tiles_to_fit = vec![
+        TileDimensions::new(1, 100, 80),
+        TileDimensions::new(2, 100, 80),
+        TileDimensions::new(3, 100, 80),
+    ];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 200, 100),
+        TileDimensions::new(11, 200, 100),
+        TileDimensions::new(12, 150, 100),
+    ];
+    (tiles_to_fit, stock_tiles)
+}
+
+#[test]
+fn test_max_sheets_rejects_solutions_needing_more_sheets() {
+    use cutlist_optimizer_cli::StatusCode;
+
+    let (tiles_to_fit, stock_tiles) = three_panels_two_sheets();
+
+    let mut uncapped = StockSolutionGenerator::new(tiles_to_fit.clone(), stock_tiles.clone(), None).unwrap();
+    assert_eq!(uncapped.min_sheets_needed(), 2);
+    assert_eq!(uncapped.max_sheets_status(), StatusCode::Ok);
+    assert!(matches!(uncapped.generate_stock_solution(), StockSolutionResult::Solution(_)));
+
+    let mut capped = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, None)
+        .unwrap()
+        .with_max_sheets(Some(1));
+    assert_eq!(capped.max_sheets_status(), StatusCode::NotEnoughStock);
+    assert!(matches!(capped.generate_stock_solution(), StockSolutionResult::NoSolution));
+}
+
+#[test]
+fn test_max_sheets_caps_every_generated_solution() {
+    let (tiles_to_fit, stock_tiles) = three_panels_two_sheets();
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, None)
+        .unwrap()
+        .with_max_sheets(Some(2));
+
+    let mut generated = 0;
+    while let StockSolutionResult::Solution(solution) = generator.generate_stock_solution() {
+        assert!(solution.len() <= 2, "solution uses {} sheets", solution.len());
+        generated += 1;
+    }
+    assert!(generated > 0);
+}
+
+#[tokio::test]
+async fn test_request_validator_reports_not_enough_stock() {
+    use cutlist_optimizer_cli::engine::service::RequestValidator;
+    use cutlist_optimizer_cli::models::{CalculationRequest, Configuration};
+    use cutlist_optimizer_cli::StatusCode;
+
+    let panel = |id: i32, width: &str, height: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    let mut request = CalculationRequest {
+        configuration: Some(Configuration { max_sheets: Some(1), ..Configuration::default() }),
+        panels: vec![panel(1, "100", "80", 3)],
+        stock_panels: vec![panel(10, "200", "100", 3)],
+    };
+
+    assert_eq!(RequestValidator::validate_request(&request).await, Some(StatusCode::NotEnoughStock));
+
+    request.configuration.as_mut().unwrap().max_sheets = Some(2);
+    assert_eq!(RequestValidator::validate_request(&request).await, None);
+}
+
+#[test]
+fn test_compute_layout_refuses_layout_beyond_max_sheets() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::{CalculationRequest, Configuration};
+
+    let panel = |id: i32, width: &str, height: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    let mut request = CalculationRequest {
+        configuration: Some(Configuration { max_sheets: Some(1), ..Configuration::default() }),
+        panels: vec![panel(1, "100", "80", 3)],
+        stock_panels: vec![panel(10, "200", "100", 3)],
+    };
+
+    let error = compute_layout(&request, 0, 0, 5).unwrap_err();
+    assert!(error.to_string().contains("allowed number of sheets"), "{}", error);
+
+    request.configuration.as_mut().unwrap().max_sheets = Some(2);
+    let (solution, _) = compute_layout(&request, 0, 0, 5).unwrap();
+    assert!(solution.mosaics.len() <= 2);
+    assert!(solution.no_fit_panels.is_empty());
+}
+
+#[test]
+fn test_single_stock_unit_generates_copies_of_one_size() {
+    let tiles_to_fit = vec![TileDimensions::new(1, 500, 350), TileDimensions::new(2, 500, 350)];
+    let stock_tiles = vec![
+        TileDimensions::new(10, 1000, 800),
+        TileDimensions::new(11, 600, 400),
+        TileDimensions::new(12, 600, 400),
+        TileDimensions::new(13, 600, 400),
+    ];
+    let mut generator = StockSolutionGenerator::new(tiles_to_fit, stock_tiles, Some(1))
+        .unwrap()
+        .with_single_stock_unit(true);
+
+    let mut sizes = Vec::new();
+    while let StockSolutionResult::Solution(solution) = generator.generate_stock_solution() {
+        assert!(solution.has_unique_panel_size());
+        let sheet = &solution.get_stock_tile_dimensions()[0];
+        sizes.push((sheet.width, sheet.height, solution.len()));
+    }
+    assert_eq!(sizes, vec![(600, 400, 2), (600, 400, 3), (1000, 800, 1)]);
+}
+
+#[test]
+fn test_compute_layout_with_single_stock_unit_uses_one_sheet_size() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::models::{CalculationRequest, Configuration};
+
+    let panel = |id: i32, width: &str, height: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_material("wood".to_string())
+            .with_enabled(true)
+    };
+    let request = CalculationRequest {
+        configuration: Some(Configuration { use_single_stock_unit: true, ..Configuration::default() }),
+        panels: vec![panel(1, "500", "350", 4), panel(2, "900", "300", 1)],
+        stock_panels: vec![panel(10, "600", "400", 10), panel(11, "1000", "800", 3), panel(12, "2000", "1000", 1)],
+    };
+
+    let (solution, _) = compute_layout(&request, 0, 0, 5).unwrap();
+    assert!(solution.no_fit_panels.is_empty());
+    assert!(solution.mosaics.len() > 1);
+    assert!(solution
+        .mosaics
+        .iter()
+        .all(|mosaic| (mosaic.width(), mosaic.height()) == (1000, 800)));
+}
+
+#[test]
+fn test_stock_count_yields_several_copies_of_one_sheet() {
+    use cutlist_optimizer_cli::engine::layout::compute_layout;
+    use cutlist_optimizer_cli::engine::service::computation::dimension_utils::DimensionUtils;
+    use cutlist_optimizer_cli::models::CalculationRequest;
+
+    let panel = |id: i32, width: &str, height: &str, count: i32| {
+        Panel::new()
+            .with_id(id)
+            .with_width(width.to_string())
+            .with_height(height.to_string())
+            .with_count(count)
+            .with_enabled(true)
+    };
+    // Two 600x600 panels cannot share a 1000x1000 sheet
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![panel(1, "600", "600", 2)],
+        stock_panels: vec![panel(100, "1000", "1000", 3)],
+    };
+
+    let (solution, _) = compute_layout(&request, 3, 0, 5).unwrap();
+    assert!(solution.no_fit_panels.is_empty());
+    assert_eq!(solution.mosaics.len(), 2);
+    assert!(solution.mosaics.iter().all(|mosaic| mosaic.stock_id() == 100));
+
+    // Copies beyond the number of tiles are never needed
+    let huge_stock = vec![panel(100, "1000", "1000", 1_000_000)];
+    let (tiles, stock_tiles, _) = DimensionUtils::convert_panels_to_tiles(&request.panels, &huge_stock, 6).unwrap();
+    assert_eq!(stock_tiles.len(), tiles.len());
+    assert_eq!(DimensionUtils::stock_copies(&request.stock_panels[0], 2), 2);
+    assert_eq!(DimensionUtils::stock_copies(&request.stock_panels[0], 5), 3);
+}
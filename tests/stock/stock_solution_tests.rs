@@ -16,6 +16,12 @@ mod tests {
             material: "TEST".to_string(),
             orientation: Orientation::Any,
             is_rotated: false,
+            priority: 0,
+            edge: None,
+            edge_trim: 0,
+            scale: 1.0,
+            keepouts: Vec::new(),
+            l_shape: None,
         }
     }
 
@@ -197,3 +197,59 @@ fn test_permutations_with_custom_types() {
     assert!(result.contains(&expected1));
     assert!(result.contains(&expected2));
 }
+
+#[test]
+fn test_seeded_shuffle_is_reproducible() {
+    let original: Vec<i32> = (0..20).collect();
+
+    let mut first = original.clone();
+    let mut second = original.clone();
+    seeded_shuffle(&mut first, 7);
+    seeded_shuffle(&mut second, 7);
+    assert_eq!(first, second);
+
+    // Still a permutation of the input
+    let mut sorted = first.clone();
+    sorted.sort();
+    assert_eq!(sorted, original);
+
+    let mut other_seed = original.clone();
+    seeded_shuffle(&mut other_seed, 8);
+    assert_ne!(first, other_seed);
+}
+
+#[test]
+fn test_seeded_shuffle_handles_short_lists() {
+    let mut empty: Vec<i32> = Vec::new();
+    seeded_shuffle(&mut empty, 1);
+    assert!(empty.is_empty());
+
+    let mut single = vec![42];
+    seeded_shuffle(&mut single, 1);
+    assert_eq!(single, vec![42]);
+}
+
+#[test]
+fn test_sample_permutations_caps_large_lists() {
+    let tiles: Vec<i32> = (1..=12).collect();
+    let orders = sample_permutations(tiles.clone(), 50, 7);
+
+    assert_eq!(orders.len(), 50);
+    assert_eq!(orders[0], tiles);
+    let unique: std::collections::HashSet<&Vec<i32>> = orders.iter().collect();
+    assert_eq!(unique.len(), orders.len());
+    for order in &orders {
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, tiles);
+    }
+    assert_eq!(sample_permutations(tiles, 50, 7), orders);
+}
+
+#[test]
+fn test_sample_permutations_returns_all_orders_of_small_lists() {
+    assert_eq!(sample_permutations(vec![1, 2, 3], 50, 7).len(), 6);
+    assert_eq!(sample_permutations(vec![1, 2, 3], 6, 7).len(), 6);
+    assert_eq!(sample_permutations(vec![1, 2, 3, 4], 6, 7).len(), 6);
+    assert!(sample_permutations(vec![1, 2, 3], 0, 7).is_empty());
+}
@@ -0,0 +1,78 @@
+//! Tests for CSV import of panels and stock
+
+use cutlist_optimizer_cli::cli::import::{from_csv, load_request};
+
+fn write_csv(content: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("panels.csv");
+    std::fs::write(&path, content).unwrap();
+    (dir, path)
+}
+
+#[test]
+fn test_from_csv_reads_both_sections() {
+    let (_dir, path) = write_csv(
+        "id,width,height,count,material,label\n\
+         1,400.5,300,2,plywood,\"Door, left\"\n\
+         2,250,120,4,plywood\n\
+         \n\
+         id,width,height,count,material,label\n\
+         100,2800,2070,3,plywood,Sheet\n",
+    );
+
+    let (panels, stock) = from_csv(&path).unwrap();
+
+    assert_eq!(panels.len(), 2);
+    assert_eq!(panels[0].id, 1);
+    assert_eq!(panels[0].width.as_deref(), Some("400.5"));
+    assert_eq!(panels[0].count, 2);
+    assert_eq!(panels[0].label.as_deref(), Some("Door, left"));
+    assert!(panels[0].enabled);
+    assert_eq!(panels[1].label, None);
+
+    assert_eq!(stock.len(), 1);
+    assert_eq!(stock[0].id, 100);
+    assert_eq!(stock[0].height.as_deref(), Some("2070"));
+}
+
+#[test]
+fn test_from_csv_stock_marker_starts_stock_section() {
+    let (_dir, path) = write_csv(
+        "1,400,300,2,plywood,A\n\
+         # stock\n\
+         100,2800,2070,3,plywood,Sheet\n",
+    );
+
+    let (panels, stock) = from_csv(&path).unwrap();
+    assert_eq!(panels.len(), 1);
+    assert_eq!(stock.len(), 1);
+
+    let request = load_request(&path).unwrap();
+    assert!(request.validate_semantic().is_ok());
+}
+
+#[test]
+fn test_from_csv_reports_missing_column_with_line_number() {
+    let (_dir, path) = write_csv(
+        "id,width,height,count,material,label\n\
+         1,400,300,2,plywood,A\n\
+         2,250,120,4\n",
+    );
+
+    let message = from_csv(&path).unwrap_err().to_string();
+    assert!(message.contains("panels.csv:3:"), "{}", message);
+    assert!(message.contains("found 4 column(s)"), "{}", message);
+}
+
+#[test]
+fn test_from_csv_reports_non_numeric_count_with_line_number() {
+    let (_dir, path) = write_csv(
+        "1,400,300,2,plywood,A\n\
+         # stock\n\
+         100,2800,2070,many,plywood,Sheet\n",
+    );
+
+    let message = from_csv(&path).unwrap_err().to_string();
+    assert!(message.contains("panels.csv:3:"), "{}", message);
+    assert!(message.contains("count must be an integer, got 'many'"), "{}", message);
+}
@@ -0,0 +1,119 @@
+//! Tests for PNG cut map rendering
+//!
+//! These tests run a small job through the layout computation and check that
+//! one printable image is produced for every used sheet.
+
+use std::collections::HashMap;
+
+use cutlist_optimizer_cli::{cli::commands::png_command, engine::layout::{compute_layout, OptimizeOptions}};
+use cutlist_optimizer_cli::models::{CalculationRequest, Configuration, Panel};
+use cutlist_optimizer_cli::utils::cut_map::{render_mosaic_png, write_cut_map_pngs};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+fn create_panel(id: i32, width: &str, height: &str, count: i32, label: Option<&str>) -> Panel {
+    Panel {
+        id,
+        width: Some(width.to_string()),
+        height: Some(height.to_string()),
+        count,
+        material: "wood".to_string(),
+        enabled: true,
+        orientation: 0,
+        label: label.map(|l| l.to_string()),
+        edge: None,
+        ..Default::default()
+    }
+}
+
+/// Two panels that each need a whole sheet, so the job uses two sheets
+fn create_two_sheet_request() -> CalculationRequest {
+    CalculationRequest {
+        configuration: Some(Configuration::default()),
+        panels: vec![create_panel(1, "800", "700", 2, Some("Door"))],
+        stock_panels: vec![create_panel(100, "1000", "1000", 3, None)],
+    }
+}
+
+#[test]
+fn test_write_cut_map_pngs_one_file_per_used_sheet() {
+    let request = create_two_sheet_request();
+    let (solution, factor) = compute_layout(&request, 0, 0, 10).unwrap();
+    assert_eq!(solution.get_mosaics().len(), 2);
+
+    let dir = tempfile::tempdir().unwrap();
+    let labels = HashMap::from([(1, "Door".to_string())]);
+    let files = write_cut_map_pngs(solution.get_mosaics(), &labels, factor, None, dir.path()).unwrap();
+
+    assert_eq!(files.len(), 2);
+    for file in &files {
+        assert!(file.exists());
+        let bytes = std::fs::read(file).unwrap();
+        assert!(!bytes.is_empty());
+        assert_eq!(&bytes[..8], &PNG_SIGNATURE);
+    }
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+}
+
+#[test]
+fn test_render_mosaic_png_header_matches_sheet_aspect() {
+    let request = CalculationRequest {
+        configuration: Some(Configuration::default()),
+        panels: vec![create_panel(1, "300", "200", 1, None)],
+        stock_panels: vec![create_panel(100, "1200", "600", 1, None)],
+    };
+    let (solution, factor) = compute_layout(&request, 0, 0, 10).unwrap();
+    let png = render_mosaic_png(&solution.get_mosaics()[0], 1, &HashMap::new(), factor, None);
+
+    // IHDR width and height follow the 8-byte signature and the chunk length/type
+    let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+    assert!(width > height);
+    assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+}
+
+#[tokio::test]
+async fn test_png_command_writes_plans() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("job.json");
+    std::fs::write(&input, serde_json::to_string(&create_two_sheet_request()).unwrap()).unwrap();
+    let plans = dir.path().join("plans");
+
+    let options = OptimizeOptions {
+        cut_thickness: Some(0),
+        min_trim: Some(0),
+        accuracy: Some(10),
+        balance_sheets: false,
+        optimize_cut_order: false,
+        ..OptimizeOptions::default()
+    };
+    png_command(input, plans.clone(), None, options).await.unwrap();
+
+    let count = std::fs::read_dir(&plans).unwrap().count();
+    assert_eq!(count, 2);
+}
+
+#[tokio::test]
+async fn test_png_command_applies_config_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("job.json");
+    let request = CalculationRequest {
+        configuration: None,
+        panels: vec![create_panel(1, "500", "1000", 2, Some("Side"))],
+        stock_panels: vec![create_panel(100, "1000", "1000", 2, None)],
+    };
+    std::fs::write(&input, serde_json::to_string(&request).unwrap()).unwrap();
+    let config = dir.path().join("config.json");
+    let no_kerf = Configuration { cut_removes_material: false, ..Configuration::default() };
+    std::fs::write(&config, serde_json::to_string(&no_kerf).unwrap()).unwrap();
+
+    // With a kerf the two sides need a sheet each; the config's blade removes nothing
+    let options = OptimizeOptions { cut_thickness: Some(10), min_trim: Some(0), ..OptimizeOptions::default() };
+    let plans = dir.path().join("plans");
+    png_command(input.clone(), plans.clone(), None, options.clone()).await.unwrap();
+    assert_eq!(std::fs::read_dir(&plans).unwrap().count(), 2);
+
+    let plans = dir.path().join("plans-with-config");
+    png_command(input, plans.clone(), Some(config), options).await.unwrap();
+    assert_eq!(std::fs::read_dir(&plans).unwrap().count(), 1);
+}
@@ -17,6 +17,7 @@ fn create_test_panel(id: i32, edge: Option<Edge>) -> Panel {
         orientation: 0,
         label: None,
         edge,
+        ..Default::default()
     }
 }
 
@@ -1,3 +1,4 @@
+use cutlist_optimizer_cli::models::TileDimensions;
 use cutlist_optimizer_cli::utils::math::*;
 
 #[test]
@@ -99,3 +100,47 @@ fn test_rectangle_perimeter() {
     assert_eq!(rectangle_perimeter(5.0, 4.0), 18.0);
     assert_eq!(rectangle_perimeter(0.0, 4.0), 8.0);
 }
+
+#[test]
+fn test_scaled_area_to_mm2() {
+    // 1000x600 at scale 100 is a 10mm x 6mm panel
+    assert_eq!(area(1000, 600, 100.0), 60.0);
+    assert_eq!(scaled_to_mm2(600_000, 100.0), 60.0);
+    assert_eq!(area(1000, 600, 1.0), 600_000.0);
+}
+
+#[test]
+fn test_scaled_perimeter() {
+    assert_eq!(perimeter(1000, 600, 100.0), 32.0);
+    assert_eq!(perimeter(1000, 600, 1.0), 3200.0);
+}
+
+#[test]
+fn test_tile_area_and_perimeter() {
+    let tile = TileDimensions::new(1, 1000, 600);
+    assert_eq!(tile_area(&tile, 100.0), 60.0);
+    assert_eq!(tile_perimeter(&tile, 100.0), 32.0);
+}
+
+#[test]
+fn test_area_does_not_overflow() {
+    // 2440x1220 at scale 1000 overflows i32 when multiplied directly
+    let scaled = area(2_440_000, 1_220_000, 1000.0);
+    assert!(approx_equal(scaled, 2440.0 * 1220.0, 1e-6));
+}
+
+#[test]
+fn test_format_dimension_in_meters() {
+    assert_eq!(format_dimension(1000.0, UnitFormat::Meters), "1.00 m");
+}
+
+#[test]
+fn test_format_dimension_in_inches() {
+    assert_eq!(format_dimension(1000.0, UnitFormat::Inches), "39.37 in");
+}
+
+#[test]
+fn test_format_dimension_in_mm_and_cm() {
+    assert_eq!(format_dimension(1000.0, UnitFormat::default()), "1000.00 mm");
+    assert_eq!(format_dimension(1000.0, UnitFormat::Centimeters), "100.00 cm");
+}
@@ -5,3 +5,5 @@ pub mod edge_banding_tests;
 pub mod timing_tests;
 pub mod math_tests;
 pub mod utils_tests;
+pub mod cut_map_tests;
+pub mod csv_import_tests;